@@ -1,3 +1,4 @@
+use crate::native::NativeContext;
 use crate::value::Value;
 use slang_derive::NumericEnum;
 pub use std::io::{Read, Write};
@@ -57,6 +58,31 @@ pub enum OpCode {
     BeginScope,
     /// End the current scope (restore variable state)
     EndScope,
+    /// Call a function in tail position, reusing the current call frame
+    TailCall,
+    /// Create a closure from a function constant and the current values of the captured variables
+    ///
+    /// Each captured variable is encoded as a flag, which is 1 for a local slot and 0 for a
+    /// global variable, followed by the slot or the index of the global's name.
+    Closure,
+    /// Bitwise AND of the top two stack values
+    BitAnd,
+    /// Bitwise OR of the top two stack values
+    BitOr,
+    /// Bitwise XOR of the top two stack values
+    BitXor,
+    /// Bitwise NOT of the top stack value
+    BitNot,
+    /// Shift the second stack value left by the top stack value
+    ShiftLeft,
+    /// Shift the second stack value right by the top stack value
+    ShiftRight,
+    /// Push the value of a local variable slot of the current function onto the stack
+    GetLocal,
+    /// Set a local variable slot of the current function to the top stack value
+    SetLocal,
+    /// Jump backwards unconditionally, to the start of a loop
+    Loop,
 }
 
 /// Function representation in bytecode
@@ -68,20 +94,35 @@ pub struct Function {
     pub arity: u8,
     /// Offset in the chunk where this function's code begins
     pub code_offset: usize,
-    /// Local variable names used by this function
+    /// Names of the local variable slots of this function, indexed by slot
+    ///
+    /// The parameters occupy the first slots, followed by the captured variables of a
+    /// closure and the variables declared in the body. A shadowing declaration gets a slot
+    /// of its own, so a name can appear more than once.
     pub locals: Vec<String>,
 }
 
+/// Closure representation: a function together with the values it captured
+#[derive(Debug, Clone)]
+pub struct Closure {
+    /// The function implementing the closure body
+    pub function: Function,
+    /// Names and values of the captured variables, captured by value on creation
+    pub captures: Vec<(String, Value)>,
+}
+
 /// Type for native function implementations
-pub type NativeFn = fn(&[Value]) -> Result<Value, String>;
+pub type NativeFn = fn(&[Value], &mut NativeContext) -> Result<Value, String>;
 
 /// Native (built-in) function representation
 #[derive(Clone)]
 pub struct NativeFunction {
     /// Name of the native function
     pub name: String,
-    /// Number of parameters
+    /// Number of parameters (the minimum number of arguments for variadic functions)
     pub arity: u8,
+    /// Whether the function accepts additional arguments after its parameters
+    pub is_variadic: bool,
     /// The Rust function that implements this native function
     pub function: NativeFn,
 }
@@ -176,6 +217,35 @@ impl Chunk {
         self.identifiers.len() - 1
     }
 
+    /// Compresses the per-byte line numbers into a table of line runs
+    ///
+    /// Each entry holds the offset of the first instruction byte of a run and the source line
+    /// shared by all bytes up to the next entry.
+    ///
+    /// ### Returns
+    ///
+    /// The (offset, line) pairs in ascending offset order
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_backend::bytecode::{Chunk, OpCode};
+    ///
+    /// let mut chunk = Chunk::new();
+    /// chunk.write_op(OpCode::Add, 1);
+    /// chunk.write_op(OpCode::Pop, 1);
+    /// chunk.write_op(OpCode::Return, 3);
+    /// assert_eq!(chunk.line_table(), vec![(0, 1), (2, 3)]);
+    /// ```
+    pub fn line_table(&self) -> Vec<(usize, usize)> {
+        let mut table: Vec<(usize, usize)> = Vec::new();
+        for (offset, &line) in self.lines.iter().enumerate() {
+            if table.last().is_none_or(|&(_, last_line)| last_line != line) {
+                table.push((offset, line));
+            }
+        }
+        table
+    }
+
     /// Serializes the chunk to binary data
     ///
     /// ### Arguments
@@ -194,63 +264,7 @@ impl Chunk {
         writer.write_all(&constants_len.to_le_bytes())?;
 
         for value in &self.constants {
-            writer.write_all(&[value.type_tag()])?;
-            match value {
-                Value::I32(i) => {
-                    writer.write_all(&i.to_le_bytes())?;
-                }
-                Value::I64(i) => {
-                    writer.write_all(&i.to_le_bytes())?;
-                }
-                Value::U32(i) => {
-                    writer.write_all(&i.to_le_bytes())?;
-                }
-                Value::U64(i) => {
-                    writer.write_all(&i.to_le_bytes())?;
-                }
-                Value::String(s) => {
-                    let bytes = s.as_bytes();
-                    let len = bytes.len() as u32;
-                    writer.write_all(&len.to_le_bytes())?;
-                    writer.write_all(bytes)?;
-                }
-                Value::F32(f) => {
-                    writer.write_all(&f.to_le_bytes())?;
-                }
-                Value::F64(f) => {
-                    writer.write_all(&f.to_le_bytes())?;
-                }
-                Value::Function(func) => {
-                    let name_bytes = func.name.as_bytes();
-                    let name_len = name_bytes.len() as u32;
-                    writer.write_all(&name_len.to_le_bytes())?;
-                    writer.write_all(name_bytes)?;
-
-                    writer.write_all(&[func.arity])?;
-                    writer.write_all(&(func.code_offset as u32).to_le_bytes())?;
-
-                    let locals_len = func.locals.len() as u32;
-                    writer.write_all(&locals_len.to_le_bytes())?;
-                    for local in &func.locals {
-                        let local_bytes = local.as_bytes();
-                        let local_len = local_bytes.len() as u32;
-                        writer.write_all(&local_len.to_le_bytes())?;
-                        writer.write_all(local_bytes)?;
-                    }
-                }
-                Value::NativeFunction(func) => {
-                    let name_bytes = func.name.as_bytes();
-                    let name_len = name_bytes.len() as u32;
-                    writer.write_all(&name_len.to_le_bytes())?;
-                    writer.write_all(name_bytes)?;
-
-                    writer.write_all(&[func.arity])?;
-                }
-                Value::Boolean(b) => {
-                    writer.write_all(&[*b as u8])?;
-                }
-                Value::Unit(_) => {}
-            }
+            Self::serialize_value(value, writer)?;
         }
 
         let identifiers_len = self.identifiers.len() as u32;
@@ -263,6 +277,121 @@ impl Chunk {
             writer.write_all(bytes)?;
         }
 
+        let line_table = self.line_table();
+        let line_table_len = line_table.len() as u32;
+        writer.write_all(&line_table_len.to_le_bytes())?;
+
+        for (offset, line) in line_table {
+            writer.write_all(&(offset as u32).to_le_bytes())?;
+            writer.write_all(&(line as u32).to_le_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    /// Serializes a single value together with its type tag
+    ///
+    /// ### Arguments
+    ///
+    /// * `value` - The value to serialize
+    /// * `writer` - The writer to write the binary data to
+    ///
+    /// ### Returns
+    ///
+    /// IO result indicating success or failure
+    fn serialize_value(value: &Value, writer: &mut dyn Write) -> std::io::Result<()> {
+        writer.write_all(&[value.type_tag()])?;
+        match value {
+            Value::I32(i) => {
+                writer.write_all(&i.to_le_bytes())?;
+            }
+            Value::I64(i) => {
+                writer.write_all(&i.to_le_bytes())?;
+            }
+            Value::U32(i) => {
+                writer.write_all(&i.to_le_bytes())?;
+            }
+            Value::U64(i) => {
+                writer.write_all(&i.to_le_bytes())?;
+            }
+            Value::I128(i) => {
+                writer.write_all(&i.to_le_bytes())?;
+            }
+            Value::U128(i) => {
+                writer.write_all(&i.to_le_bytes())?;
+            }
+            Value::String(s) => {
+                let bytes = s.as_bytes();
+                let len = bytes.len() as u32;
+                writer.write_all(&len.to_le_bytes())?;
+                writer.write_all(bytes)?;
+            }
+            Value::F32(f) => {
+                writer.write_all(&f.to_le_bytes())?;
+            }
+            Value::F64(f) => {
+                writer.write_all(&f.to_le_bytes())?;
+            }
+            Value::Function(func) => {
+                Self::serialize_function(func, writer)?;
+            }
+            Value::Closure(closure) => {
+                Self::serialize_function(&closure.function, writer)?;
+
+                let captures_len = closure.captures.len() as u32;
+                writer.write_all(&captures_len.to_le_bytes())?;
+                for (name, value) in &closure.captures {
+                    let name_bytes = name.as_bytes();
+                    let name_len = name_bytes.len() as u32;
+                    writer.write_all(&name_len.to_le_bytes())?;
+                    writer.write_all(name_bytes)?;
+                    Self::serialize_value(value, writer)?;
+                }
+            }
+            Value::NativeFunction(func) => {
+                let name_bytes = func.name.as_bytes();
+                let name_len = name_bytes.len() as u32;
+                writer.write_all(&name_len.to_le_bytes())?;
+                writer.write_all(name_bytes)?;
+
+                writer.write_all(&[func.arity])?;
+            }
+            Value::Boolean(b) => {
+                writer.write_all(&[*b as u8])?;
+            }
+            Value::Unit(_) => {}
+        }
+        Ok(())
+    }
+
+    /// Serializes the name, arity, code offset and locals of a function
+    ///
+    /// ### Arguments
+    ///
+    /// * `func` - The function to serialize
+    /// * `writer` - The writer to write the binary data to
+    ///
+    /// ### Returns
+    ///
+    /// IO result indicating success or failure
+    fn serialize_function(func: &Function, writer: &mut dyn Write) -> std::io::Result<()> {
+        let name_bytes = func.name.as_bytes();
+        let name_len = name_bytes.len() as u32;
+        writer.write_all(&name_len.to_le_bytes())?;
+        writer.write_all(name_bytes)?;
+
+        writer.write_all(&[func.arity])?;
+        writer.write_all(&(func.code_offset as u32).to_le_bytes())?;
+
+        let locals_len = func.locals.len() as u32;
+        writer.write_all(&locals_len.to_le_bytes())?;
+        for local in &func.locals {
+            let local_bytes = local.as_bytes();
+            let local_len = local_bytes.len() as u32;
+            writer.write_all(&local_len.to_le_bytes())?;
+            writer.write_all(local_bytes)?;
+        }
+
         Ok(())
     }
 
@@ -286,8 +415,6 @@ impl Chunk {
         reader.read_exact(&mut code)?;
         chunk.code = code;
 
-        chunk.lines = vec![0; code_len];
-
         let mut constants_len_bytes = [0u8; 4];
         reader.read_exact(&mut constants_len_bytes)?;
         let constants_len = u32::from_le_bytes(constants_len_bytes) as usize;
@@ -318,6 +445,32 @@ impl Chunk {
             chunk.identifiers.push(string);
         }
 
+        let mut line_table_len_bytes = [0u8; 4];
+        reader.read_exact(&mut line_table_len_bytes)?;
+        let line_table_len = u32::from_le_bytes(line_table_len_bytes) as usize;
+
+        chunk.lines = vec![0; code_len];
+        let mut previous_offset = 0;
+        let mut previous_line = 0;
+        for _ in 0..line_table_len {
+            let mut entry_bytes = [0u8; 4];
+            reader.read_exact(&mut entry_bytes)?;
+            let offset = u32::from_le_bytes(entry_bytes) as usize;
+            reader.read_exact(&mut entry_bytes)?;
+            let line = u32::from_le_bytes(entry_bytes) as usize;
+
+            if offset < previous_offset || offset > code_len {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "Invalid line table",
+                ));
+            }
+            chunk.lines[previous_offset..offset].fill(previous_line);
+            previous_offset = offset;
+            previous_line = line;
+        }
+        chunk.lines[previous_offset..].fill(previous_line);
+
         Ok(chunk)
     }
 
@@ -366,6 +519,11 @@ impl Chunk {
                 println!("{:<16} {:4} args", "CALL", arg_count);
                 offset + 2
             }
+            Some(OpCode::TailCall) => {
+                let arg_count = self.code[offset + 1];
+                println!("{:<16} {:4} args", "TAIL_CALL", arg_count);
+                offset + 2
+            }
             Some(OpCode::JumpIfFalse) => {
                 let jump_offset =
                     ((self.code[offset + 1] as usize) << 8) | (self.code[offset + 2] as usize);
@@ -388,6 +546,17 @@ impl Chunk {
                 );
                 offset + 3
             }
+            Some(OpCode::Loop) => {
+                let jump_offset =
+                    ((self.code[offset + 1] as usize) << 8) | (self.code[offset + 2] as usize);
+                println!(
+                    "{:<16} {:4} -> {}",
+                    "LOOP",
+                    offset,
+                    offset + 3 - jump_offset
+                );
+                offset + 3
+            }
             Some(OpCode::BoolNot) => self.simple_instruction("BOOL_NOT", offset),
             Some(OpCode::BoolAnd) => self.simple_instruction("BOOL_AND", offset),
             Some(OpCode::BoolOr) => self.simple_instruction("BOOL_OR", offset),
@@ -399,6 +568,40 @@ impl Chunk {
             Some(OpCode::NotEqual) => self.simple_instruction("NOT_EQUAL", offset),
             Some(OpCode::BeginScope) => self.simple_instruction("BEGIN_SCOPE", offset),
             Some(OpCode::EndScope) => self.simple_instruction("END_SCOPE", offset),
+            Some(OpCode::BitAnd) => self.simple_instruction("BIT_AND", offset),
+            Some(OpCode::BitOr) => self.simple_instruction("BIT_OR", offset),
+            Some(OpCode::BitXor) => self.simple_instruction("BIT_XOR", offset),
+            Some(OpCode::BitNot) => self.simple_instruction("BIT_NOT", offset),
+            Some(OpCode::ShiftLeft) => self.simple_instruction("SHIFT_LEFT", offset),
+            Some(OpCode::ShiftRight) => self.simple_instruction("SHIFT_RIGHT", offset),
+            Some(OpCode::Closure) => {
+                let constant_index = self.code[offset + 1];
+                let capture_count = self.code[offset + 2] as usize;
+                print!(
+                    "{:<16} {:4} '{}'",
+                    "CLOSURE", constant_index, self.constants[constant_index as usize]
+                );
+                for i in 0..capture_count {
+                    let index = self.code[offset + 4 + 2 * i];
+                    if self.code[offset + 3 + 2 * i] != 0 {
+                        print!(" slot {}", index);
+                    } else {
+                        print!(" '{}'", self.identifiers[index as usize]);
+                    }
+                }
+                println!();
+                offset + 3 + 2 * capture_count
+            }
+            Some(OpCode::GetLocal) => {
+                let slot = self.code[offset + 1];
+                println!("{:<16} {:4}", "GET_LOCAL", slot);
+                offset + 2
+            }
+            Some(OpCode::SetLocal) => {
+                let slot = self.code[offset + 1];
+                println!("{:<16} {:4}", "SET_LOCAL", slot);
+                offset + 2
+            }
             None => {
                 println!("Unknown opcode: {}", instruction);
                 offset + 1
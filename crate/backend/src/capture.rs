@@ -0,0 +1,55 @@
+use slang_ir::Visitor;
+use slang_ir::ast::{AssignmentStatement, BlockExpr, FunctionCallExpr, VariableExpr};
+
+/// Collects the names referenced inside a closure body
+///
+/// The collector doesn't track scopes, so the result may contain names that are declared
+/// inside the body itself. The code generator only captures names that refer to variables
+/// declared outside the closure, and a declaration inside the body shadows the captured
+/// copy, which makes this over-approximation harmless.
+#[derive(Default)]
+pub struct CaptureCollector {
+    /// Referenced names in order of their first appearance
+    names: Vec<String>,
+}
+
+impl CaptureCollector {
+    /// Collects the names referenced in a block expression
+    ///
+    /// ### Arguments
+    ///
+    /// * `body` - The block expression to inspect
+    ///
+    /// ### Returns
+    ///
+    /// The referenced names, without duplicates, in order of their first appearance
+    pub fn collect(body: &BlockExpr) -> Vec<String> {
+        let mut collector = CaptureCollector::default();
+        collector.visit_block_expression(body);
+        collector.names
+    }
+
+    fn add_name(&mut self, name: &str) {
+        if !self.names.iter().any(|existing| existing == name) {
+            self.names.push(name.to_string());
+        }
+    }
+}
+
+impl Visitor<()> for CaptureCollector {
+    fn visit_assignment_statement(&mut self, stmt: &AssignmentStatement) {
+        self.add_name(&stmt.name);
+        self.visit_expression(&stmt.value);
+    }
+
+    fn visit_variable_expression(&mut self, var_expr: &VariableExpr) {
+        self.add_name(&var_expr.name);
+    }
+
+    fn visit_call_expression(&mut self, expr: &FunctionCallExpr) {
+        self.add_name(&expr.name);
+        for argument in &expr.arguments {
+            self.visit_expression(argument);
+        }
+    }
+}
@@ -1,13 +1,19 @@
 use crate::bytecode::{Chunk, Function, OpCode};
+use crate::capture::CaptureCollector;
 use crate::value::Value;
 use slang_error::{CompilerError, CompileResult, ErrorCode};
 use slang_ir::Visitor;
 use slang_ir::ast::{
-    BinaryExpr, BinaryOperator, BlockExpr, ConditionalExpr, Expression, FunctionCallExpr,
-    FunctionDeclarationStmt, FunctionTypeExpr, IfStatement, LetStatement, LiteralExpr, Statement, TypeDefinitionStmt,
-    UnaryExpr, UnaryOperator,
+    BinaryExpr, BinaryOperator, BlockExpr, ClosureExpr, ConditionalExpr, EnumDefinitionStmt,
+    Expression, ForStatement, FunctionCallExpr, FunctionDeclarationStmt, FunctionTypeExpr,
+    IfStatement, ImplStmt, LetStatement, LiteralExpr, MethodCallExpr, Parameter, Statement,
+    TypeDefinitionStmt, UnaryExpr, UnaryOperator,
 };
 use slang_ir::location::Location;
+use std::collections::HashMap;
+
+/// Name of the slot holding the upper bound of a for loop, which no identifier can refer to
+const RANGE_END_SLOT: &str = "<range end>";
 
 /// Compiles AST nodes into bytecode instructions
 pub struct CodeGenerator {
@@ -15,30 +21,125 @@ pub struct CodeGenerator {
     pub chunk: Chunk,
     /// Current line number for debugging information
     line: usize,
+    /// Column of the statement or expression being compiled, used for error locations
+    column: usize,
     /// Global variable names
     variables: Vec<String>,
-    /// Function names for tracking declarations
-    functions: Vec<String>,
-    /// Stack of scopes for tracking local variables
-    local_scopes: Vec<Vec<String>>,
+    /// Arity of the first declaration of each function name, which owns the plain global name
+    functions: HashMap<String, usize>,
+    /// Stack of scopes mapping the local variables declared in them to their slots
+    local_scopes: Vec<Vec<(String, u8)>>,
+    /// Names of the slots allocated so far in the function being compiled
+    ///
+    /// A slot that is reused after its scope ended keeps the name of its latest variable.
+    slot_names: Vec<String>,
+    /// Number of slots in use by the variables of the open scopes of the current function
+    live_slots: usize,
+    /// Index of the first scope in `local_scopes` that belongs to the function being compiled
+    function_scope_start: usize,
+    /// Global name of the function whose body is currently being compiled
+    current_function: Option<String>,
+    /// Discriminants of the enum variants by their qualified name, e.g. `Color::Red`
+    enum_variants: HashMap<String, i32>,
+    /// Index of each constant in the constant pool, so that equal constants share one entry
+    constant_pool: HashMap<ConstantKey, usize>,
     /// Accumulated errors during compilation
     errors: Vec<CompilerError>,
 }
 
+/// Identity of a constant that can be shared by all loads of an equal value
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ConstantKey {
+    I32(i32),
+    I64(i64),
+    U32(u32),
+    U64(u64),
+    I128(i128),
+    U128(u128),
+    /// Floats are compared by their bits, so that `0.0` and `-0.0` stay separate constants
+    F32(u32),
+    F64(u64),
+    String(String),
+    Boolean(bool),
+    Unit,
+}
+
+impl ConstantKey {
+    /// Gets the key of a constant value
+    ///
+    /// ### Arguments
+    ///
+    /// * `value` - The constant value
+    ///
+    /// ### Returns
+    ///
+    /// The key of the value, or None for functions and closures, which are never shared
+    fn of(value: &Value) -> Option<Self> {
+        match value {
+            Value::I32(v) => Some(ConstantKey::I32(*v)),
+            Value::I64(v) => Some(ConstantKey::I64(*v)),
+            Value::U32(v) => Some(ConstantKey::U32(*v)),
+            Value::U64(v) => Some(ConstantKey::U64(*v)),
+            Value::I128(v) => Some(ConstantKey::I128(*v)),
+            Value::U128(v) => Some(ConstantKey::U128(*v)),
+            Value::F32(v) => Some(ConstantKey::F32(v.to_bits())),
+            Value::F64(v) => Some(ConstantKey::F64(v.to_bits())),
+            Value::String(v) => Some(ConstantKey::String(v.to_string())),
+            Value::Boolean(v) => Some(ConstantKey::Boolean(*v)),
+            Value::Unit(_) => Some(ConstantKey::Unit),
+            Value::Function(_) | Value::NativeFunction(_) | Value::Closure(_) => None,
+        }
+    }
+}
+
+/// Gets the runtime value of an integer literal without a type suffix
+///
+/// Literals that only fit a wider type than i64 were range checked against that type
+///
+/// ### Arguments
+///
+/// * `value` - The value of the literal
+///
+/// ### Returns
+///
+/// The value as an i64 if it fits, otherwise as a u64 or an i128
+fn unspecified_integer_value(value: i128) -> Value {
+    if let Ok(value) = i64::try_from(value) {
+        Value::I64(value)
+    } else if let Ok(value) = u64::try_from(value) {
+        Value::U64(value)
+    } else {
+        Value::I128(value)
+    }
+}
+
 pub fn generate_bytecode(statements: &[Statement]) -> CompileResult<Chunk> {
     let compiler = CodeGenerator::new();
     compiler.compile(statements)
 }
 
+impl Default for CodeGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CodeGenerator {
     /// Creates a new compiler with an empty chunk
     pub fn new() -> Self {
         CodeGenerator {
             chunk: Chunk::new(),
             line: 1,
+            column: 1,
             variables: Vec::new(),
-            functions: Vec::new(),
+            functions: HashMap::new(),
             local_scopes: Vec::new(),
+            slot_names: Vec::new(),
+            live_slots: 0,
+            function_scope_start: 0,
+            current_function: None,
+            enum_variants: HashMap::new(),
+            constant_pool: HashMap::new(),
             errors: Vec::new(),
         }
     }
@@ -46,6 +147,7 @@ impl CodeGenerator {
     /// Updates the current line from a source location
     fn set_current_location(&mut self, location: &Location) {
         self.line = location.line;
+        self.column = location.column;
     }
 
     /// Creates a CompilerError with the current location and adds it to the error list
@@ -54,7 +156,7 @@ impl CodeGenerator {
             ErrorCode::GenericCompileError,
             message,
             self.line,
-            0, // column - we don't track this in codegen currently
+            self.column,
             0, // position - we don't track this in codegen currently  
             None, // token_length - not applicable for codegen errors
         );
@@ -71,10 +173,9 @@ impl CodeGenerator {
     ///
     /// A CompileResult containing the compiled bytecode chunk or errors
     fn compile(mut self, statements: &[Statement]) -> CompileResult<Chunk> {
+        self.declare_enums(statements);
         for stmt in statements {
-            stmt.accept(&mut self).unwrap_or_else(|_| {
-                // Error already added to self.errors
-            });
+            stmt.accept(&mut self).unwrap_or(());
         }
 
         self.emit_op(OpCode::Return);
@@ -96,10 +197,9 @@ impl CodeGenerator {
     ///
     /// CompileResult indicating success or containing errors
     pub fn compile_statements(&mut self, statements: &[Statement]) -> CompileResult<()> {
+        self.declare_enums(statements);
         for stmt in statements {
-            stmt.accept(self).unwrap_or_else(|_| {
-                // Error already added to self.errors
-            });
+            stmt.accept(self).unwrap_or(());
         }
         
         if self.errors.is_empty() {
@@ -109,6 +209,27 @@ impl CodeGenerator {
         }
     }
 
+    /// Records the discriminants of the enums defined in a program
+    ///
+    /// Enums can only be defined at the top level, but their variants may be used before the
+    /// definition, so they are collected before any code is generated.
+    ///
+    /// ### Arguments
+    ///
+    /// * `statements` - The top level statements of the program
+    fn declare_enums(&mut self, statements: &[Statement]) {
+        for stmt in statements {
+            if let Statement::EnumDefinition(enum_def) = stmt {
+                for (discriminant, variant) in enum_def.variants.iter().enumerate() {
+                    self.enum_variants.insert(
+                        format!("{}::{}", enum_def.name, variant),
+                        discriminant as i32,
+                    );
+                }
+            }
+        }
+    }
+
     /// Gets a reference to the current chunk
     pub fn get_chunk(&self) -> &Chunk {
         &self.chunk
@@ -130,13 +251,36 @@ impl CodeGenerator {
     /// * `op` - The opcode to emit
     fn emit_op(&mut self, op: OpCode) {
         self.chunk.write_op(op, self.line);
-    }    /// Adds a constant value to the chunk and emits code to load it
+    }
+
+    /// Adds a constant to the constant pool, reusing the entry of an equal constant
+    ///
+    /// ### Arguments
+    ///
+    /// * `value` - The constant value to add
+    ///
+    /// ### Returns
+    ///
+    /// The index of the constant in the constant pool
+    fn add_constant(&mut self, value: Value) -> usize {
+        let Some(key) = ConstantKey::of(&value) else {
+            return self.chunk.add_constant(value);
+        };
+        if let Some(&index) = self.constant_pool.get(&key) {
+            return index;
+        }
+        let index = self.chunk.add_constant(value);
+        self.constant_pool.insert(key, index);
+        index
+    }
+
+    /// Adds a constant value to the chunk and emits code to load it
     ///
     /// ### Arguments
     ///
     /// * `value` - The constant value to add
     fn emit_constant(&mut self, value: Value) -> Result<(), ()> {
-        let constant_index = self.chunk.add_constant(value);
+        let constant_index = self.add_constant(value);
         if constant_index > 255 {
             self.add_error("Too many constants in one chunk".to_string());
             return Err(());
@@ -177,13 +321,322 @@ impl CodeGenerator {
         self.chunk.code[offset + 1] = (jump & 0xFF) as u8;
     }
 
+    /// Emits a loop instruction that jumps back to the given position
+    ///
+    /// ### Arguments
+    ///
+    /// * `loop_start` - The position of the first instruction of the loop
+    fn emit_loop(&mut self, loop_start: usize) -> Result<(), ()> {
+        self.emit_op(OpCode::Loop);
+
+        let offset = self.chunk.code.len() - loop_start + 2;
+        if offset > 0xFFFF {
+            self.add_error("Loop body too large".to_string());
+            return Err(());
+        }
+
+        self.emit_byte(((offset >> 8) & 0xFF) as u8);
+        self.emit_byte((offset & 0xFF) as u8);
+        Ok(())
+    }
+
+    /// Returns the call if the expression is a call to the function currently being compiled
+    ///
+    /// Such a call in tail position can reuse the caller's frame instead of pushing a new one.
+    ///
+    /// ### Arguments
+    ///
+    /// * `expr` - The expression in tail position
+    fn as_self_call<'e>(&self, expr: &'e Expression) -> Option<&'e FunctionCallExpr> {
+        match expr {
+            Expression::Call(call_expr)
+                if self.current_function.as_deref()
+                    == Some(
+                        self.function_global_name(&call_expr.name, call_expr.arguments.len())
+                            .as_str(),
+                    ) =>
+            {
+                Some(call_expr)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the global name under which the function with the given arity is stored
+    ///
+    /// The first declaration of a function name is stored under the name itself, overloads
+    /// with a different arity are stored under `name/arity`. Calls through local variables
+    /// always use the plain name.
+    ///
+    /// ### Arguments
+    ///
+    /// * `name` - The name of the function as written in the source
+    /// * `arity` - The number of parameters of the function
+    fn function_global_name(&self, name: &str, arity: usize) -> String {
+        match self.functions.get(name) {
+            Some(&primary_arity) if primary_arity != arity && self.resolve_local(name).is_none() => {
+                format!("{}/{}", name, arity)
+            }
+            _ => name.to_string(),
+        }
+    }
+
+    /// Compiles an expression in tail position of a function
+    ///
+    /// Self-recursive calls are emitted as tail calls, everything else is compiled normally.
+    ///
+    /// ### Arguments
+    ///
+    /// * `expr` - The expression whose value is returned from the function
+    fn compile_tail_expression(&mut self, expr: &Expression) -> Result<(), ()> {
+        match self.as_self_call(expr) {
+            Some(call_expr) => {
+                self.set_current_location(&call_expr.location);
+                self.emit_call(call_expr, OpCode::TailCall)
+            }
+            None => self.visit_expression(expr),
+        }
+    }
+
+    /// Emits the arguments, the callee and the call instruction for a function call
+    ///
+    /// ### Arguments
+    ///
+    /// * `call_expr` - The call expression to compile
+    /// * `op` - The call opcode (Call or TailCall)
+    fn emit_call(&mut self, call_expr: &FunctionCallExpr, op: OpCode) -> Result<(), ()> {
+        let Ok(arg_count) = u8::try_from(call_expr.arguments.len()) else {
+            self.add_error("Too many arguments in one call".to_string());
+            return Err(());
+        };
+
+        for arg in &call_expr.arguments {
+            self.visit_expression(arg)?;
+        }
+
+        if let Some(slot) = self.resolve_local(&call_expr.name) {
+            self.emit_op(OpCode::GetLocal);
+            self.emit_byte(slot);
+        } else {
+            let callee = self.function_global_name(&call_expr.name, call_expr.arguments.len());
+            let fn_name_idx = self.chunk.add_identifier(callee);
+            self.emit_op(OpCode::GetVariable);
+            self.emit_byte(fn_name_idx as u8);
+        }
+
+        self.emit_op(op);
+        self.emit_byte(arg_count);
+
+        Ok(())
+    }
+
+    /// Compiles the statements and the trailing expression of a function body
+    ///
+    /// ### Arguments
+    ///
+    /// * `body` - The body of the function
+    fn compile_function_body(&mut self, body: &BlockExpr) -> Result<(), ()> {
+        for stmt in &body.statements {
+            stmt.accept(self)?;
+        }
+
+        if let Some(return_expr) = &body.return_expr {
+            self.compile_tail_expression(return_expr)
+        } else {
+            self.emit_constant(Value::Unit(()))
+        }
+    }
+
+    /// Emits a literal value as a constant
+    ///
+    /// ### Arguments
+    ///
+    /// * `value` - The value of the literal
+    fn emit_literal(&mut self, value: &slang_ir::ast::LiteralValue) -> Result<(), ()> {
+        match value {
+            slang_ir::ast::LiteralValue::I32(i) => {
+                self.emit_constant(Value::I32(*i))?;
+            }
+            slang_ir::ast::LiteralValue::I64(i) => {
+                self.emit_constant(Value::I64(*i))?;
+            }
+            slang_ir::ast::LiteralValue::U32(i) => {
+                self.emit_constant(Value::U32(*i))?;
+            }
+            slang_ir::ast::LiteralValue::U64(i) => {
+                self.emit_constant(Value::U64(*i))?;
+            }
+            slang_ir::ast::LiteralValue::I128(i) => {
+                self.emit_constant(Value::I128(*i))?;
+            }
+            slang_ir::ast::LiteralValue::U128(i) => {
+                self.emit_constant(Value::U128(*i))?;
+            }
+            slang_ir::ast::LiteralValue::UnspecifiedInteger(i) => {
+                self.emit_constant(unspecified_integer_value(*i))?;
+            }
+            slang_ir::ast::LiteralValue::F32(f) => {
+                self.emit_constant(Value::F32(*f))?;
+            }
+            slang_ir::ast::LiteralValue::F64(f) => {
+                self.emit_constant(Value::F64(*f))?;
+            }
+            slang_ir::ast::LiteralValue::UnspecifiedFloat(f) => {
+                self.emit_constant(Value::F64(*f))?;
+            }
+            slang_ir::ast::LiteralValue::String(s) => {
+                self.emit_constant(Value::String(Box::new(s.clone())))?;
+            }
+            slang_ir::ast::LiteralValue::Boolean(b) => {
+                self.emit_constant(Value::Boolean(*b))?;
+            }
+            slang_ir::ast::LiteralValue::Unit => {
+                self.emit_constant(Value::Unit(()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resolves a name to the slot of the innermost local variable of the current function
+    ///
+    /// Locals of enclosing functions aren't visible, closures receive them as captures.
+    ///
+    /// ### Arguments
+    ///
+    /// * `name` - The name to look up
+    ///
+    /// ### Returns
+    ///
+    /// The slot of the variable, or None if the name doesn't refer to a local
+    fn resolve_local(&self, name: &str) -> Option<u8> {
+        self.local_scopes[self.function_scope_start..]
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter().rev())
+            .find(|(local, _)| local == name)
+            .map(|&(_, slot)| slot)
+    }
+
+    /// Allocates a new slot for a local variable in the innermost scope
+    ///
+    /// Every declaration gets a slot of its own, so a shadowing variable never overwrites
+    /// the variable it shadows. Slots of scopes that already ended are reused.
+    ///
+    /// ### Arguments
+    ///
+    /// * `name` - The name of the variable
+    ///
+    /// ### Returns
+    ///
+    /// The allocated slot, or an error if the function has run out of slots
+    fn declare_local(&mut self, name: &str) -> Result<u8, ()> {
+        if self.live_slots > u8::MAX as usize {
+            self.add_error("Too many local variables in one function".to_string());
+            return Err(());
+        }
+
+        let slot = self.live_slots as u8;
+        match self.slot_names.get_mut(self.live_slots) {
+            Some(slot_name) => *slot_name = name.to_string(),
+            None => self.slot_names.push(name.to_string()),
+        }
+        self.live_slots += 1;
+        if let Some(current_scope) = self.local_scopes.last_mut() {
+            current_scope.push((name.to_string(), slot));
+        }
+        Ok(slot)
+    }
+
+    /// Creates the function value for a compiled function or closure body
+    ///
+    /// The VM copies the arguments of a call into the first slots of the function, so
+    /// the parameters must be the first locals and their count must fit the arity byte.
+    /// The parser already limits the number of parameters, so a violation is an internal
+    /// error rather than a mistake in the program.
+    ///
+    /// ### Arguments
+    ///
+    /// * `name` - The name of the function
+    /// * `parameters` - The parameters of the function
+    /// * `code_offset` - Offset in the chunk where the function's code begins
+    /// * `locals` - The names of the local variable slots of the function
+    ///
+    /// ### Returns
+    ///
+    /// The function, or an error if it has more parameters than a call can pass
+    fn build_function(
+        &mut self,
+        name: String,
+        parameters: &[Parameter],
+        code_offset: usize,
+        locals: Vec<String>,
+    ) -> Result<Function, ()> {
+        let Ok(arity) = u8::try_from(parameters.len()) else {
+            self.add_error("Too many parameters in one function".to_string());
+            return Err(());
+        };
+        debug_assert!(
+            parameters
+                .iter()
+                .zip(&locals)
+                .all(|(param, local)| &param.name == local)
+                && locals.len() >= parameters.len(),
+            "the parameters of '{}' must occupy the first local slots",
+            name
+        );
+
+        Ok(Function {
+            name,
+            arity,
+            code_offset,
+            locals,
+        })
+    }
+
+    /// Starts compiling the body of a function or closure with fresh slots
+    ///
+    /// ### Returns
+    ///
+    /// The slot state of the enclosing function, to be passed to `end_function`
+    fn begin_function(&mut self) -> (Vec<String>, usize, usize) {
+        let enclosing = (
+            std::mem::take(&mut self.slot_names),
+            std::mem::take(&mut self.live_slots),
+            self.function_scope_start,
+        );
+        self.function_scope_start = self.local_scopes.len();
+        self.begin_scope();
+        enclosing
+    }
+
+    /// Finishes compiling the body of a function or closure
+    ///
+    /// ### Arguments
+    ///
+    /// * `enclosing` - The slot state returned by `begin_function`
+    ///
+    /// ### Returns
+    ///
+    /// The names of the slots used by the function
+    fn end_function(&mut self, enclosing: (Vec<String>, usize, usize)) -> Vec<String> {
+        self.end_scope();
+        let (slot_names, live_slots, function_scope_start) = enclosing;
+        self.live_slots = live_slots;
+        self.function_scope_start = function_scope_start;
+        std::mem::replace(&mut self.slot_names, slot_names)
+    }
+
     fn begin_scope(&mut self) {
         self.local_scopes.push(Vec::new());
         self.emit_op(OpCode::BeginScope);
     }
 
+    /// Closes the innermost scope and releases the slots of its variables
     fn end_scope(&mut self) {
-        self.local_scopes.pop();
+        if let Some(scope) = self.local_scopes.pop() {
+            self.live_slots -= scope.len();
+        }
         self.emit_op(OpCode::EndScope);
     }
 }
@@ -195,10 +648,13 @@ impl Visitor<Result<(), ()>> for CodeGenerator {
             Statement::Let(let_stmt) => let_stmt.location,
             Statement::Assignment(assign_stmt) => assign_stmt.location,
             Statement::TypeDefinition(type_stmt) => type_stmt.location,
+            Statement::EnumDefinition(enum_stmt) => enum_stmt.location,
+            Statement::Impl(impl_stmt) => impl_stmt.location,
             Statement::Expression(expr) => expr.location(),
             Statement::FunctionDeclaration(fn_decl) => fn_decl.location,
             Statement::Return(return_stmt) => return_stmt.location,
             Statement::If(if_stmt) => if_stmt.location,
+            Statement::For(for_stmt) => for_stmt.location,
         };
         self.set_current_location(&location);
         
@@ -206,12 +662,15 @@ impl Visitor<Result<(), ()>> for CodeGenerator {
             Statement::Let(let_stmt) => self.visit_let_statement(let_stmt),
             Statement::Assignment(assign_stmt) => self.visit_assignment_statement(assign_stmt),
             Statement::TypeDefinition(type_stmt) => self.visit_type_definition_statement(type_stmt),
+            Statement::EnumDefinition(enum_stmt) => self.visit_enum_definition_statement(enum_stmt),
+            Statement::Impl(impl_stmt) => self.visit_impl_statement(impl_stmt),
             Statement::Expression(expr) => self.visit_expression_statement(expr),
             Statement::FunctionDeclaration(fn_decl) => {
                 self.visit_function_declaration_statement(fn_decl)
             }
             Statement::Return(expr) => self.visit_return_statement(expr),
             Statement::If(if_stmt) => self.visit_if_statement(if_stmt),
+            Statement::For(for_stmt) => self.visit_for_statement(for_stmt),
         }
     }
 
@@ -225,9 +684,11 @@ impl Visitor<Result<(), ()>> for CodeGenerator {
             Expression::Variable(var) => self.visit_variable_expression(var),
             Expression::Unary(unary_expr) => self.visit_unary_expression(unary_expr),
             Expression::Call(call_expr) => self.visit_call_expression(call_expr),
+            Expression::MethodCall(method_call) => self.visit_method_call_expression(method_call),
             Expression::Conditional(cond_expr) => self.visit_conditional_expression(cond_expr),
             Expression::Block(block_expr) => self.visit_block_expression(block_expr),
             Expression::FunctionType(func_type_expr) => self.visit_function_type_expression(func_type_expr),
+            Expression::Closure(closure_expr) => self.visit_closure_expression(closure_expr),
         }
     }
 
@@ -235,43 +696,38 @@ impl Visitor<Result<(), ()>> for CodeGenerator {
         &mut self,
         fn_decl: &FunctionDeclarationStmt,
     ) -> Result<(), ()> {
-        self.functions.push(fn_decl.name.clone());
-        let function_name_idx = self.chunk.add_identifier(fn_decl.name.clone());
+        let arity = fn_decl.parameters.len();
+        self.functions.entry(fn_decl.name.clone()).or_insert(arity);
+        let global_name = self.function_global_name(&fn_decl.name, arity);
+        let function_name_idx = self.chunk.add_identifier(global_name.clone());
 
         let jump_over = self.emit_jump(OpCode::Jump);
 
         let code_offset = self.chunk.code.len();
-        let mut locals = Vec::new();
-
-        self.begin_scope();
-        for param in &fn_decl.parameters {
-            locals.push(param.name.clone());
-            if let Some(current_scope) = self.local_scopes.last_mut() {
-                current_scope.push(param.name.clone());
-            }
-        }
-
-        for stmt in &fn_decl.body.statements {
-            stmt.accept(self)?;
+        let enclosing_function = self.current_function.replace(global_name);
+        let enclosing_slots = self.begin_function();
+
+        let body_result = fn_decl
+            .parameters
+            .iter()
+            .try_for_each(|param| self.declare_local(&param.name).map(|_| ()))
+            .and_then(|_| self.compile_function_body(&fn_decl.body));
+        self.current_function = enclosing_function;
+        if body_result.is_ok() {
+            self.emit_op(OpCode::Return);
         }
+        let locals = self.end_function(enclosing_slots);
+        body_result?;
 
-        if let Some(return_expr) = &fn_decl.body.return_expr {
-            return_expr.accept(self)?;
-        } else {
-            self.emit_constant(Value::Unit(()))?;
-        }
-
-        self.emit_op(OpCode::Return);
-
-        self.end_scope();
         self.patch_jump(jump_over);
 
-        let function = Value::Function(Box::new(Function {
-            name: fn_decl.name.clone(),
-            arity: fn_decl.parameters.len() as u8,
+        let function = self.build_function(
+            fn_decl.name.clone(),
+            &fn_decl.parameters,
             code_offset,
             locals,
-        }));
+        )?;
+        let function = Value::Function(Box::new(function));
         let fn_constant = self.chunk.add_constant(function);
 
         self.emit_op(OpCode::DefineFunction);
@@ -283,7 +739,7 @@ impl Visitor<Result<(), ()>> for CodeGenerator {
 
     fn visit_return_statement(&mut self, return_stmt: &slang_ir::ast::ReturnStatement) -> Result<(), ()> {
         if let Some(expr) = &return_stmt.value {
-            self.visit_expression(expr)?;
+            self.compile_tail_expression(expr)?;
         } else {
             self.emit_constant(Value::Unit(()))?;
         }
@@ -297,18 +753,25 @@ impl Visitor<Result<(), ()>> for CodeGenerator {
     }
 
     fn visit_let_statement(&mut self, let_stmt: &LetStatement) -> Result<(), ()> {
-        let is_local = !self.local_scopes.is_empty();
-
-        if is_local {
-            if let Some(current_scope) = self.local_scopes.last_mut() {
-                current_scope.push(let_stmt.name.clone());
-            }
-        } else {
-            self.variables.push(let_stmt.name.clone());
+        if let_stmt.is_wildcard() {
+            self.visit_expression(&let_stmt.value)?;
+            self.emit_op(OpCode::Pop);
+            return Ok(());
         }
 
         self.visit_expression(&let_stmt.value)?;
 
+        // The slot is allocated after the initializer, which still sees a shadowed variable
+        if !self.local_scopes.is_empty() {
+            self.set_current_location(&let_stmt.location);
+            let slot = self.declare_local(&let_stmt.name)?;
+            self.emit_op(OpCode::SetLocal);
+            self.emit_byte(slot);
+            self.emit_op(OpCode::Pop);
+            return Ok(());
+        }
+
+        self.variables.push(let_stmt.name.clone());
         let var_index = self.chunk.add_identifier(let_stmt.name.clone());
         if var_index > 255 {
             self.add_error("Too many variables in one scope".to_string());
@@ -328,6 +791,12 @@ impl Visitor<Result<(), ()>> for CodeGenerator {
         assign_stmt: &slang_ir::ast::AssignmentStatement,
     ) -> Result<(), ()> {
         self.visit_expression(&assign_stmt.value)?;
+        if let Some(slot) = self.resolve_local(&assign_stmt.name) {
+            self.emit_op(OpCode::SetLocal);
+            self.emit_byte(slot);
+            return Ok(());
+        }
+
         let var_index = self.chunk.add_identifier(assign_stmt.name.clone());
         if var_index > 255 {
             self.add_error("Too many variables in one scope".to_string());
@@ -340,58 +809,44 @@ impl Visitor<Result<(), ()>> for CodeGenerator {
     }
 
     fn visit_call_expression(&mut self, call_expr: &FunctionCallExpr) -> Result<(), ()> {
-        for arg in &call_expr.arguments {
+        // A type query is answered by the semantic analyzer, its argument isn't evaluated
+        if call_expr.is_type_query() {
+            return self.emit_constant(Value::Unit(()));
+        }
+
+        self.emit_call(call_expr, OpCode::Call)
+    }
+
+    fn visit_method_call_expression(&mut self, method_call: &MethodCallExpr) -> Result<(), ()> {
+        // A method is only left unresolved after the analyzer reported an error for it
+        let function_name = method_call
+            .function_name
+            .get()
+            .map_or(method_call.method.as_str(), String::as_str);
+        // The receiver is passed as the first argument
+        let Ok(arg_count) = u8::try_from(method_call.arguments.len() + 1) else {
+            self.add_error("Too many arguments in one call".to_string());
+            return Err(());
+        };
+
+        self.visit_expression(&method_call.receiver)?;
+        for arg in &method_call.arguments {
             self.visit_expression(arg)?;
         }
 
-        let fn_name_idx = self.chunk.add_identifier(call_expr.name.clone());
+        let callee = self.function_global_name(function_name, usize::from(arg_count));
+        let fn_name_idx = self.chunk.add_identifier(callee);
         self.emit_op(OpCode::GetVariable);
         self.emit_byte(fn_name_idx as u8);
 
         self.emit_op(OpCode::Call);
-        self.emit_byte(call_expr.arguments.len() as u8);
+        self.emit_byte(arg_count);
 
         Ok(())
     }
 
     fn visit_literal_expression(&mut self, lit_expr: &LiteralExpr) -> Result<(), ()> {
-        match &lit_expr.value {
-            slang_ir::ast::LiteralValue::I32(i) => {
-                self.emit_constant(Value::I32(*i))?;
-            }
-            slang_ir::ast::LiteralValue::I64(i) => {
-                self.emit_constant(Value::I64(*i))?;
-            }
-            slang_ir::ast::LiteralValue::U32(i) => {
-                self.emit_constant(Value::U32(*i))?;
-            }
-            slang_ir::ast::LiteralValue::U64(i) => {
-                self.emit_constant(Value::U64(*i))?;
-            }
-            slang_ir::ast::LiteralValue::UnspecifiedInteger(i) => {
-                self.emit_constant(Value::I64(*i))?;
-            }
-            slang_ir::ast::LiteralValue::F32(f) => {
-                self.emit_constant(Value::F32(*f))?;
-            }
-            slang_ir::ast::LiteralValue::F64(f) => {
-                self.emit_constant(Value::F64(*f))?;
-            }
-            slang_ir::ast::LiteralValue::UnspecifiedFloat(f) => {
-                self.emit_constant(Value::F64(*f))?;
-            }
-            slang_ir::ast::LiteralValue::String(s) => {
-                self.emit_constant(Value::String(Box::new(s.clone())))?;
-            }
-            slang_ir::ast::LiteralValue::Boolean(b) => {
-                self.emit_constant(Value::Boolean(*b))?;
-            }
-            slang_ir::ast::LiteralValue::Unit => {
-                self.emit_constant(Value::Unit(()))?;
-            }
-        }
-
-        Ok(())
+        self.emit_literal(&lit_expr.typed_value())
     }
 
     fn visit_binary_expression(&mut self, bin_expr: &BinaryExpr) -> Result<(), ()> {
@@ -431,6 +886,11 @@ impl Visitor<Result<(), ()>> for CodeGenerator {
                     BinaryOperator::LessThanOrEqual => self.emit_op(OpCode::LessEqual),
                     BinaryOperator::Equal => self.emit_op(OpCode::Equal),
                     BinaryOperator::NotEqual => self.emit_op(OpCode::NotEqual),
+                    BinaryOperator::BitAnd => self.emit_op(OpCode::BitAnd),
+                    BinaryOperator::BitOr => self.emit_op(OpCode::BitOr),
+                    BinaryOperator::BitXor => self.emit_op(OpCode::BitXor),
+                    BinaryOperator::ShiftLeft => self.emit_op(OpCode::ShiftLeft),
+                    BinaryOperator::ShiftRight => self.emit_op(OpCode::ShiftRight),
                     _ => {
                         self.add_error(format!(
                             "Unsupported binary operator: {:?}",
@@ -446,11 +906,30 @@ impl Visitor<Result<(), ()>> for CodeGenerator {
     }
 
     fn visit_unary_expression(&mut self, unary_expr: &UnaryExpr) -> Result<(), ()> {
+        // A negated literal is emitted as a single constant, because the magnitude of i64::MIN
+        // only fits an unsigned type, which can not be negated at runtime
+        if unary_expr.operator == UnaryOperator::Negate
+            && let Expression::Literal(
+                literal @ LiteralExpr {
+                    value: slang_ir::ast::LiteralValue::UnspecifiedInteger(i),
+                    ..
+                },
+            ) = unary_expr.right.as_ref()
+        {
+            let negated = LiteralExpr {
+                value: slang_ir::ast::LiteralValue::UnspecifiedInteger(-*i),
+                expr_type: literal.expr_type.clone(),
+                location: literal.location,
+            };
+            return self.emit_literal(&negated.typed_value());
+        }
+
         self.visit_expression(&unary_expr.right)?;
 
         match unary_expr.operator {
             UnaryOperator::Negate => self.emit_op(OpCode::Negate),
             UnaryOperator::Not => self.emit_op(OpCode::BoolNot),
+            UnaryOperator::BitNot => self.emit_op(OpCode::BitNot),
         }
 
         Ok(())
@@ -460,6 +939,17 @@ impl Visitor<Result<(), ()>> for CodeGenerator {
         &mut self,
         var_expr: &slang_ir::ast::VariableExpr,
     ) -> Result<(), ()> {
+        if let Some(slot) = self.resolve_local(&var_expr.name) {
+            self.emit_op(OpCode::GetLocal);
+            self.emit_byte(slot);
+            return Ok(());
+        }
+
+        // Enum values are represented by the discriminant of their variant
+        if let Some(&discriminant) = self.enum_variants.get(&var_expr.name) {
+            return self.emit_constant(Value::I32(discriminant));
+        }
+
         let var_index = self.chunk.add_identifier(var_expr.name.clone());
         if var_index > 255 {
             self.add_error("Too many variables".to_string());
@@ -479,6 +969,20 @@ impl Visitor<Result<(), ()>> for CodeGenerator {
         Ok(())
     }
 
+    fn visit_impl_statement(&mut self, impl_stmt: &ImplStmt) -> Result<(), ()> {
+        // Methods are compiled like functions declared under their qualified name
+        for method in &impl_stmt.methods {
+            self.set_current_location(&method.location);
+            self.visit_function_declaration_statement(method)?;
+        }
+        Ok(())
+    }
+
+    fn visit_enum_definition_statement(&mut self, _stmt: &EnumDefinitionStmt) -> Result<(), ()> {
+        // The variants were recorded before code generation started
+        Ok(())
+    }
+
     fn visit_conditional_expression(&mut self, cond_expr: &ConditionalExpr) -> Result<(), ()> {
         self.visit_expression(&cond_expr.condition)?;
 
@@ -521,6 +1025,54 @@ impl Visitor<Result<(), ()>> for CodeGenerator {
         Ok(())
     }
 
+    fn visit_for_statement(&mut self, for_stmt: &ForStatement) -> Result<(), ()> {
+        self.begin_scope();
+
+        // Both bounds are evaluated once, before the loop variable comes into scope
+        self.visit_expression(&for_stmt.start)?;
+        self.visit_expression(&for_stmt.end)?;
+        let end_slot = self.declare_local(RANGE_END_SLOT)?;
+        self.emit_op(OpCode::SetLocal);
+        self.emit_byte(end_slot);
+        self.emit_op(OpCode::Pop);
+        let variable_slot = self.declare_local(&for_stmt.variable)?;
+        self.emit_op(OpCode::SetLocal);
+        self.emit_byte(variable_slot);
+        self.emit_op(OpCode::Pop);
+
+        let loop_start = self.chunk.code.len();
+        self.emit_op(OpCode::GetLocal);
+        self.emit_byte(variable_slot);
+        self.emit_op(OpCode::GetLocal);
+        self.emit_byte(end_slot);
+        self.emit_op(OpCode::Less);
+        let exit_jump = self.emit_jump(OpCode::JumpIfFalse);
+        self.emit_op(OpCode::Pop);
+
+        self.visit_block_expression(&for_stmt.body)?;
+        self.emit_op(OpCode::Pop);
+
+        self.emit_op(OpCode::GetLocal);
+        self.emit_byte(variable_slot);
+        let step = LiteralExpr {
+            value: slang_ir::ast::LiteralValue::UnspecifiedInteger(1),
+            expr_type: for_stmt.variable_type.clone(),
+            location: for_stmt.location,
+        };
+        self.emit_literal(&step.typed_value())?;
+        self.emit_op(OpCode::Add);
+        self.emit_op(OpCode::SetLocal);
+        self.emit_byte(variable_slot);
+        self.emit_op(OpCode::Pop);
+        self.emit_loop(loop_start)?;
+
+        self.patch_jump(exit_jump);
+        self.emit_op(OpCode::Pop);
+        self.end_scope();
+
+        Ok(())
+    }
+
     fn visit_block_expression(&mut self, block_expr: &BlockExpr) -> Result<(), ()> {
         self.begin_scope();
 
@@ -544,4 +1096,85 @@ impl Visitor<Result<(), ()>> for CodeGenerator {
         // They are used for type checking and don't produce any values at runtime
         Ok(())
     }
+
+    fn visit_closure_expression(&mut self, closure_expr: &ClosureExpr) -> Result<(), ()> {
+        // Every variable from outside the closure is copied when the closure is created,
+        // only functions and natives are still looked up when it is called
+        let captures: Vec<(String, Option<u8>)> = CaptureCollector::collect(&closure_expr.body)
+            .into_iter()
+            .filter(|name| {
+                !closure_expr
+                    .parameters
+                    .iter()
+                    .any(|param| &param.name == name)
+            })
+            .filter_map(|name| match self.resolve_local(&name) {
+                Some(slot) => Some((name, Some(slot))),
+                None if self.variables.contains(&name) => Some((name, None)),
+                None => None,
+            })
+            .collect();
+        if captures.len() > 255 {
+            self.add_error("Too many captured variables in one closure".to_string());
+            return Err(());
+        }
+
+        let jump_over = self.emit_jump(OpCode::Jump);
+
+        let code_offset = self.chunk.code.len();
+        let enclosing_function = self.current_function.take();
+        let enclosing_slots = self.begin_function();
+
+        // The captured values are stored in the slots following the parameters
+        let body_result = closure_expr
+            .parameters
+            .iter()
+            .map(|param| &param.name)
+            .chain(captures.iter().map(|(name, _)| name))
+            .try_for_each(|name| self.declare_local(name).map(|_| ()))
+            .and_then(|_| self.compile_function_body(&closure_expr.body));
+        self.current_function = enclosing_function;
+        if body_result.is_ok() {
+            self.emit_op(OpCode::Return);
+        }
+        let locals = self.end_function(enclosing_slots);
+        body_result?;
+
+        self.patch_jump(jump_over);
+
+        let function = self.build_function(
+            "closure".to_string(),
+            &closure_expr.parameters,
+            code_offset,
+            locals,
+        )?;
+        let function = Value::Function(Box::new(function));
+        let fn_constant = self.chunk.add_constant(function);
+        if fn_constant > 255 {
+            self.add_error("Too many constants in one chunk".to_string());
+            return Err(());
+        }
+
+        self.emit_op(OpCode::Closure);
+        self.emit_byte(fn_constant as u8);
+        self.emit_byte(captures.len() as u8);
+        for (name, slot) in captures {
+            // A local is read from its slot, a global by the index of its name
+            let index = match slot {
+                Some(slot) => slot,
+                None => {
+                    let var_index = self.chunk.add_identifier(name);
+                    if var_index > 255 {
+                        self.add_error("Too many variables".to_string());
+                        return Err(());
+                    }
+                    var_index as u8
+                }
+            };
+            self.emit_byte(slot.is_some() as u8);
+            self.emit_byte(index);
+        }
+
+        Ok(())
+    }
 }
@@ -1,10 +1,11 @@
 // Re-exported modules
 pub mod bytecode;
+mod capture;
 pub mod codegen;
 pub mod native;
 pub mod value;
 pub mod vm;
 
 // Re-export common types
-pub use vm::VM;
+pub use vm::{OverflowMode, VM};
 pub use codegen::CodeGenerator;
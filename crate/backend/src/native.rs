@@ -1,4 +1,13 @@
-use crate::value::Value;
+use crate::value::{ArithmeticOps, Value};
+use std::io::Write;
+
+/// State of the running program that native functions can access
+pub struct NativeContext<'a> {
+    /// The command line arguments passed to the program
+    pub program_args: &'a [String],
+    /// The sink that printed output is written to
+    pub output: &'a mut dyn Write,
+}
 
 /// Built-in function to print a value
 ///
@@ -9,13 +18,337 @@ use crate::value::Value;
 /// ### Returns
 ///
 /// Success with i32(0) if successful, or an error message
-pub fn print_value(args: &[Value]) -> Result<Value, String> {
+pub fn print_value(args: &[Value], context: &mut NativeContext) -> Result<Value, String> {
     if args.len() != 1 {
         return Err("print_value expects exactly 1 argument".to_string());
     }
 
-    println!("{}", args[0]);
+    writeln!(context.output, "{}", args[0]).map_err(|e| format!("Failed to print: {}", e))?;
+
+    // Return 0 to indicate success
+    Ok(Value::I32(0))
+}
+
+/// Built-in function to print a formatted string
+///
+/// Every `{}` placeholder in the format string is replaced by the next value.
+///
+/// ### Arguments
+///
+/// * `args` - The format string followed by one value per placeholder
+///
+/// ### Returns
+///
+/// Success with i32(0) if successful, or an error message
+pub fn print(args: &[Value], context: &mut NativeContext) -> Result<Value, String> {
+    let Some((Value::String(format), values)) = args.split_first() else {
+        return Err("print expects a format string as its first argument".to_string());
+    };
+
+    let placeholders = format.matches("{}").count();
+    if placeholders != values.len() {
+        return Err(format!(
+            "print expects {} values for the format string but got {}",
+            placeholders,
+            values.len()
+        ));
+    }
+
+    let mut output = String::with_capacity(format.len());
+    let mut rest = format.as_str();
+    for value in values {
+        if let Some(index) = rest.find("{}") {
+            output.push_str(&rest[..index]);
+            output.push_str(&value.to_string());
+            rest = &rest[index + 2..];
+        }
+    }
+    output.push_str(rest);
+
+    writeln!(context.output, "{}", output).map_err(|e| format!("Failed to print: {}", e))?;
 
     // Return 0 to indicate success
     Ok(Value::I32(0))
 }
+
+/// Extracts the two integer operands of an integer intrinsic
+///
+/// Unspecified integer literals are compiled to i64 values, so an i64 operand is converted
+/// to the type of the other operand. The semantic analysis guarantees that the value fits.
+///
+/// ### Arguments
+///
+/// * `name` - The name of the intrinsic, used in error messages
+/// * `args` - Arguments to the function (should be exactly 2)
+///
+/// ### Returns
+///
+/// Both operands with the same integer type, or an error message
+fn integer_operands(name: &str, args: &[Value]) -> Result<(Value, Value), String> {
+    let [left, right] = args else {
+        return Err(format!("{} expects exactly 2 arguments", name));
+    };
+
+    let convert = |value: &Value, target: &Value| match (value, target) {
+        (Value::I64(n), Value::I32(_)) => i32::try_from(*n).ok().map(Value::I32),
+        (Value::I64(n), Value::U32(_)) => u32::try_from(*n).ok().map(Value::U32),
+        (Value::I64(n), Value::U64(_)) => u64::try_from(*n).ok().map(Value::U64),
+        (Value::I64(n), Value::I128(_)) => Some(Value::I128(i128::from(*n))),
+        (Value::I64(n), Value::U128(_)) => u128::try_from(*n).ok().map(Value::U128),
+        _ => Some(value.clone()),
+    };
+
+    match (convert(left, right), convert(right, left)) {
+        (Some(left), Some(right)) => Ok((left, right)),
+        _ => Err(format!("{} operand is out of range", name)),
+    }
+}
+
+/// Built-in function that adds two integers, wrapping around on overflow
+///
+/// ### Arguments
+///
+/// * `args` - The two integer operands
+///
+/// ### Returns
+///
+/// The wrapped sum, or an error message
+pub fn wrapping_add(args: &[Value], _context: &mut NativeContext) -> Result<Value, String> {
+    let (left, right) = integer_operands("wrapping_add", args)?;
+    left.wrapping_add(&right)
+}
+
+/// Built-in function that subtracts two integers, wrapping around on overflow
+///
+/// ### Arguments
+///
+/// * `args` - The two integer operands
+///
+/// ### Returns
+///
+/// The wrapped difference, or an error message
+pub fn wrapping_sub(args: &[Value], _context: &mut NativeContext) -> Result<Value, String> {
+    let (left, right) = integer_operands("wrapping_sub", args)?;
+    left.wrapping_subtract(&right)
+}
+
+/// Built-in function that multiplies two integers, wrapping around on overflow
+///
+/// ### Arguments
+///
+/// * `args` - The two integer operands
+///
+/// ### Returns
+///
+/// The wrapped product, or an error message
+pub fn wrapping_mul(args: &[Value], _context: &mut NativeContext) -> Result<Value, String> {
+    let (left, right) = integer_operands("wrapping_mul", args)?;
+    left.wrapping_multiply(&right)
+}
+
+/// Built-in function that adds two integers, clamping the result to the type's bounds
+///
+/// ### Arguments
+///
+/// * `args` - The two integer operands
+///
+/// ### Returns
+///
+/// The saturated sum, or an error message
+pub fn saturating_add(args: &[Value], _context: &mut NativeContext) -> Result<Value, String> {
+    let (left, right) = integer_operands("saturating_add", args)?;
+    left.saturating_add(&right)
+}
+
+/// Built-in function that subtracts two integers, clamping the result to the type's bounds
+///
+/// ### Arguments
+///
+/// * `args` - The two integer operands
+///
+/// ### Returns
+///
+/// The saturated difference, or an error message
+pub fn saturating_sub(args: &[Value], _context: &mut NativeContext) -> Result<Value, String> {
+    let (left, right) = integer_operands("saturating_sub", args)?;
+    left.saturating_subtract(&right)
+}
+
+/// Built-in function that multiplies two integers, clamping the result to the type's bounds
+///
+/// ### Arguments
+///
+/// * `args` - The two integer operands
+///
+/// ### Returns
+///
+/// The saturated product, or an error message
+pub fn saturating_mul(args: &[Value], _context: &mut NativeContext) -> Result<Value, String> {
+    let (left, right) = integer_operands("saturating_mul", args)?;
+    left.saturating_multiply(&right)
+}
+
+/// Built-in function that raises a number to a power
+///
+/// Integer bases require a non-negative integer exponent and fail on overflow. Float bases
+/// accept integer and float exponents.
+///
+/// ### Arguments
+///
+/// * `args` - The base followed by the exponent
+///
+/// ### Returns
+///
+/// The power with the type of the base, or an error message
+pub fn pow(args: &[Value], _context: &mut NativeContext) -> Result<Value, String> {
+    let [base, exponent] = args else {
+        return Err("pow expects exactly 2 arguments".to_string());
+    };
+
+    let float_exponent = match exponent {
+        Value::F32(value) => f64::from(*value),
+        Value::F64(value) => *value,
+        Value::U64(value) => *value as f64,
+        Value::I128(value) => *value as f64,
+        Value::U128(value) => *value as f64,
+        other => match other.as_i64() {
+            Some(value) => value as f64,
+            None => return Err(format!("pow expects a numeric exponent, got {}", other)),
+        },
+    };
+
+    match base {
+        Value::F32(value) => return Ok(Value::F32(value.powf(float_exponent as f32))),
+        Value::F64(value) => return Ok(Value::F64(value.powf(float_exponent))),
+        _ => {}
+    }
+
+    let exponent = match exponent {
+        Value::U64(value) => u32::try_from(*value).ok(),
+        Value::I128(value) => u32::try_from(*value).ok(),
+        Value::U128(value) => u32::try_from(*value).ok(),
+        other => other.as_i64().and_then(|value| u32::try_from(value).ok()),
+    };
+    let Some(exponent) = exponent else {
+        return if float_exponent < 0.0 {
+            Err("pow exponent must not be negative for integer bases".to_string())
+        } else {
+            Err("Integer overflow in pow".to_string())
+        };
+    };
+
+    let result = match base {
+        Value::I32(value) => value.checked_pow(exponent).map(Value::I32),
+        Value::I64(value) => value.checked_pow(exponent).map(Value::I64),
+        Value::U32(value) => value.checked_pow(exponent).map(Value::U32),
+        Value::U64(value) => value.checked_pow(exponent).map(Value::U64),
+        Value::I128(value) => value.checked_pow(exponent).map(Value::I128),
+        Value::U128(value) => value.checked_pow(exponent).map(Value::U128),
+        other => return Err(format!("pow expects a numeric base, got {}", other)),
+    };
+    result.ok_or_else(|| "Integer overflow in pow".to_string())
+}
+
+/// Built-in function that returns the number of command line arguments passed to the program
+///
+/// ### Arguments
+///
+/// * `args` - Arguments to the function (should be empty)
+/// * `context` - The state of the running program
+///
+/// ### Returns
+///
+/// The number of program arguments as an i64, or an error message
+pub fn arg_count(args: &[Value], context: &mut NativeContext) -> Result<Value, String> {
+    if !args.is_empty() {
+        return Err("arg_count expects no arguments".to_string());
+    }
+
+    Ok(Value::I64(context.program_args.len() as i64))
+}
+
+/// Built-in function that returns a command line argument passed to the program
+///
+/// ### Arguments
+///
+/// * `args` - The zero-based index of the argument
+/// * `context` - The state of the running program
+///
+/// ### Returns
+///
+/// The argument as a string, or an error message if the index is out of range
+pub fn arg(args: &[Value], context: &mut NativeContext) -> Result<Value, String> {
+    let [index] = args else {
+        return Err("arg expects exactly 1 argument".to_string());
+    };
+    let Some(index) = index.as_i64() else {
+        return Err(format!("arg expects an integer index, got {}", index));
+    };
+
+    usize::try_from(index)
+        .ok()
+        .and_then(|index| context.program_args.get(index))
+        .map(|argument| Value::String(Box::new(argument.clone())))
+        .ok_or_else(|| {
+            format!(
+                "Argument index {} is out of range for {} program arguments",
+                index,
+                context.program_args.len()
+            )
+        })
+}
+
+/// Built-in function that returns the length of a string in characters
+///
+/// Characters are Unicode scalar values, not bytes, so `len("héllo")` is 5 although the
+/// string takes 6 bytes in UTF-8.
+///
+/// ### Arguments
+///
+/// * `args` - The string whose length is returned
+/// * `_context` - The state of the running program
+///
+/// ### Returns
+///
+/// The number of characters as an i64, or an error message
+pub fn len(args: &[Value], _context: &mut NativeContext) -> Result<Value, String> {
+    let [Value::String(string)] = args else {
+        return Err("len expects exactly 1 string argument".to_string());
+    };
+
+    Ok(Value::I64(string.chars().count() as i64))
+}
+
+/// Built-in function that returns the character of a string at an index
+///
+/// The index counts characters like `len` does. As there is no character type, the
+/// character is returned as a string of length 1.
+///
+/// ### Arguments
+///
+/// * `args` - The string followed by the zero-based index of the character
+/// * `_context` - The state of the running program
+///
+/// ### Returns
+///
+/// The character as a string, or an error message if the index is out of range
+pub fn char_at(args: &[Value], _context: &mut NativeContext) -> Result<Value, String> {
+    let [Value::String(string), index] = args else {
+        return Err("char_at expects a string and an index".to_string());
+    };
+    let Some(index) = index.as_i64() else {
+        return Err(format!("char_at expects an integer index, got {}", index));
+    };
+
+    usize::try_from(index)
+        .ok()
+        .and_then(|index| string.chars().nth(index))
+        .map(|character| Value::String(Box::new(character.to_string())))
+        .ok_or_else(|| {
+            format!(
+                "Character index {} is out of range for a string of length {}",
+                index,
+                string.chars().count()
+            )
+        })
+}
@@ -3,6 +3,7 @@ pub mod operations;
 use std::fmt;
 use std::io::Read;
 
+use crate::bytecode::Closure;
 use crate::bytecode::Function;
 use crate::bytecode::NativeFunction;
 
@@ -52,6 +53,22 @@ impl DeserializeFromReader for u64 {
     }
 }
 
+impl DeserializeFromReader for i128 {
+    fn deserialize(reader: &mut dyn Read) -> std::io::Result<Self> {
+        let mut bytes = [0u8; 16];
+        reader.read_exact(&mut bytes)?;
+        Ok(i128::from_le_bytes(bytes))
+    }
+}
+
+impl DeserializeFromReader for u128 {
+    fn deserialize(reader: &mut dyn Read) -> std::io::Result<Self> {
+        let mut bytes = [0u8; 16];
+        reader.read_exact(&mut bytes)?;
+        Ok(u128::from_le_bytes(bytes))
+    }
+}
+
 impl DeserializeFromReader for f32 {
     fn deserialize(reader: &mut dyn Read) -> std::io::Result<Self> {
         let mut bytes = [0u8; 4];
@@ -134,6 +151,32 @@ impl DeserializeFromReader for Box<Function> {
     }
 }
 
+impl DeserializeFromReader for Box<Closure> {
+    fn deserialize(reader: &mut dyn Read) -> std::io::Result<Self> {
+        let function = Box::<Function>::deserialize(reader)?;
+
+        let mut captures_len_bytes = [0u8; 4];
+        reader.read_exact(&mut captures_len_bytes)?;
+        let captures_len = u32::from_le_bytes(captures_len_bytes) as usize;
+
+        let mut captures = Vec::new();
+        for _ in 0..captures_len {
+            let name = Box::<String>::deserialize(reader)?;
+
+            let mut type_tag = [0u8; 1];
+            reader.read_exact(&mut type_tag)?;
+            let value = Value::deserialize_from_type_tag(type_tag[0], reader)?;
+
+            captures.push((*name, value));
+        }
+
+        Ok(Box::new(Closure {
+            function: *function,
+            captures,
+        }))
+    }
+}
+
 impl DeserializeFromReader for Box<NativeFunction> {
     fn deserialize(reader: &mut dyn Read) -> std::io::Result<Self> {
         // For now, we'll just read the name since NativeFunction might have 
@@ -148,12 +191,13 @@ impl DeserializeFromReader for Box<NativeFunction> {
         // For the function pointer, we can't really deserialize it from bytes,
         // so we'll use a placeholder function. In a real implementation, you might
         // have a registry of native functions that you look up by name.
-        let placeholder_fn: fn(&[crate::value::Value]) -> Result<crate::value::Value, String> = 
-            |_args| Err("Placeholder native function".to_string());
+        let placeholder_fn: crate::bytecode::NativeFn =
+            |_args, _context| Err("Placeholder native function".to_string());
             
         Ok(Box::new(NativeFunction {
             name: *name_string,
             arity,
+            is_variadic: false,
             function: placeholder_fn,
         }))
     }
@@ -184,6 +228,18 @@ impl DisplayValue for u64 {
     }
 }
 
+impl DisplayValue for i128 {
+    fn display_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl DisplayValue for u128 {
+    fn display_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
 impl DisplayValue for f32 {
     fn display_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self)
@@ -220,6 +276,12 @@ impl DisplayValue for Box<Function> {
     }
 }
 
+impl DisplayValue for Box<Closure> {
+    fn display_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<closure>")
+    }
+}
+
 impl DisplayValue for Box<NativeFunction> {
     fn display_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "<native fn {}>", self.name)
@@ -312,17 +374,23 @@ define_value_enum! {
     Boolean(bool) => 9,
     /// Unit value (similar to Rust's ())
     Unit(()) => 10,
+    /// Closure value with its captured variables
+    Closure(Box<Closure>) => 11,
+    /// 128-bit signed integer
+    I128(i128) => 12,
+    /// 128-bit unsigned integer
+    U128(u128) => 13,
 }
 
 impl Value {
     /// Check if the value is numeric (integer or float)
     pub fn is_numeric(&self) -> bool {
-        matches!(self, Value::I32(_) | Value::I64(_) | Value::U32(_) | Value::U64(_) | Value::F32(_) | Value::F64(_))
+        self.is_integer() || self.is_float()
     }
 
     /// Check if the value is an integer type
     pub fn is_integer(&self) -> bool {
-        matches!(self, Value::I32(_) | Value::I64(_) | Value::U32(_) | Value::U64(_))
+        self.is_signed_integer() || self.is_unsigned_integer()
     }
 
     /// Check if the value is a float type
@@ -332,12 +400,12 @@ impl Value {
 
     /// Check if the value is a signed integer
     pub fn is_signed_integer(&self) -> bool {
-        matches!(self, Value::I32(_) | Value::I64(_))
+        matches!(self, Value::I32(_) | Value::I64(_) | Value::I128(_))
     }
 
     /// Check if the value is an unsigned integer
     pub fn is_unsigned_integer(&self) -> bool {
-        matches!(self, Value::U32(_) | Value::U64(_))
+        matches!(self, Value::U32(_) | Value::U64(_) | Value::U128(_))
     }
 
     /// Check if the value is a string
@@ -349,4 +417,138 @@ impl Value {
     pub fn is_boolean(&self) -> bool {
         matches!(self, Value::Boolean(_))
     }
+
+    /// Check if the value is the unit value
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_backend::value::Value;
+    ///
+    /// assert!(Value::Unit(()).is_unit());
+    /// assert!(!Value::I32(0).is_unit());
+    /// assert!(!Value::Boolean(false).is_unit());
+    /// assert!(!Value::String(Box::default()).is_unit());
+    /// ```
+    pub fn is_unit(&self) -> bool {
+        matches!(self, Value::Unit(_))
+    }
+
+    /// Check if the value can be called, which covers functions, native functions and closures
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_backend::bytecode::{Closure, Function, NativeFunction};
+    /// use slang_backend::value::Value;
+    ///
+    /// let function = Function { name: "f".to_string(), arity: 0, code_offset: 0, locals: Vec::new() };
+    /// let native = NativeFunction {
+    ///     name: "print_value".to_string(),
+    ///     arity: 1,
+    ///     is_variadic: false,
+    ///     function: slang_backend::native::print_value,
+    /// };
+    /// let closure = Closure { function: function.clone(), captures: Vec::new() };
+    ///
+    /// assert!(Value::Function(Box::new(function)).is_function());
+    /// assert!(Value::NativeFunction(Box::new(native)).is_function());
+    /// assert!(Value::Closure(Box::new(closure)).is_function());
+    /// assert!(!Value::Unit(()).is_function());
+    /// assert!(!Value::String(Box::new("f".to_string())).is_function());
+    /// ```
+    pub fn is_function(&self) -> bool {
+        matches!(self, Value::Function(_) | Value::NativeFunction(_) | Value::Closure(_))
+    }
+
+    /// Widens any integer value to an i64
+    ///
+    /// ### Returns
+    /// The value as an i64, or None if the value isn't an integer or exceeds the i64 range
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_backend::value::Value;
+    ///
+    /// assert_eq!(Value::I32(-5).as_i64(), Some(-5));
+    /// assert_eq!(Value::I64(i64::MIN).as_i64(), Some(i64::MIN));
+    /// assert_eq!(Value::U32(u32::MAX).as_i64(), Some(u32::MAX as i64));
+    /// assert_eq!(Value::U64(42).as_i64(), Some(42));
+    /// assert_eq!(Value::U64(u64::MAX).as_i64(), None);
+    /// assert_eq!(Value::I128(-7).as_i64(), Some(-7));
+    /// assert_eq!(Value::U128(u128::MAX).as_i64(), None);
+    /// assert_eq!(Value::F32(1.0).as_i64(), None);
+    /// assert_eq!(Value::F64(1.0).as_i64(), None);
+    /// assert_eq!(Value::String(Box::new("1".to_string())).as_i64(), None);
+    /// assert_eq!(Value::Boolean(true).as_i64(), None);
+    /// assert_eq!(Value::Unit(()).as_i64(), None);
+    /// ```
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::I32(value) => Some(i64::from(*value)),
+            Value::I64(value) => Some(*value),
+            Value::U32(value) => Some(i64::from(*value)),
+            Value::U64(value) => i64::try_from(*value).ok(),
+            Value::I128(value) => i64::try_from(*value).ok(),
+            Value::U128(value) => i64::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+
+    /// Compares two values for equality by their contents
+    ///
+    /// Strings are compared by their characters, functions and native functions by their
+    /// name and closures by their function and captured values. Floats follow IEEE 754, so
+    /// NaN is unequal to every value including itself and `0.0` equals `-0.0`. Values of
+    /// different variants are rejected by the type checker, so they are reported as
+    /// incomparable instead of unequal.
+    ///
+    /// ### Arguments
+    /// * `other` - The value to compare with
+    ///
+    /// ### Returns
+    /// Whether the values are equal, or None if the variants can't be compared
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_backend::bytecode::Function;
+    /// use slang_backend::value::Value;
+    ///
+    /// let hello = Value::String(Box::new("hello".to_string()));
+    /// assert_eq!(hello.deep_equal(&Value::String(Box::new("hello".to_string()))), Some(true));
+    /// assert_eq!(Value::I32(1).deep_equal(&Value::I32(2)), Some(false));
+    /// assert_eq!(Value::F64(f64::NAN).deep_equal(&Value::F64(f64::NAN)), Some(false));
+    /// assert_eq!(Value::F32(0.0).deep_equal(&Value::F32(-0.0)), Some(true));
+    ///
+    /// let function = Function { name: "f".to_string(), arity: 0, code_offset: 0, locals: Vec::new() };
+    /// assert_eq!(Value::Function(Box::new(function)).deep_equal(&hello), None);
+    /// assert_eq!(Value::I32(1).deep_equal(&Value::I64(1)), None);
+    /// ```
+    pub fn deep_equal(&self, other: &Value) -> Option<bool> {
+        match (self, other) {
+            (Value::I32(a), Value::I32(b)) => Some(a == b),
+            (Value::I64(a), Value::I64(b)) => Some(a == b),
+            (Value::U32(a), Value::U32(b)) => Some(a == b),
+            (Value::U64(a), Value::U64(b)) => Some(a == b),
+            (Value::I128(a), Value::I128(b)) => Some(a == b),
+            (Value::U128(a), Value::U128(b)) => Some(a == b),
+            (Value::F32(a), Value::F32(b)) => Some(a == b),
+            (Value::F64(a), Value::F64(b)) => Some(a == b),
+            (Value::Boolean(a), Value::Boolean(b)) => Some(a == b),
+            (Value::String(a), Value::String(b)) => Some(a == b),
+            (Value::Unit(_), Value::Unit(_)) => Some(true),
+            // Functions are identified by their name and the offset of their code
+            (Value::Function(a), Value::Function(b)) => {
+                Some(a.name == b.name && a.code_offset == b.code_offset)
+            }
+            (Value::NativeFunction(a), Value::NativeFunction(b)) => Some(a.name == b.name),
+            (Value::Closure(a), Value::Closure(b)) => Some(
+                a.function.code_offset == b.function.code_offset
+                    && a.captures.len() == b.captures.len()
+                    && a.captures
+                        .iter()
+                        .zip(&b.captures)
+                        .all(|((_, a), (_, b))| a.deep_equal(b) == Some(true)),
+            ),
+            _ => None,
+        }
+    }
 }
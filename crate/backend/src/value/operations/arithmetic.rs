@@ -58,6 +58,171 @@ pub trait ArithmeticOps {
     fn negate(&self) -> Result<Self, String>
     where
         Self: Sized;
+
+    /// Adds two values, wrapping around on integer overflow.
+    ///
+    /// ### Arguments
+    /// * `other` - The other value to add
+    ///
+    /// ### Returns
+    /// * The result of the addition
+    /// * An error message if the types are incompatible
+    fn wrapping_add(&self, other: &Self) -> Result<Self, String>
+    where
+        Self: Sized;
+
+    /// Subtracts one value from another, wrapping around on integer overflow.
+    ///
+    /// ### Arguments
+    /// * `other` - The value to subtract
+    ///
+    /// ### Returns
+    /// * The result of the subtraction
+    /// * An error message if the types are incompatible
+    fn wrapping_subtract(&self, other: &Self) -> Result<Self, String>
+    where
+        Self: Sized;
+
+    /// Multiplies two values, wrapping around on integer overflow.
+    ///
+    /// ### Arguments
+    /// * `other` - The other value to multiply
+    ///
+    /// ### Returns
+    /// * The result of the multiplication
+    /// * An error message if the types are incompatible
+    fn wrapping_multiply(&self, other: &Self) -> Result<Self, String>
+    where
+        Self: Sized;
+
+    /// Divides one value by another, wrapping around on integer overflow.
+    ///
+    /// ### Arguments
+    /// * `other` - The value to divide by
+    ///
+    /// ### Returns
+    /// * The result of the division
+    /// * An error message if the types are incompatible or if division by zero occurs
+    fn wrapping_divide(&self, other: &Self) -> Result<Self, String>
+    where
+        Self: Sized;
+
+    /// Negates a value, wrapping around on integer overflow.
+    ///
+    /// ### Returns
+    /// * The negated value
+    /// * An error message if the type is incompatible
+    fn wrapping_negate(&self) -> Result<Self, String>
+    where
+        Self: Sized;
+
+    /// Adds two values, clamping the result to the bounds of the integer type.
+    ///
+    /// ### Arguments
+    /// * `other` - The other value to add
+    ///
+    /// ### Returns
+    /// * The result of the addition
+    /// * An error message if the values aren't integers of the same type
+    fn saturating_add(&self, other: &Self) -> Result<Self, String>
+    where
+        Self: Sized;
+
+    /// Subtracts one value from another, clamping the result to the bounds of the integer type.
+    ///
+    /// ### Arguments
+    /// * `other` - The value to subtract
+    ///
+    /// ### Returns
+    /// * The result of the subtraction
+    /// * An error message if the values aren't integers of the same type
+    fn saturating_subtract(&self, other: &Self) -> Result<Self, String>
+    where
+        Self: Sized;
+
+    /// Multiplies two values, clamping the result to the bounds of the integer type.
+    ///
+    /// ### Arguments
+    /// * `other` - The other value to multiply
+    ///
+    /// ### Returns
+    /// * The result of the multiplication
+    /// * An error message if the values aren't integers of the same type
+    fn saturating_multiply(&self, other: &Self) -> Result<Self, String>
+    where
+        Self: Sized;
+
+    /// Computes the bitwise AND of two integer values.
+    ///
+    /// ### Arguments
+    /// * `other` - The other operand
+    ///
+    /// ### Returns
+    /// * The result of the bitwise AND
+    /// * An error message if the values aren't integers of the same type
+    fn bit_and(&self, other: &Self) -> Result<Self, String>
+    where
+        Self: Sized;
+
+    /// Computes the bitwise OR of two integer values.
+    ///
+    /// ### Arguments
+    /// * `other` - The other operand
+    ///
+    /// ### Returns
+    /// * The result of the bitwise OR
+    /// * An error message if the values aren't integers of the same type
+    fn bit_or(&self, other: &Self) -> Result<Self, String>
+    where
+        Self: Sized;
+
+    /// Computes the bitwise XOR of two integer values.
+    ///
+    /// ### Arguments
+    /// * `other` - The other operand
+    ///
+    /// ### Returns
+    /// * The result of the bitwise XOR
+    /// * An error message if the values aren't integers of the same type
+    fn bit_xor(&self, other: &Self) -> Result<Self, String>
+    where
+        Self: Sized;
+
+    /// Shifts an integer value to the left.
+    /// Shift amounts of at least the bit width of the value produce zero.
+    ///
+    /// ### Arguments
+    /// * `amount` - The number of bits to shift by
+    ///
+    /// ### Returns
+    /// * The shifted value
+    /// * An error message if the values aren't integers or the amount is negative
+    fn shift_left(&self, amount: &Self) -> Result<Self, String>
+    where
+        Self: Sized;
+
+    /// Shifts an integer value to the right, filling with the sign bit for signed integers.
+    /// Shift amounts of at least the bit width of the value produce zero, or -1 for
+    /// negative signed values.
+    ///
+    /// ### Arguments
+    /// * `amount` - The number of bits to shift by
+    ///
+    /// ### Returns
+    /// * The shifted value
+    /// * An error message if the values aren't integers or the amount is negative
+    fn shift_right(&self, amount: &Self) -> Result<Self, String>
+    where
+        Self: Sized;
+
+    /// Inverts all bits of an integer value.
+    ///
+    /// ### Returns
+    /// * The bitwise complement of the value
+    /// * An error message if the value isn't an integer
+    fn bit_not(&self) -> Result<Self, String>
+    where
+        Self: Sized;
 }
 
 impl ArithmeticOps for Value {
@@ -80,6 +245,14 @@ impl ArithmeticOps for Value {
                 Some(result) => Ok(Value::U64(result)),
                 None => Err("Integer overflow in U64 addition".to_string()),
             },
+            (Value::I128(a), Value::I128(b)) => match a.checked_add(*b) {
+                Some(result) => Ok(Value::I128(result)),
+                None => Err("Integer overflow in I128 addition".to_string()),
+            },
+            (Value::U128(a), Value::U128(b)) => match a.checked_add(*b) {
+                Some(result) => Ok(Value::U128(result)),
+                None => Err("Integer overflow in U128 addition".to_string()),
+            },
             // Float addition with overflow checking
             (Value::F32(a), Value::F32(b)) => {
                 let result = *a + *b;
@@ -123,6 +296,14 @@ impl ArithmeticOps for Value {
                 Some(result) => Ok(Value::U64(result)),
                 None => Err("Integer underflow in U64 subtraction".to_string()),
             },
+            (Value::I128(a), Value::I128(b)) => match a.checked_sub(*b) {
+                Some(result) => Ok(Value::I128(result)),
+                None => Err("Integer underflow in I128 subtraction".to_string()),
+            },
+            (Value::U128(a), Value::U128(b)) => match a.checked_sub(*b) {
+                Some(result) => Ok(Value::U128(result)),
+                None => Err("Integer underflow in U128 subtraction".to_string()),
+            },
             (Value::F32(a), Value::F32(b)) => {
                 let result = *a - *b;
                 if result.is_infinite() && !a.is_infinite() && !b.is_infinite() {
@@ -161,6 +342,14 @@ impl ArithmeticOps for Value {
                 Some(result) => Ok(Value::U64(result)),
                 None => Err("Integer overflow in U64 multiplication".to_string()),
             },
+            (Value::I128(a), Value::I128(b)) => match a.checked_mul(*b) {
+                Some(result) => Ok(Value::I128(result)),
+                None => Err("Integer overflow in I128 multiplication".to_string()),
+            },
+            (Value::U128(a), Value::U128(b)) => match a.checked_mul(*b) {
+                Some(result) => Ok(Value::U128(result)),
+                None => Err("Integer overflow in U128 multiplication".to_string()),
+            },
             (Value::F32(a), Value::F32(b)) => {
                 let result = *a * *b;
                 if result.is_infinite() && !a.is_infinite() && !b.is_infinite() {
@@ -225,6 +414,27 @@ impl ArithmeticOps for Value {
                     None => Err("Integer division error".to_string()),
                 }
             }
+            (Value::I128(a), Value::I128(b)) => {
+                if *b == 0 {
+                    return Err("Division by zero".to_string());
+                }
+                if *a == i128::MIN && *b == -1 {
+                    return Err("Integer overflow in I128 division".to_string());
+                }
+                match a.checked_div(*b) {
+                    Some(result) => Ok(Value::I128(result)),
+                    None => Err("Integer division error".to_string()),
+                }
+            }
+            (Value::U128(a), Value::U128(b)) => {
+                if *b == 0 {
+                    return Err("Division by zero".to_string());
+                }
+                match a.checked_div(*b) {
+                    Some(result) => Ok(Value::U128(result)),
+                    None => Err("Integer division error".to_string()),
+                }
+            }
             (Value::F32(a), Value::F32(b)) => {
                 if *b == 0.0 {
                     return Err("Division by zero".to_string());
@@ -267,9 +477,203 @@ impl ArithmeticOps for Value {
             }
             Value::U32(_) => Err("Cannot negate unsigned integer U32".to_string()),
             Value::U64(_) => Err("Cannot negate unsigned integer U64".to_string()),
+            Value::I128(i) => {
+                if *i == i128::MIN {
+                    return Err("Integer overflow in I128 negation".to_string());
+                }
+                Ok(Value::I128(-i))
+            }
+            Value::U128(_) => Err("Cannot negate unsigned integer U128".to_string()),
             Value::F32(f) => Ok(Value::F32(-f)),
             Value::F64(f) => Ok(Value::F64(-f)),
             _ => Err("Can only negate numbers".to_string()),
         }
     }
+
+    fn wrapping_add(&self, other: &Self) -> Result<Value, String> {
+        match (self, other) {
+            (Value::I32(a), Value::I32(b)) => Ok(Value::I32(a.wrapping_add(*b))),
+            (Value::I64(a), Value::I64(b)) => Ok(Value::I64(a.wrapping_add(*b))),
+            (Value::U32(a), Value::U32(b)) => Ok(Value::U32(a.wrapping_add(*b))),
+            (Value::U64(a), Value::U64(b)) => Ok(Value::U64(a.wrapping_add(*b))),
+            (Value::I128(a), Value::I128(b)) => Ok(Value::I128(a.wrapping_add(*b))),
+            (Value::U128(a), Value::U128(b)) => Ok(Value::U128(a.wrapping_add(*b))),
+            _ => self.add(other),
+        }
+    }
+
+    fn wrapping_subtract(&self, other: &Self) -> Result<Value, String> {
+        match (self, other) {
+            (Value::I32(a), Value::I32(b)) => Ok(Value::I32(a.wrapping_sub(*b))),
+            (Value::I64(a), Value::I64(b)) => Ok(Value::I64(a.wrapping_sub(*b))),
+            (Value::U32(a), Value::U32(b)) => Ok(Value::U32(a.wrapping_sub(*b))),
+            (Value::U64(a), Value::U64(b)) => Ok(Value::U64(a.wrapping_sub(*b))),
+            (Value::I128(a), Value::I128(b)) => Ok(Value::I128(a.wrapping_sub(*b))),
+            (Value::U128(a), Value::U128(b)) => Ok(Value::U128(a.wrapping_sub(*b))),
+            _ => self.subtract(other),
+        }
+    }
+
+    fn wrapping_multiply(&self, other: &Self) -> Result<Value, String> {
+        match (self, other) {
+            (Value::I32(a), Value::I32(b)) => Ok(Value::I32(a.wrapping_mul(*b))),
+            (Value::I64(a), Value::I64(b)) => Ok(Value::I64(a.wrapping_mul(*b))),
+            (Value::U32(a), Value::U32(b)) => Ok(Value::U32(a.wrapping_mul(*b))),
+            (Value::U64(a), Value::U64(b)) => Ok(Value::U64(a.wrapping_mul(*b))),
+            (Value::I128(a), Value::I128(b)) => Ok(Value::I128(a.wrapping_mul(*b))),
+            (Value::U128(a), Value::U128(b)) => Ok(Value::U128(a.wrapping_mul(*b))),
+            _ => self.multiply(other),
+        }
+    }
+
+    fn wrapping_divide(&self, other: &Self) -> Result<Value, String> {
+        match (self, other) {
+            (Value::I32(a), Value::I32(b)) if *b != 0 => Ok(Value::I32(a.wrapping_div(*b))),
+            (Value::I64(a), Value::I64(b)) if *b != 0 => Ok(Value::I64(a.wrapping_div(*b))),
+            (Value::I128(a), Value::I128(b)) if *b != 0 => Ok(Value::I128(a.wrapping_div(*b))),
+            _ => self.divide(other),
+        }
+    }
+
+    fn wrapping_negate(&self) -> Result<Value, String> {
+        match self {
+            Value::I32(i) => Ok(Value::I32(i.wrapping_neg())),
+            Value::I64(i) => Ok(Value::I64(i.wrapping_neg())),
+            Value::I128(i) => Ok(Value::I128(i.wrapping_neg())),
+            _ => self.negate(),
+        }
+    }
+
+    fn saturating_add(&self, other: &Self) -> Result<Value, String> {
+        match (self, other) {
+            (Value::I32(a), Value::I32(b)) => Ok(Value::I32(a.saturating_add(*b))),
+            (Value::I64(a), Value::I64(b)) => Ok(Value::I64(a.saturating_add(*b))),
+            (Value::U32(a), Value::U32(b)) => Ok(Value::U32(a.saturating_add(*b))),
+            (Value::U64(a), Value::U64(b)) => Ok(Value::U64(a.saturating_add(*b))),
+            (Value::I128(a), Value::I128(b)) => Ok(Value::I128(a.saturating_add(*b))),
+            (Value::U128(a), Value::U128(b)) => Ok(Value::U128(a.saturating_add(*b))),
+            _ => Err("Saturating arithmetic requires integers of the same type".to_string()),
+        }
+    }
+
+    fn saturating_subtract(&self, other: &Self) -> Result<Value, String> {
+        match (self, other) {
+            (Value::I32(a), Value::I32(b)) => Ok(Value::I32(a.saturating_sub(*b))),
+            (Value::I64(a), Value::I64(b)) => Ok(Value::I64(a.saturating_sub(*b))),
+            (Value::U32(a), Value::U32(b)) => Ok(Value::U32(a.saturating_sub(*b))),
+            (Value::U64(a), Value::U64(b)) => Ok(Value::U64(a.saturating_sub(*b))),
+            (Value::I128(a), Value::I128(b)) => Ok(Value::I128(a.saturating_sub(*b))),
+            (Value::U128(a), Value::U128(b)) => Ok(Value::U128(a.saturating_sub(*b))),
+            _ => Err("Saturating arithmetic requires integers of the same type".to_string()),
+        }
+    }
+
+    fn saturating_multiply(&self, other: &Self) -> Result<Value, String> {
+        match (self, other) {
+            (Value::I32(a), Value::I32(b)) => Ok(Value::I32(a.saturating_mul(*b))),
+            (Value::I64(a), Value::I64(b)) => Ok(Value::I64(a.saturating_mul(*b))),
+            (Value::U32(a), Value::U32(b)) => Ok(Value::U32(a.saturating_mul(*b))),
+            (Value::U64(a), Value::U64(b)) => Ok(Value::U64(a.saturating_mul(*b))),
+            (Value::I128(a), Value::I128(b)) => Ok(Value::I128(a.saturating_mul(*b))),
+            (Value::U128(a), Value::U128(b)) => Ok(Value::U128(a.saturating_mul(*b))),
+            _ => Err("Saturating arithmetic requires integers of the same type".to_string()),
+        }
+    }
+
+    fn bit_and(&self, other: &Self) -> Result<Value, String> {
+        match (self, other) {
+            (Value::I32(a), Value::I32(b)) => Ok(Value::I32(a & b)),
+            (Value::I64(a), Value::I64(b)) => Ok(Value::I64(a & b)),
+            (Value::U32(a), Value::U32(b)) => Ok(Value::U32(a & b)),
+            (Value::U64(a), Value::U64(b)) => Ok(Value::U64(a & b)),
+            (Value::I128(a), Value::I128(b)) => Ok(Value::I128(a & b)),
+            (Value::U128(a), Value::U128(b)) => Ok(Value::U128(a & b)),
+            _ => Err("Cannot apply bitwise AND to these types".to_string()),
+        }
+    }
+
+    fn bit_or(&self, other: &Self) -> Result<Value, String> {
+        match (self, other) {
+            (Value::I32(a), Value::I32(b)) => Ok(Value::I32(a | b)),
+            (Value::I64(a), Value::I64(b)) => Ok(Value::I64(a | b)),
+            (Value::U32(a), Value::U32(b)) => Ok(Value::U32(a | b)),
+            (Value::U64(a), Value::U64(b)) => Ok(Value::U64(a | b)),
+            (Value::I128(a), Value::I128(b)) => Ok(Value::I128(a | b)),
+            (Value::U128(a), Value::U128(b)) => Ok(Value::U128(a | b)),
+            _ => Err("Cannot apply bitwise OR to these types".to_string()),
+        }
+    }
+
+    fn bit_xor(&self, other: &Self) -> Result<Value, String> {
+        match (self, other) {
+            (Value::I32(a), Value::I32(b)) => Ok(Value::I32(a ^ b)),
+            (Value::I64(a), Value::I64(b)) => Ok(Value::I64(a ^ b)),
+            (Value::U32(a), Value::U32(b)) => Ok(Value::U32(a ^ b)),
+            (Value::U64(a), Value::U64(b)) => Ok(Value::U64(a ^ b)),
+            (Value::I128(a), Value::I128(b)) => Ok(Value::I128(a ^ b)),
+            (Value::U128(a), Value::U128(b)) => Ok(Value::U128(a ^ b)),
+            _ => Err("Cannot apply bitwise XOR to these types".to_string()),
+        }
+    }
+
+    fn bit_not(&self) -> Result<Value, String> {
+        match self {
+            Value::I32(i) => Ok(Value::I32(!i)),
+            Value::I64(i) => Ok(Value::I64(!i)),
+            Value::U32(i) => Ok(Value::U32(!i)),
+            Value::U64(i) => Ok(Value::U64(!i)),
+            Value::I128(i) => Ok(Value::I128(!i)),
+            Value::U128(i) => Ok(Value::U128(!i)),
+            _ => Err("Can only apply bitwise NOT to integers".to_string()),
+        }
+    }
+
+    fn shift_left(&self, amount: &Self) -> Result<Value, String> {
+        let amount = shift_amount(amount)?;
+        match self {
+            Value::I32(a) => Ok(Value::I32(a.checked_shl(amount).unwrap_or(0))),
+            Value::I64(a) => Ok(Value::I64(a.checked_shl(amount).unwrap_or(0))),
+            Value::U32(a) => Ok(Value::U32(a.checked_shl(amount).unwrap_or(0))),
+            Value::U64(a) => Ok(Value::U64(a.checked_shl(amount).unwrap_or(0))),
+            Value::I128(a) => Ok(Value::I128(a.checked_shl(amount).unwrap_or(0))),
+            Value::U128(a) => Ok(Value::U128(a.checked_shl(amount).unwrap_or(0))),
+            _ => Err("Can only shift integers".to_string()),
+        }
+    }
+
+    fn shift_right(&self, amount: &Self) -> Result<Value, String> {
+        let amount = shift_amount(amount)?;
+        match self {
+            Value::I32(a) => Ok(Value::I32(a >> amount.min(i32::BITS - 1))),
+            Value::I64(a) => Ok(Value::I64(a >> amount.min(i64::BITS - 1))),
+            Value::U32(a) => Ok(Value::U32(a.checked_shr(amount).unwrap_or(0))),
+            Value::U64(a) => Ok(Value::U64(a.checked_shr(amount).unwrap_or(0))),
+            Value::I128(a) => Ok(Value::I128(a >> amount.min(i128::BITS - 1))),
+            Value::U128(a) => Ok(Value::U128(a.checked_shr(amount).unwrap_or(0))),
+            _ => Err("Can only shift integers".to_string()),
+        }
+    }
+}
+
+/// Converts the right operand of a shift operation into a shift amount.
+/// Amounts that don't fit into a u32 are clamped, as they shift out all bits anyway.
+///
+/// ### Arguments
+/// * `amount` - The shift amount value
+///
+/// ### Returns
+/// * The shift amount
+/// * An error message if the amount is negative or not an integer
+fn shift_amount(amount: &Value) -> Result<u32, String> {
+    let amount = match amount.as_i64() {
+        Some(n) => n,
+        // Integers beyond the i64 range only fail if they are negative
+        None if matches!(amount, Value::I128(n) if *n < 0) => i64::MIN,
+        None if amount.is_integer() => i64::MAX,
+        None => return Err("Shift amount must be an integer".to_string()),
+    };
+    if amount < 0 {
+        return Err(format!("Negative shift amount {}", amount));
+    }
+    Ok(u32::try_from(amount).unwrap_or(u32::MAX))
 }
@@ -77,47 +77,15 @@ pub trait ComparisonOps {
 
 impl ComparisonOps for Value {
     fn equal(&self, other: &Self) -> Result<Value, String> {
-        match (self, other) {
-            (Value::I32(a), Value::I32(b)) => Ok(Value::Boolean(a == b)),
-            (Value::I64(a), Value::I64(b)) => Ok(Value::Boolean(a == b)),
-            (Value::U32(a), Value::U32(b)) => Ok(Value::Boolean(a == b)),
-            (Value::U64(a), Value::U64(b)) => Ok(Value::Boolean(a == b)),
-            (Value::F32(a), Value::F32(b)) => Ok(Value::Boolean(a == b)),
-            (Value::F64(a), Value::F64(b)) => Ok(Value::Boolean(a == b)),
-            (Value::Boolean(a), Value::Boolean(b)) => Ok(Value::Boolean(a == b)),
-            (Value::String(a), Value::String(b)) => Ok(Value::Boolean(a == b)),
-            (Value::Function(a), Value::Function(b)) => {
-                // Functions are equal if they have the same name and code offset
-                Ok(Value::Boolean(a.name == b.name && a.code_offset == b.code_offset))
-            },
-            (Value::NativeFunction(a), Value::NativeFunction(b)) => {
-                // Native functions are equal if they have the same name
-                Ok(Value::Boolean(a.name == b.name))
-            },
-            _ => Err("Cannot compare these types with ==".to_string()),
-        }
+        self.deep_equal(other)
+            .map(Value::Boolean)
+            .ok_or_else(|| "Cannot compare these types with ==".to_string())
     }
 
     fn not_equal(&self, other: &Self) -> Result<Value, String> {
-        match (self, other) {
-            (Value::I32(a), Value::I32(b)) => Ok(Value::Boolean(a != b)),
-            (Value::I64(a), Value::I64(b)) => Ok(Value::Boolean(a != b)),
-            (Value::U32(a), Value::U32(b)) => Ok(Value::Boolean(a != b)),
-            (Value::U64(a), Value::U64(b)) => Ok(Value::Boolean(a != b)),
-            (Value::F32(a), Value::F32(b)) => Ok(Value::Boolean(a != b)),
-            (Value::F64(a), Value::F64(b)) => Ok(Value::Boolean(a != b)),
-            (Value::Boolean(a), Value::Boolean(b)) => Ok(Value::Boolean(a != b)),
-            (Value::String(a), Value::String(b)) => Ok(Value::Boolean(a != b)),
-            (Value::Function(a), Value::Function(b)) => {
-                // Functions are not equal if they have different names or code offsets
-                Ok(Value::Boolean(a.name != b.name || a.code_offset != b.code_offset))
-            },
-            (Value::NativeFunction(a), Value::NativeFunction(b)) => {
-                // Native functions are not equal if they have different names
-                Ok(Value::Boolean(a.name != b.name))
-            },
-            _ => Err("Cannot compare these types with !=".to_string()),
-        }
+        self.deep_equal(other)
+            .map(|equal| Value::Boolean(!equal))
+            .ok_or_else(|| "Cannot compare these types with !=".to_string())
     }
 
     fn less_than(&self, other: &Self) -> Result<Value, String> {
@@ -126,8 +94,11 @@ impl ComparisonOps for Value {
             (Value::I64(a), Value::I64(b)) => Ok(Value::Boolean(a < b)),
             (Value::U32(a), Value::U32(b)) => Ok(Value::Boolean(a < b)),
             (Value::U64(a), Value::U64(b)) => Ok(Value::Boolean(a < b)),
+            (Value::I128(a), Value::I128(b)) => Ok(Value::Boolean(a < b)),
+            (Value::U128(a), Value::U128(b)) => Ok(Value::Boolean(a < b)),
             (Value::F32(a), Value::F32(b)) => Ok(Value::Boolean(a < b)),
             (Value::F64(a), Value::F64(b)) => Ok(Value::Boolean(a < b)),
+            (Value::String(a), Value::String(b)) => Ok(Value::Boolean(a < b)),
             _ => Err("Cannot compare these types with <".to_string()),
         }
     }
@@ -138,8 +109,11 @@ impl ComparisonOps for Value {
             (Value::I64(a), Value::I64(b)) => Ok(Value::Boolean(a <= b)),
             (Value::U32(a), Value::U32(b)) => Ok(Value::Boolean(a <= b)),
             (Value::U64(a), Value::U64(b)) => Ok(Value::Boolean(a <= b)),
+            (Value::I128(a), Value::I128(b)) => Ok(Value::Boolean(a <= b)),
+            (Value::U128(a), Value::U128(b)) => Ok(Value::Boolean(a <= b)),
             (Value::F32(a), Value::F32(b)) => Ok(Value::Boolean(a <= b)),
             (Value::F64(a), Value::F64(b)) => Ok(Value::Boolean(a <= b)),
+            (Value::String(a), Value::String(b)) => Ok(Value::Boolean(a <= b)),
             _ => Err("Cannot compare these types with <=".to_string()),
         }
     }
@@ -150,8 +124,11 @@ impl ComparisonOps for Value {
             (Value::I64(a), Value::I64(b)) => Ok(Value::Boolean(a > b)),
             (Value::U32(a), Value::U32(b)) => Ok(Value::Boolean(a > b)),
             (Value::U64(a), Value::U64(b)) => Ok(Value::Boolean(a > b)),
+            (Value::I128(a), Value::I128(b)) => Ok(Value::Boolean(a > b)),
+            (Value::U128(a), Value::U128(b)) => Ok(Value::Boolean(a > b)),
             (Value::F32(a), Value::F32(b)) => Ok(Value::Boolean(a > b)),
             (Value::F64(a), Value::F64(b)) => Ok(Value::Boolean(a > b)),
+            (Value::String(a), Value::String(b)) => Ok(Value::Boolean(a > b)),
             _ => Err("Cannot compare these types with >".to_string()),
         }
     }
@@ -162,8 +139,11 @@ impl ComparisonOps for Value {
             (Value::I64(a), Value::I64(b)) => Ok(Value::Boolean(a >= b)),
             (Value::U32(a), Value::U32(b)) => Ok(Value::Boolean(a >= b)),
             (Value::U64(a), Value::U64(b)) => Ok(Value::Boolean(a >= b)),
+            (Value::I128(a), Value::I128(b)) => Ok(Value::Boolean(a >= b)),
+            (Value::U128(a), Value::U128(b)) => Ok(Value::Boolean(a >= b)),
             (Value::F32(a), Value::F32(b)) => Ok(Value::Boolean(a >= b)),
             (Value::F64(a), Value::F64(b)) => Ok(Value::Boolean(a >= b)),
+            (Value::String(a), Value::String(b)) => Ok(Value::Boolean(a >= b)),
             _ => Err("Cannot compare these types with >=".to_string()),
         }
     }
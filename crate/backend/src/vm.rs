@@ -1,7 +1,8 @@
-use crate::bytecode::{Chunk, NativeFunction, OpCode};
+use crate::bytecode::{Chunk, Closure, Function, NativeFn, NativeFunction, OpCode};
 use crate::value::{Value, ArithmeticOps, LogicalOps, ComparisonOps};
-use crate::native;
+use crate::native::{self, NativeContext};
 use std::collections::HashMap;
+use std::io::{self, Write};
 
 /// Represents a single scope with its variables
 struct Scope {
@@ -10,28 +11,51 @@ struct Scope {
 
 /// Call frame to track function calls
 struct CallFrame {
-    /// Parameter names for the function (for local variable checking)
-    param_names: Vec<String>,
     /// Address to return to after function completes
     return_address: usize,
     /// Stack position before function call
     stack_offset: usize,
-    /// Local variables for the function
-    locals: HashMap<String, Value>,
+    /// Local variable slots of the function, starting with the parameters and captures
+    slots: Vec<Value>,
+    /// Number of scopes that were active when the function was called
+    scope_depth: usize,
 }
 
+/// Determines how integer arithmetic behaves when a result doesn't fit its type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowMode {
+    /// Overflow halts execution with a runtime error
+    #[default]
+    Checked,
+    /// Overflow wraps around using two's complement arithmetic
+    Wrapping,
+}
+
+/// Default maximum number of nested function calls before execution is halted
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 10_000;
+
 /// Virtual Machine that executes bytecode
-pub struct VM {
+pub struct VM<'a> {
     /// Instruction pointer
     ip: usize,
     /// Stack for values
     stack: Vec<Value>,
     /// Stack of scopes, with global scope at index 0
     scopes: Vec<Scope>,
+    /// Local variable slots of code running outside of any function
+    slots: Vec<Value>,
     /// Call frames for function calls
     frames: Vec<CallFrame>,
     /// Index of the current call frame
     current_frame: Option<usize>,
+    /// How integer overflow is handled by arithmetic instructions
+    overflow_mode: OverflowMode,
+    /// Maximum number of nested function calls
+    max_call_depth: usize,
+    /// Command line arguments passed to the program
+    program_args: Vec<String>,
+    /// Sink that printed output is written to
+    output: Box<dyn Write + 'a>,
 }
 
 
@@ -50,29 +74,119 @@ pub fn execute_bytecode(chunk: &Chunk) -> Result<(), String> {
 }
 
 
-impl Default for VM {
+impl Default for VM<'_> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl VM {
-    /// Creates a new virtual machine
+impl<'a> VM<'a> {
+    /// Creates a new virtual machine that prints to stdout
     pub fn new() -> Self {
+        Self::with_output(Box::new(io::stdout()))
+    }
+
+    /// Creates a new virtual machine that prints to the given writer
+    ///
+    /// ### Arguments
+    ///
+    /// * `output` - The sink that printed output is written to
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_backend::bytecode::{Chunk, OpCode};
+    /// use slang_backend::value::Value;
+    /// use slang_backend::vm::VM;
+    ///
+    /// let mut chunk = Chunk::new();
+    /// let constant = chunk.add_constant(Value::I32(42));
+    /// chunk.write_op(OpCode::Constant, 1);
+    /// chunk.write_byte(constant as u8, 1);
+    /// chunk.write_op(OpCode::Print, 1);
+    ///
+    /// let mut output = Vec::new();
+    /// VM::with_output(Box::new(&mut output)).interpret(&chunk).unwrap();
+    /// assert_eq!(String::from_utf8(output).unwrap(), "42\n");
+    /// ```
+    pub fn with_output(output: Box<dyn Write + 'a>) -> Self {
         let mut vm = VM {
             ip: 0,
             stack: Vec::new(),
             scopes: vec![Scope { variables: HashMap::new() }], // Global scope
+            slots: Vec::new(),
             frames: Vec::new(),
             current_frame: None,
+            overflow_mode: OverflowMode::default(),
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            program_args: Vec::new(),
+            output,
         };
         vm.register_native_functions();
         vm
     }
 
+    /// Sets how integer overflow is handled by arithmetic instructions
+    ///
+    /// ### Arguments
+    ///
+    /// * `mode` - The overflow mode to use
+    pub fn set_overflow_mode(&mut self, mode: OverflowMode) {
+        self.overflow_mode = mode;
+    }
+
+    /// Returns how integer overflow is handled by arithmetic instructions
+    pub fn overflow_mode(&self) -> OverflowMode {
+        self.overflow_mode
+    }
+
+    /// Sets the maximum number of nested function calls
+    ///
+    /// Exceeding the limit halts execution with a runtime error instead of growing
+    /// the call stack without bounds. Tail calls reuse their frame and don't count.
+    ///
+    /// ### Arguments
+    ///
+    /// * `depth` - The maximum number of active call frames
+    pub fn set_max_call_depth(&mut self, depth: usize) {
+        self.max_call_depth = depth;
+    }
+
+    /// Returns the maximum number of nested function calls
+    pub fn max_call_depth(&self) -> usize {
+        self.max_call_depth
+    }
+
+    /// Sets the command line arguments that are available to the program
+    ///
+    /// The program reads them with the `arg_count` and `arg` native functions.
+    ///
+    /// ### Arguments
+    ///
+    /// * `args` - The arguments passed to the program
+    pub fn set_program_args(&mut self, args: Vec<String>) {
+        self.program_args = args;
+    }
+
+    /// Returns the command line arguments that are available to the program
+    pub fn program_args(&self) -> &[String] {
+        &self.program_args
+    }
+
     /// Registers built-in functions
     fn register_native_functions(&mut self) {
-        self.define_native("print_value", 1, native::print_value);
+        self.define_native("print_value", 1, false, native::print_value);
+        self.define_native("print", 1, true, native::print);
+        self.define_native("wrapping_add", 2, false, native::wrapping_add);
+        self.define_native("wrapping_sub", 2, false, native::wrapping_sub);
+        self.define_native("wrapping_mul", 2, false, native::wrapping_mul);
+        self.define_native("saturating_add", 2, false, native::saturating_add);
+        self.define_native("saturating_sub", 2, false, native::saturating_sub);
+        self.define_native("saturating_mul", 2, false, native::saturating_mul);
+        self.define_native("pow", 2, false, native::pow);
+        self.define_native("arg_count", 0, false, native::arg_count);
+        self.define_native("arg", 1, false, native::arg);
+        self.define_native("len", 1, false, native::len);
+        self.define_native("char_at", 2, false, native::char_at);
     }
 
     /// Defines a native (built-in) function
@@ -81,16 +195,19 @@ impl VM {
     ///
     /// * `name` - Name of the native function
     /// * `arity` - Number of parameters
+    /// * `is_variadic` - Whether the function accepts additional arguments after its parameters
     /// * `function` - The Rust function implementing this native function
     fn define_native(
         &mut self,
         name: &str,
         arity: u8,
-        function: fn(&[Value]) -> Result<Value, String>,
+        is_variadic: bool,
+        function: NativeFn,
     ) {
         let native_fn = Value::NativeFunction(Box::new(NativeFunction {
             name: name.to_string(),
             arity,
+            is_variadic,
             function,
         }));
 
@@ -108,9 +225,113 @@ impl VM {
     ///
     /// Ok(()) on success, or an error message on failure
     pub fn interpret(&mut self, chunk: &Chunk) -> Result<(), String> {
+        self.run(chunk, None)
+    }
+
+    /// Interprets a bytecode chunk, halting after a maximum number of instructions
+    ///
+    /// This allows running untrusted code that might never terminate. Exceeding the limit
+    /// is a runtime error that leaves the VM in the same state as any other runtime error.
+    ///
+    /// ### Arguments
+    ///
+    /// * `chunk` - The bytecode chunk to execute
+    /// * `max_steps` - The maximum number of instructions to execute
+    ///
+    /// ### Returns
+    ///
+    /// Ok(()) on success, or an error message on failure
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_backend::bytecode::{Chunk, OpCode};
+    /// use slang_backend::vm::VM;
+    ///
+    /// // A loop that jumps back to itself forever
+    /// let mut chunk = Chunk::new();
+    /// chunk.write_op(OpCode::Loop, 1);
+    /// chunk.write_byte(0, 1);
+    /// chunk.write_byte(3, 1);
+    ///
+    /// let mut vm = VM::new();
+    /// let error = vm.run_with_limit(&chunk, 1000).unwrap_err();
+    /// assert_eq!(error, "Step limit of 1000 exceeded at line 1 (offset 0)");
+    ///
+    /// // The VM can still run other code afterwards
+    /// assert!(vm.run_with_limit(&Chunk::new(), 1000).is_ok());
+    /// ```
+    pub fn run_with_limit(&mut self, chunk: &Chunk, max_steps: usize) -> Result<(), String> {
+        self.run(chunk, Some(max_steps))
+    }
+
+    /// Executes the instructions of a chunk until its end is reached
+    ///
+    /// ### Arguments
+    ///
+    /// * `chunk` - The bytecode chunk to execute
+    /// * `max_steps` - The maximum number of instructions to execute, if limited
+    ///
+    /// ### Returns
+    ///
+    /// Ok(()) on success, or an error message on failure
+    fn run(&mut self, chunk: &Chunk, max_steps: Option<usize>) -> Result<(), String> {
         self.ip = 0;
+        self.resume(chunk, max_steps)
+    }
+
+    /// Calls a global function without arguments after its chunk has been interpreted
+    ///
+    /// This is used to invoke the entry point of a program once its top-level
+    /// statements have defined all functions.
+    ///
+    /// ### Arguments
+    ///
+    /// * `chunk` - The bytecode chunk that defined the function
+    /// * `name` - The name of the global function
+    ///
+    /// ### Returns
+    ///
+    /// The value returned by the function, or an error message on failure
+    pub fn call_global(&mut self, chunk: &Chunk, name: &str) -> Result<Value, String> {
+        let function = self.lookup_variable(name)?;
+        self.stack.push(function);
+
+        // The function returns to the end of the chunk, which stops execution
+        self.ip = chunk.code.len();
+        if let Err(message) = self.call_value(0, false) {
+            self.reset_call_state();
+            return Err(message);
+        }
+        self.resume(chunk, None)?;
+        self.pop()
+    }
+
+    /// Executes the instructions of a chunk from the current instruction pointer on
+    ///
+    /// ### Arguments
+    ///
+    /// * `chunk` - The bytecode chunk to execute
+    /// * `max_steps` - The maximum number of instructions to execute, if limited
+    ///
+    /// ### Returns
+    ///
+    /// Ok(()) on success, or an error message on failure
+    fn resume(&mut self, chunk: &Chunk, max_steps: Option<usize>) -> Result<(), String> {
+        let mut steps = 0;
         while self.ip < chunk.code.len() {
-            self.execute_instruction(chunk)?;
+            let offset = self.ip;
+            if let Some(max_steps) = max_steps {
+                if steps == max_steps {
+                    self.reset_call_state();
+                    let message = format!("Step limit of {} exceeded", max_steps);
+                    return Err(Self::locate_error(chunk, offset, message));
+                }
+                steps += 1;
+            }
+            if let Err(message) = self.execute_instruction(chunk) {
+                self.reset_call_state();
+                return Err(Self::locate_error(chunk, offset, message));
+            }
         }
 
         #[cfg(feature = "trace-execution")]
@@ -126,6 +347,17 @@ impl VM {
         Ok(())
     }
 
+    /// Discards all call frames, temporaries and local scopes after a runtime error
+    ///
+    /// Global variables are kept, so the VM can be used to interpret another chunk.
+    fn reset_call_state(&mut self) {
+        self.stack.clear();
+        self.slots.clear();
+        self.frames.clear();
+        self.current_frame = None;
+        self.scopes.truncate(1);
+    }
+
     /// Executes a single instruction
     ///
     /// ### Arguments
@@ -149,21 +381,29 @@ impl VM {
                 let constant = &chunk.constants[constant_idx];
                 self.stack.push(constant.clone());
             }
-            OpCode::Add => {
-                self.binary_op(|a, b| a.add(b))?;
-            }
-            OpCode::Subtract => {
-                self.binary_op(|a, b| a.subtract(b))?;
-            }
-            OpCode::Multiply => {
-                self.binary_op(|a, b| a.multiply(b))?;
-            }
-            OpCode::Divide => {
-                self.binary_op(|a, b| a.divide(b))?;
-            }
+            OpCode::Add => match self.overflow_mode {
+                OverflowMode::Checked => self.binary_op(|a, b| a.add(b))?,
+                OverflowMode::Wrapping => self.binary_op(|a, b| a.wrapping_add(b))?,
+            },
+            OpCode::Subtract => match self.overflow_mode {
+                OverflowMode::Checked => self.binary_op(|a, b| a.subtract(b))?,
+                OverflowMode::Wrapping => self.binary_op(|a, b| a.wrapping_subtract(b))?,
+            },
+            OpCode::Multiply => match self.overflow_mode {
+                OverflowMode::Checked => self.binary_op(|a, b| a.multiply(b))?,
+                OverflowMode::Wrapping => self.binary_op(|a, b| a.wrapping_multiply(b))?,
+            },
+            OpCode::Divide => match self.overflow_mode {
+                OverflowMode::Checked => self.binary_op(|a, b| a.divide(b))?,
+                OverflowMode::Wrapping => self.binary_op(|a, b| a.wrapping_divide(b))?,
+            },
             OpCode::Negate => {
                 let value = self.pop()?;
-                self.stack.push(value.negate()?);
+                let result = match self.overflow_mode {
+                    OverflowMode::Checked => value.negate()?,
+                    OverflowMode::Wrapping => value.wrapping_negate()?,
+                };
+                self.stack.push(result);
             }
             OpCode::Return => {
                 if let Some(frame_index) = self.current_frame {
@@ -176,10 +416,12 @@ impl VM {
                     let frame = &self.frames[frame_index];
                     let return_address = frame.return_address;
                     let stack_offset = frame.stack_offset;
+                    let scope_depth = frame.scope_depth;
 
                     while self.stack.len() > stack_offset {
                         self.pop()?;
                     }
+                    self.scopes.truncate(scope_depth);
 
                     self.stack.push(return_value);
 
@@ -197,7 +439,8 @@ impl VM {
             }
             OpCode::Print => {
                 let value = self.pop()?;
-                println!("{}", value);
+                writeln!(self.output, "{}", value)
+                    .map_err(|e| format!("Failed to print: {}", e))?;
             }
             OpCode::GetVariable => {
                 let var_index = self.read_byte(chunk) as usize;
@@ -206,20 +449,7 @@ impl VM {
                 }
                 let var_name = &chunk.identifiers[var_index];
 
-                let value = if let Some(frame_idx) = self.current_frame {
-                    if let Some(value) = self.frames[frame_idx].locals.get(var_name) {
-                        value.clone()
-                    } else if let Some(value) = self.get_variable(var_name) {
-                        value.clone()
-                    } else {
-                        return Err(format!("Undefined variable '{}'", var_name));
-                    }
-                } else if let Some(value) = self.get_variable(var_name) {
-                    value.clone()
-                } else {
-                    return Err(format!("Undefined variable '{}'", var_name));
-                };
-
+                let value = self.lookup_variable(var_name)?;
                 self.stack.push(value);
             }
             OpCode::SetVariable => {
@@ -233,15 +463,26 @@ impl VM {
                 let var_name = chunk.identifiers[var_index].clone();
                 let value = self.stack.last().unwrap().clone();
 
-                if let Some(frame_idx) = self.current_frame {
-                    if self.frames[frame_idx].param_names.contains(&var_name) {
-                        self.frames[frame_idx].locals.insert(var_name, value);
-                    } else {
-                        self.set_variable(var_name, value);
-                    }
-                } else {
-                    self.set_variable(var_name, value);
+                // Locals live in slots, so a named variable is always a global
+                self.scopes[0].variables.insert(var_name, value);
+            }
+            OpCode::GetLocal => {
+                let slot = self.read_byte(chunk) as usize;
+                let value = self
+                    .current_slots()
+                    .get(slot)
+                    .cloned()
+                    .ok_or_else(|| format!("Invalid local slot {}", slot))?;
+                self.stack.push(value);
+            }
+            OpCode::SetLocal => {
+                let slot = self.read_byte(chunk) as usize;
+                let value = self.peek(0)?.clone();
+                let slots = self.current_slots_mut();
+                if slot >= slots.len() {
+                    slots.resize(slot + 1, Value::Unit(()));
                 }
+                slots[slot] = value;
             }
             OpCode::Pop => {
                 self.pop()?;
@@ -261,78 +502,22 @@ impl VM {
             }
             OpCode::Call => {
                 let arg_count = self.read_byte(chunk) as usize;
-
-                if self.stack.len() < arg_count + 1 {
-                    return Err("Stack underflow during function call".to_string());
-                }
-
-                let function_pos = self.stack.len() - 1;
-                let function_value = self.stack[function_pos].clone();
-
-                match function_value {
-                    Value::Function(func) => {
-                        if arg_count != func.arity as usize {
-                            return Err(format!(
-                                "Expected {} arguments but got {}",
-                                func.arity, arg_count
-                            ));
-                        }
-
-                        let mut locals = HashMap::new();
-
-                        for i in 0..arg_count {
-                            if i < func.locals.len() {
-                                let param_name = &func.locals[i];
-                                let arg_value = self.stack[function_pos - arg_count + i].clone();
-                                locals.insert(param_name.clone(), arg_value);
-                            }
-                        }
-
-                        let frame = CallFrame {
-                            param_names: func.locals.clone(),
-                            return_address: self.ip,
-                            stack_offset: function_pos - arg_count,
-                            locals,
-                        };
-
-                        // Remove function and arguments from stack
-                        for _ in 0..=arg_count {
-                            self.pop()?;
-                        }
-
-                        self.frames.push(frame);
-                        self.current_frame = Some(self.frames.len() - 1);
-
-                        self.ip = func.code_offset;
-                    }
-                    Value::NativeFunction(native_fn) => {
-                        if arg_count != native_fn.arity as usize {
-                            return Err(format!(
-                                "Expected {} arguments but got {}",
-                                native_fn.arity, arg_count
-                            ));
-                        }
-
-                        let mut args = Vec::with_capacity(arg_count);
-                        for i in 0..arg_count {
-                            args.push(self.stack[function_pos - 1 - i].clone());
-                        }
-
-                        let result = (native_fn.function)(&args)?;
-                        for _ in 0..=arg_count {
-                            self.pop()?;
-                        }
-
-                        self.stack.push(result);
-                    }
-                    _ => return Err("Can only call functions".to_string()),
-                }
+                self.call_value(arg_count, false)?;
+            }
+            OpCode::TailCall => {
+                let arg_count = self.read_byte(chunk) as usize;
+                self.call_value(arg_count, true)?;
             }
             OpCode::Jump => {
                 let offset =
                     ((self.read_byte(chunk) as usize) << 8) | self.read_byte(chunk) as usize;
                 self.ip += offset;
             }
+            OpCode::Loop => {
+                let offset =
+                    ((self.read_byte(chunk) as usize) << 8) | self.read_byte(chunk) as usize;
+                self.ip -= offset;
+            }
             OpCode::JumpIfFalse => {
                 let offset =
                     ((self.read_byte(chunk) as usize) << 8) | self.read_byte(chunk) as usize;
@@ -386,11 +571,209 @@ impl VM {
                 }
                 self.scopes.pop();
             }
+            OpCode::BitAnd => {
+                self.binary_op(|a, b| a.bit_and(b))?;
+            }
+            OpCode::BitOr => {
+                self.binary_op(|a, b| a.bit_or(b))?;
+            }
+            OpCode::BitXor => {
+                self.binary_op(|a, b| a.bit_xor(b))?;
+            }
+            OpCode::ShiftLeft => {
+                self.binary_op(|a, b| a.shift_left(b))?;
+            }
+            OpCode::ShiftRight => {
+                self.binary_op(|a, b| a.shift_right(b))?;
+            }
+            OpCode::BitNot => {
+                let value = self.pop()?;
+                self.stack.push(value.bit_not()?);
+            }
+            OpCode::Closure => {
+                let constant_index = self.read_byte(chunk) as usize;
+                let capture_count = self.read_byte(chunk) as usize;
+
+                let function = match chunk.constants.get(constant_index) {
+                    Some(Value::Function(function)) => function.as_ref().clone(),
+                    _ => return Err("Invalid function constant for closure".to_string()),
+                };
+
+                let mut captures = Vec::with_capacity(capture_count);
+                for i in 0..capture_count {
+                    let is_local = self.read_byte(chunk) != 0;
+                    let index = self.read_byte(chunk) as usize;
+                    let value = if is_local {
+                        self.current_slots()
+                            .get(index)
+                            .cloned()
+                            .ok_or_else(|| format!("Invalid local slot {}", index))?
+                    } else {
+                        let var_name = chunk
+                            .identifiers
+                            .get(index)
+                            .ok_or_else(|| "Invalid variable index".to_string())?;
+                        self.lookup_variable(var_name)?
+                    };
+                    let name = function
+                        .locals
+                        .get(function.arity as usize + i)
+                        .cloned()
+                        .unwrap_or_default();
+                    captures.push((name, value));
+                }
+
+                self.stack
+                    .push(Value::Closure(Box::new(Closure { function, captures })));
+            }
         }
 
         Ok(())
     }
 
+    /// Calls the function on top of the stack with the arguments below it
+    ///
+    /// ### Arguments
+    ///
+    /// * `arg_count` - The number of arguments passed to the function
+    /// * `is_tail_call` - Whether the call is in tail position and may reuse the current call frame
+    ///
+    /// ### Returns
+    ///
+    /// Ok(()) if the call was set up successfully, or an error message
+    fn call_value(&mut self, arg_count: usize, is_tail_call: bool) -> Result<(), String> {
+        if self.stack.len() < arg_count + 1 {
+            return Err("Stack underflow during function call".to_string());
+        }
+
+        let function_pos = self.stack.len() - 1;
+        let function_value = self.stack[function_pos].clone();
+
+        match function_value {
+            Value::Function(func) => self.call_function(&func, &[], arg_count, is_tail_call)?,
+            Value::Closure(closure) => self.call_function(
+                &closure.function,
+                &closure.captures,
+                arg_count,
+                is_tail_call,
+            )?,
+            Value::NativeFunction(native_fn) => {
+                let arity = native_fn.arity as usize;
+                if arg_count < arity || (arg_count > arity && !native_fn.is_variadic) {
+                    return Err(format!(
+                        "Expected {} arguments but got {}",
+                        native_fn.arity, arg_count
+                    ));
+                }
+
+                let args = self.stack[function_pos - arg_count..function_pos].to_vec();
+
+                let mut context = NativeContext {
+                    program_args: &self.program_args,
+                    output: &mut *self.output,
+                };
+                let result = (native_fn.function)(&args, &mut context)?;
+                for _ in 0..=arg_count {
+                    self.pop()?;
+                }
+
+                self.stack.push(result);
+            }
+            _ => return Err("Can only call functions".to_string()),
+        }
+
+        Ok(())
+    }
+
+    /// Sets up a call frame for a function and jumps to its code
+    ///
+    /// ### Arguments
+    ///
+    /// * `func` - The function to call
+    /// * `captures` - Captured variables of a closure, empty for plain functions
+    /// * `arg_count` - The number of arguments passed to the function
+    /// * `is_tail_call` - Whether the call is in tail position and may reuse the current call frame
+    ///
+    /// ### Returns
+    ///
+    /// Ok(()) if the call was set up successfully, or an error message
+    fn call_function(
+        &mut self,
+        func: &Function,
+        captures: &[(String, Value)],
+        arg_count: usize,
+        is_tail_call: bool,
+    ) -> Result<(), String> {
+        if arg_count != func.arity as usize {
+            return Err(format!(
+                "Expected {} arguments but got {}",
+                func.arity, arg_count
+            ));
+        }
+
+        let function_pos = self.stack.len() - 1;
+        let mut slots = Vec::with_capacity(func.locals.len().max(arg_count + captures.len()));
+        slots.extend_from_slice(&self.stack[function_pos - arg_count..function_pos]);
+        slots.extend(captures.iter().map(|(_, value)| value.clone()));
+
+        // Remove function and arguments from stack
+        for _ in 0..=arg_count {
+            self.pop()?;
+        }
+
+        match self.current_frame {
+            Some(frame_idx) if is_tail_call => {
+                // Reuse the caller's frame so that tail recursion runs in constant space
+                let frame = &mut self.frames[frame_idx];
+                frame.slots = slots;
+                let stack_offset = frame.stack_offset;
+                let scope_depth = frame.scope_depth;
+
+                self.stack.truncate(stack_offset);
+                self.scopes.truncate(scope_depth);
+            }
+            _ => {
+                if self.frames.len() >= self.max_call_depth {
+                    return Err(format!(
+                        "Maximum recursion depth of {} exceeded in function '{}'",
+                        self.max_call_depth, func.name
+                    ));
+                }
+
+                let frame = CallFrame {
+                    return_address: self.ip,
+                    stack_offset: function_pos - arg_count,
+                    slots,
+                    scope_depth: self.scopes.len(),
+                };
+
+                self.frames.push(frame);
+                self.current_frame = Some(self.frames.len() - 1);
+            }
+        }
+
+        self.ip = func.code_offset;
+        Ok(())
+    }
+
+    /// Attaches the location of the failing instruction to a runtime error message
+    ///
+    /// ### Arguments
+    ///
+    /// * `chunk` - The bytecode chunk that was executing
+    /// * `offset` - The offset of the instruction that failed
+    /// * `message` - The runtime error message
+    ///
+    /// ### Returns
+    ///
+    /// The error message including the instruction offset and, if known, the source line
+    fn locate_error(chunk: &Chunk, offset: usize, message: String) -> String {
+        match chunk.lines.get(offset) {
+            Some(&line) if line > 0 => format!("{} at line {} (offset {})", message, line, offset),
+            _ => format!("{} at offset {}", message, offset),
+        }
+    }
+
     /// Reads the next byte from the chunk and advances the instruction pointer
     ///
     /// ### Arguments
@@ -457,6 +840,37 @@ impl VM {
         Ok(())
     }
 
+    /// Looks up the value of a named variable in the scopes
+    ///
+    /// ### Arguments
+    ///
+    /// * `name` - The name of the variable
+    ///
+    /// ### Returns
+    ///
+    /// A copy of the variable's value, or an error if the variable is undefined
+    fn lookup_variable(&self, name: &str) -> Result<Value, String> {
+        self.get_variable(name)
+            .cloned()
+            .ok_or_else(|| format!("Undefined variable '{}'", name))
+    }
+
+    /// Returns the local variable slots of the running function, or of the top-level code
+    fn current_slots(&self) -> &Vec<Value> {
+        match self.current_frame {
+            Some(frame_idx) => &self.frames[frame_idx].slots,
+            None => &self.slots,
+        }
+    }
+
+    /// Returns the mutable local variable slots of the running function, or of the top-level code
+    fn current_slots_mut(&mut self) -> &mut Vec<Value> {
+        match self.current_frame {
+            Some(frame_idx) => &mut self.frames[frame_idx].slots,
+            None => &mut self.slots,
+        }
+    }
+
     /// Helper method to find a variable in any scope (from innermost to outermost)
     fn get_variable(&self, name: &str) -> Option<&Value> {
         for scope in self.scopes.iter().rev() {
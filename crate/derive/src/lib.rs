@@ -18,7 +18,26 @@ use syn::{Data, DeriveInput, Expr, ExprLit, Lit, Meta, MetaNameValue, Variant, p
 ///  Third, // Implicit name: "third"
 /// }
 /// ```
-#[proc_macro_derive(NamedEnum, attributes(name))]
+///
+/// The `#[named_enum(case_insensitive)]` container attribute additionally generates
+/// `from_str_ignore_ascii_case()`, which matches the names regardless of ASCII case.
+///
+/// ```
+/// use slang_derive::NamedEnum;
+///
+/// #[derive(Debug, PartialEq, NamedEnum)]
+/// #[named_enum(case_insensitive)]
+/// enum MyEnum {
+///  #[name = "first_variant"]
+///  First,
+///  Second,
+/// }
+///
+/// assert_eq!(MyEnum::from_str("FIRST_VARIANT"), None);
+/// assert_eq!(MyEnum::from_str_ignore_ascii_case("FIRST_VARIANT"), Some(MyEnum::First));
+/// assert_eq!(MyEnum::from_str_ignore_ascii_case("Second"), Some(MyEnum::Second));
+/// ```
+#[proc_macro_derive(NamedEnum, attributes(name, named_enum))]
 pub fn derive_named_enum(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let enum_name = &input.ident;
@@ -50,6 +69,24 @@ pub fn derive_named_enum(input: TokenStream) -> TokenStream {
         }
     });
 
+    let from_str_ignore_ascii_case = if is_case_insensitive(&input) {
+        let checks = variant_mappings.iter().map(|(variant_name, string_name)| {
+            quote! {
+                if s.eq_ignore_ascii_case(#string_name) {
+                    return Some(#enum_name::#variant_name);
+                }
+            }
+        });
+        quote! {
+            pub fn from_str_ignore_ascii_case(s: &str) -> Option<Self> {
+                #(#checks)*
+                None
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let expanded = quote! {
         impl #enum_name {
             pub const fn name(&self) -> &'static str {
@@ -64,12 +101,35 @@ pub fn derive_named_enum(input: TokenStream) -> TokenStream {
                     _ => None,
                 }
             }
+
+            #from_str_ignore_ascii_case
         }
     };
 
     proc_macro::TokenStream::from(expanded)
 }
 
+/// Check whether the enum has a `#[named_enum(case_insensitive)]` attribute
+fn is_case_insensitive(input: &DeriveInput) -> bool {
+    let mut case_insensitive = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("named_enum"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("case_insensitive") {
+                case_insensitive = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported named_enum option, expected `case_insensitive`"))
+            }
+        })
+        .unwrap_or_else(|err| panic!("{}", err));
+    }
+    case_insensitive
+}
+
 /// Extract the string value from a `#[name = "..."]` attribute if present
 fn extract_name_attribute(variant: &Variant) -> Option<String> {
     variant
@@ -184,6 +184,7 @@ fn test_roundtrip_conversion() {
 
 // Test enum mixing unit variants and variants with data
 #[derive(Debug, PartialEq, Clone, NamedEnum)]
+#[allow(clippy::enum_variant_names)]
 enum MixedEnum {
     #[name = "first_unit"]
     FirstUnit,
@@ -215,3 +216,36 @@ fn test_unit_variants_only() {
     assert_eq!(MixedEnum::from_str("invalid"), None);
 }
 
+// Test enum that also generates case-insensitive lookup
+#[derive(Debug, PartialEq, Clone, Copy, NamedEnum)]
+#[named_enum(case_insensitive)]
+enum CaseInsensitiveEnum {
+    #[name = "lowercase"]
+    Lowercase,
+    #[name = "UPPERCASE"]
+    Uppercase,
+    Implicit,
+}
+
+#[test]
+fn test_from_str_ignore_ascii_case() {
+    assert_eq!(
+        CaseInsensitiveEnum::from_str_ignore_ascii_case("LOWERCASE"),
+        Some(CaseInsensitiveEnum::Lowercase)
+    );
+    assert_eq!(
+        CaseInsensitiveEnum::from_str_ignore_ascii_case("uppercase"),
+        Some(CaseInsensitiveEnum::Uppercase)
+    );
+    assert_eq!(
+        CaseInsensitiveEnum::from_str_ignore_ascii_case("ImPlIcIt"),
+        Some(CaseInsensitiveEnum::Implicit)
+    );
+    assert_eq!(
+        CaseInsensitiveEnum::from_str_ignore_ascii_case("unknown"),
+        None
+    );
+
+    // The exact lookup stays case-sensitive
+    assert_eq!(CaseInsensitiveEnum::from_str("LOWERCASE"), None);
+}
@@ -1,11 +1,58 @@
 use crate::error_codes::ErrorCode;
-use colored::Colorize;
+use colored::{ColoredString, Colorize};
+
+/// The severity of a reported compiler diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// An error that fails the compilation
+    Error,
+    /// A warning that is reported without failing the compilation
+    Warning,
+    /// An informational note
+    Note,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Note => write!(f, "note"),
+        }
+    }
+}
+
+/// The compilation phase that reported a diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// Turning the source text into tokens
+    Lex,
+    /// Building the syntax tree from the tokens
+    Parse,
+    /// Semantic analysis, including type checking
+    Type,
+    /// Generating bytecode from the analyzed syntax tree
+    Codegen,
+}
+
+impl std::fmt::Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Lex => write!(f, "lex"),
+            Phase::Parse => write!(f, "parse"),
+            Phase::Type => write!(f, "type"),
+            Phase::Codegen => write!(f, "codegen"),
+        }
+    }
+}
 
 /// Represents a compiler error with a message, line number, column number, position, and token length
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct CompilerError {
     /// The structured error code for this error
     pub error_code: ErrorCode,
+    /// Whether this is an error, a warning or a note
+    pub severity: Severity,
     /// The error message
     pub message: String,
     /// The line number where the error occurred
@@ -16,6 +63,12 @@ pub struct CompilerError {
     pub position: usize,
     /// The length of the token causing the error, if applicable
     pub token_length: Option<usize>,
+    /// A hint on how to fix the error, if one is known
+    pub suggestion: Option<String>,
+    /// The compilation phase that reported the error, if known
+    pub phase: Option<Phase>,
+    /// Notes pointing at other locations involved in the error, like a previous definition
+    pub related: Vec<CompilerError>,
 }
 
 impl CompilerError {
@@ -30,7 +83,7 @@ impl CompilerError {
     /// * `token_length` - The length of the token, if applicable
     ///
     /// ### Returns
-    /// A new CompilerError object
+    /// A new CompilerError object with error severity
     ///
     /// ### Example
     /// ```
@@ -48,14 +101,110 @@ impl CompilerError {
     ) -> Self {
         Self {
             error_code,
+            severity: Severity::Error,
             message,
             line,
             column,
             position,
             token_length,
+            suggestion: None,
+            phase: None,
+            related: Vec::new(),
         }
     }
 
+    /// Attaches a hint on how to fix the error
+    ///
+    /// ### Arguments
+    /// * `suggestion` - The text of the hint
+    ///
+    /// ### Returns
+    /// The error with the given suggestion
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::{CompilerError, ErrorCode};
+    ///
+    /// let error = CompilerError::new(ErrorCode::ExpectedSemicolon, "Missing semicolon".to_string(), 1, 5, 4, None)
+    ///     .with_suggestion("Add a ';' at the end of the statement");
+    /// assert_eq!(error.suggestion.as_deref(), Some("Add a ';' at the end of the statement"));
+    /// ```
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    /// Changes the severity of the error
+    ///
+    /// ### Arguments
+    /// * `severity` - The new severity
+    ///
+    /// ### Returns
+    /// The error with the given severity
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::{CompilerError, ErrorCode, Severity};
+    ///
+    /// let warning = CompilerError::new(ErrorCode::VariableShadowing, "Shadowed".to_string(), 1, 1, 0, None)
+    ///     .with_severity(Severity::Warning);
+    /// assert!(!warning.is_error());
+    /// ```
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Tags the error with the compilation phase that reported it
+    ///
+    /// ### Arguments
+    /// * `phase` - The phase that reported the error
+    ///
+    /// ### Returns
+    /// The error with the given phase
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::{CompilerError, ErrorCode, Phase};
+    ///
+    /// let error = CompilerError::new(ErrorCode::ExpectedSemicolon, "Missing semicolon".to_string(), 1, 5, 4, None)
+    ///     .with_phase(Phase::Parse);
+    /// assert_eq!(error.phase, Some(Phase::Parse));
+    /// ```
+    pub fn with_phase(mut self, phase: Phase) -> Self {
+        self.phase = Some(phase);
+        self
+    }
+
+    /// Attaches a note that points at another location involved in the error
+    ///
+    /// The note is reported below the error, so its severity is always set to note.
+    ///
+    /// ### Arguments
+    /// * `note` - The note, located at the related source position
+    ///
+    /// ### Returns
+    /// The error with the note attached
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::{CompilerError, ErrorCode, Severity};
+    ///
+    /// let error = CompilerError::new(ErrorCode::SymbolRedefinition, "Redefined".to_string(), 2, 4, 13, Some(1))
+    ///     .with_related(CompilerError::new(ErrorCode::SymbolRedefinition, "First defined here".to_string(), 1, 4, 3, Some(1)));
+    /// assert_eq!(error.related[0].line, 1);
+    /// assert_eq!(error.related[0].severity, Severity::Note);
+    /// ```
+    pub fn with_related(mut self, note: CompilerError) -> Self {
+        self.related.push(note.with_severity(Severity::Note));
+        self
+    }
+
+    /// Checks whether this diagnostic fails the compilation
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
     /// Format an error message with line information and source code snippet
     ///
     /// This creates a nicely formatted error message similar to Rust's compiler errors,
@@ -70,14 +219,16 @@ impl CompilerError {
         let (line, col) = line_info.get_line_col(self.position);
 
         let current_line_text = line_info
-            .get_line_text(line)
-            .unwrap_or("<line not available>");
+            .get_display_line_text(line)
+            .unwrap_or_else(|| "<line not available>".to_string());
 
         let line_num_str = format!("{}", line);
 
-        let token_display_length = self.token_length.unwrap_or(1).max(1);
+        let token_display_length = line_info
+            .span_width(self.position, self.token_length.unwrap_or(1))
+            .max(1);
         let error_marker = " ".repeat(col.saturating_sub(1))
-            + &"^".repeat(token_display_length).bold().red().to_string();
+            + &self.colorize("^".repeat(token_display_length)).bold().to_string();
 
         let indent_width = line_num_str.len() + 1;
         let indent = " ".repeat(indent_width);
@@ -85,10 +236,15 @@ impl CompilerError {
         let arrow = "-->".yellow();
         let pipe = "|".yellow();
 
+        let phase = self
+            .phase
+            .map(|phase| format!("[{}] ", phase))
+            .unwrap_or_default();
         let mut result = format!(
-            "{} {}: {}\n  {} {}:{}:{}\n",
-            "error".red().bold(),
-            self.error_code.to_string().bold().red(),
+            "{}{} {}: {}\n  {} {}:{}:{}\n",
+            phase,
+            self.colorize(self.severity.to_string()).bold(),
+            self.colorize(self.error_code.to_string()).bold(),
             self.error_code.description(),
             arrow,
             "main", // TODO: replace if actual filename is available
@@ -102,11 +258,26 @@ impl CompilerError {
             "{indent}{} {} {}\n",
             pipe,
             error_marker,
-            self.message.bold().red()
+            self.colorize(self.message.clone()).bold()
         );
+        if let Some(suggestion) = &self.suggestion {
+            result += &format!("{indent}{} {}: {}\n", pipe, "help".green().bold(), suggestion);
+        }
+        for note in &self.related {
+            result += &note.format_for_display(line_info);
+        }
 
         result
     }
+
+    /// Colors a text in the color of the error's severity
+    fn colorize(&self, text: String) -> ColoredString {
+        match self.severity {
+            Severity::Error => text.red(),
+            Severity::Warning => text.yellow(),
+            Severity::Note => text.blue(),
+        }
+    }
 }
 
 impl std::fmt::Display for CompilerError {
@@ -124,7 +295,50 @@ impl std::error::Error for CompilerError {
 /// A type alias for a result that can either be a value of type T or a list of compiler errors
 pub type CompileResult<T> = Result<T, Vec<CompilerError>>;
 
-/// Reports a list of compiler errors to stderr
+/// Extension methods for the result of a compilation phase
+pub trait CompileResultExt {
+    /// Tags all errors of the result with the phase that reported them
+    ///
+    /// ### Arguments
+    /// * `phase` - The phase that produced the result
+    ///
+    /// ### Returns
+    /// The result with every error tagged with the given phase
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::{CompileResult, CompileResultExt, CompilerError, ErrorCode, LineInfo, Phase};
+    ///
+    /// let result: CompileResult<()> = Err(vec![CompilerError::new(
+    ///     ErrorCode::TypeMismatch,
+    ///     "Mismatch".to_string(),
+    ///     1, 9, 8, Some(4),
+    /// )]);
+    ///
+    /// let errors = result.with_phase(Phase::Type).unwrap_err();
+    /// assert_eq!(errors[0].phase, Some(Phase::Type));
+    ///
+    /// colored::control::set_override(false);
+    /// let display = errors[0].format_for_display(&LineInfo::new("let x = true;"));
+    /// assert!(display.starts_with("[type] error [E2005]"));
+    /// ```
+    fn with_phase(self, phase: Phase) -> Self;
+}
+
+impl<T> CompileResultExt for CompileResult<T> {
+    fn with_phase(self, phase: Phase) -> Self {
+        self.map_err(|errors| {
+            errors
+                .into_iter()
+                .map(|error| error.with_phase(phase))
+                .collect()
+        })
+    }
+}
+
+/// Reports a list of compiler diagnostics to stderr
+///
+/// Every diagnostic is colored according to its severity.
 ///
 /// ### Arguments
 /// * `errors` - A slice of CompilerError to report
@@ -136,8 +350,18 @@ pub fn report_errors(errors: &[CompilerError], source: &str) {
     }
 }
 
+/// Collects compiler errors from all compilation phases
+///
+/// Errors are kept ordered by their source position (line, then column), so they are
+/// reported in the order they appear in the source regardless of the phase that found them.
+/// Exact duplicates are dropped.
+///
+/// An optional cap limits the number of reported errors. All errors are collected, and the
+/// ones that come first in the source are kept when the errors are taken. The errors beyond
+/// the cap are summarized by a note.
 pub struct ErrorCollector {
     errors: Vec<CompilerError>,
+    max_errors: Option<usize>,
 }
 
 impl Default for ErrorCollector {
@@ -148,19 +372,182 @@ impl Default for ErrorCollector {
 
 impl ErrorCollector {
     pub fn new() -> Self {
-        Self { errors: Vec::new() }
+        Self {
+            errors: Vec::new(),
+            max_errors: None,
+        }
     }
 
+    /// Sets the maximum number of errors that are reported
+    ///
+    /// ### Arguments
+    /// * `max_errors` - The cap, or None to collect all errors
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::{CompilerError, ErrorCode, ErrorCollector, Severity};
+    ///
+    /// let mut collector = ErrorCollector::new();
+    /// collector.set_max_errors(Some(2));
+    /// for line in 2..=6 {
+    ///     collector.add_error(CompilerError::new(ErrorCode::ExpectedSemicolon, "missing".to_string(), line, 1, 0, None));
+    /// }
+    /// collector.add_error(CompilerError::new(ErrorCode::TypeMismatch, "mismatch".to_string(), 1, 1, 0, None));
+    /// assert_eq!(collector.omitted_count(), 4);
+    ///
+    /// let errors = collector.take_errors();
+    /// assert_eq!(errors.len(), 3);
+    /// assert_eq!(errors[0].message, "mismatch");
+    /// assert_eq!(errors[1].line, 2);
+    /// assert_eq!(errors[2].severity, Severity::Note);
+    /// assert_eq!(errors[2].message, "... and 4 more errors");
+    /// ```
+    pub fn set_max_errors(&mut self, max_errors: Option<usize>) {
+        self.max_errors = max_errors;
+    }
+
+    /// Returns the number of errors that are dropped because the cap was reached
+    pub fn omitted_count(&self) -> usize {
+        self.errors.len() - self.len()
+    }
+
+    /// Adds an error at its source position, ignoring exact duplicates
+    ///
+    /// Errors on the same line and column keep the order in which they were added.
+    ///
+    /// ### Arguments
+    /// * `error` - The error to add
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::{CompilerError, ErrorCode, ErrorCollector};
+    ///
+    /// let mut collector = ErrorCollector::new();
+    /// collector.add_error(CompilerError::new(ErrorCode::ExpectedSemicolon, "late".to_string(), 5, 1, 40, None));
+    /// collector.add_error(CompilerError::new(ErrorCode::ExpectedSemicolon, "early".to_string(), 2, 3, 12, None));
+    /// collector.add_error(CompilerError::new(ErrorCode::ExpectedSemicolon, "early".to_string(), 2, 3, 12, None));
+    ///
+    /// let messages: Vec<String> = collector.take_errors().into_iter().map(|e| e.message).collect();
+    /// assert_eq!(messages, vec!["early", "late"]);
+    /// ```
     pub fn add_error(&mut self, error: CompilerError) {
-        self.errors.push(error);
+        if self.errors.contains(&error) {
+            return;
+        }
+        let index = self
+            .errors
+            .partition_point(|e| (e.line, e.column) <= (error.line, error.column));
+        self.errors.insert(index, error);
+    }
+
+    /// Moves all errors of another collector into this one
+    ///
+    /// The merged errors are kept in source order and exact duplicates are dropped, so the
+    /// errors of several compilation phases can be reported together.
+    ///
+    /// ### Arguments
+    /// * `other` - The collector whose errors are added
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::{CompilerError, ErrorCode, ErrorCollector};
+    ///
+    /// let mut parse_errors = ErrorCollector::new();
+    /// parse_errors.add_error(CompilerError::new(ErrorCode::ExpectedSemicolon, "parse".to_string(), 4, 1, 30, None));
+    ///
+    /// let mut type_errors = ErrorCollector::new();
+    /// type_errors.add_error(CompilerError::new(ErrorCode::TypeMismatch, "type".to_string(), 2, 5, 10, None));
+    ///
+    /// parse_errors.merge(type_errors);
+    /// let messages: Vec<String> = parse_errors.take_errors().into_iter().map(|e| e.message).collect();
+    /// assert_eq!(messages, vec!["type", "parse"]);
+    /// ```
+    pub fn merge(&mut self, mut other: ErrorCollector) {
+        for error in other.take_errors() {
+            self.add_error(error);
+        }
+    }
+
+    /// Accumulates the errors of a compilation phase and returns its output on success
+    ///
+    /// ### Arguments
+    /// * `result` - The result of the compilation phase
+    ///
+    /// ### Returns
+    /// The output of the phase, or None if the phase failed
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::{CompileResult, CompilerError, ErrorCode, ErrorCollector};
+    ///
+    /// let mut collector = ErrorCollector::new();
+    /// let lexed: CompileResult<u32> = Ok(3);
+    /// let parsed: CompileResult<u32> = Err(vec![
+    ///     CompilerError::new(ErrorCode::ExpectedSemicolon, "parse".to_string(), 1, 9, 8, None),
+    /// ]);
+    ///
+    /// assert_eq!(collector.collect(lexed), Some(3));
+    /// assert_eq!(collector.collect(parsed), None);
+    /// assert!(collector.has_errors());
+    /// ```
+    pub fn collect<T>(&mut self, result: CompileResult<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(errors) => {
+                for error in errors {
+                    self.add_error(error);
+                }
+                None
+            }
+        }
     }
 
     pub fn has_errors(&self) -> bool {
         !self.errors.is_empty()
     }
 
+    /// Iterates over the collected errors in source order
+    ///
+    /// Unlike `take_errors`, the errors stay in the collector and no note is added for
+    /// errors that were dropped because of the cap.
+    ///
+    /// ### Returns
+    /// An iterator over the collected errors
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::{CompilerError, ErrorCategory, ErrorCode, ErrorCollector};
+    ///
+    /// let mut collector = ErrorCollector::new();
+    /// collector.add_error(CompilerError::new(ErrorCode::ExpectedSemicolon, "semicolon".to_string(), 1, 9, 8, None));
+    /// collector.add_error(CompilerError::new(ErrorCode::TypeMismatch, "mismatch".to_string(), 2, 5, 14, None));
+    /// collector.add_error(CompilerError::new(ErrorCode::UndefinedVariable, "undefined".to_string(), 3, 1, 20, None));
+    ///
+    /// let type_errors: Vec<&str> = collector
+    ///     .iter()
+    ///     .filter(|error| error.error_code.category() == ErrorCategory::Type)
+    ///     .map(|error| error.message.as_str())
+    ///     .collect();
+    /// assert_eq!(type_errors, vec!["mismatch"]);
+    /// assert_eq!(collector.len(), 3);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, CompilerError> {
+        self.errors[..self.len()].iter()
+    }
+
+    /// Returns the number of collected errors, not counting errors dropped because of the cap
+    pub fn len(&self) -> usize {
+        self.max_errors
+            .map_or(self.errors.len(), |max| self.errors.len().min(max))
+    }
+
+    /// Returns true if no errors were collected
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
     pub fn report_errors(&self) {
-        for error in self.errors.iter() {
+        for error in self.iter() {
             eprintln!("{}", error.message);
         }
     }
@@ -169,11 +556,94 @@ impl ErrorCollector {
         self.errors.clear();
     }
 
+    /// Takes the collected errors out of the collector
+    ///
+    /// If errors were dropped because of the cap, a note that summarizes them follows the
+    /// last collected error.
+    ///
+    /// ### Returns
+    /// The collected errors in source order
     pub fn take_errors(&mut self) -> Vec<CompilerError> {
-        std::mem::take(&mut self.errors)
+        let omitted = self.omitted_count();
+        let mut errors = std::mem::take(&mut self.errors);
+        errors.truncate(errors.len() - omitted);
+        if let Some(last) = errors.last().filter(|_| omitted > 0) {
+            let note = CompilerError::new(
+                ErrorCode::GenericCompileError,
+                omitted_errors_message(omitted),
+                last.line,
+                last.column,
+                last.position,
+                None,
+            )
+            .with_severity(Severity::Note);
+            errors.push(note);
+        }
+        errors
     }
 }
 
+impl<'a> IntoIterator for &'a ErrorCollector {
+    type Item = &'a CompilerError;
+    type IntoIter = std::slice::Iter<'a, CompilerError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Consumes the collector, yielding the same errors as `take_errors`
+///
+/// ### Example
+/// ```
+/// use slang_error::{CompilerError, ErrorCategory, ErrorCode, ErrorCollector, Severity};
+///
+/// let mut collector = ErrorCollector::new();
+/// collector.set_max_errors(Some(3));
+/// collector.add_error(CompilerError::new(ErrorCode::InvalidToken, "token".to_string(), 1, 1, 0, None));
+/// collector.add_error(CompilerError::new(ErrorCode::TypeMismatch, "first".to_string(), 2, 1, 10, None));
+/// collector.add_error(CompilerError::new(ErrorCode::ReturnTypeMismatch, "second".to_string(), 3, 1, 20, None));
+/// collector.add_error(CompilerError::new(ErrorCode::TypeMismatch, "dropped".to_string(), 4, 1, 30, None));
+///
+/// let categories: Vec<ErrorCategory> = collector
+///     .into_iter()
+///     .filter(|error| error.severity == Severity::Error)
+///     .map(|error| error.error_code.category())
+///     .collect();
+/// assert_eq!(categories, vec![ErrorCategory::Lexical, ErrorCategory::Type, ErrorCategory::Type]);
+/// ```
+impl IntoIterator for ErrorCollector {
+    type Item = CompilerError;
+    type IntoIter = std::vec::IntoIter<CompilerError>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        self.take_errors().into_iter()
+    }
+}
+
+/// Formats the note that summarizes the errors dropped because of an error cap
+///
+/// ### Arguments
+/// * `omitted` - The number of dropped errors
+///
+/// ### Returns
+/// The note message, e.g. "... and 3 more errors"
+///
+/// ### Example
+/// ```
+/// use slang_error::omitted_errors_message;
+///
+/// assert_eq!(omitted_errors_message(1), "... and 1 more error");
+/// assert_eq!(omitted_errors_message(3), "... and 3 more errors");
+/// ```
+pub fn omitted_errors_message(omitted: usize) -> String {
+    format!(
+        "... and {} more {}",
+        omitted,
+        if omitted == 1 { "error" } else { "errors" }
+    )
+}
+
 pub struct LineInfo<'a> {
     /// Number of tokens on each line (run-length encoded)
     /// (line_number, tokens_on_line)
@@ -182,6 +652,10 @@ pub struct LineInfo<'a> {
     source: &'a str,
     /// The starting position of each line in the source code
     pub line_starts: Vec<usize>,
+    /// The character index of the first character of each line
+    char_line_starts: Vec<usize>,
+    /// Number of columns a tab advances to, 1 counts a tab as a single character
+    tab_width: usize,
 }
 
 impl LineInfo<'_> {
@@ -192,12 +666,14 @@ impl LineInfo<'_> {
     ///
     /// ### Returns
     /// A new LineInfo object with the line starts calculated
-    pub fn new(source: &str) -> LineInfo {
+    pub fn new(source: &str) -> LineInfo<'_> {
         let mut line_starts = vec![0];
+        let mut char_line_starts = vec![0];
 
-        for (i, c) in source.char_indices() {
+        for (char_index, (i, c)) in source.char_indices().enumerate() {
             if c == '\n' {
                 line_starts.push(i + 1);
+                char_line_starts.push(char_index + 1);
             }
         }
 
@@ -205,27 +681,134 @@ impl LineInfo<'_> {
             per_line: Vec::new(),
             source,
             line_starts,
+            char_line_starts,
+            tab_width: 1,
         }
     }
 
+    /// Sets the width of a tab character used for display columns
+    ///
+    /// A tab advances the column to the next multiple of the tab width. The default
+    /// width of 1 counts a tab as a single column. A width of 0 is treated as 1.
+    ///
+    /// ### Arguments
+    /// * `tab_width` - The number of columns between two tab stops
+    ///
+    /// ### Returns
+    /// The LineInfo object with the tab width configured
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::LineInfo;
+    ///
+    /// let source = "let a = 1;\n\tlet b = 2;";
+    /// let position = source.find("b").unwrap();
+    ///
+    /// assert_eq!(LineInfo::new(source).get_line_col(position), (2, 6));
+    /// assert_eq!(LineInfo::new(source).with_tab_width(4).get_line_col(position), (2, 9));
+    /// ```
+    pub fn with_tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width.max(1);
+        self
+    }
+
+    /// Returns the width of a tab character used for display columns
+    pub fn tab_width(&self) -> usize {
+        self.tab_width
+    }
+
     /// Get the line and column number for a token position
     ///
+    /// Token positions count characters rather than bytes, so a multi-byte UTF-8
+    /// character before the token advances the column by one. A `\r\n` sequence is a
+    /// single line break, so both of its characters are on the column directly after
+    /// the last character of the line.
+    ///
     /// ### Arguments
-    /// * `pos` - The position of the token in the source code
+    /// * `pos` - The character position of the token in the source code
     ///
     /// ### Returns
     /// A tuple containing the line number and column number
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::LineInfo;
+    ///
+    /// let source = "let a = 1;\r\nlet b = 2;\r\n";
+    /// let line_info = LineInfo::new(source);
+    ///
+    /// assert_eq!(line_info.get_line_col(source.find('b').unwrap()), (2, 5));
+    /// assert_eq!(line_info.get_line_col(source.find('\r').unwrap()), (1, 11));
+    /// assert_eq!(line_info.get_line_col(source.find('\n').unwrap()), (1, 11));
+    /// assert_eq!(line_info.get_line_text(1), Some("let a = 1;"));
+    ///
+    /// let source = "let s = \"\u{1F600}\"; x";
+    /// let position = source.chars().position(|c| c == 'x').unwrap();
+    ///
+    /// assert_eq!(LineInfo::new(source).get_line_col(position), (1, 14));
+    /// ```
     pub fn get_line_col(&self, pos: usize) -> (usize, usize) {
-        match self.line_starts.binary_search(&pos) {
+        match self.char_line_starts.binary_search(&pos) {
             Ok(line) => (line + 1, 1),
             Err(line) => {
                 let line_idx = line - 1;
-                let col = pos - self.line_starts[line_idx] + 1;
+                let chars_into_line = pos - self.char_line_starts[line_idx];
+                let line_text = &self.source[self.line_starts[line_idx]..];
+
+                let (prefix, past_end) = match line_text.char_indices().nth(chars_into_line) {
+                    Some((offset, c)) => {
+                        let prefix = &line_text[..offset];
+                        // The line feed of a CRLF line break shares the column of the carriage return
+                        let prefix = if c == '\n' {
+                            prefix.strip_suffix('\r').unwrap_or(prefix)
+                        } else {
+                            prefix
+                        };
+                        (prefix, 0)
+                    }
+                    None => (line_text, chars_into_line - line_text.chars().count()),
+                };
+
+                let col = display_column(prefix, self.tab_width) + past_end;
                 (line_idx + 1, col)
             }
         }
     }
 
+    /// Get the number of characters in a span of the source code
+    ///
+    /// The span starts at a character position, but its length counts bytes, so a
+    /// multi-byte UTF-8 character inside the span is counted once.
+    ///
+    /// ### Arguments
+    /// * `pos` - The character position where the span starts
+    /// * `length` - The length of the span in bytes
+    ///
+    /// ### Returns
+    /// The number of characters in the span, bytes past the end of the source count as one
+    /// character each
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::LineInfo;
+    ///
+    /// let source = "let s = \"h\u{e9}llo\u{1F600}\";";
+    /// let line_info = LineInfo::new(source);
+    ///
+    /// assert_eq!(line_info.span_width(8, 12), 8);
+    /// assert_eq!(line_info.span_width(0, 3), 3);
+    /// assert_eq!(line_info.span_width(17, 4), 4);
+    /// ```
+    pub fn span_width(&self, pos: usize, length: usize) -> usize {
+        let Some((start, _)) = self.source.char_indices().nth(pos) else {
+            return length;
+        };
+
+        let span = &self.source[start..];
+        let inside = |&(offset, _): &(usize, char)| offset < length;
+        span.char_indices().take_while(inside).count() + length.saturating_sub(span.len())
+    }
+
     /// Get the text for a specific line
     ///
     /// ### Arguments
@@ -252,6 +835,74 @@ impl LineInfo<'_> {
                 end
             };
 
-        Some(&self.source[start..actual_end])
+        let line = &self.source[start..actual_end];
+        // A line ending with CRLF doesn't include the carriage return either
+        Some(if actual_end < end {
+            line.strip_suffix('\r').unwrap_or(line)
+        } else {
+            line
+        })
     }
+
+    /// Get the text for a specific line as it is displayed in diagnostics
+    ///
+    /// Tabs are expanded to spaces up to the next tab stop, so that markers placed at
+    /// display columns line up with the text. With the default tab width of 1 the line
+    /// is returned unchanged.
+    ///
+    /// ### Arguments
+    /// * `line` - The line number to retrieve
+    ///
+    /// ### Returns
+    /// The displayed text of the line, or None if the line number is invalid
+    pub fn get_display_line_text(&self, line: usize) -> Option<String> {
+        let text = self.get_line_text(line)?;
+        if self.tab_width == 1 {
+            return Some(text.to_string());
+        }
+
+        let mut displayed = String::with_capacity(text.len());
+        for c in text.chars() {
+            if c == '\t' {
+                let width = display_column(&displayed, self.tab_width) - 1;
+                let next_stop = (width / self.tab_width + 1) * self.tab_width;
+                displayed.push_str(&" ".repeat(next_stop - width));
+            } else {
+                displayed.push(c);
+            }
+        }
+        Some(displayed)
+    }
+}
+
+/// Computes the 1-based display column that follows a piece of text on a line
+///
+/// Every character occupies one column, so multi-byte UTF-8 characters don't shift the
+/// column. Tabs advance to the next multiple of the tab width.
+///
+/// ### Arguments
+/// * `prefix` - The text between the start of the line and the column
+/// * `tab_width` - The number of columns between two tab stops
+///
+/// ### Returns
+/// The display column directly after the prefix
+///
+/// ### Example
+/// ```
+/// use slang_error::display_column;
+///
+/// assert_eq!(display_column("let x = ", 1), 9);
+/// assert_eq!(display_column("let s = \"\u{1F600}\"; ", 1), 14);
+/// assert_eq!(display_column("\tx", 4), 6);
+/// ```
+pub fn display_column(prefix: &str, tab_width: usize) -> usize {
+    let tab_width = tab_width.max(1);
+    let width = prefix.chars().fold(0, |width, c| {
+        if c == '\t' {
+            (width / tab_width + 1) * tab_width
+        } else {
+            width + 1
+        }
+    });
+    width + 1
 }
@@ -1,3 +1,5 @@
+use slang_derive::{IterableEnum, NumericEnum};
+
 /// Comprehensive error codes for all compilation errors in the Slang compiler.
 ///
 /// This enum provides unified error codes and descriptions for both parsing and semantic analysis errors.
@@ -7,7 +9,7 @@
 /// - 1000-1999: Parse errors (syntax and structural issues)
 /// - 2000-2999: Semantic analysis errors (type checking, scope resolution)
 /// - 3000-3999: Generic compile errors (not specifically categorized)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, NumericEnum, IterableEnum)]
 pub enum ErrorCode {
     // Parse Errors (1000-1999)
     /// Expected a semicolon after a statement
@@ -74,6 +76,8 @@ pub enum ErrorCode {
     ExpectedElse = 1031,
     /// Expected a closing quote for a string literal
     ExpectedClosingQuote = 1032,
+    /// Character that can't start any token
+    UnexpectedCharacter = 1033,
 
     // Semantic Analysis Errors (2000-2999)
     /// Variable used before being defined
@@ -112,6 +116,28 @@ pub enum ErrorCode {
     InvalidExpression = 2017,
     /// Attempt to call a non-callable value (non-function)
     VariableNotCallable = 2018,
+    /// Variable shadows a variable from an enclosing scope
+    VariableShadowing = 2019,
+    /// Number of format placeholders doesn't match the number of arguments
+    FormatArgumentCountMismatch = 2020,
+    /// Struct type contains itself by value
+    RecursiveStructType = 2021,
+    /// Division by a constant zero
+    DivisionByZero = 2022,
+    /// Inferred type reported by a `__type_of` query
+    TypeQuery = 2023,
+    /// Enum has no variant with the given name
+    UndefinedEnumVariant = 2024,
+    /// Floating-point values are compared for exact equality
+    FloatEquality = 2025,
+    /// The required entry point function is not defined
+    MissingEntryPoint = 2026,
+    /// The entry point function has parameters or an unsupported return type
+    InvalidEntryPoint = 2027,
+    /// The value of an expression statement is never used
+    UnusedResult = 2028,
+    /// Type has no method with the given name
+    UndefinedMethod = 2029,
 
     // Generic Compile Errors (3000-3999)
     /// Generic compile error not categorized
@@ -159,6 +185,7 @@ impl ErrorCode {
             ErrorCode::UnknownType => "Unknow type",
             ErrorCode::ExpectedElse => "Expected 'else' after if expression",
             ErrorCode::ExpectedClosingQuote => "Expected closing quote for string literal",
+            ErrorCode::UnexpectedCharacter => "Unexpected character",
 
             // Semantic Analysis Errors
             ErrorCode::UndefinedVariable => "Undefined variable",
@@ -179,20 +206,147 @@ impl ErrorCode {
             ErrorCode::AssignmentToImmutableVariable => "Assignment to immutable variable",
             ErrorCode::InvalidExpression => "Invalid expression",
             ErrorCode::VariableNotCallable => "Variable is not callable",
+            ErrorCode::VariableShadowing => "Variable shadows an outer variable",
+            ErrorCode::FormatArgumentCountMismatch => "Format arguments don't match placeholders",
+            ErrorCode::RecursiveStructType => "Struct type contains itself by value",
+            ErrorCode::DivisionByZero => "Division by zero",
+            ErrorCode::TypeQuery => "Inferred type of expression",
+            ErrorCode::UndefinedEnumVariant => "Undefined enum variant",
+            ErrorCode::FloatEquality => "Floating-point values compared for exact equality",
+            ErrorCode::MissingEntryPoint => "Missing entry point function",
+            ErrorCode::InvalidEntryPoint => "Invalid entry point signature",
+            ErrorCode::UnusedResult => "Result of expression is discarded",
+            ErrorCode::UndefinedMethod => "Undefined method",
             ErrorCode::GenericCompileError => "Generic compile error",
         }
     }
 
+    /// Get the stable numeric error code, as shown in the `E2002` style of diagnostics
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::ErrorCode;
+    ///
+    /// assert_eq!(ErrorCode::ExpectedSemicolon.code_number(), 1001);
+    /// assert_eq!(ErrorCode::UndefinedVariable.code_number(), 2001);
+    /// assert_eq!(ErrorCode::GenericCompileError.code_number(), 3000);
+    /// ```
+    pub fn code_number(&self) -> u32 {
+        u32::from(self.code())
+    }
+
+    /// Look up the error code with the given stable number, the inverse of `code_number`
+    ///
+    /// ### Arguments
+    /// * `number` - The numeric error code, such as 2001 for `E2001`
+    ///
+    /// ### Returns
+    /// The error code with that number, or None if no error code uses it
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::ErrorCode;
+    ///
+    /// assert_eq!(ErrorCode::from_number(2001), Some(ErrorCode::UndefinedVariable));
+    /// assert_eq!(ErrorCode::from_number(1000), None);
+    /// assert_eq!(ErrorCode::from_number(u32::MAX), None);
+    ///
+    /// for code in ErrorCode::iter() {
+    ///     assert_eq!(ErrorCode::from_number(code.code_number()), Some(code));
+    /// }
+    /// ```
+    pub fn from_number(number: u32) -> Option<ErrorCode> {
+        u16::try_from(number).ok().and_then(ErrorCode::from_int)
+    }
+
+    /// Get the category this error belongs to
+    ///
+    /// Parse errors (1000-1999) are split into lexical errors about malformed tokens and
+    /// syntax errors about their arrangement. Semantic analysis errors (2000-2999) are split
+    /// into type errors and other semantic errors. Generic errors (3000-3999) form their
+    /// own category.
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_error::{ErrorCategory, ErrorCode};
+    ///
+    /// assert_eq!(ErrorCode::UnterminatedString.category(), ErrorCategory::Lexical);
+    /// assert_eq!(ErrorCode::InvalidNumberLiteral.category(), ErrorCategory::Lexical);
+    /// assert_eq!(ErrorCode::ExpectedSemicolon.category(), ErrorCategory::Syntax);
+    /// assert_eq!(ErrorCode::TypeMismatch.category(), ErrorCategory::Type);
+    /// assert_eq!(ErrorCode::ArgumentTypeMismatch.category(), ErrorCategory::Type);
+    /// assert_eq!(ErrorCode::UndefinedVariable.category(), ErrorCategory::Semantic);
+    /// assert_eq!(ErrorCode::ReturnOutsideFunction.category(), ErrorCategory::Semantic);
+    /// assert_eq!(ErrorCode::GenericCompileError.category(), ErrorCategory::Generic);
+    /// ```
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            ErrorCode::InvalidNumberLiteral
+            | ErrorCode::InvalidStringLiteral
+            | ErrorCode::InvalidCharLiteral
+            | ErrorCode::InvalidEscapeSequence
+            | ErrorCode::UnterminatedString
+            | ErrorCode::UnterminatedChar
+            | ErrorCode::MalformedComment
+            | ErrorCode::InvalidToken
+            | ErrorCode::ExpectedClosingQuote
+            | ErrorCode::UnexpectedCharacter => ErrorCategory::Lexical,
+
+            ErrorCode::InvalidFieldType
+            | ErrorCode::TypeMismatch
+            | ErrorCode::OperationTypeMismatch
+            | ErrorCode::LogicalOperatorTypeMismatch
+            | ErrorCode::ValueOutOfRange
+            | ErrorCode::ArgumentTypeMismatch
+            | ErrorCode::ReturnTypeMismatch
+            | ErrorCode::MissingReturnValue
+            | ErrorCode::InvalidUnaryOperation
+            | ErrorCode::VariableNotCallable
+            | ErrorCode::RecursiveStructType => ErrorCategory::Type,
+
+            _ if self.is_parse_error() => ErrorCategory::Syntax,
+            _ if self.is_semantic_error() => ErrorCategory::Semantic,
+            _ => ErrorCategory::Generic,
+        }
+    }
+
     /// Check if this is a parse error (1000-1999 range)
     pub fn is_parse_error(&self) -> bool {
         let code = self.code();
-        code >= 1000 && code < 2000
+        (1000..2000).contains(&code)
     }
 
     /// Check if this is a semantic error (2000-2999 range)
     pub fn is_semantic_error(&self) -> bool {
         let code = self.code();
-        code >= 2000 && code < 3000
+        (2000..3000).contains(&code)
+    }
+}
+
+/// Groups of related error codes, used to document and filter diagnostics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// Malformed tokens, such as invalid literals or unterminated strings
+    Lexical,
+    /// Tokens that are arranged in a way the grammar doesn't allow
+    Syntax,
+    /// Values and operations whose types don't fit together
+    Type,
+    /// Other violations of the language rules, such as undefined names
+    Semantic,
+    /// Errors that aren't categorized any further
+    Generic,
+}
+
+impl std::fmt::Display for ErrorCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorCategory::Lexical => write!(f, "lexical"),
+            ErrorCategory::Syntax => write!(f, "syntax"),
+            ErrorCategory::Type => write!(f, "type"),
+            ErrorCategory::Semantic => write!(f, "semantic"),
+            ErrorCategory::Generic => write!(f, "generic"),
+        }
     }
 }
 
@@ -7,5 +7,8 @@
 pub mod error_codes;
 pub mod compiler_error;
 
-pub use error_codes::ErrorCode;
-pub use compiler_error::{CompilerError, CompileResult, ErrorCollector, LineInfo, report_errors};
+pub use error_codes::{ErrorCategory, ErrorCode};
+pub use compiler_error::{
+    CompileResult, CompileResultExt, CompilerError, ErrorCollector, LineInfo, Phase, Severity,
+    display_column, omitted_errors_message, report_errors,
+};
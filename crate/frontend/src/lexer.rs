@@ -1,5 +1,5 @@
-use slang_error::{CompileResult, CompilerError, LineInfo, ErrorCode};
-use crate::token::{Token, Tokentype};
+use slang_error::{CompileResult, CompilerError, LineInfo, ErrorCode, display_column};
+use crate::token::{Token, TokenWithTrivia, Tokentype, Trivia, TriviaKind};
 
 pub struct LexerResult<'a> {
     /// The list of tokens generated from the input
@@ -8,6 +8,14 @@ pub struct LexerResult<'a> {
     pub line_info: LineInfo<'a>,
 }
 
+/// The result of tokenizing with trivia, see `tokenize_with_trivia`
+pub struct TriviaLexerResult<'a> {
+    /// The tokens generated from the input, together with their trivia
+    pub tokens: Vec<TokenWithTrivia>,
+    /// The line information for the tokens
+    pub line_info: LineInfo<'a>,
+}
+
 /// Lexer state for tracking position during tokenization
 struct LexerState<'a> {
     /// Source text being tokenized
@@ -26,6 +34,10 @@ struct LexerState<'a> {
     line_tokens: Vec<(u16, u16)>,
     /// Collected lexer errors
     errors: Vec<CompilerError>,
+    /// Number of columns a tab advances to in reported columns
+    tab_width: usize,
+    /// Kinds and character ranges of the skipped trivia, only recorded if requested
+    trivia: Option<Vec<(TriviaKind, usize, usize)>>,
 }
 
 impl<'a> LexerState<'a> {
@@ -33,10 +45,11 @@ impl<'a> LexerState<'a> {
     ///
     /// ### Arguments
     /// * `input` - The source code to tokenize
+    /// * `tab_width` - The number of columns between two tab stops
     ///
     /// ### Returns
     /// A new LexerState object
-    fn new(input: &'a str) -> Self {
+    fn new(input: &'a str, tab_width: usize) -> Self {
         LexerState {
             input,
             chars: input.chars().peekable(),
@@ -46,6 +59,8 @@ impl<'a> LexerState<'a> {
             tokens: Vec::new(),
             line_tokens: Vec::new(),
             errors: Vec::new(),
+            tab_width,
+            trivia: None,
         }
     }
 
@@ -69,6 +84,16 @@ impl<'a> LexerState<'a> {
         self.chars.peek()
     }
 
+    /// Peeks at the character after the next one without consuming anything
+    fn peek_next(&self) -> Option<char> {
+        self.remaining().nth(1)
+    }
+
+    /// Returns the characters that haven't been consumed yet, without consuming them
+    fn remaining(&self) -> std::iter::Peekable<std::str::Chars<'a>> {
+        self.chars.clone()
+    }
+
     /// Adds a token to the token list
     ///
     /// ### Arguments
@@ -91,8 +116,10 @@ impl<'a> LexerState<'a> {
     /// * `token_length` - The length of the problematic token
     fn add_error(&mut self, error_code: ErrorCode, message: String, start_pos: usize, token_length: Option<usize>) {
         // Calculate column position from start_pos
-        let line_start = self.input[..start_pos].rfind('\n').map_or(0, |pos| pos + 1);
-        let column = start_pos - line_start + 1;
+        // Positions count characters, so the prefix can't be sliced by byte offset
+        let prefix: String = self.input.chars().take(start_pos).collect();
+        let line_start = prefix.rfind('\n').map_or(0, |pos| pos + 1);
+        let column = display_column(&prefix[line_start..], self.tab_width);
         
         self.errors.push(CompilerError::new(
             error_code,
@@ -104,6 +131,26 @@ impl<'a> LexerState<'a> {
         ));
     }
 
+    /// Records skipped trivia if trivia is kept, merging adjacent whitespace
+    ///
+    /// ### Arguments
+    /// * `kind` - The kind of the trivia
+    /// * `start_pos` - The starting position of the trivia in the input
+    fn add_trivia(&mut self, kind: TriviaKind, start_pos: usize) {
+        let end_pos = self.current_pos;
+        let Some(trivia) = &mut self.trivia else {
+            return;
+        };
+        match trivia.last_mut() {
+            Some((TriviaKind::Whitespace, _, end))
+                if kind == TriviaKind::Whitespace && *end == start_pos =>
+            {
+                *end = end_pos
+            }
+            _ => trivia.push((kind, start_pos, end_pos)),
+        }
+    }
+
     /// Records a line break, updating line counts
     ///
     /// ### Arguments
@@ -129,7 +176,7 @@ impl<'a> LexerState<'a> {
         }
         self.tokens
             .push(Token::new(Tokentype::Eof, "".to_string(), self.current_pos));
-        let mut info = LineInfo::new(self.input);
+        let mut info = LineInfo::new(self.input).with_tab_width(self.tab_width);
         info.per_line = self.line_tokens;
 
         // If there are errors, return them
@@ -153,39 +200,195 @@ impl<'a> LexerState<'a> {
 /// ### Returns
 ///
 /// A CompileResult containing LexerResult (tokens and line information) or lexer errors
-pub fn tokenize(input: &str) -> CompileResult<LexerResult> {
-    let mut state = LexerState::new(input);
+///
+/// ### Example
+/// ```
+/// use slang_error::ErrorCode;
+/// use slang_frontend::lexer::tokenize;
+///
+/// let Err(errors) = tokenize("let x = @#$;") else { unreachable!() };
+/// assert_eq!(errors.len(), 3);
+/// for (error, (character, position)) in errors.iter().zip([('@', 8), ('#', 9), ('$', 10)]) {
+///     assert_eq!(error.error_code, ErrorCode::UnexpectedCharacter);
+///     assert_eq!(error.message, format!("Unexpected character '{}'", character));
+///     assert_eq!(error.position, position);
+///     assert_eq!(error.column, position + 1);
+/// }
+/// ```
+pub fn tokenize(input: &str) -> CompileResult<LexerResult<'_>> {
+    tokenize_with_tab_width(input, 1)
+}
+
+/// Converts source code text into a sequence of tokens, reporting tab-aware columns
+///
+/// ### Arguments
+///
+/// * `input` - The source code to tokenize
+/// * `tab_width` - The number of columns between two tab stops
+///
+/// ### Returns
+///
+/// A CompileResult containing LexerResult (tokens and line information) or lexer errors
+///
+/// ### Example
+/// ```
+/// use slang_frontend::lexer::tokenize_with_tab_width;
+///
+/// let Err(errors) = tokenize_with_tab_width("\t\t\"unterminated", 4) else { unreachable!() };
+/// assert_eq!(errors[0].column, 9);
+/// ```
+pub fn tokenize_with_tab_width(input: &str, tab_width: usize) -> CompileResult<LexerResult<'_>> {
+    let mut state = LexerState::new(input, tab_width.max(1));
+    scan_tokens(&mut state);
+    state.finish()
+}
+
+/// Converts source code text into a sequence of tokens that keep the comments and whitespace
+///
+/// The trivia isn't needed for parsing, but tools that reproduce the source, like a
+/// formatter, need it. Trivia on the same line after a token is attached to that token as
+/// trailing trivia, all other trivia is attached to the following token as leading trivia.
+///
+/// ### Arguments
+///
+/// * `input` - The source code to tokenize
+///
+/// ### Returns
+///
+/// A CompileResult containing the tokens with their trivia and line information or lexer errors
+///
+/// ### Example
+/// ```
+/// use slang_frontend::lexer::{tokenize, tokenize_with_trivia};
+/// use slang_frontend::token::{Tokentype, TriviaKind};
+///
+/// let source = "let x = 1; // one\n/* two */ let y = 2;";
+///
+/// // The normal token stream discards the comments
+/// let plain = tokenize(source).unwrap();
+/// assert_eq!(plain.tokens.len(), 11);
+///
+/// let lexed = tokenize_with_trivia(source).unwrap();
+/// assert_eq!(lexed.tokens.len(), 11);
+///
+/// let semicolon = &lexed.tokens[4];
+/// assert_eq!(semicolon.token.token_type, Tokentype::Semicolon);
+/// let comment = &semicolon.trailing_trivia[1];
+/// assert_eq!(comment.kind, TriviaKind::LineComment);
+/// assert_eq!(comment.text, "// one");
+/// assert_eq!(comment.pos, 11);
+///
+/// let second_let = &lexed.tokens[5];
+/// let kinds: Vec<_> = second_let.leading_trivia.iter().map(|trivia| trivia.kind).collect();
+/// assert_eq!(kinds, [TriviaKind::Newline, TriviaKind::BlockComment, TriviaKind::Whitespace]);
+/// assert_eq!(second_let.leading_trivia[1].text, "/* two */");
+/// assert_eq!(second_let.leading_trivia[1].pos, 18);
+/// ```
+pub fn tokenize_with_trivia(input: &str) -> CompileResult<TriviaLexerResult<'_>> {
+    let mut state = LexerState::new(input, 1);
+    state.trivia = Some(Vec::new());
+    scan_tokens(&mut state);
+    let trivia = state.trivia.take().unwrap_or_default();
+    let LexerResult { tokens, line_info } = state.finish()?;
+
+    // Byte offsets of the character positions, so that the trivia text can be sliced
+    let mut offsets: Vec<usize> = input.char_indices().map(|(offset, _)| offset).collect();
+    offsets.push(input.len());
+
+    let mut tokens: Vec<TokenWithTrivia> = tokens
+        .into_iter()
+        .map(|token| TokenWithTrivia {
+            token,
+            leading_trivia: Vec::new(),
+            trailing_trivia: Vec::new(),
+        })
+        .collect();
+
+    let mut next_token = 0;
+    let mut trailing = false;
+    for (kind, start, end) in trivia {
+        while next_token < tokens.len() && tokens[next_token].token.pos < start {
+            next_token += 1;
+            trailing = true;
+        }
+        if kind == TriviaKind::Newline {
+            trailing = false;
+        }
+
+        let trivia = Trivia {
+            kind,
+            text: input[offsets[start]..offsets[end]].to_string(),
+            pos: start,
+        };
+        if trailing {
+            tokens[next_token - 1].trailing_trivia.push(trivia);
+        } else {
+            tokens[next_token].leading_trivia.push(trivia);
+        }
+    }
+
+    Ok(TriviaLexerResult { tokens, line_info })
+}
 
+/// Operators and punctuation, each with the token it is lexed as
+///
+/// The longest entry matching the input wins, so an operator may share a prefix with
+/// shorter operators, e.g. `<<=`, `<<`, `<=` and `<`.
+const OPERATORS: &[(&str, Tokentype)] = &[
+    ("+", Tokentype::Plus),
+    ("-", Tokentype::Minus),
+    ("->", Tokentype::Arrow),
+    ("*", Tokentype::Multiply),
+    ("/", Tokentype::Divide),
+    ("%", Tokentype::Percent),
+    ("=", Tokentype::Equal),
+    ("==", Tokentype::EqualEqual),
+    ("!", Tokentype::Not),
+    ("!=", Tokentype::NotEqual),
+    ("<", Tokentype::Less),
+    ("<=", Tokentype::LessEqual),
+    ("<<", Tokentype::ShiftLeft),
+    ("<<=", Tokentype::ShiftLeftEqual),
+    (">", Tokentype::Greater),
+    (">=", Tokentype::GreaterEqual),
+    (">>", Tokentype::ShiftRight),
+    (">>=", Tokentype::ShiftRightEqual),
+    ("&", Tokentype::Ampersand),
+    ("&&", Tokentype::And),
+    ("|", Tokentype::Pipe),
+    ("||", Tokentype::Or),
+    ("^", Tokentype::Caret),
+    ("~", Tokentype::Tilde),
+    (":", Tokentype::Colon),
+    ("::", Tokentype::PathSeparator),
+    (";", Tokentype::Semicolon),
+    (",", Tokentype::Comma),
+    (".", Tokentype::Dot),
+    ("{", Tokentype::LeftBrace),
+    ("}", Tokentype::RightBrace),
+    ("(", Tokentype::LeftParen),
+    (")", Tokentype::RightParen),
+];
+
+/// Scans the whole input, adding the tokens and errors to the lexer state
+///
+/// ### Arguments
+/// * `state` - The lexer state positioned at the start of the input
+fn scan_tokens(state: &mut LexerState) {
     while let Some(&c) = state.peek() {
         let token_start_pos = state.current_pos;
 
         match c {
-            c if c.is_whitespace() => handle_whitespace(&mut state),
-            c if c.is_alphabetic() => handle_identifier(&mut state, token_start_pos),
-            c if c.is_ascii_digit() => handle_number(&mut state, token_start_pos),
-            '"' => handle_string(&mut state),
-            ':' => handle_simple_token(&mut state, Tokentype::Colon, ":", token_start_pos),
-            '+' => handle_simple_token(&mut state, Tokentype::Plus, "+", token_start_pos),
-            '-' => handle_dash(&mut state, token_start_pos),
-            '*' => handle_simple_token(&mut state, Tokentype::Multiply, "*", token_start_pos),
-            '/' => handle_slash(&mut state, token_start_pos),
-            '=' => handle_equals(&mut state, token_start_pos),
-            '<' => handle_less_than(&mut state, token_start_pos),
-            '>' => handle_greater_than(&mut state, token_start_pos),
-            '!' => handle_exclamation(&mut state, token_start_pos),
-            ';' => handle_simple_token(&mut state, Tokentype::Semicolon, ";", token_start_pos),
-            '{' => handle_simple_token(&mut state, Tokentype::LeftBrace, "{", token_start_pos),
-            '}' => handle_simple_token(&mut state, Tokentype::RightBrace, "}", token_start_pos),
-            ',' => handle_simple_token(&mut state, Tokentype::Comma, ",", token_start_pos),
-            '(' => handle_simple_token(&mut state, Tokentype::LeftParen, "(", token_start_pos),
-            ')' => handle_simple_token(&mut state, Tokentype::RightParen, ")", token_start_pos),
-            '&' => handle_ampersand(&mut state, token_start_pos),
-            '|' => handle_pipe(&mut state, token_start_pos),
-            _ => handle_invalid_char(&mut state, token_start_pos),
+            c if c.is_whitespace() => handle_whitespace(state),
+            c if c.is_alphabetic() || c == '_' => handle_identifier(state, token_start_pos),
+            c if c.is_ascii_digit() => handle_number(state, token_start_pos),
+            '"' => handle_string(state),
+            '/' if matches!(state.peek_next(), Some('/' | '*')) => {
+                handle_comment(state, token_start_pos)
+            }
+            _ => handle_operator(state, token_start_pos),
         }
     }
-
-    state.finish()
 }
 
 /// Handles whitespace characters in the input
@@ -193,10 +396,14 @@ pub fn tokenize(input: &str) -> CompileResult<LexerResult> {
 /// ### Arguments
 /// * `state` - The current lexer state
 fn handle_whitespace(state: &mut LexerState) {
+    let start_pos = state.current_pos;
     let c = state.advance().unwrap();
 
     if c == '\n' {
         state.record_line_break();
+        state.add_trivia(TriviaKind::Newline, start_pos);
+    } else {
+        state.add_trivia(TriviaKind::Whitespace, start_pos);
     }
 }
 
@@ -221,11 +428,17 @@ fn handle_identifier(state: &mut LexerState, start_pos: usize) {
         "let" => Tokentype::Let,
         "mut" => Tokentype::Mut,
         "struct" => Tokentype::Struct,
+        "enum" => Tokentype::Enum,
+        "impl" => Tokentype::Impl,
         "fn" => Tokentype::Fn,
         "return" => Tokentype::Return,
         "if" => Tokentype::If,
         "else" => Tokentype::Else,
+        "for" => Tokentype::For,
+        "in" => Tokentype::In,
         "true" | "false" => Tokentype::BooleanLiteral,
+        "inf" | "nan" => Tokentype::FloatLiteral,
+        "_" => Tokentype::Underscore,
         _ => Tokentype::Identifier,
     };
 
@@ -241,8 +454,30 @@ fn handle_number(state: &mut LexerState, start_pos: usize) {
     let mut number = String::new();
     let mut is_float = false;
 
+    // Hexadecimal integers keep their prefix in the lexeme, the parser converts them
+    if state.peek() == Some(&'0') {
+        number.push('0');
+        state.advance();
+        if let Some(&prefix) = state.peek()
+            && (prefix == 'x' || prefix == 'X')
+        {
+            number.push(prefix);
+            state.advance();
+            while let Some(&c) = state.peek() {
+                if !(c.is_ascii_hexdigit() || c == '_') {
+                    break;
+                }
+                number.push(c);
+                state.advance();
+            }
+            state.add_token(Tokentype::IntegerLiteral, number, start_pos);
+            return;
+        }
+    }
+
     while let Some(&c) = state.peek() {
-        if c.is_ascii_digit() {
+        // Underscores separate groups of digits and are removed by the parser
+        if c.is_ascii_digit() || c == '_' {
             number.push(c);
             state.advance();
         } else if c == '.' {
@@ -255,11 +490,11 @@ fn handle_number(state: &mut LexerState, start_pos: usize) {
         } else if c == 'e' || c == 'E' {
             number.push(c);
             state.advance();
-            if let Some(&next_c) = state.peek() {
-                if next_c == '+' || next_c == '-' {
-                    number.push(next_c);
-                    state.advance();
-                }
+            if let Some(&next_c) = state.peek()
+                && (next_c == '+' || next_c == '-')
+            {
+                number.push(next_c);
+                state.advance();
             }
         } else {
             break;
@@ -314,77 +549,66 @@ fn handle_string(state: &mut LexerState) {
     }
 }
 
-/// Handles simple one-character tokens
+/// Handles operators and punctuation by the longest entry of `OPERATORS` that matches
 ///
 /// ### Arguments
 /// * `state` - The current lexer state
-/// * `token_type` - The type of token to add
-/// * `lexeme` - The string representation of the token
-/// * `start_pos` - The starting position of the token in the input
-fn handle_simple_token(
-    state: &mut LexerState,
-    token_type: Tokentype,
-    lexeme: &str,
-    start_pos: usize,
-) {
-    state.advance();
-    state.add_token(token_type, lexeme.to_string(), start_pos);
-}
-
-/// Handles dash character (minus or arrow)
-///
-/// ### Arguments
-/// * `state` - The current lexer state
-/// * `start_pos` - The starting position of the dash in the input
-fn handle_dash(state: &mut LexerState, start_pos: usize) {
-    state.advance();
-    if state.peek() == Some(&'>') {
-        state.advance();
-        state.add_token(Tokentype::Arrow, "->".to_string(), start_pos);
-    } else {
-        state.add_token(Tokentype::Minus, "-".to_string(), start_pos);
+/// * `start_pos` - The starting position of the operator in the input
+fn handle_operator(state: &mut LexerState, start_pos: usize) {
+    let longest_match = OPERATORS
+        .iter()
+        .filter(|(lexeme, _)| state.remaining().take(lexeme.len()).eq(lexeme.chars()))
+        .max_by_key(|(lexeme, _)| lexeme.len());
+
+    match longest_match {
+        Some(&(lexeme, token_type)) => {
+            for _ in 0..lexeme.len() {
+                state.advance();
+            }
+            state.add_token(token_type, lexeme.to_string(), start_pos);
+        }
+        None => handle_invalid_char(state, start_pos),
     }
 }
 
-/// Handles slash character (divide or comments)
+/// Handles comments, which start with a slash followed by another slash or an asterisk
 ///
 /// ### Arguments
 /// * `state` - The current lexer state
-/// * `start_pos` - The starting position of the slash in the input
-fn handle_slash(state: &mut LexerState, start_pos: usize) {
+/// * `start_pos` - The starting position of the comment in the input
+fn handle_comment(state: &mut LexerState, start_pos: usize) {
     state.advance();
 
     if state.peek() == Some(&'/') {
-        handle_line_comment(state);
-    } else if state.peek() == Some(&'*') {
-        handle_block_comment(state);
+        handle_line_comment(state, start_pos);
     } else {
-        state.add_token(Tokentype::Divide, "/".to_string(), start_pos);
+        handle_block_comment(state, start_pos);
     }
 }
 
-/// Handles single-line comments
+/// Handles single-line comments, the line break that ends them is left as whitespace
 ///
 /// ### Arguments
 /// * `state` - The current lexer state
-fn handle_line_comment(state: &mut LexerState) {
+/// * `start_pos` - The starting position of the comment in the input
+fn handle_line_comment(state: &mut LexerState, start_pos: usize) {
     state.advance();
 
     while let Some(&c) = state.peek() {
         if c == '\n' {
-            state.advance();
-            state.record_line_break();
             break;
         }
         state.advance();
     }
+    state.add_trivia(TriviaKind::LineComment, start_pos);
 }
 
 /// Handles multi-line block comments
 ///
 /// ### Arguments
 /// * `state` - The current lexer state
-fn handle_block_comment(state: &mut LexerState) {
+/// * `start_pos` - The starting position of the comment in the input
+fn handle_block_comment(state: &mut LexerState, start_pos: usize) {
     state.advance();
 
     let mut nesting = 1;
@@ -393,10 +617,10 @@ fn handle_block_comment(state: &mut LexerState) {
             break;
         }
 
-        if let Some(&c) = state.peek() {
-            if c == '\n' {
-                state.record_line_break();
-            }
+        if let Some(&c) = state.peek()
+            && c == '\n'
+        {
+            state.record_line_break();
         }
 
         if state.peek() == Some(&'*') {
@@ -417,104 +641,22 @@ fn handle_block_comment(state: &mut LexerState) {
             state.advance();
         }
     }
-}
-
-/// Handles equals character (assignment or equality)
-///
-/// ### Arguments
-/// * `state` - The current lexer state
-/// * `start_pos` - The starting position of the equals in the input
-fn handle_equals(state: &mut LexerState, start_pos: usize) {
-    state.advance();
-    if state.peek() == Some(&'=') {
-        state.advance();
-        state.add_token(Tokentype::EqualEqual, "==".to_string(), start_pos);
-    } else {
-        state.add_token(Tokentype::Equal, "=".to_string(), start_pos);
-    }
-}
-
-/// Handles less than character (less than or less than or equal)
-///
-/// ### Arguments
-/// * `state` - The current lexer state
-/// * `start_pos` - The starting position of the less than in the input
-fn handle_less_than(state: &mut LexerState, start_pos: usize) {
-    state.advance();
-    if state.peek() == Some(&'=') {
-        state.advance();
-        state.add_token(Tokentype::LessEqual, "<=".to_string(), start_pos);
-    } else {
-        state.add_token(Tokentype::Less, "<".to_string(), start_pos);
-    }
-}
-
-/// Handles greater than character (greater than or greater than or equal)
-///
-/// ### Arguments
-/// * `state` - The current lexer state
-/// * `start_pos` - The starting position of the greater than in the input
-fn handle_greater_than(state: &mut LexerState, start_pos: usize) {
-    state.advance();
-    if state.peek() == Some(&'=') {
-        state.advance();
-        state.add_token(Tokentype::GreaterEqual, ">=".to_string(), start_pos);
-    } else {
-        state.add_token(Tokentype::Greater, ">".to_string(), start_pos);
-    }
-}
-
-/// Handles exclamation mark (not or not equal)
-///
-/// ### Arguments
-/// * `state` - The current lexer state
-/// * `start_pos` - The starting position of the exclamation mark in the input
-fn handle_exclamation(state: &mut LexerState, start_pos: usize) {
-    state.advance();
-    if state.peek() == Some(&'=') {
-        state.advance();
-        state.add_token(Tokentype::NotEqual, "!=".to_string(), start_pos);
-    } else {
-        state.add_token(Tokentype::Not, "!".to_string(), start_pos);
-    }
-}
-
-/// Handles ampersand character (logical AND)
-///
-/// ### Arguments
-/// * `state` - The current lexer state
-/// * `start_pos` - The starting position of the ampersand in the input
-fn handle_ampersand(state: &mut LexerState, start_pos: usize) {
-    state.advance();
-    if state.peek() == Some(&'&') {
-        state.advance();
-        state.add_token(Tokentype::And, "&&".to_string(), start_pos);
-    } else {
-        state.add_token(Tokentype::Invalid, "&".to_string(), start_pos);
-    }
-}
-
-/// Handles pipe character (logical OR)
-///
-/// ### Arguments
-/// * `state` - The current lexer state
-/// * `start_pos` - The starting position of the pipe in the input
-fn handle_pipe(state: &mut LexerState, start_pos: usize) {
-    state.advance();
-    if state.peek() == Some(&'|') {
-        state.advance();
-        state.add_token(Tokentype::Or, "||".to_string(), start_pos);
-    } else {
-        state.add_token(Tokentype::Invalid, "|".to_string(), start_pos);
-    }
+    state.add_trivia(TriviaKind::BlockComment, start_pos);
 }
 
 /// Handles invalid characters
 ///
+/// Reports the character and skips it, so that every invalid character in the input is reported.
+///
 /// ### Arguments
 /// * `state` - The current lexer state
 /// * `start_pos` - The starting position of the invalid character in the input
 fn handle_invalid_char(state: &mut LexerState, start_pos: usize) {
     let invalid_char = state.advance().unwrap();
-    state.add_token(Tokentype::Invalid, invalid_char.to_string(), start_pos);
+    state.add_error(
+        ErrorCode::UnexpectedCharacter,
+        format!("Unexpected character '{}'", invalid_char),
+        start_pos,
+        Some(1),
+    );
 }
@@ -1,15 +1,19 @@
 pub mod lexer;
+pub mod optimizer;
 pub mod parser;
 pub mod parse_error;
 pub mod semantic_analysis;
+pub mod source_printer;
 pub mod token;
 #[cfg(feature = "print-tokens")]
 pub mod token_printer;
 
 // Re-export error handling from slang_error
-pub use slang_error::{ErrorCode, CompilerError, CompileResult, ErrorCollector, LineInfo, report_errors};
+pub use slang_error::{
+    CompileResult, CompilerError, ErrorCode, ErrorCollector, LineInfo, Severity, report_errors,
+};
 pub use lexer::tokenize;
 pub use parser::Parser;
-pub use semantic_analysis::{execute};
+pub use semantic_analysis::{const_eval, execute};
 pub use semantic_analysis::SemanticAnalysisError;
 pub use token::{Token, Tokentype};
@@ -0,0 +1,591 @@
+use slang_ir::Location;
+use slang_ir::ast::{
+    BinaryExpr, BinaryOperator, BlockExpr, Expression, LetStatement, LiteralExpr, LiteralValue,
+    Parameter, Statement,
+};
+use slang_shared::CompilationContext;
+use slang_types::TypeId;
+use std::cell::Cell;
+use std::collections::HashMap;
+
+use crate::semantic_analysis::const_eval;
+use crate::semantic_analysis::validation::TypeInference;
+
+/// How aggressively the analyzed program is optimized before code generation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OptLevel {
+    /// No optimization passes run, the bytecode mirrors the source structure
+    #[default]
+    None,
+    /// Cheap passes that don't change observable behavior, such as constant folding and
+    /// propagation
+    Basic,
+}
+
+/// Runs the optimization passes enabled by the given level on a program
+///
+/// The statements must have passed semantic analysis.
+///
+/// ### Arguments
+/// * `statements` - The analyzed statements of the program
+/// * `context` - The compilation context of the program
+/// * `level` - The optimization level
+///
+/// ### Example
+/// ```
+/// use slang_frontend::optimizer::{OptLevel, optimize};
+/// use slang_frontend::{parser, tokenize};
+/// use slang_ir::ast::{Expression, LiteralValue, Statement};
+/// use slang_shared::CompilationContext;
+///
+/// let mut context = CompilationContext::new();
+/// let lexed = tokenize("2 * (3 + 4);").unwrap();
+/// let mut statements = parser::parse(&lexed.tokens, &lexed.line_info, &mut context).unwrap();
+///
+/// optimize(&mut statements, &context, OptLevel::None);
+/// assert!(matches!(statements[0], Statement::Expression(Expression::Binary(_))));
+///
+/// optimize(&mut statements, &context, OptLevel::Basic);
+/// let Statement::Expression(Expression::Literal(literal)) = &statements[0] else {
+///     unreachable!()
+/// };
+/// assert_eq!(literal.value, LiteralValue::UnspecifiedInteger(14));
+/// ```
+pub fn optimize(statements: &mut [Statement], context: &CompilationContext, level: OptLevel) {
+    match level {
+        OptLevel::None => {}
+        OptLevel::Basic => {
+            fold_constants(statements, context);
+            // Folding may turn the value of another binding into a literal
+            while propagate_constants(statements) {
+                fold_constants(statements, context);
+            }
+        }
+    }
+}
+
+/// Replaces reads of immutable variables that are bound to a literal with that literal
+///
+/// Mutable variables and variables whose value isn't a literal are left untouched. A binding
+/// is only propagated to the reads that follow it, so reads before the binding, like calls
+/// of a function that is declared earlier, still fail at runtime.
+///
+/// ### Arguments
+/// * `statements` - The analyzed statements of the program
+///
+/// ### Returns
+/// True if a variable read was replaced, false otherwise
+///
+/// ### Example
+/// ```
+/// use slang_frontend::optimizer::propagate_constants;
+/// use slang_frontend::{parser, tokenize};
+/// use slang_ir::ast::{Expression, LiteralValue, Statement};
+/// use slang_shared::CompilationContext;
+///
+/// let mut context = CompilationContext::new();
+/// let lexed = tokenize("let x = 5; let mut y = 1; x + y;").unwrap();
+/// let mut statements = parser::parse(&lexed.tokens, &lexed.line_info, &mut context).unwrap();
+///
+/// assert!(propagate_constants(&mut statements));
+/// let Statement::Expression(Expression::Binary(sum)) = &statements[2] else {
+///     unreachable!()
+/// };
+/// let Expression::Literal(x) = sum.left.as_ref() else { unreachable!() };
+/// assert_eq!(x.value, LiteralValue::UnspecifiedInteger(5));
+/// assert!(matches!(sum.right.as_ref(), Expression::Variable(_)));
+///
+/// assert!(!propagate_constants(&mut statements));
+/// ```
+pub fn propagate_constants(statements: &mut [Statement]) -> bool {
+    let mut propagation = ConstantPropagation {
+        scopes: vec![HashMap::new()],
+        replaced: false,
+    };
+    propagation.statements(statements);
+    propagation.replaced
+}
+
+/// Replaces constant subexpressions with the literal they evaluate to
+///
+/// If statements and conditional expressions with a constant condition are replaced by
+/// the selected branch, so the other branch is eliminated.
+///
+/// Expressions whose evaluation fails at runtime, like an overflowing addition, are left
+/// untouched so that the runtime error is still reported.
+///
+/// ### Arguments
+/// * `statements` - The statements to fold
+/// * `context` - The compilation context used for range checks of integer literals
+pub fn fold_constants(statements: &mut [Statement], context: &CompilationContext) {
+    for statement in statements {
+        fold_statement(statement, context);
+    }
+}
+
+/// Folds the constant subexpressions of a single statement
+///
+/// ### Arguments
+/// * `statement` - The statement to fold
+/// * `context` - The compilation context used for range checks of integer literals
+fn fold_statement(statement: &mut Statement, context: &CompilationContext) {
+    match statement {
+        Statement::Let(let_stmt) => fold_expression(&mut let_stmt.value, context),
+        Statement::Assignment(assign_stmt) => fold_expression(&mut assign_stmt.value, context),
+        Statement::Expression(expr) => fold_expression(expr, context),
+        Statement::TypeDefinition(_) | Statement::EnumDefinition(_) => {}
+        Statement::FunctionDeclaration(fn_decl) => fold_block(&mut fn_decl.body, context),
+        Statement::Impl(impl_stmt) => {
+            for method in &mut impl_stmt.methods {
+                fold_block(&mut method.body, context);
+            }
+        }
+        Statement::Return(return_stmt) => {
+            if let Some(value) = &mut return_stmt.value {
+                fold_expression(value, context);
+            }
+        }
+        Statement::If(if_stmt) => {
+            fold_expression(&mut if_stmt.condition, context);
+            fold_block(&mut if_stmt.then_branch, context);
+            if let Some(else_branch) = &mut if_stmt.else_branch {
+                fold_block(else_branch, context);
+            }
+
+            // Only the branch selected by a constant condition is kept, as a plain block
+            let Some(condition) = constant_condition(&if_stmt.condition) else {
+                return;
+            };
+            let location = if_stmt.location;
+            let Statement::If(if_stmt) = std::mem::replace(statement, empty_statement(location))
+            else {
+                unreachable!("the statement was matched as an if statement");
+            };
+            let branch = if condition {
+                Some(if_stmt.then_branch)
+            } else {
+                if_stmt.else_branch
+            };
+            if let Some(branch) = branch {
+                *statement = Statement::Expression(Expression::Block(branch));
+            }
+        }
+        Statement::For(for_stmt) => {
+            fold_expression(&mut for_stmt.start, context);
+            fold_expression(&mut for_stmt.end, context);
+            fold_block(&mut for_stmt.body, context);
+        }
+    }
+}
+
+/// Folds the constant subexpressions of a block
+///
+/// ### Arguments
+/// * `block` - The block to fold
+/// * `context` - The compilation context used for range checks of integer literals
+fn fold_block(block: &mut BlockExpr, context: &CompilationContext) {
+    fold_constants(&mut block.statements, context);
+    if let Some(return_expr) = &mut block.return_expr {
+        fold_expression(return_expr, context);
+    }
+}
+
+/// Folds an expression bottom-up, replacing it with a literal if it is constant
+///
+/// ### Arguments
+/// * `expr` - The expression to fold
+/// * `context` - The compilation context used for range checks of integer literals
+fn fold_expression(expr: &mut Expression, context: &CompilationContext) {
+    match expr {
+        Expression::Literal(_)
+        | Expression::Variable(_)
+        | Expression::FunctionType(_) => return,
+        Expression::Binary(bin_expr) => {
+            fold_expression(&mut bin_expr.left, context);
+            fold_expression(&mut bin_expr.right, context);
+            merge_concatenated_strings(bin_expr);
+        }
+        Expression::Unary(unary_expr) => fold_expression(&mut unary_expr.right, context),
+        Expression::Call(call_expr) => {
+            for argument in &mut call_expr.arguments {
+                fold_expression(argument, context);
+            }
+            return;
+        }
+        Expression::MethodCall(method_call) => {
+            fold_expression(&mut method_call.receiver, context);
+            for argument in &mut method_call.arguments {
+                fold_expression(argument, context);
+            }
+            return;
+        }
+        Expression::Conditional(cond_expr) => {
+            fold_expression(&mut cond_expr.condition, context);
+            fold_expression(&mut cond_expr.then_branch, context);
+            fold_expression(&mut cond_expr.else_branch, context);
+
+            // A constant condition selects a branch, the other one is dead code
+            if let Some(condition) = constant_condition(&cond_expr.condition) {
+                let branch = if condition {
+                    &mut cond_expr.then_branch
+                } else {
+                    &mut cond_expr.else_branch
+                };
+                let location = branch.location();
+                let placeholder = Expression::Literal(LiteralExpr {
+                    value: LiteralValue::Unit,
+                    expr_type: Cell::new(TypeId::unit()),
+                    location,
+                });
+                *expr = std::mem::replace(branch.as_mut(), placeholder);
+                return;
+            }
+        }
+        Expression::Block(block_expr) => fold_block(block_expr, context),
+        Expression::Closure(closure_expr) => {
+            fold_block(&mut closure_expr.body, context);
+            return;
+        }
+    }
+
+    // An operand that couldn't be folded fails at runtime, so the expression must be kept
+    if !operands_folded(expr) {
+        return;
+    }
+
+    let Some(value) = const_eval(expr, context) else {
+        return;
+    };
+
+    // The VM reports float overflow and division by zero, so these results must not be folded
+    let non_finite = match value {
+        LiteralValue::F32(f) => !f.is_finite(),
+        LiteralValue::F64(f) | LiteralValue::UnspecifiedFloat(f) => !f.is_finite(),
+        _ => false,
+    };
+    if non_finite {
+        return;
+    }
+
+    *expr = Expression::Literal(LiteralExpr {
+        expr_type: Cell::new(TypeInference {}.infer_literal_type(&value)),
+        value,
+        location: expr.location(),
+    });
+}
+
+/// Gets the value of a condition that has been folded to a boolean literal
+///
+/// ### Arguments
+/// * `condition` - The folded condition
+///
+/// ### Returns
+/// The value of the condition, or `None` if it isn't known at compile time
+fn constant_condition(condition: &Expression) -> Option<bool> {
+    match condition {
+        Expression::Literal(LiteralExpr {
+            value: LiteralValue::Boolean(value),
+            ..
+        }) => Some(*value),
+        _ => None,
+    }
+}
+
+/// Creates a statement that does nothing, used in place of an eliminated if statement
+///
+/// ### Arguments
+/// * `location` - The location of the eliminated statement
+///
+/// ### Returns
+/// An expression statement with an empty block
+fn empty_statement(location: Location) -> Statement {
+    Statement::Expression(Expression::Block(BlockExpr {
+        statements: Vec::new(),
+        return_expr: None,
+        expr_type: TypeId::unit(),
+        location,
+    }))
+}
+
+/// Merges adjacent string literals at the end of a concatenation chain
+///
+/// Concatenation is associative, so `x + "a" + "b"`, which is parsed as `(x + "a") + "b"`,
+/// can be rewritten to `x + "ab"`. Concatenations of two literals are folded by
+/// `const_eval` instead.
+///
+/// ### Arguments
+/// * `bin_expr` - The binary expression whose operands have already been folded
+fn merge_concatenated_strings(bin_expr: &mut BinaryExpr) {
+    let Expression::Literal(LiteralExpr {
+        value: LiteralValue::String(tail),
+        ..
+    }) = &*bin_expr.right
+    else {
+        return;
+    };
+    let Expression::Binary(inner) = &*bin_expr.left else {
+        return;
+    };
+    let Expression::Literal(LiteralExpr {
+        value: LiteralValue::String(head),
+        ..
+    }) = &*inner.right
+    else {
+        return;
+    };
+    if bin_expr.operator != BinaryOperator::Add || inner.operator != BinaryOperator::Add {
+        return;
+    }
+
+    let merged = format!("{}{}", head, tail);
+    let location = inner.right.location();
+    let placeholder = Expression::Literal(LiteralExpr {
+        value: LiteralValue::Unit,
+        expr_type: Cell::new(TypeId::unit()),
+        location,
+    });
+    let Expression::Binary(inner) = std::mem::replace(&mut *bin_expr.left, placeholder) else {
+        unreachable!("the left operand was checked to be a binary expression");
+    };
+
+    bin_expr.left = inner.left;
+    *bin_expr.right = Expression::Literal(LiteralExpr {
+        value: LiteralValue::String(merged),
+        expr_type: Cell::new(TypeId::string()),
+        location,
+    });
+}
+
+/// Checks whether all operands an expression evaluates at runtime are literals
+///
+/// ### Arguments
+/// * `expr` - The expression whose operands have already been folded
+///
+/// ### Returns
+/// True if the operands are literals, false otherwise
+fn operands_folded(expr: &Expression) -> bool {
+    fn literal(expr: &Expression) -> Option<&LiteralValue> {
+        match expr {
+            Expression::Literal(literal) => Some(&literal.value),
+            _ => None,
+        }
+    }
+
+    match expr {
+        Expression::Unary(unary_expr) => literal(&unary_expr.right).is_some(),
+        Expression::Binary(bin_expr) => match (&bin_expr.operator, literal(&bin_expr.left)) {
+            (_, None) => false,
+            // The right operand isn't evaluated if the left one determines the result
+            (BinaryOperator::And, Some(LiteralValue::Boolean(false)))
+            | (BinaryOperator::Or, Some(LiteralValue::Boolean(true))) => true,
+            (_, Some(_)) => literal(&bin_expr.right).is_some(),
+        },
+        Expression::Conditional(cond_expr) => match literal(&cond_expr.condition) {
+            Some(LiteralValue::Boolean(true)) => literal(&cond_expr.then_branch).is_some(),
+            Some(LiteralValue::Boolean(false)) => literal(&cond_expr.else_branch).is_some(),
+            _ => false,
+        },
+        Expression::Block(block_expr) => {
+            block_expr.statements.is_empty()
+                && block_expr
+                    .return_expr
+                    .as_deref()
+                    .is_some_and(|return_expr| literal(return_expr).is_some())
+        }
+        _ => false,
+    }
+}
+
+/// The literal values of the variables that are visible while constants are propagated
+struct ConstantPropagation {
+    /// The innermost scope is last, variables that can't be propagated map to None
+    scopes: Vec<HashMap<String, Option<LiteralExpr>>>,
+    /// Whether a variable read was replaced
+    replaced: bool,
+}
+
+impl ConstantPropagation {
+    /// Propagates constants through a sequence of statements in the current scope
+    ///
+    /// ### Arguments
+    /// * `statements` - The statements to propagate through
+    fn statements(&mut self, statements: &mut [Statement]) {
+        for statement in statements {
+            self.statement(statement);
+        }
+    }
+
+    /// Propagates constants through a single statement
+    ///
+    /// ### Arguments
+    /// * `statement` - The statement to propagate through
+    fn statement(&mut self, statement: &mut Statement) {
+        match statement {
+            Statement::Let(let_stmt) => {
+                self.expression(&mut let_stmt.value);
+                if let_stmt.name != LetStatement::WILDCARD {
+                    self.define(&let_stmt.name, propagated_value(let_stmt));
+                }
+            }
+            Statement::Assignment(assign_stmt) => self.expression(&mut assign_stmt.value),
+            Statement::Expression(expr) => self.expression(expr),
+            Statement::TypeDefinition(_) | Statement::EnumDefinition(_) => {}
+            Statement::FunctionDeclaration(fn_decl) => {
+                self.define(&fn_decl.name, None);
+                self.scoped_block(&fn_decl.parameters, &mut fn_decl.body);
+            }
+            Statement::Impl(impl_stmt) => {
+                for method in &mut impl_stmt.methods {
+                    self.scoped_block(&method.parameters, &mut method.body);
+                }
+            }
+            Statement::Return(return_stmt) => {
+                if let Some(value) = &mut return_stmt.value {
+                    self.expression(value);
+                }
+            }
+            Statement::If(if_stmt) => {
+                self.expression(&mut if_stmt.condition);
+                self.scoped_block(&[], &mut if_stmt.then_branch);
+                if let Some(else_branch) = &mut if_stmt.else_branch {
+                    self.scoped_block(&[], else_branch);
+                }
+            }
+            Statement::For(for_stmt) => {
+                self.expression(&mut for_stmt.start);
+                self.expression(&mut for_stmt.end);
+                let loop_scope = HashMap::from([(for_stmt.variable.clone(), None)]);
+                self.scopes.push(loop_scope);
+                self.block(&mut for_stmt.body);
+                self.scopes.pop();
+            }
+        }
+    }
+
+    /// Propagates constants through a block in a new scope that holds the given parameters
+    ///
+    /// ### Arguments
+    /// * `parameters` - The parameters that shadow outer variables inside the block
+    /// * `block` - The block to propagate through
+    fn scoped_block(&mut self, parameters: &[Parameter], block: &mut BlockExpr) {
+        let parameters = parameters
+            .iter()
+            .map(|parameter| (parameter.name.clone(), None));
+        self.scopes.push(parameters.collect());
+        self.block(block);
+        self.scopes.pop();
+    }
+
+    /// Propagates constants through the statements and the final expression of a block
+    ///
+    /// ### Arguments
+    /// * `block` - The block to propagate through
+    fn block(&mut self, block: &mut BlockExpr) {
+        self.statements(&mut block.statements);
+        if let Some(return_expr) = &mut block.return_expr {
+            self.expression(return_expr);
+        }
+    }
+
+    /// Replaces the reads of propagated variables inside an expression
+    ///
+    /// ### Arguments
+    /// * `expr` - The expression to propagate through
+    fn expression(&mut self, expr: &mut Expression) {
+        match expr {
+            Expression::Literal(_) | Expression::FunctionType(_) => {}
+            Expression::Variable(var_expr) => {
+                let Some(literal) = self.lookup(&var_expr.name) else {
+                    return;
+                };
+                *expr = Expression::Literal(LiteralExpr {
+                    location: var_expr.location,
+                    ..literal
+                });
+                self.replaced = true;
+            }
+            Expression::Binary(bin_expr) => {
+                self.expression(&mut bin_expr.left);
+                self.expression(&mut bin_expr.right);
+            }
+            Expression::Unary(unary_expr) => self.expression(&mut unary_expr.right),
+            Expression::Call(call_expr) => {
+                for argument in &mut call_expr.arguments {
+                    self.expression(argument);
+                }
+            }
+            Expression::MethodCall(method_call) => {
+                self.expression(&mut method_call.receiver);
+                for argument in &mut method_call.arguments {
+                    self.expression(argument);
+                }
+            }
+            Expression::Conditional(cond_expr) => {
+                self.expression(&mut cond_expr.condition);
+                self.expression(&mut cond_expr.then_branch);
+                self.expression(&mut cond_expr.else_branch);
+            }
+            Expression::Block(block_expr) => self.scoped_block(&[], block_expr),
+            Expression::Closure(closure_expr) => {
+                self.scoped_block(&closure_expr.parameters, &mut closure_expr.body)
+            }
+        }
+    }
+
+    /// Defines a variable in the innermost scope, shadowing outer variables of the same name
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the variable
+    /// * `value` - The literal the variable is bound to, or None if it can't be propagated
+    fn define(&mut self, name: &str, value: Option<LiteralExpr>) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.to_string(), value);
+        }
+    }
+
+    /// Looks up the literal a variable is bound to
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the variable
+    ///
+    /// ### Returns
+    /// A copy of the literal, or None if the variable can't be propagated
+    fn lookup(&self, name: &str) -> Option<LiteralExpr> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name))
+            .and_then(|value| value.as_ref())
+            .map(|literal| LiteralExpr {
+                value: literal.value.clone(),
+                expr_type: literal.expr_type.clone(),
+                location: literal.location,
+            })
+    }
+}
+
+/// Gets the literal an immutable let binding can be propagated as
+///
+/// The literal must have the type of the variable, so bindings whose annotation differs from
+/// the literal type, like `let x: i32 = 5;`, are not propagated.
+///
+/// ### Arguments
+/// * `let_stmt` - The let binding, whose value has already been propagated through
+///
+/// ### Returns
+/// A copy of the bound literal, or None if the binding can't be propagated
+fn propagated_value(let_stmt: &LetStatement) -> Option<LiteralExpr> {
+    let Expression::Literal(literal) = &let_stmt.value else {
+        return None;
+    };
+    let annotated = let_stmt.expr_type != TypeId::unknown();
+    if let_stmt.is_mutable || (annotated && let_stmt.expr_type != literal.expr_type.get()) {
+        return None;
+    }
+
+    Some(LiteralExpr {
+        value: literal.value.clone(),
+        expr_type: literal.expr_type.clone(),
+        location: literal.location,
+    })
+}
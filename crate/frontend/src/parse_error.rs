@@ -11,6 +11,8 @@ pub struct ParseError {
     position: usize,
     /// Length of the underlined part
     underline_length: usize,
+    /// Optional hint on how to fix the error
+    suggestion: Option<String>,
 }
 
 impl ParseError {
@@ -26,19 +28,36 @@ impl ParseError {
             message: message.to_string(),
             position,
             underline_length,
+            suggestion: None,
         }
     }
 
+    /// Attaches a hint on how to fix the error
+    ///
+    /// ### Arguments
+    /// * `suggestion` - The text of the hint
+    ///
+    /// ### Returns
+    /// The error with the given suggestion
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
     pub fn to_compiler_error(&self, line_info: &LineInfo) -> CompilerError {
         let line_pos = line_info.get_line_col(self.position);
-        CompilerError::new(
+        let error = CompilerError::new(
             self.error_code,
             self.message.clone(),
             line_pos.0,
             line_pos.1,
             self.position,
             Some(self.underline_length),
-        )
+        );
+        match &self.suggestion {
+            Some(suggestion) => error.with_suggestion(suggestion.clone()),
+            None => error,
+        }
     }
 }
 
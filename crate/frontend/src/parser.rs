@@ -1,16 +1,20 @@
-use slang_error::{LineInfo, CompileResult, CompilerError, ErrorCode};
+use slang_error::{LineInfo, CompileResult, CompilerError, ErrorCode, ErrorCollector};
 use crate::token::{Token, Tokentype};
 use crate::parse_error::ParseError;
 use slang_ir::Location;
 use slang_ir::ast::{
-    BinaryExpr, BinaryOperator, BlockExpr, ConditionalExpr, Expression, FunctionCallExpr,
-    FunctionDeclarationStmt, FunctionTypeExpr, IfStatement, LetStatement, LiteralExpr, LiteralValue, Parameter,
+    BinaryExpr, BinaryOperator, BlockExpr, ClosureExpr, ConditionalExpr, EnumDefinitionStmt,
+    Expression, ForStatement, FunctionCallExpr, FunctionDeclarationStmt, FunctionTypeExpr,
+    IfStatement, ImplStmt, LetStatement, LiteralExpr, LiteralValue, MethodCallExpr, Parameter,
     Statement, TypeDefinitionStmt, UnaryExpr, UnaryOperator,
 };
-use slang_shared::{CompilationContext, SymbolKind};
+use slang_shared::{CompilationContext, SymbolKind, qualified_name};
+use std::cell::{Cell, OnceCell};
+use std::collections::HashSet;
 use slang_types::{
     PrimitiveType, TYPE_NAME_F32, TYPE_NAME_F64, TYPE_NAME_FLOAT, TYPE_NAME_I32, TYPE_NAME_I64,
-    TYPE_NAME_INT, TYPE_NAME_U32, TYPE_NAME_U64, TYPE_NAME_UNKNOWN, TypeId,
+    TYPE_NAME_I128, TYPE_NAME_INT, TYPE_NAME_U32, TYPE_NAME_U64, TYPE_NAME_U128, TYPE_NAME_UNKNOWN,
+    TypeId, TypeKind,
 };
 
 /// Parser that converts tokens into an abstract syntax tree
@@ -25,17 +29,150 @@ pub struct Parser<'a> {
     errors: Vec<CompilerError>,
     /// Compilation context for type information
     context: &'a mut CompilationContext,
+    /// Enums registered before parsing whose definition hasn't been parsed yet
+    declared_enums: HashSet<String>,
+    /// Whether a trailing bare expression is accepted at the top level
+    mode: ParseMode,
 }
 
+/// Determines which programs the parser accepts at the top level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    /// Every statement must be terminated, as required in source files
+    #[default]
+    File,
+    /// The last expression of the input may omit its semicolon, so that interactive input
+    /// like `1 + 2` is parsed as an expression statement whose value can be shown
+    Repl,
+}
+
+/// Parses a list of tokens into the statements of a program
+///
+/// Symbols of other modules are referred to by their qualified path, e.g. `math::add`,
+/// and resolve against the symbols a module defined in the context.
+///
+/// ### Arguments
+///
+/// * `tokens` - The tokens to parse
+/// * `line_info` - Line information for error reporting
+/// * `context` - The compilation context
+///
+/// ### Returns
+///
+/// The parsed statements or the syntax errors that were found
+///
+/// ### Example
+/// ```
+/// use slang_frontend::{execute, parser, tokenize, ErrorCode};
+/// use slang_shared::CompilationContext;
+/// use slang_types::TypeId;
+///
+/// let mut context = CompilationContext::new();
+/// let add_type = context.register_function_type(vec![TypeId::i64(), TypeId::i64()], TypeId::i64());
+/// context.module("math").define_function("add", add_type, 2).unwrap();
+///
+/// let lexed = tokenize("let sum: i64 = math::add(1, 2);").unwrap();
+/// let statements = parser::parse(&lexed.tokens, &lexed.line_info, &mut context).unwrap();
+/// assert!(execute(&statements, &mut context).is_ok());
+///
+/// let lexed = tokenize("add(1, 2);").unwrap();
+/// let statements = parser::parse(&lexed.tokens, &lexed.line_info, &mut context).unwrap();
+/// let errors = execute(&statements, &mut context).unwrap_err();
+/// assert_eq!(errors[0].error_code, ErrorCode::UndefinedFunction);
+/// ```
 pub fn parse<'a>(
     tokens: &'a [Token],
     line_info: &'a LineInfo,
     context: &'a mut CompilationContext,
+) -> CompileResult<Vec<Statement>> {
+    parse_with_mode(tokens, line_info, context, ParseMode::File)
+}
+
+/// Parses a list of tokens into the statements of a program, using the given parse mode
+///
+/// ### Arguments
+///
+/// * `tokens` - The tokens to parse
+/// * `line_info` - Line information for error reporting
+/// * `context` - The compilation context
+/// * `mode` - Whether the input is a source file or interactive input
+///
+/// ### Returns
+///
+/// The parsed statements or the syntax errors that were found
+///
+/// ### Example
+/// ```
+/// use slang_frontend::parser::{ParseMode, parse_with_mode};
+/// use slang_frontend::{ErrorCode, tokenize};
+/// use slang_ir::ast::{Expression, Statement};
+/// use slang_shared::CompilationContext;
+///
+/// let mut context = CompilationContext::new();
+/// let lexed = tokenize("1 + 2").unwrap();
+///
+/// let statements =
+///     parse_with_mode(&lexed.tokens, &lexed.line_info, &mut context, ParseMode::Repl).unwrap();
+/// assert_eq!(statements.len(), 1);
+/// assert!(matches!(statements[0], Statement::Expression(Expression::Binary(_))));
+///
+/// let errors =
+///     parse_with_mode(&lexed.tokens, &lexed.line_info, &mut context, ParseMode::File).unwrap_err();
+/// assert_eq!(errors[0].error_code, ErrorCode::ExpectedSemicolon);
+/// ```
+pub fn parse_with_mode<'a>(
+    tokens: &'a [Token],
+    line_info: &'a LineInfo,
+    context: &'a mut CompilationContext,
+    mode: ParseMode,
 ) -> CompileResult<Vec<Statement>> {
     let mut parser = Parser::new(tokens, line_info, context);
+    parser.mode = mode;
     parser.parse()
 }
 
+/// Parses the tokens, keeping the statements that parsed successfully alongside the errors
+///
+/// Statements containing a syntax error are skipped, so that later compilation phases can
+/// still check the rest of the program. A malformed variable or function declaration is
+/// replaced by a binding of unknown type, so its name stays declared.
+///
+/// ### Arguments
+///
+/// * `tokens` - The tokens to parse
+/// * `line_info` - Line information for error reporting
+/// * `context` - The compilation context
+///
+/// ### Returns
+///
+/// The successfully parsed statements and the collected syntax errors
+///
+/// ### Example
+/// ```
+/// use slang_frontend::{parser, tokenize};
+/// use slang_shared::CompilationContext;
+///
+/// let mut context = CompilationContext::new();
+/// let lexed = tokenize("let a = ;\nlet b = 2;\nlet = 3;").unwrap();
+/// let (statements, mut errors) = parser::parse_partial(&lexed.tokens, &lexed.line_info, &mut context);
+///
+/// assert_eq!(statements.len(), 2);
+/// assert_eq!(errors.take_errors().len(), 2);
+/// ```
+pub fn parse_partial<'a>(
+    tokens: &'a [Token],
+    line_info: &'a LineInfo,
+    context: &'a mut CompilationContext,
+) -> (Vec<Statement>, ErrorCollector) {
+    let mut parser = Parser::new(tokens, line_info, context);
+    let statements = parser.parse_statements();
+    let mut errors = ErrorCollector::new();
+    for error in std::mem::take(&mut parser.errors) {
+        errors.add_error(error);
+    }
+    (statements, errors)
+}
+
 impl<'a> Parser<'a> {
     /// Creates a new parser for the given tokens and line information
     ///
@@ -55,6 +192,8 @@ impl<'a> Parser<'a> {
             line_info,
             errors: Vec::new(),
             context,
+            declared_enums: HashSet::new(),
+            mode: ParseMode::File,
         }
     }
 
@@ -64,22 +203,88 @@ impl<'a> Parser<'a> {
     ///
     /// The parsed statements or an error message
     fn parse(&mut self) -> CompileResult<Vec<Statement>> {
+        let statements = self.parse_statements();
+
+        if !self.errors.is_empty() {
+            Err(std::mem::take(&mut self.errors))
+        } else {
+            Ok(statements)
+        }
+    }
+
+    /// Parses all statements, recording the errors of statements that fail to parse
+    ///
+    /// ### Returns
+    ///
+    /// The statements that were parsed successfully
+    fn parse_statements(&mut self) -> Vec<Statement> {
         let mut statements = Vec::new();
+        self.declare_types();
 
         while !self.is_at_end() {
-            match self.statement() {
+            let start = self.current;
+            let statement = if self.match_token(&Tokentype::Enum) {
+                self.enum_definition_statement()
+            } else if self.match_token(&Tokentype::Impl) {
+                self.impl_statement()
+            } else {
+                self.statement()
+            };
+            match statement {
                 Ok(stmt) => statements.push(stmt),
                 Err(e) => {
                     self.errors.push(e.to_compiler_error(self.line_info));
-                    self.synchronize();
+                    statements.extend(self.declaration_placeholder(start));
+                    self.synchronize(start);
                 }
             }
         }
 
-        if !self.errors.is_empty() {
-            Err(std::mem::take(&mut self.errors))
-        } else {
-            Ok(statements)
+        statements
+    }
+
+    /// Declares the structs and enums defined at the top level before any statement is parsed
+    ///
+    /// This allows field types and type annotations to refer to types that are defined later
+    /// in the file. The fields of structs are validated and registered by the semantic analysis.
+    fn declare_types(&mut self) {
+        let mut depth = 0usize;
+        for (index, window) in self.tokens.windows(2).enumerate() {
+            match window[0].token_type {
+                Tokentype::LeftBrace => depth += 1,
+                Tokentype::RightBrace => depth = depth.saturating_sub(1),
+                Tokentype::Struct
+                    if depth == 0
+                        && window[1].token_type == Tokentype::Identifier
+                        && self.context.lookup_symbol(&window[1].lexeme).is_none() =>
+                {
+                    self.context.declare_struct_type(&window[1].lexeme).ok();
+                }
+                Tokentype::Enum
+                    if depth == 0
+                        && window[1].token_type == Tokentype::Identifier
+                        && self.context.lookup_symbol(&window[1].lexeme).is_none() =>
+                {
+                    let variants = self.tokens[index + 2..]
+                        .iter()
+                        .skip_while(|token| token.token_type == Tokentype::LeftBrace)
+                        .take_while(|token| {
+                            matches!(token.token_type, Tokentype::Identifier | Tokentype::Comma)
+                        })
+                        .filter(|token| token.token_type == Tokentype::Identifier)
+                        .map(|token| token.lexeme.clone())
+                        .collect();
+                    let name = window[1].lexeme.clone();
+                    if self
+                        .context
+                        .register_enum_type(name.clone(), variants)
+                        .is_ok()
+                    {
+                        self.declared_enums.insert(name);
+                    }
+                }
+                _ => {}
+            }
         }
     }
 
@@ -119,17 +324,111 @@ impl<'a> Parser<'a> {
         )
     }
 
+    /// Creates a binding of unknown type for a variable or function that failed to parse
+    ///
+    /// The name is still declared, so that the later uses of it aren't reported as undefined
+    /// on top of the syntax error of the declaration.
+    ///
+    /// ### Arguments
+    ///
+    /// * `start` - The position of the first token of the malformed statement
+    ///
+    /// ### Returns
+    ///
+    /// A let statement binding the declared name, or None if the statement doesn't declare one
+    fn declaration_placeholder(&self, start: usize) -> Option<Statement> {
+        let name_index = match self.tokens.get(start)?.token_type {
+            Tokentype::Let if self.tokens.get(start + 1)?.token_type == Tokentype::Mut => start + 2,
+            Tokentype::Let | Tokentype::Fn => start + 1,
+            _ => return None,
+        };
+        let name_token = self.tokens.get(name_index)?;
+        if name_token.token_type != Tokentype::Identifier {
+            return None;
+        }
+
+        let location = self.source_location_from_token(name_token);
+        Some(Statement::Let(LetStatement {
+            name: name_token.lexeme.clone(),
+            is_mutable: name_index == start + 2,
+            value: Expression::Literal(LiteralExpr {
+                value: LiteralValue::Unit,
+                expr_type: Cell::new(TypeId::unknown()),
+                location,
+            }),
+            expr_type: TypeId::unknown(),
+            location,
+        }))
+    }
+
     /// Skip until a safe synchronization point (e.g., semicolon or statement start)
-    fn synchronize(&mut self) {
-        self.advance();
+    ///
+    /// If the malformed statement didn't consume any token, the token it starts with is
+    /// skipped, so that parsing always makes progress.
+    ///
+    /// ### Arguments
+    ///
+    /// * `start` - The position of the first token of the malformed statement
+    fn synchronize(&mut self, start: usize) {
+        if self.current == start {
+            self.advance();
+        }
+        self.skip_to_statement_boundary();
+
+        // Outside of a block, an unmatched `}` closes the malformed statement
+        if self.match_token(&Tokentype::RightBrace) {
+            self.match_token(&Tokentype::Semicolon);
+        }
+    }
+
+    /// Skips the rest of a malformed statement without moving past the given closing token
+    ///
+    /// Used inside delimited statement lists like blocks, where the closing token has to be
+    /// left for the caller to consume.
+    ///
+    /// ### Arguments
+    ///
+    /// * `closing` - The token that ends the enclosing statement list
+    /// * `start` - The position of the first token of the malformed statement
+    fn recover_to(&mut self, closing: &Tokentype, start: usize) {
+        if self.check(closing) {
+            return;
+        }
+        if self.current == start {
+            self.advance();
+        }
+        self.skip_to_statement_boundary();
+    }
+
+    /// Skips tokens until the next statement boundary
+    ///
+    /// A boundary is right after a semicolon, or before a statement keyword, an unmatched `}`
+    /// or the first token on a new line. A line break between an operator and its operand
+    /// continues the malformed expression, so it isn't a boundary. Braces and parentheses
+    /// opened while skipping have to be closed before a boundary is reached.
+    fn skip_to_statement_boundary(&mut self) {
+        let mut depth = 0usize;
 
         while !self.is_at_end() {
-            if self.previous().token_type == Tokentype::Semicolon {
+            if depth == 0 && self.previous().token_type == Tokentype::Semicolon {
                 return;
             }
 
             match self.peek().token_type {
-                Tokentype::Let | Tokentype::Fn | Tokentype::Struct | Tokentype::Return => {
+                Tokentype::LeftBrace | Tokentype::LeftParen => depth += 1,
+                Tokentype::RightBrace if depth == 0 => return,
+                Tokentype::RightBrace | Tokentype::RightParen => depth = depth.saturating_sub(1),
+                Tokentype::Let
+                | Tokentype::Fn
+                | Tokentype::Struct
+                | Tokentype::Enum
+                | Tokentype::Impl
+                | Tokentype::Return
+                    if depth == 0 =>
+                {
+                    return;
+                }
+                _ if depth == 0 && self.starts_new_line() && !self.continues_expression() => {
                     return;
                 }
                 _ => {}
@@ -138,6 +437,64 @@ impl<'a> Parser<'a> {
             self.advance();
         }
     }
+
+    /// Checks whether an operator connects the previous and the current token
+    ///
+    /// A statement can't end with an operator, and the infix operators can't start one.
+    /// Prefix operators like `-` and `!` are only treated as a continuation after another
+    /// token, as they may also start an expression statement.
+    ///
+    /// ### Returns
+    ///
+    /// True if the current token continues the expression of the previous one, false otherwise
+    fn continues_expression(&self) -> bool {
+        let is_infix_operator = |token_type: &Tokentype| {
+            matches!(
+                token_type,
+                Tokentype::Plus
+                    | Tokentype::Multiply
+                    | Tokentype::Divide
+                    | Tokentype::Percent
+                    | Tokentype::And
+                    | Tokentype::Or
+                    | Tokentype::Pipe
+                    | Tokentype::Ampersand
+                    | Tokentype::Caret
+                    | Tokentype::Greater
+                    | Tokentype::Less
+                    | Tokentype::GreaterEqual
+                    | Tokentype::LessEqual
+                    | Tokentype::ShiftLeft
+                    | Tokentype::ShiftRight
+                    | Tokentype::ShiftLeftEqual
+                    | Tokentype::ShiftRightEqual
+                    | Tokentype::EqualEqual
+                    | Tokentype::NotEqual
+                    | Tokentype::Equal
+                    | Tokentype::Comma
+                    | Tokentype::Dot
+            )
+        };
+        let previous = &self.previous().token_type;
+        is_infix_operator(previous)
+            || matches!(
+                previous,
+                Tokentype::Minus | Tokentype::Not | Tokentype::Tilde
+            )
+            || is_infix_operator(&self.peek().token_type)
+    }
+
+    /// Checks whether the current token is on a later line than the previous one
+    ///
+    /// ### Returns
+    ///
+    /// True if a line break separates the previous and the current token, false otherwise
+    fn starts_new_line(&self) -> bool {
+        let (previous_line, _) = self.line_info.get_line_col(self.previous().pos);
+        let (current_line, _) = self.line_info.get_line_col(self.peek().pos);
+        current_line > previous_line
+    }
+
     /// Parses a single statement
     ///
     /// ### Returns
@@ -154,6 +511,18 @@ impl<'a> Parser<'a> {
             self.return_statement()
         } else if self.match_token(&Tokentype::If) {
             self.if_statement()
+        } else if self.match_token(&Tokentype::For) {
+            self.for_statement()
+        } else if self.check(&Tokentype::Enum) {
+            Err(self.error(
+                ErrorCode::InvalidSyntax,
+                "Enums can only be defined at the top level",
+            ))
+        } else if self.check(&Tokentype::Impl) {
+            Err(self.error(
+                ErrorCode::InvalidSyntax,
+                "Methods can only be implemented at the top level",
+            ))
         } else if self.check(&Tokentype::Identifier) && self.check_next(&Tokentype::Equal) {
             self.assignment_statement()
         } else {
@@ -203,6 +572,24 @@ impl<'a> Parser<'a> {
     ///
     /// The parsed function declaration or an error message
     fn function_declaration_statement(&mut self) -> Result<Statement, ParseError> {
+        self.function_declaration(None)
+            .map(Statement::FunctionDeclaration)
+    }
+
+    /// Parses the name, parameters, return type and body of a function after the 'fn' keyword
+    ///
+    /// ### Arguments
+    ///
+    /// * `self_type` - The type a method belongs to, which allows its first parameter to be
+    ///   a bare `self` of that type. None for functions outside of an impl block.
+    ///
+    /// ### Returns
+    ///
+    /// The parsed function declaration or an error message
+    fn function_declaration(
+        &mut self,
+        self_type: Option<TypeId>,
+    ) -> Result<FunctionDeclarationStmt, ParseError> {
         if !self.check(&Tokentype::Identifier) {
             return Err(self.error(
                 ErrorCode::ExpectedIdentifier,
@@ -229,8 +616,16 @@ impl<'a> Parser<'a> {
 
         let mut parameters = Vec::new();
         if !self.check(&Tokentype::RightParen) {
-            parameters.push(self.parameter()?);
+            let parameter = match self_type {
+                Some(self_type) if self.check_self_parameter() => self.self_parameter(self_type),
+                _ => self.parameter()?,
+            };
+            parameters.push(parameter);
             while self.match_token(&Tokentype::Comma) {
+                // Allow a trailing comma before the closing parenthesis
+                if self.check(&Tokentype::RightParen) {
+                    break;
+                }
                 if parameters.len() >= 255 {
                     return Err(self.error(
                         ErrorCode::InvalidSyntax,
@@ -266,13 +661,43 @@ impl<'a> Parser<'a> {
 
         let body = self.parse_block_expression()?;
 
-        Ok(Statement::FunctionDeclaration(FunctionDeclarationStmt {
+        Ok(FunctionDeclarationStmt {
             name,
             parameters,
             return_type,
             body,
             location,
-        }))
+        })
+    }
+
+    /// Checks whether the current token is a `self` parameter without a type annotation
+    ///
+    /// ### Returns
+    ///
+    /// True if the current token is `self` and isn't followed by a ':', false otherwise
+    fn check_self_parameter(&self) -> bool {
+        self.check(&Tokentype::Identifier)
+            && self.peek().lexeme == Parameter::SELF
+            && !self.check_next(&Tokentype::Colon)
+    }
+
+    /// Parses the `self` parameter of a method
+    ///
+    /// ### Arguments
+    ///
+    /// * `self_type` - The type the method belongs to
+    ///
+    /// ### Returns
+    ///
+    /// The `self` parameter with the type of the method
+    fn self_parameter(&mut self, self_type: TypeId) -> Parameter {
+        let location = self.source_location_from_token(self.peek());
+        self.advance();
+        Parameter {
+            name: Parameter::SELF.to_string(),
+            param_type: self_type,
+            location,
+        }
     }
 
     /// Parses a function parameter
@@ -325,6 +750,12 @@ impl<'a> Parser<'a> {
         let location = self.source_location_from_token(token);
         let name = self.advance().lexeme.clone();
 
+        // Declare the struct before parsing its fields so that they can refer to it.
+        // Redefinitions are left to the semantic analysis to report.
+        if self.context.lookup_symbol(&name).is_none() {
+            self.context.declare_struct_type(&name).ok();
+        }
+
         if !self.match_token(&Tokentype::LeftBrace) {
             return Err(self.error(
                 ErrorCode::ExpectedOpeningBrace,
@@ -374,6 +805,171 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    /// Parses an enum definition, like `enum Color { Red, Green, Blue }`
+    ///
+    /// Enums defined at the top level are registered before parsing starts, so that type
+    /// annotations can refer to them.
+    ///
+    /// ### Returns
+    ///
+    /// The parsed enum definition or an error message
+    fn enum_definition_statement(&mut self) -> Result<Statement, ParseError> {
+        if !self.check(&Tokentype::Identifier) {
+            return Err(self.error(
+                ErrorCode::ExpectedIdentifier,
+                "Expected enum name after 'enum' keyword",
+            ));
+        }
+
+        let token = self.peek();
+        let location = self.source_location_from_token(token);
+        let name = self.advance().lexeme.clone();
+
+        let declared = self.declared_enums.remove(&name);
+        if !declared && self.context.lookup_symbol(&name).is_some() {
+            return Err(self.error_previous(
+                ErrorCode::SymbolRedefinition,
+                &format!("Type '{}' is already defined", name),
+            ));
+        }
+
+        if !self.match_token(&Tokentype::LeftBrace) {
+            return Err(self.error(
+                ErrorCode::ExpectedOpeningBrace,
+                "Expected '{' after enum name",
+            ));
+        }
+
+        let mut variants: Vec<String> = Vec::new();
+
+        while !self.check(&Tokentype::RightBrace) && !self.is_at_end() {
+            if !self.check(&Tokentype::Identifier) {
+                return Err(self.error(ErrorCode::ExpectedIdentifier, "Expected variant name"));
+            }
+            let variant = self.advance().lexeme.clone();
+
+            if variants.contains(&variant) {
+                return Err(self.error_previous(
+                    ErrorCode::SymbolRedefinition,
+                    &format!(
+                        "Variant '{}' is already defined in enum '{}'",
+                        variant, name
+                    ),
+                ));
+            }
+            variants.push(variant);
+
+            if !self.match_token(&Tokentype::Comma) && !self.check(&Tokentype::RightBrace) {
+                return Err(self.error(
+                    ErrorCode::ExpectedComma,
+                    "Expected ',' after variant or '}'",
+                ));
+            }
+        }
+
+        if !self.match_token(&Tokentype::RightBrace) {
+            return Err(self.error(
+                ErrorCode::ExpectedClosingBrace,
+                "Expected '}' after enum variants",
+            ));
+        }
+
+        // Unlike struct definitions, the semicolon after an enum is optional
+        self.match_token(&Tokentype::Semicolon);
+
+        if !declared {
+            self.context
+                .register_enum_type(name.clone(), variants.clone())
+                .ok();
+        }
+
+        Ok(Statement::EnumDefinition(EnumDefinitionStmt {
+            name,
+            variants,
+            location,
+        }))
+    }
+
+    /// Parses the methods of a type, like `impl Color { fn name(self) -> string { ... } }`
+    ///
+    /// Methods are declared under their qualified name, e.g. `Color::name`, so that they can
+    /// also be called by their path. A method that takes a bare `self` as its first parameter
+    /// can be called on a value of the type, like `color.name()`.
+    ///
+    /// ### Returns
+    ///
+    /// The parsed impl statement or an error message
+    fn impl_statement(&mut self) -> Result<Statement, ParseError> {
+        if !self.check(&Tokentype::Identifier) {
+            return Err(self.error(
+                ErrorCode::ExpectedIdentifier,
+                "Expected type name after 'impl' keyword",
+            ));
+        }
+
+        let token = self.peek();
+        let location = self.source_location_from_token(token);
+        let type_name = self.advance().lexeme.clone();
+
+        let self_type = self
+            .context
+            .lookup_symbol(&type_name)
+            .filter(|symbol| symbol.kind() == SymbolKind::Type)
+            .map(|symbol| symbol.type_id)
+            .filter(|type_id| {
+                self.context.get_struct_type(type_id).is_some()
+                    || self.context.get_enum_type(type_id).is_some()
+            });
+        let Some(self_type) = self_type else {
+            return Err(self.error_previous(
+                ErrorCode::UnknownType,
+                &format!(
+                    "Methods can only be implemented for structs and enums, found '{}'",
+                    type_name
+                ),
+            ));
+        };
+
+        if !self.match_token(&Tokentype::LeftBrace) {
+            return Err(self.error(
+                ErrorCode::ExpectedOpeningBrace,
+                "Expected '{' after type name",
+            ));
+        }
+
+        let mut methods = Vec::new();
+        while !self.check(&Tokentype::RightBrace) && !self.is_at_end() {
+            if !self.match_token(&Tokentype::Fn) {
+                return Err(self.error(
+                    ErrorCode::UnexpectedToken,
+                    &format!(
+                        "Expected method declaration, found {}",
+                        self.peek().token_type
+                    ),
+                ));
+            }
+            let mut method = self.function_declaration(Some(self_type))?;
+            method.name = qualified_name(&type_name, &method.name);
+            methods.push(method);
+        }
+
+        if !self.match_token(&Tokentype::RightBrace) {
+            return Err(self.error(
+                ErrorCode::ExpectedClosingBrace,
+                "Expected '}' after methods",
+            ));
+        }
+
+        // Like after an enum, the semicolon after an impl block is optional
+        self.match_token(&Tokentype::Semicolon);
+
+        Ok(Statement::Impl(ImplStmt {
+            type_name,
+            methods,
+            location,
+        }))
+    }
+
     /// Parses a variable declaration
     ///
     /// ### Returns
@@ -382,7 +978,8 @@ impl<'a> Parser<'a> {
     fn let_statement(&mut self) -> Result<Statement, ParseError> {
         let is_mutable = self.match_token(&Tokentype::Mut);
 
-        if !self.check(&Tokentype::Identifier) {
+        // `_` is only a name in let bindings, where it discards the value
+        if !self.check(&Tokentype::Identifier) && !self.check(&Tokentype::Underscore) {
             return Err(self.error(
                 ErrorCode::ExpectedIdentifier,
                 "Expected identifier after 'let'",
@@ -440,6 +1037,8 @@ impl<'a> Parser<'a> {
             Expression::Block(_) => {
                 // No semicolon required for block expressions
             }
+            // Interactive input may end with an unterminated expression
+            _ if self.mode == ParseMode::Repl && self.is_at_end() => {}
             _ => {
                 if !self.match_token(&Tokentype::Semicolon) {
                     return Err(self.error(
@@ -520,7 +1119,7 @@ impl<'a> Parser<'a> {
     ///
     /// The parsed equality expression or an error message
     fn equality(&mut self) -> Result<Expression, ParseError> {
-        let mut expr = self.comparison()?;
+        let mut expr = self.bitwise_or()?;
 
         while self.match_any(&[Tokentype::EqualEqual, Tokentype::NotEqual]) {
             let left_location = expr.location();
@@ -530,7 +1129,7 @@ impl<'a> Parser<'a> {
                 Tokentype::NotEqual => BinaryOperator::NotEqual,
                 _ => unreachable!(),
             };
-            let right = self.comparison()?;
+            let right = self.bitwise_or()?;
             let right_location = right.location();
             let span_location = left_location.span_to(&right_location);
 
@@ -546,13 +1145,91 @@ impl<'a> Parser<'a> {
         Ok(expr)
     }
 
+    /// Parses a bitwise OR expression (|)
+    ///
+    /// ### Returns
+    ///
+    /// The parsed bitwise OR expression or an error message
+    fn bitwise_or(&mut self) -> Result<Expression, ParseError> {
+        let mut expr = self.bitwise_xor()?;
+
+        while self.match_token(&Tokentype::Pipe) {
+            let left_location = expr.location();
+            let right = self.bitwise_xor()?;
+            let right_location = right.location();
+            let span_location = left_location.span_to(&right_location);
+
+            expr = Expression::Binary(BinaryExpr {
+                left: Box::new(expr),
+                operator: BinaryOperator::BitOr,
+                right: Box::new(right),
+                expr_type: PrimitiveType::Unknown.into(),
+                location: span_location,
+            });
+        }
+
+        Ok(expr)
+    }
+
+    /// Parses a bitwise XOR expression (^)
+    ///
+    /// ### Returns
+    ///
+    /// The parsed bitwise XOR expression or an error message
+    fn bitwise_xor(&mut self) -> Result<Expression, ParseError> {
+        let mut expr = self.bitwise_and()?;
+
+        while self.match_token(&Tokentype::Caret) {
+            let left_location = expr.location();
+            let right = self.bitwise_and()?;
+            let right_location = right.location();
+            let span_location = left_location.span_to(&right_location);
+
+            expr = Expression::Binary(BinaryExpr {
+                left: Box::new(expr),
+                operator: BinaryOperator::BitXor,
+                right: Box::new(right),
+                expr_type: PrimitiveType::Unknown.into(),
+                location: span_location,
+            });
+        }
+
+        Ok(expr)
+    }
+
+    /// Parses a bitwise AND expression (&)
+    ///
+    /// ### Returns
+    ///
+    /// The parsed bitwise AND expression or an error message
+    fn bitwise_and(&mut self) -> Result<Expression, ParseError> {
+        let mut expr = self.comparison()?;
+
+        while self.match_token(&Tokentype::Ampersand) {
+            let left_location = expr.location();
+            let right = self.comparison()?;
+            let right_location = right.location();
+            let span_location = left_location.span_to(&right_location);
+
+            expr = Expression::Binary(BinaryExpr {
+                left: Box::new(expr),
+                operator: BinaryOperator::BitAnd,
+                right: Box::new(right),
+                expr_type: PrimitiveType::Unknown.into(),
+                location: span_location,
+            });
+        }
+
+        Ok(expr)
+    }
+
     /// Parses a comparison expression (>, <, >=, <=)
     ///
     /// ### Returns
     ///
     /// The parsed comparison expression or an error message
     fn comparison(&mut self) -> Result<Expression, ParseError> {
-        let mut expr = self.term()?;
+        let mut expr = self.shift()?;
 
         while self.match_any(&[
             Tokentype::Greater,
@@ -569,7 +1246,7 @@ impl<'a> Parser<'a> {
                 Tokentype::LessEqual => BinaryOperator::LessThanOrEqual,
                 _ => unreachable!(),
             };
-            let right = self.term()?;
+            let right = self.shift()?;
             let right_location = right.location();
             let span_location = left_location.span_to(&right_location);
 
@@ -585,6 +1262,38 @@ impl<'a> Parser<'a> {
         Ok(expr)
     }
 
+    /// Parses a shift expression (<<, >>)
+    ///
+    /// ### Returns
+    ///
+    /// The parsed shift expression or an error message
+    fn shift(&mut self) -> Result<Expression, ParseError> {
+        let mut expr = self.term()?;
+
+        while self.match_any(&[Tokentype::ShiftLeft, Tokentype::ShiftRight]) {
+            let left_location = expr.location();
+            let token = self.previous();
+            let operator = match token.token_type {
+                Tokentype::ShiftLeft => BinaryOperator::ShiftLeft,
+                Tokentype::ShiftRight => BinaryOperator::ShiftRight,
+                _ => unreachable!(),
+            };
+            let right = self.term()?;
+            let right_location = right.location();
+            let span_location = left_location.span_to(&right_location);
+
+            expr = Expression::Binary(BinaryExpr {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+                expr_type: PrimitiveType::Unknown.into(),
+                location: span_location,
+            });
+        }
+
+        Ok(expr)
+    }
+
     /// Parses a term (addition/subtraction)
     ///
     /// ### Returns
@@ -658,34 +1367,94 @@ impl<'a> Parser<'a> {
         if self.match_token(&Tokentype::Minus) {
             let token = self.previous();
             let operator_location = self.source_location_from_token(token);
-            let right = self.primary()?;
+            if let Some(literal) = self.signed_minimum_literal(&operator_location) {
+                return Ok(literal);
+            }
+            let right = self.method_call()?;
             let right_location = right.location();
             let span_location = operator_location.span_to(&right_location);
 
             return Ok(Expression::Unary(UnaryExpr {
                 operator: UnaryOperator::Negate,
                 right: Box::new(right),
-                expr_type: PrimitiveType::Unknown.into(),
+                expr_type: PrimitiveType::Unknown.into(),
+                location: span_location,
+            }));
+        }
+
+        if self.match_token(&Tokentype::Not) {
+            let token = self.previous();
+            let operator_location = self.source_location_from_token(token);
+            let right = self.method_call()?;
+            let right_location = right.location();
+            let span_location = operator_location.span_to(&right_location);
+
+            return Ok(Expression::Unary(UnaryExpr {
+                operator: UnaryOperator::Not,
+                right: Box::new(right),
+                expr_type: PrimitiveType::Bool.into(),
                 location: span_location,
             }));
         }
 
-        if self.match_token(&Tokentype::Not) {
+        if self.match_token(&Tokentype::Tilde) {
             let token = self.previous();
             let operator_location = self.source_location_from_token(token);
-            let right = self.primary()?;
+            let right = self.method_call()?;
             let right_location = right.location();
             let span_location = operator_location.span_to(&right_location);
 
             return Ok(Expression::Unary(UnaryExpr {
-                operator: UnaryOperator::Not,
+                operator: UnaryOperator::BitNot,
                 right: Box::new(right),
-                expr_type: PrimitiveType::Bool.into(),
+                expr_type: PrimitiveType::Unknown.into(),
                 location: span_location,
             }));
         }
 
-        self.primary()
+        self.method_call()
+    }
+
+    /// Parses a primary expression followed by any number of method calls, like `color.name()`
+    ///
+    /// ### Returns
+    ///
+    /// The parsed expression or an error message
+    fn method_call(&mut self) -> Result<Expression, ParseError> {
+        let mut expr = self.primary()?;
+
+        while self.match_token(&Tokentype::Dot) {
+            if !self.check(&Tokentype::Identifier) {
+                return Err(self.error(
+                    ErrorCode::ExpectedIdentifier,
+                    "Expected method name after '.'",
+                ));
+            }
+            let method = self.advance().lexeme.clone();
+
+            if !self.match_token(&Tokentype::LeftParen) {
+                return Err(self.error(
+                    ErrorCode::ExpectedOpeningParen,
+                    &format!(
+                        "Expected '(' after method name, found {}",
+                        self.peek().token_type
+                    ),
+                ));
+            }
+            let (arguments, arguments_location) = self.arguments()?;
+            let location = expr.location().span_to(&arguments_location);
+
+            expr = Expression::MethodCall(MethodCallExpr {
+                receiver: Box::new(expr),
+                method,
+                arguments,
+                arguments_location,
+                function_name: OnceCell::new(),
+                location,
+            });
+        }
+
+        Ok(expr)
     }
 
     /// Parses a primary expression (literal, variable, or grouped expression)
@@ -705,10 +1474,13 @@ impl<'a> Parser<'a> {
         if self.match_token(&Tokentype::StringLiteral) {
             let token = self.previous();
             let value = token.lexeme.clone();
+            let mut location = self.source_location_from_token(token);
+            // The lexeme doesn't include the surrounding quotes
+            location.length += 2;
             return Ok(Expression::Literal(LiteralExpr {
                 value: LiteralValue::String(value),
-                expr_type: PrimitiveType::String.into(),
-                location: self.source_location_from_token(token),
+                expr_type: Cell::new(PrimitiveType::String.into()),
+                location,
             }));
         }
 
@@ -718,7 +1490,7 @@ impl<'a> Parser<'a> {
             let bool_value = lexeme == "true";
             return Ok(Expression::Literal(LiteralExpr {
                 value: LiteralValue::Boolean(bool_value),
-                expr_type: PrimitiveType::Bool.into(),
+                expr_type: Cell::new(PrimitiveType::Bool.into()),
                 location: self.source_location_from_token(token),
             }));
         }
@@ -731,6 +1503,10 @@ impl<'a> Parser<'a> {
             return self.parse_function_type_expression();
         }
 
+        if self.match_token(&Tokentype::Pipe) || self.match_token(&Tokentype::Or) {
+            return self.parse_closure_expression();
+        }
+
         if self.match_token(&Tokentype::LeftParen) {
             // Check for unit literal ()
             if self.check(&Tokentype::RightParen) {
@@ -746,18 +1522,21 @@ impl<'a> Parser<'a> {
                 );
                 return Ok(Expression::Literal(LiteralExpr {
                     value: LiteralValue::Unit,
-                    expr_type: PrimitiveType::Unit.into(),
+                    expr_type: Cell::new(PrimitiveType::Unit.into()),
                     location,
                 }));
             }
 
-            let expr = self.expression()?;
+            let open_paren_location = self.source_location_from_token(self.previous());
+            let mut expr = self.expression()?;
             if !self.match_token(&Tokentype::RightParen) {
                 return Err(self.error(
                     ErrorCode::ExpectedClosingParen,
                     "Expected ')' after expression",
                 ));
             }
+            let close_paren_location = self.source_location_from_token(self.previous());
+            expr.set_location(open_paren_location.span_to(&close_paren_location));
             return Ok(expr);
         }
 
@@ -767,14 +1546,28 @@ impl<'a> Parser<'a> {
         }
 
         if self.match_token(&Tokentype::Identifier) {
-            let name = self.previous().lexeme.clone();
+            let token = self.previous();
+            let mut name = token.lexeme.clone();
+            let mut location = self.source_location_from_token(token);
+
+            // Symbols of other modules are accessed by their qualified path, e.g. math::add
+            while self.match_token(&Tokentype::PathSeparator) {
+                if !self.check(&Tokentype::Identifier) {
+                    return Err(self.error(
+                        ErrorCode::ExpectedIdentifier,
+                        "Expected identifier after '::'",
+                    ));
+                }
+                self.advance();
+                let segment = self.previous();
+                name = qualified_name(&name, &segment.lexeme);
+                location = location.span_to(&self.source_location_from_token(segment));
+            }
 
             if self.match_token(&Tokentype::LeftParen) {
-                return self.finish_call(name);
+                return self.finish_call(name, location);
             }
 
-            let token = self.previous();
-            let location = self.source_location_from_token(token);
             return Ok(Expression::Variable(slang_ir::ast::VariableExpr {
                 name,
                 location,
@@ -796,22 +1589,36 @@ impl<'a> Parser<'a> {
         let token = self.previous();
         let value_str = token.lexeme.clone();
         let location = self.source_location_from_token(token);
-        let value = value_str.parse::<f64>().map_err(|_| {
+        let value = value_str.replace('_', "").parse::<f64>().map_err(|_| {
             self.error_previous(
                 ErrorCode::InvalidNumberLiteral,
                 &format!("Invalid float: {}", value_str),
             )
         })?;
 
+        // Only the `inf` literal may be infinite, a written out number must fit into an f64
+        if value.is_infinite() && value_str != "inf" {
+            return Err(self.error_previous(
+                ErrorCode::ValueOutOfRange,
+                &format!("Value {} is out of range for {}", value_str, TYPE_NAME_F64),
+            ));
+        }
+
         if self.check(&Tokentype::Identifier) {
             let type_name = self.peek().lexeme.clone();
 
             match type_name.as_str() {
                 TYPE_NAME_F32 => {
                     self.advance();
+                    if value.is_finite() && (value as f32).is_infinite() {
+                        return Err(self.error_previous(
+                            ErrorCode::ValueOutOfRange,
+                            &format!("Value {} is out of range for {}", value_str, TYPE_NAME_F32),
+                        ));
+                    }
                     return Ok(Expression::Literal(LiteralExpr {
                         value: LiteralValue::F32(value as f32),
-                        expr_type: PrimitiveType::F32.into(),
+                        expr_type: Cell::new(PrimitiveType::F32.into()),
                         location,
                     }));
                 }
@@ -819,7 +1626,7 @@ impl<'a> Parser<'a> {
                     self.advance();
                     return Ok(Expression::Literal(LiteralExpr {
                         value: LiteralValue::F64(value),
-                        expr_type: PrimitiveType::F64.into(),
+                        expr_type: Cell::new(PrimitiveType::F64.into()),
                         location,
                     }));
                 }
@@ -829,7 +1636,7 @@ impl<'a> Parser<'a> {
 
         Ok(Expression::Literal(LiteralExpr {
             value: LiteralValue::UnspecifiedFloat(value),
-            expr_type: PrimitiveType::UnspecifiedFloat.into(),
+            expr_type: Cell::new(PrimitiveType::UnspecifiedFloat.into()),
             location,
         }))
     }
@@ -839,20 +1646,46 @@ impl<'a> Parser<'a> {
     /// #### Arguments
     ///
     /// * `name` - The name of the function being called
+    /// * `start_location` - The location of the function name
     ///
     /// ### Returns
     ///
     /// The parsed function call expression or an error message
-    fn finish_call(&mut self, name: String) -> Result<Expression, ParseError> {
-        let name_token = self.previous();
-        let start_location = self.source_location_from_token(name_token);
+    fn finish_call(
+        &mut self,
+        name: String,
+        start_location: Location,
+    ) -> Result<Expression, ParseError> {
+        let (arguments, arguments_location) = self.arguments()?;
+        let span_location = Location::merge(&[start_location, arguments_location]);
+
+        Ok(Expression::Call(FunctionCallExpr {
+            name,
+            arguments,
+            arguments_location,
+            expr_type: PrimitiveType::Unknown.into(),
+            location: span_location,
+        }))
+    }
 
+    /// Parses the arguments of a call after the '('
+    ///
+    /// ### Returns
+    ///
+    /// The parsed arguments and the location of the argument list, including the parentheses,
+    /// or an error message
+    fn arguments(&mut self) -> Result<(Vec<Expression>, Location), ParseError> {
+        let open_paren_location = self.source_location_from_token(self.previous());
         let mut arguments = Vec::new();
 
         if !self.check(&Tokentype::RightParen) {
             arguments.push(self.expression()?);
 
             while self.match_token(&Tokentype::Comma) {
+                // Allow a trailing comma before the closing parenthesis
+                if self.check(&Tokentype::RightParen) {
+                    break;
+                }
                 if arguments.len() >= 255 {
                     return Err(self.error(
                         ErrorCode::InvalidSyntax,
@@ -872,113 +1705,148 @@ impl<'a> Parser<'a> {
 
         let closing_paren_token = self.previous();
         let end_location = self.source_location_from_token(closing_paren_token);
-        let span_location = start_location.span_to(&end_location);
+        let arguments_location = Location::merge(&[open_paren_location, end_location]);
 
-        Ok(Expression::Call(FunctionCallExpr {
-            name,
-            arguments,
-            expr_type: PrimitiveType::Unknown.into(),
-            location: span_location,
-        }))
+        Ok((arguments, arguments_location))
     }
 
     /// Parses an integer literal with optional type suffix
     ///
+    /// The literal may be hexadecimal and contain `_` separators, which are removed before the
+    /// value is range checked against the suffix type. The value is parsed as a u128, so that
+    /// literals with an `i128` or `u128` suffix may exceed the i64 range.
+    ///
     /// ### Returns
     ///
     /// The parsed integer literal expression or an error message
     fn parse_integer(&mut self) -> Result<Expression, ParseError> {
         let token = self.previous();
         let value_str = token.lexeme.clone();
-        let base_value = value_str.parse::<i64>().map_err(|_| {
-            self.error_previous(
+        let parsed = integer_magnitude(&value_str);
+        let invalid_integer = |parser: &Self| {
+            parser.error_previous(
                 ErrorCode::InvalidNumberLiteral,
                 &format!("Invalid integer: {}", value_str),
             )
-        })?;
+        };
+        let wide_value = parsed.map_err(|_| invalid_integer(self))?;
         let location = self.source_location_from_token(token);
 
-        if self.check(&Tokentype::Identifier) {
-            let type_name = self.peek().lexeme.clone();
+        let suffix = match self.peek().lexeme.as_str() {
+            _ if !self.check(&Tokentype::Identifier) => None,
+            TYPE_NAME_I32 => Some(PrimitiveType::I32),
+            TYPE_NAME_I64 => Some(PrimitiveType::I64),
+            TYPE_NAME_I128 => Some(PrimitiveType::I128),
+            TYPE_NAME_U32 => Some(PrimitiveType::U32),
+            TYPE_NAME_U64 => Some(PrimitiveType::U64),
+            TYPE_NAME_U128 => Some(PrimitiveType::U128),
+            TYPE_NAME_F32 => Some(PrimitiveType::F32),
+            TYPE_NAME_F64 => Some(PrimitiveType::F64),
+            _ => None,
+        };
 
-            match type_name.as_str() {
-                TYPE_NAME_I32 => {
-                    self.advance();
-                    if base_value > i32::MAX as i64 || base_value < i32::MIN as i64 {
-                        return Err(self.error_previous(
-                            ErrorCode::ValueOutOfRange,
-                            &format!("Value {} is out of range for {}", base_value, TYPE_NAME_I32),
-                        ));
-                    }
-                    return Ok(Expression::Literal(LiteralExpr {
-                        value: LiteralValue::I32(base_value as i32),
-                        expr_type: PrimitiveType::I32.into(),
-                        location,
-                    }));
-                }
-                TYPE_NAME_I64 => {
-                    self.advance();
-                    return Ok(Expression::Literal(LiteralExpr {
-                        value: LiteralValue::I64(base_value),
-                        expr_type: PrimitiveType::I64.into(),
-                        location,
-                    }));
+        if let Some(primitive) = suffix {
+            self.advance();
+            let value = match primitive {
+                PrimitiveType::I32 => {
+                    LiteralValue::I32(self.integer_in_range(wide_value, primitive)?)
                 }
-                TYPE_NAME_U32 => {
-                    self.advance();
-                    if base_value < 0 || base_value > u32::MAX as i64 {
-                        return Err(self.error_previous(
-                            ErrorCode::ValueOutOfRange,
-                            &format!("Value {} is out of range for {}", base_value, TYPE_NAME_U32),
-                        ));
-                    }
-                    return Ok(Expression::Literal(LiteralExpr {
-                        value: LiteralValue::U32(base_value as u32),
-                        expr_type: PrimitiveType::U32.into(),
-                        location,
-                    }));
+                PrimitiveType::I64 => {
+                    LiteralValue::I64(self.integer_in_range(wide_value, primitive)?)
                 }
-                TYPE_NAME_U64 => {
-                    self.advance();
-                    if base_value < 0 {
-                        return Err(self.error_previous(
-                            ErrorCode::ValueOutOfRange,
-                            &format!("Value {} is out of range for {}", base_value, TYPE_NAME_U64),
-                        ));
-                    }
-                    return Ok(Expression::Literal(LiteralExpr {
-                        value: LiteralValue::U64(base_value as u64),
-                        expr_type: PrimitiveType::U64.into(),
-                        location,
-                    }));
+                PrimitiveType::I128 => {
+                    LiteralValue::I128(self.integer_in_range(wide_value, primitive)?)
                 }
-                TYPE_NAME_F32 => {
-                    self.advance();
-                    return Ok(Expression::Literal(LiteralExpr {
-                        value: LiteralValue::F32(base_value as f32),
-                        expr_type: PrimitiveType::F32.into(),
-                        location,
-                    }));
+                PrimitiveType::U32 => {
+                    LiteralValue::U32(self.integer_in_range(wide_value, primitive)?)
                 }
-                TYPE_NAME_F64 => {
-                    self.advance();
-                    return Ok(Expression::Literal(LiteralExpr {
-                        value: LiteralValue::F64(base_value as f64),
-                        expr_type: PrimitiveType::F64.into(),
-                        location,
-                    }));
+                PrimitiveType::U64 => {
+                    LiteralValue::U64(self.integer_in_range(wide_value, primitive)?)
                 }
-                _ => {}
-            }
+                PrimitiveType::U128 => LiteralValue::U128(wide_value),
+                PrimitiveType::F32 => LiteralValue::F32(wide_value as f32),
+                _ => LiteralValue::F64(wide_value as f64),
+            };
+            return Ok(Expression::Literal(LiteralExpr {
+                value,
+                expr_type: Cell::new(primitive.into()),
+                location,
+            }));
         }
 
+        // Unsuffixed literals keep their value as an i128, so that they can be range checked
+        // against every integer type once their type is inferred
+        let value = i128::try_from(wide_value).map_err(|_| invalid_integer(self))?;
         Ok(Expression::Literal(LiteralExpr {
-            value: LiteralValue::UnspecifiedInteger(base_value),
-            expr_type: PrimitiveType::UnspecifiedInt.into(),
+            value: LiteralValue::UnspecifiedInteger(value),
+            expr_type: Cell::new(PrimitiveType::UnspecifiedInt.into()),
+            location,
+        }))
+    }
+
+    /// Parses a negated integer literal whose magnitude is only in range once it is negated
+    ///
+    /// The magnitude of the minimum of a signed type, e.g. `9223372036854775808i64`, exceeds the
+    /// maximum of that type, so the literal and its `-` are folded into a single literal.
+    ///
+    /// ### Arguments
+    ///
+    /// * `operator_location` - The location of the `-` that was consumed
+    ///
+    /// ### Returns
+    ///
+    /// The folded literal, or None if the upcoming tokens are not such a literal
+    fn signed_minimum_literal(&mut self, operator_location: &Location) -> Option<Expression> {
+        if !self.check(&Tokentype::IntegerLiteral) || !self.check_next(&Tokentype::Identifier) {
+            return None;
+        }
+        let magnitude = integer_magnitude(&self.peek().lexeme).ok()?;
+        let (value, primitive) = match self.tokens[self.current + 1].lexeme.as_str() {
+            TYPE_NAME_I32 if magnitude == i32::MIN.unsigned_abs() as u128 => {
+                (LiteralValue::I32(i32::MIN), PrimitiveType::I32)
+            }
+            TYPE_NAME_I64 if magnitude == i64::MIN.unsigned_abs() as u128 => {
+                (LiteralValue::I64(i64::MIN), PrimitiveType::I64)
+            }
+            TYPE_NAME_I128 if magnitude == i128::MIN.unsigned_abs() => {
+                (LiteralValue::I128(i128::MIN), PrimitiveType::I128)
+            }
+            _ => return None,
+        };
+
+        let location = operator_location.span_to(&self.source_location_from_token(self.peek()));
+        self.advance(); // consume the literal
+        self.advance(); // consume the suffix
+        Some(Expression::Literal(LiteralExpr {
+            value,
+            expr_type: Cell::new(primitive.into()),
             location,
         }))
     }
 
+    /// Converts the value of an integer literal to the type given by its suffix
+    ///
+    /// ### Arguments
+    ///
+    /// * `value` - The value of the literal
+    /// * `primitive` - The type of the suffix
+    ///
+    /// ### Returns
+    ///
+    /// The converted value or a ValueOutOfRange error at the suffix
+    fn integer_in_range<T: TryFrom<u128>>(
+        &self,
+        value: u128,
+        primitive: PrimitiveType,
+    ) -> Result<T, ParseError> {
+        T::try_from(value).map_err(|_| {
+            self.error_previous(
+                ErrorCode::ValueOutOfRange,
+                &integer_out_of_range_message(value, primitive),
+            )
+        })
+    }
+
     /// Parses a type name
     ///
     /// ### Returns
@@ -1074,7 +1942,7 @@ impl<'a> Parser<'a> {
         }
         if let Some(symbol) = self.context.lookup_symbol(&type_name) {
             if symbol.kind() == SymbolKind::Type {
-                Ok(symbol.type_id.clone())
+                Ok(symbol.type_id)
             } else {
                 Err(self.error_previous(
                     ErrorCode::UnknownType,
@@ -1082,10 +1950,16 @@ impl<'a> Parser<'a> {
                 ))
             }
         } else {
-            Err(self.error_previous(
+            let error = self.error_previous(
                 ErrorCode::UnknownType,
                 &format!("Unknown type: {}", type_name),
-            ))
+            );
+            match self.context.closest_type_name(&type_name) {
+                Some(candidate) => {
+                    Err(error.with_suggestion(format!("did you mean '{}'?", candidate)))
+                }
+                None => Err(error),
+            }
         }
     }
 
@@ -1271,10 +2145,12 @@ impl<'a> Parser<'a> {
 
         let then_branch = self.parse_block_expression()?;
 
+        // Without an else branch there would be no value if the condition is false. Where no
+        // value is needed, `if` without `else` is parsed as an if statement instead
         if !self.match_token(&Tokentype::Else) {
             return Err(self.error(
                 ErrorCode::ExpectedElse,
-                "Expected 'else' after if expression",
+                "Expected 'else' after if expression, an if that produces a value needs both branches",
             ));
         }
 
@@ -1303,6 +2179,10 @@ impl<'a> Parser<'a> {
 
     /// Parses a block expression - a sequence of statements with an optional return expression
     ///
+    /// A trailing `if` doesn't need a semicolon. With an else branch it is parsed as the
+    /// block's tail expression, whose branches are unit-typed if they end in statements.
+    /// Without one it is an if statement, so the block has no tail and is unit-typed as well.
+    ///
     /// ### Returns
     ///
     /// The parsed block expression or an error message
@@ -1322,13 +2202,20 @@ impl<'a> Parser<'a> {
                     break;
                 } else if self.match_token(&Tokentype::Semicolon) {
                     statements.push(Statement::Expression(expr));
-                } else {
-                    self.current = checkpoint;
-                    statements.push(self.statement()?);
+                    continue;
+                }
+            }
+
+            // A malformed statement is reported and skipped, so the rest of the block is
+            // still parsed without the error cascading into it
+            self.current = checkpoint;
+            match self.statement() {
+                Ok(statement) => statements.push(statement),
+                Err(error) => {
+                    self.errors.push(error.to_compiler_error(self.line_info));
+                    statements.extend(self.declaration_placeholder(checkpoint));
+                    self.recover_to(&Tokentype::RightBrace, checkpoint);
                 }
-            } else {
-                self.current = checkpoint;
-                statements.push(self.statement()?);
             }
         }
 
@@ -1397,6 +2284,75 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    /// Parses a for statement: `for i in range(start, end) { ... }`
+    ///
+    /// ### Returns
+    ///
+    /// The parsed for statement or an error message
+    fn for_statement(&mut self) -> Result<Statement, ParseError> {
+        let for_token_pos = self.previous().pos;
+        let (line, column) = self.line_info.get_line_col(for_token_pos);
+
+        if !self.match_token(&Tokentype::Identifier) {
+            return Err(self.error(
+                ErrorCode::ExpectedIdentifier,
+                "Expected loop variable name after 'for'",
+            ));
+        }
+        let variable = self.previous().lexeme.clone();
+
+        if !self.match_token(&Tokentype::In) {
+            return Err(self.error(
+                ErrorCode::UnexpectedToken,
+                "Expected 'in' after loop variable",
+            ));
+        }
+
+        if !(self.check(&Tokentype::Identifier) && self.peek().lexeme == "range") {
+            return Err(self.error(ErrorCode::InvalidSyntax, "Expected 'range' after 'in'"));
+        }
+        self.advance();
+
+        if !self.match_token(&Tokentype::LeftParen) {
+            return Err(self.error(
+                ErrorCode::ExpectedOpeningParen,
+                "Expected '(' after 'range'",
+            ));
+        }
+        let start = self.expression()?;
+        if !self.match_token(&Tokentype::Comma) {
+            return Err(self.error(ErrorCode::ExpectedComma, "Expected ',' after range start"));
+        }
+        let end = self.expression()?;
+        if !self.match_token(&Tokentype::RightParen) {
+            return Err(self.error(
+                ErrorCode::ExpectedClosingParen,
+                "Expected ')' after range end",
+            ));
+        }
+
+        if !self.match_token(&Tokentype::LeftBrace) {
+            return Err(self.error(
+                ErrorCode::ExpectedOpeningBrace,
+                "Expected '{' after for range",
+            ));
+        }
+        let body = self.parse_block_expression()?;
+
+        let end_pos = self.previous().pos + self.previous().lexeme.len();
+        let location =
+            slang_ir::location::Location::new(for_token_pos, line, column, end_pos - for_token_pos);
+
+        Ok(Statement::For(ForStatement {
+            variable,
+            start,
+            end,
+            body,
+            variable_type: Cell::new(TypeId::unknown()),
+            location,
+        }))
+    }
+
     /// Parses a function type expression: `fn(type1, type2) -> return_type`
     ///
     /// ### Returns
@@ -1462,4 +2418,128 @@ impl<'a> Parser<'a> {
             location,
         }))
     }
+
+    /// Parses a closure expression: `|param: type, ...| -> return_type { body }`
+    ///
+    /// The opening delimiter has already been consumed. A `||` token opens a
+    /// closure without parameters.
+    ///
+    /// ### Returns
+    ///
+    /// The parsed closure expression or an error message
+    fn parse_closure_expression(&mut self) -> Result<Expression, ParseError> {
+        let start_token = self.previous();
+        let start_pos = start_token.pos;
+        let has_parameter_list = start_token.token_type == Tokentype::Pipe;
+        let (line, column) = self.line_info.get_line_col(start_pos);
+
+        let mut parameters = Vec::new();
+        if has_parameter_list {
+            if !self.check(&Tokentype::Pipe) {
+                parameters.push(self.parameter()?);
+                while self.match_token(&Tokentype::Comma) {
+                    // Allow a trailing comma before the closing pipe
+                    if self.check(&Tokentype::Pipe) {
+                        break;
+                    }
+                    if parameters.len() >= 255 {
+                        return Err(self.error(
+                            ErrorCode::InvalidSyntax,
+                            "Cannot have more than 255 parameters",
+                        ));
+                    }
+                    parameters.push(self.parameter()?);
+                }
+            }
+
+            if !self.match_token(&Tokentype::Pipe) {
+                return Err(self.error(
+                    ErrorCode::InvalidSyntax,
+                    &format!(
+                        "Expected '|' after closure parameters, found {}",
+                        self.peek().token_type
+                    ),
+                ));
+            }
+        }
+
+        let return_type = if self.match_token(&Tokentype::Arrow) {
+            self.parse_type()?
+        } else {
+            PrimitiveType::Unit.into()
+        };
+
+        if !self.match_token(&Tokentype::LeftBrace) {
+            return Err(self.error(
+                ErrorCode::ExpectedOpeningBrace,
+                "Expected '{' before closure body",
+            ));
+        }
+
+        let body = self.parse_block_expression()?;
+
+        let end_pos = self.previous().pos + self.previous().lexeme.len();
+        let location =
+            slang_ir::location::Location::new(start_pos, line, column, end_pos - start_pos);
+
+        Ok(Expression::Closure(ClosureExpr {
+            parameters,
+            return_type,
+            body,
+            // Will be determined by the semantic analyzer
+            expr_type: PrimitiveType::Unknown.into(),
+            location,
+        }))
+    }
+}
+
+/// Formats the error message for an integer literal that doesn't fit its suffix type
+///
+/// ### Arguments
+///
+/// * `value` - The value of the literal
+/// * `primitive` - The integer type named by the suffix
+///
+/// ### Returns
+///
+/// The error message, including the valid range of the type
+/// Parses the magnitude of an integer literal
+///
+/// The literal may be hexadecimal and contain `_` separators.
+///
+/// ### Arguments
+///
+/// * `lexeme` - The lexeme of the literal, without its type suffix
+///
+/// ### Returns
+///
+/// The value of the literal or an error if it does not fit a u128
+fn integer_magnitude(lexeme: &str) -> Result<u128, std::num::ParseIntError> {
+    let digits = lexeme.replace('_', "");
+    match digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        Some(hex_digits) => u128::from_str_radix(hex_digits, 16),
+        None => digits.parse::<u128>(),
+    }
+}
+
+fn integer_out_of_range_message(
+    value: impl std::fmt::Display,
+    primitive: PrimitiveType,
+) -> String {
+    match primitive.to_type_kind() {
+        TypeKind::Integer(int_type) => match int_type.bounds() {
+            Some((min, max)) => format!(
+                "Value {} is out of range for {} (valid range is {}..={})",
+                value,
+                primitive.name(),
+                min,
+                max
+            ),
+            None => format!("Value {} is out of range for {}", value, primitive.name()),
+        },
+        _ => format!("Value {} is out of range for {}", value, primitive.name()),
+    }
 }
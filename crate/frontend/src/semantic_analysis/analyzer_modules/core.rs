@@ -48,8 +48,13 @@ impl<'a> CoreAnalyzer<'a> {
             Statement::TypeDefinition(type_def) => {
                 stmt_visitor.visit_type_definition_statement(type_def)
             }
+            Statement::EnumDefinition(enum_def) => {
+                stmt_visitor.visit_enum_definition_statement(enum_def)
+            }
+            Statement::Impl(impl_stmt) => stmt_visitor.visit_impl_statement(impl_stmt),
             Statement::Expression(expr) => stmt_visitor.visit_expression_statement(expr),
             Statement::If(if_stmt) => stmt_visitor.visit_if_statement(if_stmt),
+            Statement::For(for_stmt) => stmt_visitor.visit_for_statement(for_stmt),
         }
     }
 
@@ -1,6 +1,25 @@
+use slang_error::ErrorCode;
+use slang_ir::ast::{Expression, FunctionCallExpr, LiteralValue};
 use slang_shared::{CompilationContext, SymbolKind};
 use slang_types::TypeId;
 
+use super::super::{
+    const_eval::const_eval, error::SemanticAnalysisError, type_system::finalize_inferred_type,
+};
+
+/// Integer intrinsics that take two integers of the same type and return that type
+pub const INTEGER_INTRINSICS: [&str; 6] = [
+    "wrapping_add",
+    "wrapping_sub",
+    "wrapping_mul",
+    "saturating_add",
+    "saturating_sub",
+    "saturating_mul",
+];
+
+/// Intrinsic that raises its first argument to the power of its second argument
+pub const POW_INTRINSIC: &str = "pow";
+
 /// Registers the built-in native functions that are available to all programs.
 pub fn register_native_functions(context: &mut CompilationContext) {
     // Register print_value function
@@ -19,6 +38,7 @@ pub fn register_native_functions(context: &mut CompilationContext) {
             SymbolKind::Function,
             function_type_id,
             false,
+            None,
         )
         .is_err()
     {
@@ -28,17 +48,323 @@ pub fn register_native_functions(context: &mut CompilationContext) {
         eprintln!("Error: Failed to register native function 'print_value'.");
     }
 
-    // Add other native functions here in the future
-    // Example:
-    // let len_param_types = vec![TypeId::string()]; // Assuming a string type ID
-    // let len_return_type = TypeId::i64(); // Or u64, i32 etc.
-    // let len_function_type_id = context.register_function_type(len_param_types, len_return_type);
-    // if context.define_symbol(
-    //     "len".to_string(),
-    //     SymbolKind::Function,
-    //     len_function_type_id,
-    //     false,
-    // ).is_err() {
-    //     eprintln!("Error: Failed to register native function 'len'.");
-    // }
+    // Register print function
+    // It takes a format string followed by any number of values that replace
+    // the `{}` placeholders of the format string in order.
+    if context
+        .define_variadic_native_function("print", vec![TypeId::string()], TypeId::i32())
+        .is_err()
+    {
+        eprintln!("Error: Failed to register native function 'print'.");
+    }
+
+    // Register the integer intrinsics
+    // Their signature is generic over the integer types, the actual operand and result
+    // types are determined per call by `check_integer_intrinsic_call`.
+    // `pow` is registered the same way, its result type is determined by `check_pow_call`.
+    for name in INTEGER_INTRINSICS.into_iter().chain([POW_INTRINSIC]) {
+        let function_type_id = context.register_function_type(
+            vec![TypeId::unknown(), TypeId::unknown()],
+            TypeId::unknown(),
+        );
+        if context
+            .define_symbol(
+                name.to_string(),
+                SymbolKind::Function,
+                function_type_id,
+                false,
+                None,
+            )
+            .is_err()
+        {
+            eprintln!("Error: Failed to register native function '{}'.", name);
+        }
+    }
+
+    // Register the type query
+    // It accepts any argument and is replaced by unit during code generation, its note
+    // about the argument's type is emitted by `check_type_query_call`.
+    let function_type_id =
+        context.register_function_type(vec![TypeId::unknown()], TypeId::unknown());
+    if context
+        .define_symbol(
+            FunctionCallExpr::TYPE_QUERY.to_string(),
+            SymbolKind::Function,
+            function_type_id,
+            false,
+            None,
+        )
+        .is_err()
+    {
+        eprintln!(
+            "Error: Failed to register native function '{}'.",
+            FunctionCallExpr::TYPE_QUERY
+        );
+    }
+
+    // Register the program argument accessors
+    // `arg_count` returns the number of command line arguments passed to the program
+    // and `arg` returns the argument at the given index.
+    let program_arg_functions = [
+        ("arg_count", vec![], TypeId::i64()),
+        ("arg", vec![TypeId::i64()], TypeId::string()),
+    ];
+    for (name, param_types, return_type) in program_arg_functions {
+        let function_type_id = context.register_function_type(param_types, return_type);
+        if context
+            .define_symbol(
+                name.to_string(),
+                SymbolKind::Function,
+                function_type_id,
+                false,
+                None,
+            )
+            .is_err()
+        {
+            eprintln!("Error: Failed to register native function '{}'.", name);
+        }
+    }
+
+    // Register the string functions
+    // `len` returns the number of characters of a string and `char_at` returns the
+    // character at the given index as a string of length 1.
+    let string_functions = [
+        ("len", vec![TypeId::string()], TypeId::i64()),
+        (
+            "char_at",
+            vec![TypeId::string(), TypeId::i64()],
+            TypeId::string(),
+        ),
+    ];
+    for (name, param_types, return_type) in string_functions {
+        let function_type_id = context.register_function_type(param_types, return_type);
+        if context
+            .define_symbol(
+                name.to_string(),
+                SymbolKind::Function,
+                function_type_id,
+                false,
+                None,
+            )
+            .is_err()
+        {
+            eprintln!("Error: Failed to register native function '{}'.", name);
+        }
+    }
+}
+
+/// Performs checks specific to individual native functions that their signature can't express
+///
+/// For `print`, the number of `{}` placeholders in a literal format string must match
+/// the number of values passed after it.
+///
+/// ### Arguments
+/// * `call_expr` - The call of the native function
+///
+/// ### Returns
+/// Ok if the call is valid, otherwise the error describing the problem
+pub fn validate_native_call(call_expr: &FunctionCallExpr) -> Result<(), SemanticAnalysisError> {
+    if call_expr.name != "print" {
+        return Ok(());
+    }
+
+    if let Some(Expression::Literal(format)) = call_expr.arguments.first()
+        && let LiteralValue::String(format_string) = &format.value
+    {
+        let placeholders = format_string.matches("{}").count();
+        let arguments = call_expr.arguments.len() - 1;
+        if placeholders != arguments {
+            return Err(SemanticAnalysisError::FormatArgumentCountMismatch {
+                function_name: call_expr.name.clone(),
+                placeholders,
+                arguments,
+                location: format.location,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks whether a function name refers to one of the integer intrinsics
+///
+/// ### Arguments
+/// * `name` - The name of the called function
+///
+/// ### Returns
+/// True if the function is an integer intrinsic, false otherwise
+pub fn is_integer_intrinsic(name: &str) -> bool {
+    INTEGER_INTRINSICS.contains(&name)
+}
+
+/// Checks whether a function name refers to an intrinsic that only exists as a call
+///
+/// Calls of intrinsics are compiled to dedicated instructions, so there is no function
+/// value that could be stored in a variable or passed as an argument.
+///
+/// ### Arguments
+/// * `name` - The name of the function
+///
+/// ### Returns
+/// True if the function can only be called directly, false otherwise
+pub fn is_call_only_intrinsic(name: &str) -> bool {
+    is_integer_intrinsic(name) || name == POW_INTRINSIC || name == FunctionCallExpr::TYPE_QUERY
+}
+
+/// Determines the result type of a call to an integer intrinsic
+///
+/// Both operands must be integers of the same type. An unspecified integer literal takes
+/// the type of the other operand if it fits into it, two unspecified operands are
+/// inferred to be i64.
+///
+/// ### Arguments
+/// * `call_expr` - The call of the integer intrinsic
+/// * `arg_types` - The types of the two arguments
+/// * `context` - The compilation context used for type names and range checks
+///
+/// ### Returns
+/// The integer type of the result, otherwise the error describing the problem
+pub fn check_integer_intrinsic_call(
+    call_expr: &FunctionCallExpr,
+    arg_types: &[TypeId],
+    context: &CompilationContext,
+) -> Result<TypeId, SemanticAnalysisError> {
+    for (i, arg_type) in arg_types.iter().enumerate() {
+        if !context.is_integer_type(arg_type) {
+            return Err(SemanticAnalysisError::InvalidExpression {
+                message: format!(
+                    "Function '{}' expects integer arguments, but argument {} is {}",
+                    call_expr.name,
+                    i + 1,
+                    context.get_type_name(arg_type)
+                ),
+                location: call_expr.arguments[i].location(),
+            });
+        }
+    }
+
+    let (left, right) = (arg_types[0], arg_types[1]);
+    if left == right {
+        return Ok(finalize_inferred_type(left));
+    }
+
+    let (result_type, literal_position) = if left == TypeId::unspecified_int() {
+        (right, 0)
+    } else if right == TypeId::unspecified_int() {
+        (left, 1)
+    } else {
+        return Err(SemanticAnalysisError::ArgumentTypeMismatch {
+            function_name: call_expr.name.clone(),
+            argument_position: 2,
+            expected: left,
+            actual: right,
+            note: None,
+            location: call_expr.arguments[1].location(),
+        });
+    };
+
+    let literal = &call_expr.arguments[literal_position];
+    let fits = match const_eval(literal, context) {
+        Some(LiteralValue::UnspecifiedInteger(value)) => {
+            context.check_value_in_range(&value, &result_type)
+        }
+        _ => true,
+    };
+    if !fits {
+        return Err(SemanticAnalysisError::ArgumentTypeMismatch {
+            function_name: call_expr.name.clone(),
+            argument_position: literal_position + 1,
+            expected: result_type,
+            actual: TypeId::unspecified_int(),
+            note: None,
+            location: literal.location(),
+        });
+    }
+
+    Ok(result_type)
+}
+
+/// Determines the result type of a call to the `pow` intrinsic
+///
+/// The result has the type of the base, an unspecified literal base is inferred to be i64
+/// or f64. Integer bases require an integer exponent, float bases accept any numeric
+/// exponent.
+///
+/// ### Arguments
+/// * `call_expr` - The call of the `pow` intrinsic
+/// * `arg_types` - The types of the base and the exponent
+/// * `context` - The compilation context used for type names
+///
+/// ### Returns
+/// The numeric type of the result, otherwise the error describing the problem
+pub fn check_pow_call(
+    call_expr: &FunctionCallExpr,
+    arg_types: &[TypeId],
+    context: &CompilationContext,
+) -> Result<TypeId, SemanticAnalysisError> {
+    let (base, exponent) = (arg_types[0], arg_types[1]);
+    if !context.is_numeric_type(&base) {
+        return Err(SemanticAnalysisError::InvalidExpression {
+            message: format!(
+                "Function '{}' expects a numeric base, but argument 1 is {}",
+                call_expr.name,
+                context.get_type_name(&base)
+            ),
+            location: call_expr.arguments[0].location(),
+        });
+    }
+
+    let valid_exponent = if context.is_integer_type(&base) {
+        context.is_integer_type(&exponent)
+    } else {
+        context.is_numeric_type(&exponent)
+    };
+    if !valid_exponent {
+        let expected = if context.is_integer_type(&base) {
+            "an integer exponent for an integer base"
+        } else {
+            "a numeric exponent"
+        };
+        return Err(SemanticAnalysisError::InvalidExpression {
+            message: format!(
+                "Function '{}' expects {}, but argument 2 is {}",
+                call_expr.name,
+                expected,
+                context.get_type_name(&exponent)
+            ),
+            location: call_expr.arguments[1].location(),
+        });
+    }
+
+    Ok(finalize_inferred_type(base))
+}
+
+/// Reports the inferred type of the argument of a `__type_of` query as a note
+///
+/// ### Arguments
+/// * `call_expr` - The call of the type query
+/// * `arg_type` - The inferred type of the argument
+/// * `context` - The compilation context that collects the note
+///
+/// ### Returns
+/// The unit type, which is the type of every type query
+pub fn check_type_query_call(
+    call_expr: &FunctionCallExpr,
+    arg_type: TypeId,
+    context: &mut CompilationContext,
+) -> Result<TypeId, SemanticAnalysisError> {
+    let mut message = format!(
+        "Inferred type of the expression is {}",
+        context.format_type(&arg_type)
+    );
+    let finalized_type = finalize_inferred_type(arg_type);
+    if finalized_type != arg_type {
+        message.push_str(&format!(
+            ", which becomes {} if no other type is required",
+            context.format_type(&finalized_type)
+        ));
+    }
+
+    context.add_note(ErrorCode::TypeQuery, message, call_expr.location);
+    Ok(TypeId::unit())
 }
@@ -1,4 +1,5 @@
 use super::super::traits::ScopeManager;
+use slang_ir::Location;
 use slang_shared::{CompilationContext, SymbolKind};
 use slang_types::TypeId;
 
@@ -36,7 +37,9 @@ impl ScopeManager for ContextScopeManager<'_> {
         kind: SymbolKind,
         type_id: TypeId,
         is_mutable: bool,
+        location: Option<Location>,
     ) -> Result<(), String> {
-        self.context.define_symbol(name, kind, type_id, is_mutable)
+        self.context
+            .define_symbol(name, kind, type_id, is_mutable, location)
     }
 }
@@ -0,0 +1,403 @@
+use slang_ir::ast::{BinaryOperator, Expression, LiteralValue, UnaryOperator};
+use slang_shared::CompilationContext;
+use slang_types::PrimitiveType;
+use std::cmp::Ordering;
+
+/// Applies a checked integer operation to two integer literals of the same type
+macro_rules! checked_integer_op {
+    ($left:expr, $right:expr, $method:ident) => {
+        match (&$left, &$right) {
+            (LiteralValue::I32(a), LiteralValue::I32(b)) => a.$method(*b).map(LiteralValue::I32),
+            (LiteralValue::I64(a), LiteralValue::I64(b)) => a.$method(*b).map(LiteralValue::I64),
+            (LiteralValue::U32(a), LiteralValue::U32(b)) => a.$method(*b).map(LiteralValue::U32),
+            (LiteralValue::U64(a), LiteralValue::U64(b)) => a.$method(*b).map(LiteralValue::U64),
+            (LiteralValue::I128(a), LiteralValue::I128(b)) => a.$method(*b).map(LiteralValue::I128),
+            (LiteralValue::U128(a), LiteralValue::U128(b)) => a.$method(*b).map(LiteralValue::U128),
+            (LiteralValue::UnspecifiedInteger(a), LiteralValue::UnspecifiedInteger(b)) => {
+                a.$method(*b).map(LiteralValue::UnspecifiedInteger)
+            }
+            _ => None,
+        }
+    };
+}
+
+/// Applies a bitwise operator to two integer literals of the same type
+macro_rules! bitwise_integer_op {
+    ($left:expr, $right:expr, $op:tt) => {
+        match (&$left, &$right) {
+            (LiteralValue::I32(a), LiteralValue::I32(b)) => Some(LiteralValue::I32(a $op b)),
+            (LiteralValue::I64(a), LiteralValue::I64(b)) => Some(LiteralValue::I64(a $op b)),
+            (LiteralValue::U32(a), LiteralValue::U32(b)) => Some(LiteralValue::U32(a $op b)),
+            (LiteralValue::U64(a), LiteralValue::U64(b)) => Some(LiteralValue::U64(a $op b)),
+            (LiteralValue::I128(a), LiteralValue::I128(b)) => Some(LiteralValue::I128(a $op b)),
+            (LiteralValue::U128(a), LiteralValue::U128(b)) => Some(LiteralValue::U128(a $op b)),
+            (LiteralValue::UnspecifiedInteger(a), LiteralValue::UnspecifiedInteger(b)) => {
+                Some(LiteralValue::UnspecifiedInteger(a $op b))
+            }
+            _ => None,
+        }
+    };
+}
+
+/// Applies an arithmetic operator to two float literals of the same type
+macro_rules! float_op {
+    ($left:expr, $right:expr, $op:tt) => {
+        match (&$left, &$right) {
+            (LiteralValue::F32(a), LiteralValue::F32(b)) => Some(LiteralValue::F32(a $op b)),
+            (LiteralValue::F64(a), LiteralValue::F64(b)) => Some(LiteralValue::F64(a $op b)),
+            (LiteralValue::UnspecifiedFloat(a), LiteralValue::UnspecifiedFloat(b)) => {
+                Some(LiteralValue::UnspecifiedFloat(a $op b))
+            }
+            _ => None,
+        }
+    };
+}
+
+/// Evaluates a constant expression at compile time
+///
+/// Literal, unary, binary and conditional expressions whose operands are all constant are
+/// folded into a single literal. Evaluation gives up on the first non-constant
+/// subexpression, on integer overflow and on division by zero.
+///
+/// ### Arguments
+/// * `expr` - The expression to evaluate
+/// * `context` - The compilation context used for range checks of integer literals
+///
+/// ### Returns
+/// The folded literal value, or `None` if the expression isn't a compile-time constant
+///
+/// ### Example
+/// ```
+/// use slang_frontend::{const_eval, parser, tokenize};
+/// use slang_ir::ast::{LiteralValue, Statement};
+/// use slang_shared::CompilationContext;
+///
+/// let mut context = CompilationContext::new();
+/// let lexed = tokenize("2 * (3 + 4); x + 1;").unwrap();
+/// let statements = parser::parse(&lexed.tokens, &lexed.line_info, &mut context).unwrap();
+///
+/// let Statement::Expression(constant) = &statements[0] else { unreachable!() };
+/// assert_eq!(
+///     const_eval(constant, &context),
+///     Some(LiteralValue::UnspecifiedInteger(14))
+/// );
+///
+/// let Statement::Expression(non_constant) = &statements[1] else { unreachable!() };
+/// assert_eq!(const_eval(non_constant, &context), None);
+/// ```
+pub fn const_eval(expr: &Expression, context: &CompilationContext) -> Option<LiteralValue> {
+    match expr {
+        Expression::Literal(literal) => Some(literal.typed_value()),
+        Expression::Unary(unary_expr) => {
+            let operand = const_eval(&unary_expr.right, context)?;
+            eval_unary(&unary_expr.operator, operand)
+        }
+        Expression::Binary(bin_expr) => {
+            let left = const_eval(&bin_expr.left, context)?;
+
+            // Logical operators short-circuit, so the right operand doesn't need to be constant
+            match (&bin_expr.operator, &left) {
+                (BinaryOperator::And, LiteralValue::Boolean(false)) => {
+                    return Some(LiteralValue::Boolean(false));
+                }
+                (BinaryOperator::Or, LiteralValue::Boolean(true)) => {
+                    return Some(LiteralValue::Boolean(true));
+                }
+                _ => {}
+            }
+
+            let right = const_eval(&bin_expr.right, context)?;
+            eval_binary(&bin_expr.operator, left, right, context)
+        }
+        Expression::Conditional(cond_expr) => match const_eval(&cond_expr.condition, context)? {
+            LiteralValue::Boolean(true) => const_eval(&cond_expr.then_branch, context),
+            LiteralValue::Boolean(false) => const_eval(&cond_expr.else_branch, context),
+            _ => None,
+        },
+        Expression::Block(block_expr) if block_expr.statements.is_empty() => {
+            const_eval(block_expr.return_expr.as_deref()?, context)
+        }
+        _ => None,
+    }
+}
+
+/// Folds a unary operation on a constant operand
+///
+/// ### Arguments
+/// * `operator` - The unary operator
+/// * `operand` - The constant operand
+///
+/// ### Returns
+/// The folded literal value, or `None` if the operation can't be folded
+fn eval_unary(operator: &UnaryOperator, operand: LiteralValue) -> Option<LiteralValue> {
+    match (operator, operand) {
+        (UnaryOperator::Negate, LiteralValue::I32(n)) => n.checked_neg().map(LiteralValue::I32),
+        (UnaryOperator::Negate, LiteralValue::I64(n)) => n.checked_neg().map(LiteralValue::I64),
+        (UnaryOperator::Negate, LiteralValue::I128(n)) => n.checked_neg().map(LiteralValue::I128),
+        (UnaryOperator::Negate, LiteralValue::UnspecifiedInteger(n)) => {
+            n.checked_neg().map(LiteralValue::UnspecifiedInteger)
+        }
+        (UnaryOperator::Negate, LiteralValue::F32(f)) => Some(LiteralValue::F32(-f)),
+        (UnaryOperator::Negate, LiteralValue::F64(f)) => Some(LiteralValue::F64(-f)),
+        (UnaryOperator::Negate, LiteralValue::UnspecifiedFloat(f)) => {
+            Some(LiteralValue::UnspecifiedFloat(-f))
+        }
+        (UnaryOperator::Not, LiteralValue::Boolean(b)) => Some(LiteralValue::Boolean(!b)),
+        (UnaryOperator::BitNot, LiteralValue::I32(n)) => Some(LiteralValue::I32(!n)),
+        (UnaryOperator::BitNot, LiteralValue::I64(n)) => Some(LiteralValue::I64(!n)),
+        (UnaryOperator::BitNot, LiteralValue::U32(n)) => Some(LiteralValue::U32(!n)),
+        (UnaryOperator::BitNot, LiteralValue::U64(n)) => Some(LiteralValue::U64(!n)),
+        (UnaryOperator::BitNot, LiteralValue::I128(n)) => Some(LiteralValue::I128(!n)),
+        (UnaryOperator::BitNot, LiteralValue::U128(n)) => Some(LiteralValue::U128(!n)),
+        (UnaryOperator::BitNot, LiteralValue::UnspecifiedInteger(n)) => {
+            Some(LiteralValue::UnspecifiedInteger(!n))
+        }
+        _ => None,
+    }
+}
+
+/// Folds a binary operation on two constant operands
+///
+/// ### Arguments
+/// * `operator` - The binary operator
+/// * `left` - The constant left operand
+/// * `right` - The constant right operand
+/// * `context` - The compilation context used for range checks of integer literals
+///
+/// ### Returns
+/// The folded literal value, or `None` if the operation can't be folded
+fn eval_binary(
+    operator: &BinaryOperator,
+    left: LiteralValue,
+    right: LiteralValue,
+    context: &CompilationContext,
+) -> Option<LiteralValue> {
+    if matches!(
+        operator,
+        BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight
+    ) {
+        return eval_shift(operator, left, right);
+    }
+
+    let (left, right) = unify_operands(left, right, context)?;
+
+    match operator {
+        BinaryOperator::Add => match (&left, &right) {
+            (LiteralValue::String(a), LiteralValue::String(b)) => {
+                Some(LiteralValue::String(format!("{}{}", a, b)))
+            }
+            _ => checked_integer_op!(left, right, checked_add).or(float_op!(left, right, +)),
+        },
+        BinaryOperator::Subtract => {
+            checked_integer_op!(left, right, checked_sub).or(float_op!(left, right, -))
+        }
+        BinaryOperator::Multiply => {
+            checked_integer_op!(left, right, checked_mul).or(float_op!(left, right, *))
+        }
+        BinaryOperator::Divide => {
+            checked_integer_op!(left, right, checked_div).or(float_op!(left, right, /))
+        }
+        BinaryOperator::BitAnd => bitwise_integer_op!(left, right, &),
+        BinaryOperator::BitOr => bitwise_integer_op!(left, right, |),
+        BinaryOperator::BitXor => bitwise_integer_op!(left, right, ^),
+        BinaryOperator::And | BinaryOperator::Or => match (left, right) {
+            (LiteralValue::Boolean(a), LiteralValue::Boolean(b)) => {
+                Some(LiteralValue::Boolean(if *operator == BinaryOperator::And {
+                    a && b
+                } else {
+                    a || b
+                }))
+            }
+            _ => None,
+        },
+        BinaryOperator::Equal => compare(&left, &right, |o| o == Ordering::Equal),
+        BinaryOperator::NotEqual => compare(&left, &right, |o| o != Ordering::Equal),
+        BinaryOperator::GreaterThan => compare(&left, &right, |o| o == Ordering::Greater),
+        BinaryOperator::LessThan => compare(&left, &right, |o| o == Ordering::Less),
+        BinaryOperator::GreaterThanOrEqual => compare(&left, &right, |o| o != Ordering::Less),
+        BinaryOperator::LessThanOrEqual => compare(&left, &right, |o| o != Ordering::Greater),
+        BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight => None,
+    }
+}
+
+/// Folds a shift operation, the shift amount may be any non-negative integer
+///
+/// ### Arguments
+/// * `operator` - The shift operator
+/// * `value` - The constant value being shifted
+/// * `amount` - The constant shift amount
+///
+/// ### Returns
+/// The folded literal value, or `None` if the operation can't be folded
+fn eval_shift(
+    operator: &BinaryOperator,
+    value: LiteralValue,
+    amount: LiteralValue,
+) -> Option<LiteralValue> {
+    let amount = match amount {
+        LiteralValue::I32(n) => u32::try_from(n).ok()?,
+        LiteralValue::I64(n) => u32::try_from(n).ok()?,
+        LiteralValue::UnspecifiedInteger(n) => u32::try_from(n).ok()?,
+        LiteralValue::U32(n) => n,
+        LiteralValue::U64(n) => u32::try_from(n).ok()?,
+        LiteralValue::I128(n) => u32::try_from(n).ok()?,
+        LiteralValue::U128(n) => u32::try_from(n).ok()?,
+        _ => return None,
+    };
+    let shift_left = *operator == BinaryOperator::ShiftLeft;
+
+    match value {
+        LiteralValue::I32(n) if shift_left => {
+            Some(LiteralValue::I32(n.checked_shl(amount).unwrap_or(0)))
+        }
+        LiteralValue::I32(n) => Some(LiteralValue::I32(n >> amount.min(i32::BITS - 1))),
+        LiteralValue::I64(n) if shift_left => {
+            Some(LiteralValue::I64(n.checked_shl(amount).unwrap_or(0)))
+        }
+        LiteralValue::I64(n) => Some(LiteralValue::I64(n >> amount.min(i64::BITS - 1))),
+        // Unspecified integers are shifted like the i64 values they become at runtime
+        LiteralValue::UnspecifiedInteger(n) if shift_left => {
+            let shifted = i64::try_from(n).ok()?.checked_shl(amount).unwrap_or(0);
+            Some(LiteralValue::UnspecifiedInteger(i128::from(shifted)))
+        }
+        LiteralValue::UnspecifiedInteger(n) => Some(LiteralValue::UnspecifiedInteger(
+            n >> amount.min(i128::BITS - 1),
+        )),
+        LiteralValue::U32(n) if shift_left => {
+            Some(LiteralValue::U32(n.checked_shl(amount).unwrap_or(0)))
+        }
+        LiteralValue::U32(n) => Some(LiteralValue::U32(n.checked_shr(amount).unwrap_or(0))),
+        LiteralValue::U64(n) if shift_left => {
+            Some(LiteralValue::U64(n.checked_shl(amount).unwrap_or(0)))
+        }
+        LiteralValue::U64(n) => Some(LiteralValue::U64(n.checked_shr(amount).unwrap_or(0))),
+        LiteralValue::I128(n) if shift_left => {
+            Some(LiteralValue::I128(n.checked_shl(amount).unwrap_or(0)))
+        }
+        LiteralValue::I128(n) => Some(LiteralValue::I128(n >> amount.min(i128::BITS - 1))),
+        LiteralValue::U128(n) if shift_left => {
+            Some(LiteralValue::U128(n.checked_shl(amount).unwrap_or(0)))
+        }
+        LiteralValue::U128(n) => Some(LiteralValue::U128(n.checked_shr(amount).unwrap_or(0))),
+        _ => None,
+    }
+}
+
+/// Converts an unspecified literal operand to the type of the other operand
+///
+/// ### Arguments
+/// * `left` - The constant left operand
+/// * `right` - The constant right operand
+/// * `context` - The compilation context used for range checks of integer literals
+///
+/// ### Returns
+/// Both operands with matching types, or `None` if an unspecified integer doesn't fit
+/// into the type of the other operand
+fn unify_operands(
+    left: LiteralValue,
+    right: LiteralValue,
+    context: &CompilationContext,
+) -> Option<(LiteralValue, LiteralValue)> {
+    match (&left, &right) {
+        (LiteralValue::UnspecifiedInteger(n), typed) if is_typed_integer(typed) => {
+            Some((coerce_integer(*n, typed, context)?, right))
+        }
+        (typed, LiteralValue::UnspecifiedInteger(n)) if is_typed_integer(typed) => {
+            let coerced = coerce_integer(*n, typed, context)?;
+            Some((left, coerced))
+        }
+        (LiteralValue::UnspecifiedFloat(_), LiteralValue::F32(_)) => {
+            Some((left.coerce_to(PrimitiveType::F32)?, right))
+        }
+        (LiteralValue::UnspecifiedFloat(_), LiteralValue::F64(_)) => {
+            Some((left.coerce_to(PrimitiveType::F64)?, right))
+        }
+        (LiteralValue::F32(_), LiteralValue::UnspecifiedFloat(_)) => {
+            let coerced = right.coerce_to(PrimitiveType::F32)?;
+            Some((left, coerced))
+        }
+        (LiteralValue::F64(_), LiteralValue::UnspecifiedFloat(_)) => {
+            let coerced = right.coerce_to(PrimitiveType::F64)?;
+            Some((left, coerced))
+        }
+        _ => Some((left, right)),
+    }
+}
+
+/// Checks whether a literal is an integer with an explicit type
+fn is_typed_integer(value: &LiteralValue) -> bool {
+    matches!(
+        value,
+        LiteralValue::I32(_)
+            | LiteralValue::I64(_)
+            | LiteralValue::U32(_)
+            | LiteralValue::U64(_)
+            | LiteralValue::I128(_)
+            | LiteralValue::U128(_)
+    )
+}
+
+/// Converts an unspecified integer literal to the integer type of another literal
+///
+/// ### Arguments
+/// * `value` - The value of the unspecified integer literal
+/// * `typed` - A literal with the target integer type
+/// * `context` - The compilation context used for the range check
+///
+/// ### Returns
+/// The converted literal, or `None` if the value is out of range for the target type
+fn coerce_integer(
+    value: i128,
+    typed: &LiteralValue,
+    context: &CompilationContext,
+) -> Option<LiteralValue> {
+    let target_type = match typed {
+        LiteralValue::I32(_) => PrimitiveType::I32,
+        LiteralValue::I64(_) => PrimitiveType::I64,
+        LiteralValue::U32(_) => PrimitiveType::U32,
+        LiteralValue::U64(_) => PrimitiveType::U64,
+        LiteralValue::I128(_) => PrimitiveType::I128,
+        LiteralValue::U128(_) => PrimitiveType::U128,
+        _ => return None,
+    };
+    if !context.check_value_in_range(&value, &target_type.into()) {
+        return None;
+    }
+
+    LiteralValue::UnspecifiedInteger(value).coerce_to(target_type)
+}
+
+/// Compares two constant operands of the same type
+///
+/// ### Arguments
+/// * `left` - The constant left operand
+/// * `right` - The constant right operand
+/// * `predicate` - Maps the ordering of the operands to the result of the comparison
+///
+/// ### Returns
+/// The boolean result of the comparison, or `None` if the operands can't be compared
+fn compare(
+    left: &LiteralValue,
+    right: &LiteralValue,
+    predicate: impl Fn(Ordering) -> bool,
+) -> Option<LiteralValue> {
+    let ordering = match (left, right) {
+        (LiteralValue::I32(a), LiteralValue::I32(b)) => a.partial_cmp(b),
+        (LiteralValue::I64(a), LiteralValue::I64(b)) => a.partial_cmp(b),
+        (LiteralValue::U32(a), LiteralValue::U32(b)) => a.partial_cmp(b),
+        (LiteralValue::U64(a), LiteralValue::U64(b)) => a.partial_cmp(b),
+        (LiteralValue::I128(a), LiteralValue::I128(b)) => a.partial_cmp(b),
+        (LiteralValue::U128(a), LiteralValue::U128(b)) => a.partial_cmp(b),
+        (LiteralValue::UnspecifiedInteger(a), LiteralValue::UnspecifiedInteger(b)) => {
+            a.partial_cmp(b)
+        }
+        (LiteralValue::F32(a), LiteralValue::F32(b)) => a.partial_cmp(b),
+        (LiteralValue::F64(a), LiteralValue::F64(b)) => a.partial_cmp(b),
+        (LiteralValue::UnspecifiedFloat(a), LiteralValue::UnspecifiedFloat(b)) => a.partial_cmp(b),
+        (LiteralValue::String(a), LiteralValue::String(b)) => a.partial_cmp(b),
+        (LiteralValue::Boolean(a), LiteralValue::Boolean(b)) => a.partial_cmp(b),
+        (LiteralValue::Unit, LiteralValue::Unit) => Some(Ordering::Equal),
+        _ => None,
+    }?;
+    Some(LiteralValue::Boolean(predicate(ordering)))
+}
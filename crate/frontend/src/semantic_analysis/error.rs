@@ -1,7 +1,7 @@
 use slang_error::{CompilerError, ErrorCode};
 use slang_ir::Location;
 use slang_shared::CompilationContext;
-use slang_types::TypeId;
+use slang_types::{TypeId, TypeKind};
 
 /// Represents different categories of semantic analysis errors
 /// that occur during static analysis of the program.
@@ -24,6 +24,8 @@ pub enum SemanticAnalysisError {
         name: String,
         /// The location where the redefinition occurred
         location: Location,
+        /// The location of the original definition, if known
+        previous_location: Option<Location>,
     },
 
     /// A symbol (type, variable, function) is being redefined.
@@ -34,6 +36,8 @@ pub enum SemanticAnalysisError {
         kind: String,
         /// The location where the redefinition occurred
         location: Location,
+        /// The location of the original definition, if known
+        previous_location: Option<Location>,
     },
 
     /// A struct field is defined with an invalid type (e.g. unknown, unspecified)
@@ -100,8 +104,8 @@ pub enum SemanticAnalysisError {
     ArgumentCountMismatch {
         /// Function name
         function_name: String,
-        /// Expected number of arguments
-        expected: usize,
+        /// Expected numbers of arguments, one for each overload of the function
+        expected: Vec<usize>,
         /// Actual number of arguments provided
         actual: usize,
         /// The location where the argument count mismatch occurred
@@ -118,6 +122,8 @@ pub enum SemanticAnalysisError {
         expected: TypeId,
         /// Actual type
         actual: TypeId,
+        /// Optional note explaining why the argument doesn't fit (like a negative value)
+        note: Option<String>,
         /// The location where the argument type mismatch occurred
         location: Location,
     },
@@ -189,6 +195,72 @@ pub enum SemanticAnalysisError {
         /// The location where the invalid call was attempted
         location: Location,
     },
+
+    /// Format string placeholders don't match the number of values passed
+    FormatArgumentCountMismatch {
+        /// The name of the formatting function
+        function_name: String,
+        /// Number of `{}` placeholders in the format string
+        placeholders: usize,
+        /// Number of values passed after the format string
+        arguments: usize,
+        /// The location of the format string
+        location: Location,
+    },
+
+    /// Struct type contains itself by value, which would give it an infinite size
+    RecursiveStructType {
+        /// The name of the struct being defined
+        struct_name: String,
+        /// The names of the struct types forming the cycle
+        cycle: Vec<String>,
+        /// The location of the struct definition
+        location: Location,
+    },
+
+    /// The right operand of a division is a constant zero
+    DivisionByZero {
+        /// The division operator
+        operator: String,
+        /// The location of the zero operand
+        location: Location,
+    },
+
+    /// An enum variant is accessed that the enum doesn't declare
+    UndefinedEnumVariant {
+        /// The name of the enum
+        enum_name: String,
+        /// The name of the missing variant
+        variant: String,
+        /// The location of the variant access
+        location: Location,
+    },
+
+    /// A method is called on a value whose type doesn't declare it
+    UndefinedMethod {
+        /// The type of the value the method is called on
+        type_id: TypeId,
+        /// The name of the missing method
+        method: String,
+        /// The location of the method call
+        location: Location,
+    },
+
+    /// The function required as entry point of the program is not defined
+    MissingEntryPoint {
+        /// The name of the entry point function
+        name: String,
+        /// The location the error is reported at, the start of the program
+        location: Location,
+    },
+
+    /// The entry point function takes parameters or returns neither `i32` nor `()`
+    InvalidEntryPoint {
+        /// The name of the entry point function
+        name: String,
+        /// The location of the function declaration
+        location: Location,
+    },
 }
 
 impl SemanticAnalysisError {
@@ -237,7 +309,7 @@ impl SemanticAnalysisError {
             } => {
                 format!(
                     "Invalid type '{}' for field '{}' in struct '{}'. Fields cannot be of unknown or unspecified type.",
-                    context.get_type_name(type_id),
+                    context.format_type(type_id),
                     field_name,
                     struct_name
                 )
@@ -253,14 +325,14 @@ impl SemanticAnalysisError {
                     format!(
                         "Type mismatch: variable {} is {} but expression is {}",
                         ctx,
-                        context.get_type_name(expected),
-                        context.get_type_name(actual)
+                        context.format_type(expected),
+                        context.format_type(actual)
                     )
                 } else {
                     format!(
                         "Type mismatch: expected {}, got {}",
-                        context.get_type_name(expected),
-                        context.get_type_name(actual)
+                        context.format_type(expected),
+                        context.format_type(actual)
                     )
                 }
             }
@@ -274,8 +346,8 @@ impl SemanticAnalysisError {
                 format!(
                     "Type mismatch: cannot apply '{}' operator on {} and {}",
                     operator,
-                    context.get_type_name(left_type),
-                    context.get_type_name(right_type)
+                    context.format_type(left_type),
+                    context.format_type(right_type)
                 )
             }
 
@@ -288,8 +360,8 @@ impl SemanticAnalysisError {
                 format!(
                     "Logical operator '{}' requires boolean operands, got {} and {}",
                     operator,
-                    context.get_type_name(left_type),
-                    context.get_type_name(right_type)
+                    context.format_type(left_type),
+                    context.format_type(right_type)
                 )
             }
 
@@ -303,14 +375,27 @@ impl SemanticAnalysisError {
                     format!(
                         "Float literal {} is out of range for type {}",
                         value,
-                        context.get_type_name(target_type)
+                        context.format_type(target_type)
                     )
                 } else {
-                    format!(
-                        "Integer literal {} is out of range for type {}",
-                        value,
-                        context.get_type_name(target_type)
-                    )
+                    let bounds = match context.get_type_info(target_type).map(|info| &info.kind) {
+                        Some(TypeKind::Integer(int_type)) => int_type.bounds(),
+                        _ => None,
+                    };
+                    match bounds {
+                        Some((min, max)) => format!(
+                            "Integer literal {} is out of range for type {} (valid range is {}..={})",
+                            value,
+                            context.format_type(target_type),
+                            min,
+                            max
+                        ),
+                        None => format!(
+                            "Integer literal {} is out of range for type {}",
+                            value,
+                            context.format_type(target_type)
+                        ),
+                    }
                 }
             }
 
@@ -322,7 +407,9 @@ impl SemanticAnalysisError {
             } => {
                 format!(
                     "Function '{}' expects {} arguments, but got {}",
-                    function_name, expected, actual
+                    function_name,
+                    format_arities(expected),
+                    actual
                 )
             }
 
@@ -331,15 +418,20 @@ impl SemanticAnalysisError {
                 argument_position,
                 expected,
                 actual,
+                note,
                 ..
             } => {
-                format!(
+                let message = format!(
                     "Type mismatch: function '{}' expects argument {} to be {}, but got {}",
                     function_name,
                     argument_position,
-                    context.get_type_name(expected),
-                    context.get_type_name(actual)
-                )
+                    context.format_type(expected),
+                    context.format_type(actual)
+                );
+                match note {
+                    Some(note) => format!("{} (note: {})", message, note),
+                    None => message,
+                }
             }
 
             SemanticAnalysisError::ReturnOutsideFunction { .. } => {
@@ -351,15 +443,15 @@ impl SemanticAnalysisError {
             } => {
                 format!(
                     "Type mismatch: function returns {} but got {}",
-                    context.get_type_name(expected),
-                    context.get_type_name(actual)
+                    context.format_type(expected),
+                    context.format_type(actual)
                 )
             }
 
             SemanticAnalysisError::MissingReturnValue { expected, .. } => {
                 format!(
                     "Type mismatch: function returns {} but no return value provided",
-                    context.get_type_name(expected)
+                    context.format_type(expected)
                 )
             }
 
@@ -375,24 +467,25 @@ impl SemanticAnalysisError {
                 if operator == "!" {
                     format!(
                         "Boolean not operator '!' can only be applied to boolean types, but got {}",
-                        context.get_type_name(operand_type)
+                        context.format_type(operand_type)
                     )
                 } else if operator == "-" {
-                    if context.get_type_name(operand_type) == "u32"
-                        || context.get_type_name(operand_type) == "u64"
-                    {
-                        "Cannot negate unsigned type".to_string()
+                    if context.is_unsigned_integer_type(operand_type) {
+                        format!(
+                            "Cannot negate unsigned type {}",
+                            context.format_type(operand_type)
+                        )
                     } else {
                         format!(
                             "Cannot negate non-numeric type '{}'",
-                            context.get_type_name(operand_type)
+                            context.format_type(operand_type)
                         )
                     }
                 } else {
                     format!(
                         "Cannot apply operator '{}' to type {}",
                         operator,
-                        context.get_type_name(operand_type)
+                        context.format_type(operand_type)
                     )
                 }
             }
@@ -410,10 +503,63 @@ impl SemanticAnalysisError {
             } => {
                 format!(
                     "Cannot call {} type '{}' as a function",
-                    context.get_type_name(variable_type),
+                    context.format_type(variable_type),
                     variable_name
                 )
             }
+
+            SemanticAnalysisError::FormatArgumentCountMismatch {
+                function_name,
+                placeholders,
+                arguments,
+                ..
+            } => {
+                format!(
+                    "Format string of '{}' has {} placeholders, but {} values were given",
+                    function_name, placeholders, arguments
+                )
+            }
+
+            SemanticAnalysisError::RecursiveStructType {
+                struct_name, cycle, ..
+            } => {
+                format!(
+                    "Struct '{}' contains itself by value and would have infinite size: {}",
+                    struct_name,
+                    cycle.join(" -> ")
+                )
+            }
+
+            SemanticAnalysisError::DivisionByZero { operator, .. } => {
+                format!("Division by zero: right operand of '{}' is zero", operator)
+            }
+
+            SemanticAnalysisError::UndefinedEnumVariant {
+                enum_name, variant, ..
+            } => {
+                format!("Enum '{}' has no variant named '{}'", enum_name, variant)
+            }
+
+            SemanticAnalysisError::UndefinedMethod {
+                type_id, method, ..
+            } => {
+                format!(
+                    "Type '{}' has no method named '{}'",
+                    context.format_type(type_id),
+                    method
+                )
+            }
+
+            SemanticAnalysisError::MissingEntryPoint { name, .. } => {
+                format!("Entry point function '{}' is not defined", name)
+            }
+
+            SemanticAnalysisError::InvalidEntryPoint { name, .. } => {
+                format!(
+                    "Entry point function '{}' must have the signature '() -> i32' or '() -> ()'",
+                    name
+                )
+            }
         }
     }
 
@@ -438,6 +584,13 @@ impl SemanticAnalysisError {
             SemanticAnalysisError::AssignmentToImmutableVariable { location, .. } => location,
             SemanticAnalysisError::InvalidExpression { location, .. } => location,
             SemanticAnalysisError::VariableNotCallable { location, .. } => location,
+            SemanticAnalysisError::FormatArgumentCountMismatch { location, .. } => location,
+            SemanticAnalysisError::RecursiveStructType { location, .. } => location,
+            SemanticAnalysisError::DivisionByZero { location, .. } => location,
+            SemanticAnalysisError::UndefinedEnumVariant { location, .. } => location,
+            SemanticAnalysisError::UndefinedMethod { location, .. } => location,
+            SemanticAnalysisError::MissingEntryPoint { location, .. } => location,
+            SemanticAnalysisError::InvalidEntryPoint { location, .. } => location,
         }
     }
 
@@ -445,7 +598,7 @@ impl SemanticAnalysisError {
     /// Falls back to heuristics only for cases where location information may not be available.
     fn get_token_length(&self) -> Option<usize> {
         let location = self.get_location();
-        return Some(location.length);
+        Some(location.length)
     }
 
     /// Convert a SemanticAnalysisError to a CompilerError that can be used by the rest of the compiler.
@@ -458,14 +611,76 @@ impl SemanticAnalysisError {
     pub fn to_compiler_error(&self, context: &CompilationContext) -> CompilerError {
         let location = self.get_location();
         let token_length = self.get_token_length();
-        CompilerError::new(
+        let error = CompilerError::new(
             self.error_code(),
             self.format_message(context),
             location.line,
             location.column,
             location.position,
             token_length,
-        )
+        );
+        let error = match self.suggestion(context) {
+            Some(suggestion) => error.with_suggestion(suggestion),
+            None => error,
+        };
+        match self.previous_location() {
+            Some((name, previous)) => error.with_related(CompilerError::new(
+                self.error_code(),
+                format!("'{}' is first defined here", name),
+                previous.line,
+                previous.column,
+                previous.position,
+                Some(previous.length),
+            )),
+            None => error,
+        }
+    }
+
+    /// Returns the original definition of a redefined symbol, if it is known
+    ///
+    /// ### Returns
+    /// The name of the symbol and the location of its first definition
+    fn previous_location(&self) -> Option<(&str, &Location)> {
+        match self {
+            SemanticAnalysisError::VariableRedefinition {
+                name,
+                previous_location: Some(previous),
+                ..
+            }
+            | SemanticAnalysisError::SymbolRedefinition {
+                name,
+                previous_location: Some(previous),
+                ..
+            } => Some((name, previous)),
+            _ => None,
+        }
+    }
+
+    /// Proposes a fix for the error, if there is an obvious one
+    ///
+    /// ### Arguments
+    /// * `context` - The compilation context used for type names
+    ///
+    /// ### Returns
+    /// The text of the suggestion, or None if no fix is known
+    pub fn suggestion(&self, context: &CompilationContext) -> Option<String> {
+        match self {
+            SemanticAnalysisError::InvalidUnaryOperation {
+                operator,
+                operand_type,
+                ..
+            } if operator == "-" => {
+                let signed = context
+                    .get_primitive_type_from_id(operand_type)?
+                    .signed_counterpart()?;
+                Some(format!(
+                    "Consider changing the type from {} to the signed type {}",
+                    context.format_type(operand_type),
+                    signed.name()
+                ))
+            }
+            _ => None,
+        }
     }
 
     /// Get the appropriate error code for this semantic error
@@ -493,6 +708,33 @@ impl SemanticAnalysisError {
             }
             SemanticAnalysisError::InvalidExpression { .. } => ErrorCode::InvalidExpression,
             SemanticAnalysisError::VariableNotCallable { .. } => ErrorCode::VariableNotCallable,
+            SemanticAnalysisError::FormatArgumentCountMismatch { .. } => {
+                ErrorCode::FormatArgumentCountMismatch
+            }
+            SemanticAnalysisError::RecursiveStructType { .. } => ErrorCode::RecursiveStructType,
+            SemanticAnalysisError::DivisionByZero { .. } => ErrorCode::DivisionByZero,
+            SemanticAnalysisError::UndefinedEnumVariant { .. } => ErrorCode::UndefinedEnumVariant,
+            SemanticAnalysisError::UndefinedMethod { .. } => ErrorCode::UndefinedMethod,
+            SemanticAnalysisError::MissingEntryPoint { .. } => ErrorCode::MissingEntryPoint,
+            SemanticAnalysisError::InvalidEntryPoint { .. } => ErrorCode::InvalidEntryPoint,
+        }
+    }
+}
+
+/// Formats a list of accepted argument counts, e.g. "2" or "1, 2 or 3"
+///
+/// ### Arguments
+/// * `arities` - The accepted argument counts
+///
+/// ### Returns
+/// The argument counts joined for use in an error message
+fn format_arities(arities: &[usize]) -> String {
+    match arities.split_last() {
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => {
+            let rest: Vec<String> = rest.iter().map(usize::to_string).collect();
+            format!("{} or {}", rest.join(", "), last)
         }
+        None => "0".to_string(),
     }
 }
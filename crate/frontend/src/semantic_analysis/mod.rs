@@ -1,4 +1,5 @@
 pub mod analyzer_modules;
+pub mod const_eval;
 pub mod error;
 pub mod error_collector;
 pub mod operations;
@@ -9,6 +10,7 @@ pub mod validation;
 pub mod visitors;
 
 pub use analyzer_modules::CoreAnalyzer;
+pub use const_eval::const_eval;
 pub use error::SemanticAnalysisError;
 pub use error_collector::ErrorCollector;
 pub use semantic_analyzer::execute;
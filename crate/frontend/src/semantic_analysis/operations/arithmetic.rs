@@ -1,7 +1,10 @@
 use super::super::traits::SemanticResult;
 use super::helpers;
+use super::super::type_system;
 use slang_ir::Location;
-use slang_ir::ast::{BinaryExpr, BinaryOperator};
+use super::super::const_eval::const_eval;
+use super::super::error::SemanticAnalysisError;
+use slang_ir::ast::{BinaryExpr, BinaryOperator, LiteralValue};
 use slang_shared::CompilationContext;
 use slang_types::TypeId;
 
@@ -46,7 +49,7 @@ pub fn check_same_type_arithmetic(
         ));
     }
 
-    Ok(type_id.clone())
+    Ok(*type_id)
 }
 
 /// Checks if mixed-type arithmetic operations are allowed, particularly handling
@@ -73,27 +76,19 @@ pub fn check_mixed_arithmetic_operation(
     right_type: &TypeId,
     bin_expr: &BinaryExpr,
 ) -> SemanticResult {
-    if helpers::is_unspecified_integer_type(left_type) && is_integer_type(context, right_type) {
-        return check_unspecified_int_for_type(context, &bin_expr.left, right_type);
+    if context.can_coerce(left_type, right_type, None) {
+        return type_system::check_literal_coercion(context, &bin_expr.left, left_type, right_type);
     }
 
-    if helpers::is_unspecified_integer_type(right_type) && is_integer_type(context, left_type) {
-        return check_unspecified_int_for_type(context, &bin_expr.right, left_type);
-    }
-
-    if helpers::is_unspecified_float_type(left_type) && is_float_type(context, right_type) {
-        return check_unspecified_float_for_type(context, &bin_expr.left, right_type);
-    }
-
-    if helpers::is_unspecified_float_type(right_type) && is_float_type(context, left_type) {
-        return check_unspecified_float_for_type(context, &bin_expr.right, left_type);
+    if context.can_coerce(right_type, left_type, None) {
+        return type_system::check_literal_coercion(context, &bin_expr.right, right_type, left_type);
     }
 
     if bin_expr.operator == BinaryOperator::Add
         && helpers::is_string_type(left_type)
         && helpers::is_string_type(right_type)
     {
-        return Ok(left_type.clone());
+        return Ok(*left_type);
     }
 
     Err(helpers::operation_type_mismatch_error(
@@ -104,80 +99,46 @@ pub fn check_mixed_arithmetic_operation(
     ))
 }
 
-/// Helper function to check if a type is an integer type.
-/// This includes all signed and unsigned integer types but not unspecified integers.
+/// Rejects divisions whose right operand is a compile-time constant zero
 ///
-/// ### Arguments
-/// * `context` - The compilation context
-/// * `type_id` - The type to check
-///
-/// ### Returns
-/// * `true` if the type is a specific integer type
-/// * `false` otherwise
-fn is_integer_type(context: &CompilationContext, type_id: &TypeId) -> bool {
-    helpers::is_numeric_type(context, type_id)
-        && !helpers::is_unspecified_integer_type(type_id)
-        && !is_float_type(context, type_id)
-}
-
-/// Helper function to check if a type is a float type.
-/// This includes all floating-point types but not unspecified floats.
+/// The divisor is evaluated with `const_eval`, so negated and folded zeros like `-0` or
+/// `(2 - 2)` are caught as well. A divisor that only evaluates to zero at runtime is
+/// still reported by the virtual machine.
 ///
 /// ### Arguments
-/// * `_context` - The compilation context (unused but kept for API consistency)
-/// * `type_id` - The type to check
+/// * `context` - The compilation context used to evaluate the divisor
+/// * `bin_expr` - The binary expression to check
 ///
 /// ### Returns
-/// * `true` if the type is a specific float type
-/// * `false` otherwise
-fn is_float_type(_context: &CompilationContext, type_id: &TypeId) -> bool {
-    use slang_types::PrimitiveType;
-
-    if let Some(primitive) = PrimitiveType::from_int(type_id.0) {
-        matches!(primitive, PrimitiveType::F32 | PrimitiveType::F64)
-    } else {
-        false
+/// * `Ok(())` if the operation isn't a division by a constant zero
+/// * `Err` with a division by zero error pointing at the divisor otherwise
+pub fn check_division_by_zero(
+    context: &CompilationContext,
+    bin_expr: &BinaryExpr,
+) -> Result<(), SemanticAnalysisError> {
+    if bin_expr.operator != BinaryOperator::Divide {
+        return Ok(());
     }
-}
 
-/// Checks if an unspecified integer literal is in the valid range for a target type.
-/// This is used when coercing an integer literal to a specific integer type.
-///
-/// ### Arguments
-/// * `context` - The compilation context
-/// * `expr` - The expression that might contain an unspecified integer literal
-/// * `target_type` - The target integer type to coerce to
-///
-/// ### Returns
-/// * `Ok(target_type)` if the coercion is valid
-/// * `Err` with a range error if the literal is out of bounds
-fn check_unspecified_int_for_type(
-    context: &CompilationContext,
-    expr: &slang_ir::ast::Expression,
-    target_type: &TypeId,
-) -> SemanticResult {
-    // For now, we'll delegate to the type system module
-    // In a future refactor, this logic could be moved here for better encapsulation
-    super::super::type_system::check_unspecified_int_for_type(context, expr, target_type)
-}
+    let is_zero = match const_eval(&bin_expr.right, context) {
+        Some(LiteralValue::I32(value)) => value == 0,
+        Some(LiteralValue::I64(value)) => value == 0,
+        Some(LiteralValue::UnspecifiedInteger(value)) => value == 0,
+        Some(LiteralValue::U32(value)) => value == 0,
+        Some(LiteralValue::U64(value)) => value == 0,
+        Some(LiteralValue::I128(value)) => value == 0,
+        Some(LiteralValue::U128(value)) => value == 0,
+        Some(LiteralValue::F32(value)) => value == 0.0,
+        Some(LiteralValue::F64(value) | LiteralValue::UnspecifiedFloat(value)) => value == 0.0,
+        _ => false,
+    };
 
-/// Checks if an unspecified float literal is in the valid range for a target type.
-/// This is used when coercing a float literal to a specific float type.
-///
-/// ### Arguments
-/// * `context` - The compilation context
-/// * `expr` - The expression that might contain an unspecified float literal
-/// * `target_type` - The target float type to coerce to
-///
-/// ### Returns
-/// * `Ok(target_type)` if the coercion is valid
-/// * `Err` with a range error if the literal is out of bounds
-fn check_unspecified_float_for_type(
-    context: &CompilationContext,
-    expr: &slang_ir::ast::Expression,
-    target_type: &TypeId,
-) -> SemanticResult {
-    // For now, we'll delegate to the type system module
-    // In a future refactor, this logic could be moved here for better encapsulation
-    super::super::type_system::check_unspecified_float_for_type(context, expr, target_type)
+    if is_zero {
+        return Err(SemanticAnalysisError::DivisionByZero {
+            operator: bin_expr.operator.to_string(),
+            location: bin_expr.right.location(),
+        });
+    }
+
+    Ok(())
 }
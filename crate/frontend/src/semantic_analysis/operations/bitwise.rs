@@ -0,0 +1,123 @@
+use super::super::{error::SemanticAnalysisError, traits::SemanticResult, type_system};
+use super::helpers;
+use slang_ir::Location;
+use slang_ir::ast::BinaryExpr;
+use slang_shared::CompilationContext;
+use slang_types::TypeId;
+
+/// Checks if types are compatible for bitwise operations (&, |, ^).
+/// Both operands must be integers of the same type. An unspecified integer literal
+/// is coerced to the integer type of the other operand.
+///
+/// ### Arguments
+/// * `context` - The compilation context
+/// * `left_type` - The type of the left operand
+/// * `right_type` - The type of the right operand
+/// * `bin_expr` - The binary expression containing both operands and the operator
+///
+/// ### Returns
+/// * `Ok(type_id)` with the resulting integer type if the operation is allowed
+/// * `Err` with an operation type mismatch error otherwise
+pub fn check_bitwise_operation(
+    context: &CompilationContext,
+    left_type: &TypeId,
+    right_type: &TypeId,
+    bin_expr: &BinaryExpr,
+) -> SemanticResult {
+    let left_is_integer = type_system::is_integer_type(context, left_type);
+    let right_is_integer = type_system::is_integer_type(context, right_type);
+
+    if left_is_integer && right_is_integer {
+        if left_type == right_type {
+            return Ok(*left_type);
+        }
+
+        if helpers::is_unspecified_integer_type(left_type) {
+            return type_system::check_unspecified_int_for_type(
+                context,
+                &bin_expr.left,
+                right_type,
+            );
+        }
+
+        if helpers::is_unspecified_integer_type(right_type) {
+            return type_system::check_unspecified_int_for_type(
+                context,
+                &bin_expr.right,
+                left_type,
+            );
+        }
+    }
+
+    Err(helpers::operation_type_mismatch_error(
+        &bin_expr.operator.to_string(),
+        left_type,
+        right_type,
+        &bin_expr.location,
+    ))
+}
+
+/// Checks if types are compatible for shift operations (<<, >>).
+/// Both operands must be integers, the result has the type of the left operand.
+/// An unspecified integer literal as shift amount must fit into a u32, so negative
+/// shift amounts are rejected.
+///
+/// ### Arguments
+/// * `context` - The compilation context
+/// * `left_type` - The type of the value being shifted
+/// * `right_type` - The type of the shift amount
+/// * `bin_expr` - The binary expression containing both operands and the operator
+///
+/// ### Returns
+/// * `Ok(left_type)` if the operation is allowed
+/// * `Err` with a descriptive error message otherwise
+pub fn check_shift_operation(
+    context: &CompilationContext,
+    left_type: &TypeId,
+    right_type: &TypeId,
+    bin_expr: &BinaryExpr,
+) -> SemanticResult {
+    if !type_system::is_integer_type(context, left_type)
+        || !type_system::is_integer_type(context, right_type)
+    {
+        return Err(helpers::operation_type_mismatch_error(
+            &bin_expr.operator.to_string(),
+            left_type,
+            right_type,
+            &bin_expr.location,
+        ));
+    }
+
+    if helpers::is_unspecified_integer_type(right_type) {
+        type_system::check_unspecified_int_for_type(context, &bin_expr.right, &TypeId::u32())?;
+    }
+
+    Ok(*left_type)
+}
+
+/// Checks if bitwise negation (~) is valid for the given operand type.
+/// Only integer types can be bitwise negated.
+///
+/// ### Arguments
+/// * `context` - The compilation context
+/// * `operand_type` - The type of the operand
+/// * `location` - The source location of the operation
+///
+/// ### Returns
+/// * `Ok(type_id)` with the operand type if the operand is an integer
+/// * `Err` with a descriptive error message if the operand is not an integer
+pub fn check_bitwise_not_operation(
+    context: &CompilationContext,
+    operand_type: &TypeId,
+    location: &Location,
+) -> SemanticResult {
+    if type_system::is_integer_type(context, operand_type) {
+        Ok(*operand_type)
+    } else {
+        Err(SemanticAnalysisError::InvalidUnaryOperation {
+            operator: "~".to_string(),
+            operand_type: *operand_type,
+            location: *location,
+        })
+    }
+}
@@ -1,22 +1,17 @@
 use super::super::traits::SemanticResult;
-use super::helpers::{
-    bool_type, is_unspecified_float_type, is_unspecified_integer_type,
-    operation_type_mismatch_error, types_are_identical,
-};
+use super::helpers::{bool_type, operation_type_mismatch_error, types_are_identical};
 use slang_ir::Location;
 use slang_ir::ast::BinaryOperator;
 use slang_shared::CompilationContext;
 use slang_types::TypeId;
 
-use super::super::type_system;
-
-/// Checks if an operator is a relational operator (requires numeric types)
+/// Checks if an operator is a relational operator (requires ordered types)
 ///
 /// ### Arguments
 /// * `operator` - The binary operator to check
 ///
 /// ### Returns
-/// * `true` if the operator requires numeric types, `false` otherwise
+/// * `true` if the operator requires ordered types, `false` otherwise
 fn is_strictly_relational_operator(operator: &BinaryOperator) -> bool {
     matches!(
         operator,
@@ -41,16 +36,27 @@ fn can_coerce_for_relational(
     left_type: &TypeId,
     right_type: &TypeId,
 ) -> bool {
-    (is_unspecified_integer_type(left_type) && type_system::is_integer_type(context, right_type))
-        || (is_unspecified_integer_type(right_type)
-            && type_system::is_integer_type(context, left_type))
-        || (is_unspecified_float_type(left_type) && type_system::is_float_type(context, right_type))
-        || (is_unspecified_float_type(right_type) && type_system::is_float_type(context, left_type))
+    left_type != right_type
+        && (context.can_coerce(left_type, right_type, None)
+            || context.can_coerce(right_type, left_type, None))
+}
+
+/// Checks if a type has an ordering, so it can be used with >, <, >= and <=.
+/// Numeric types are ordered by value and strings lexicographically.
+///
+/// ### Arguments
+/// * `context` - The compilation context
+/// * `type_id` - The type to check
+///
+/// ### Returns
+/// * `true` if values of the type can be ordered, `false` otherwise
+fn is_ordered_type(context: &CompilationContext, type_id: &TypeId) -> bool {
+    context.is_numeric_type(type_id) || *type_id == TypeId::string()
 }
 
 /// Checks if types are compatible for relational operations (>, <, >=, <=, ==, !=).
 /// Types must be comparable with each other, which means they're either:
-/// - Exactly the same type (except Unit)
+/// - Exactly the same type, where bool and unit only support == and !=
 /// - Unspecified integer literal and an integer type
 /// - Unspecified float literal and a float type
 ///
@@ -71,9 +77,9 @@ pub fn check_relational_operation(
     operator: &BinaryOperator,
     location: &Location,
 ) -> SemanticResult {
-    // Strictly relational operators (>, <, >=, <=) require numeric types
+    // Strictly relational operators (>, <, >=, <=) require ordered types
     if is_strictly_relational_operator(operator)
-        && (!context.is_numeric_type(left_type) || !context.is_numeric_type(right_type))
+        && (!is_ordered_type(context, left_type) || !is_ordered_type(context, right_type))
     {
         return Err(operation_type_mismatch_error(
             &operator.to_string(),
@@ -83,8 +89,8 @@ pub fn check_relational_operation(
         ));
     }
 
-    // Check for type compatibility
-    if (types_are_identical(left_type, right_type) && *left_type != TypeId::unit())
+    // Check for type compatibility, unit values are all equal to each other
+    if types_are_identical(left_type, right_type)
         || can_coerce_for_relational(context, left_type, right_type)
     {
         Ok(bool_type())
@@ -1,7 +1,9 @@
-use crate::semantic_analysis::ErrorCollector;
+use crate::semantic_analysis::{ErrorCollector, SemanticAnalysisError};
 use slang_error::CompileResult;
+use slang_ir::Location;
 use slang_ir::ast::Statement;
 use slang_shared::CompilationContext;
+use slang_types::TypeId;
 
 use super::analyzer_modules::core::CoreAnalyzer;
 use super::analyzer_modules::native_functions;
@@ -15,6 +17,25 @@ use super::analyzer_modules::native_functions;
 ///
 /// ### Returns
 /// * `CompileResult<()>` - Ok if no semantic errors were found, otherwise Err with the list of errors
+///
+/// ### Example
+/// ```
+/// use slang_frontend::{parser, semantic_analysis, tokenize};
+/// use slang_shared::CompilationContext;
+///
+/// let analyze = |source: &str| {
+///     let mut context = CompilationContext::new();
+///     context.set_entry_point(Some("main".to_string()));
+///     let lexed = tokenize(source).unwrap();
+///     let statements = parser::parse(&lexed.tokens, &lexed.line_info, &mut context).unwrap();
+///     semantic_analysis::execute(&statements, &mut context)
+/// };
+///
+/// assert!(analyze("fn main() -> i32 { return 0; }").is_ok());
+///
+/// let errors = analyze("fn start() {}").unwrap_err();
+/// assert_eq!(errors[0].message, "Entry point function 'main' is not defined");
+/// ```
 pub fn execute(statements: &[Statement], context: &mut CompilationContext) -> CompileResult<()> {
     let mut analyzer = CoreAnalyzer::new(context);
     native_functions::register_native_functions(analyzer.context()); // Register native functions using the accessor
@@ -27,6 +48,12 @@ pub fn execute(statements: &[Statement], context: &mut CompilationContext) -> Co
         }
     }
 
+    if let Some(name) = analyzer.context().entry_point()
+        && let Err(error) = check_entry_point(statements, name)
+    {
+        error_collector.add_semantic_error(error, analyzer.context());
+    }
+
     if error_collector.has_errors() {
         Err(error_collector.into_errors())
     } else {
@@ -34,3 +61,39 @@ pub fn execute(statements: &[Statement], context: &mut CompilationContext) -> Co
     }
 }
 
+/// Checks that the program declares a top-level function that can serve as its entry point
+///
+/// The entry point takes no parameters and returns either `i32`, which becomes the exit
+/// code of the program, or `()`. If the name is overloaded, its first declaration is the
+/// entry point.
+///
+/// ### Arguments
+/// * `statements` - The top-level statements of the program
+/// * `name` - The name of the entry point function
+///
+/// ### Returns
+/// Ok if a suitable function is declared, otherwise the error describing the problem
+fn check_entry_point(statements: &[Statement], name: &str) -> Result<(), SemanticAnalysisError> {
+    let declaration = statements.iter().find_map(|stmt| match stmt {
+        Statement::FunctionDeclaration(fn_decl) if fn_decl.name == name => Some(fn_decl),
+        _ => None,
+    });
+
+    match declaration {
+        None => Err(SemanticAnalysisError::MissingEntryPoint {
+            name: name.to_string(),
+            location: Location::new(0, 1, 1, 0),
+        }),
+        Some(fn_decl)
+            if fn_decl.parameters.is_empty()
+                && (fn_decl.return_type == TypeId::i32()
+                    || fn_decl.return_type == TypeId::unit()) =>
+        {
+            Ok(())
+        }
+        Some(fn_decl) => Err(SemanticAnalysisError::InvalidEntryPoint {
+            name: name.to_string(),
+            location: fn_decl.location,
+        }),
+    }
+}
@@ -1,5 +1,6 @@
 // Core traits for semantic analysis components
 use super::error::SemanticAnalysisError;
+use slang_ir::Location;
 use slang_shared::{Symbol, SymbolKind};
 use slang_types::TypeId;
 
@@ -46,5 +47,6 @@ pub trait ScopeManager {
         kind: SymbolKind,
         type_id: TypeId,
         is_mutable: bool,
+        location: Option<Location>,
     ) -> Result<(), String>;
 }
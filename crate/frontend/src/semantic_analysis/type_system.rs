@@ -1,9 +1,9 @@
 use super::{error::SemanticAnalysisError, traits::SemanticResult};
 use slang_ir::ast::{
-    BinaryExpr, BinaryOperator, Expression, LetStatement, LiteralValue, UnaryOperator,
+    BinaryOperator, BlockExpr, Expression, LetStatement, LiteralValue, Statement, UnaryOperator,
 };
 use slang_shared::CompilationContext;
-use slang_types::{TypeId, TYPE_NAME_U32, TYPE_NAME_U64};
+use slang_types::{PrimitiveType, TypeId, TYPE_NAME_U32, TYPE_NAME_U64, TYPE_NAME_U128};
 
 /// Checks if a type is an integer type
 ///
@@ -36,10 +36,10 @@ pub fn is_float_type(context: &CompilationContext, type_id: &TypeId) -> bool {
 /// * `type_id` - The type to check
 ///
 /// ### Returns
-/// * `true` if the type is u32 or u64, `false` otherwise
+/// * `true` if the type is u32, u64 or u128, `false` otherwise
 pub fn is_unsigned_type(context: &CompilationContext, type_id: &TypeId) -> bool {
     let type_name = context.get_type_name(type_id);
-    type_name == TYPE_NAME_U64 || type_name == TYPE_NAME_U32
+    type_name == TYPE_NAME_U128 || type_name == TYPE_NAME_U64 || type_name == TYPE_NAME_U32
 }
 
 /// Checks if an unspecified integer literal is in the valid range for a target type.
@@ -59,42 +59,7 @@ pub fn check_unspecified_int_for_type(
     expr: &Expression,
     target_type: &TypeId,
 ) -> SemanticResult {
-    if let Expression::Unary(unary_expr) = expr {
-        if unary_expr.operator == UnaryOperator::Negate {
-            if let Expression::Literal(lit) = &*unary_expr.right {
-                if let LiteralValue::UnspecifiedInteger(n) = &lit.value {
-                    if context.get_type_name(target_type) == "u32"
-                        || context.get_type_name(target_type) == "u64"
-                    {
-                        return Err(SemanticAnalysisError::ValueOutOfRange {
-                            value: format!("-{}", n),
-                            target_type: target_type.clone(),
-                            is_float: false,
-                            location: expr.location(),
-                        });
-                    }
-                }
-            }
-        }
-    }
-
-    if let Expression::Literal(lit) = expr {
-        if let LiteralValue::UnspecifiedInteger(n) = &lit.value {
-            let value_in_range = context.check_value_in_range(n, target_type);
-
-            if value_in_range {
-                return Ok(target_type.clone());
-            } else {
-                return Err(SemanticAnalysisError::ValueOutOfRange {
-                    value: n.to_string(),
-                    target_type: target_type.clone(),
-                    is_float: false,
-                    location: expr.location(),
-                });
-            }
-        }
-    }
-    Ok(target_type.clone())
+    check_literal_coercion(context, expr, &TypeId::unspecified_int(), target_type)
 }
 
 /// Checks if an unspecified float literal is in the valid range for a target type.
@@ -114,23 +79,65 @@ pub fn check_unspecified_float_for_type(
     expr: &Expression,
     target_type: &TypeId,
 ) -> SemanticResult {
-    if let Expression::Literal(lit) = expr {
-        if let LiteralValue::UnspecifiedFloat(f) = &lit.value {
-            let value_in_range = context.check_float_value_in_range(f, target_type);
+    check_literal_coercion(context, expr, &TypeId::unspecified_float(), target_type)
+}
 
-            if value_in_range {
-                return Ok(target_type.clone());
-            } else {
-                return Err(SemanticAnalysisError::ValueOutOfRange {
-                    value: f.to_string(),
-                    target_type: target_type.clone(),
-                    is_float: true,
-                    location: expr.location(),
-                });
+/// Checks that the value of an unspecified literal, which may be negated or be the tail
+/// of a block, can be coerced to a target type.
+///
+/// ### Arguments
+/// * `context` - The compilation context
+/// * `expr` - The expression that might contain an unspecified literal
+/// * `literal_type` - The unspecified literal type of the expression
+/// * `target_type` - The type the literal is coerced to
+///
+/// ### Returns
+/// * `Ok(target_type)` if the literal is in range for the target type or the expression isn't a literal
+/// * `Err` with a ValueOutOfRange error if the literal is out of range
+pub fn check_literal_coercion(
+    context: &CompilationContext,
+    expr: &Expression,
+    literal_type: &TypeId,
+    target_type: &TypeId,
+) -> SemanticResult {
+    let value = match expr {
+        Expression::Literal(lit) => lit.value.clone(),
+        Expression::Unary(unary_expr) if unary_expr.operator == UnaryOperator::Negate => {
+            match &*unary_expr.right {
+                Expression::Literal(lit) => match lit.value {
+                    LiteralValue::UnspecifiedInteger(n) => LiteralValue::UnspecifiedInteger(-n),
+                    LiteralValue::UnspecifiedFloat(f) => LiteralValue::UnspecifiedFloat(-f),
+                    _ => return Ok(*target_type),
+                },
+                _ => return Ok(*target_type),
             }
         }
+        // The value of a block is the value of its tail expression
+        Expression::Block(block) => {
+            return match &block.return_expr {
+                Some(tail) => check_literal_coercion(context, tail, literal_type, target_type),
+                None => Ok(*target_type),
+            };
+        }
+        _ => return Ok(*target_type),
+    };
+
+    let (formatted_value, is_float) = match value {
+        LiteralValue::UnspecifiedInteger(n) => (n.to_string(), false),
+        LiteralValue::UnspecifiedFloat(f) => (f.to_string(), true),
+        _ => return Ok(*target_type),
+    };
+
+    if context.can_coerce(literal_type, target_type, Some(&value)) {
+        Ok(*target_type)
+    } else {
+        Err(SemanticAnalysisError::ValueOutOfRange {
+            value: formatted_value,
+            target_type: *target_type,
+            is_float,
+            location: expr.location(),
+        })
     }
-    Ok(target_type.clone())
 }
 
 /// Converts unspecified literal types to concrete types.
@@ -171,6 +178,10 @@ pub fn determine_let_statement_type(
     expr_type: TypeId,
 ) -> SemanticResult {
     if let_stmt.expr_type == TypeId::unknown() {
+        // An unspecified integer literal is inferred to be an i64, so it has to fit into one
+        if expr_type == TypeId::unspecified_int() {
+            check_unspecified_int_for_type(context, &let_stmt.value, &TypeId::i64())?;
+        }
         return Ok(expr_type);
     }
 
@@ -178,143 +189,321 @@ pub fn determine_let_statement_type(
         if is_unsigned_type(context, &let_stmt.expr_type) {
             check_unspecified_int_for_type(context, &let_stmt.value, &let_stmt.expr_type)?;
         }
-        return Ok(let_stmt.expr_type.clone());
+        return Ok(let_stmt.expr_type);
     }
 
-    if context.get_function_type(&let_stmt.expr_type).is_some()
-        && context.get_function_type(&expr_type).is_some()
-    {
-        if let_stmt.expr_type == expr_type {
-            return Ok(let_stmt.expr_type.clone());
-        } else {
-            return Err(SemanticAnalysisError::TypeMismatch {
-                expected: let_stmt.expr_type.clone(),
-                actual: expr_type,
-                context: Some(let_stmt.name.clone()),
-                location: let_stmt.location,
-            });
-        }
-    }
-
-    if expr_type == TypeId::unspecified_int() {
-        return handle_unspecified_int_assignment(context, let_stmt, &expr_type);
-    }
-
-    if expr_type == TypeId::unspecified_float() {
-        return handle_unspecified_float_assignment(context, let_stmt, &expr_type);
-    }
-
-    Err(SemanticAnalysisError::TypeMismatch {
-        expected: let_stmt.expr_type.clone(),
-        actual: expr_type,
-        context: Some(let_stmt.name.clone()),
-        location: let_stmt.location,
-    })
+    check_assignable(
+        context,
+        &let_stmt.value,
+        &expr_type,
+        &let_stmt.expr_type,
+        || SemanticAnalysisError::TypeMismatch {
+            expected: let_stmt.expr_type,
+            actual: expr_type,
+            context: Some(let_stmt.name.clone()),
+            location: let_stmt.location,
+        },
+    )
 }
 
-/// Handles assignment of an unspecified integer literal to a variable with a declared type.
+/// Checks that the value of an expression can be stored in a place of the target type.
+/// This is the single rule used for let bindings, assignments, function arguments and
+/// return values: the types have to match exactly, or the expression is an unspecified
+/// literal whose value is in range for the target type.
 ///
 /// ### Arguments
 /// * `context` - The compilation context
-/// * `let_stmt` - The let statement being analyzed.
-/// * `expr_type` - The type of the initialization expression (should be unspecified_int_type).
+/// * `expr` - The expression whose value is stored
+/// * `expr_type` - The type of the expression
+/// * `target_type` - The type of the place the value is stored in
+/// * `mismatch` - Creates the error that is reported if the types are incompatible
 ///
 /// ### Returns
-/// * `Ok(type_id)` with the declared type if the literal is valid for that type.
-/// * `Err` with a SemanticAnalysisError if there's a type mismatch or value out of range.
-pub fn handle_unspecified_int_assignment(
+/// * `Ok(target_type)` if the value can be stored
+/// * `Err` with a ValueOutOfRange error if a literal doesn't fit the target type
+/// * `Err` with the error created by `mismatch` if the types are incompatible
+///
+/// ### Example
+/// ```
+/// use slang_frontend::semantic_analysis::{SemanticAnalysisError, type_system::check_assignable};
+/// use slang_ir::Location;
+/// use slang_ir::ast::{Expression, LiteralExpr, LiteralValue};
+/// use slang_shared::CompilationContext;
+/// use slang_types::TypeId;
+/// use std::cell::Cell;
+///
+/// let context = CompilationContext::new();
+/// let literal = |value| {
+///     Expression::Literal(LiteralExpr {
+///         value: LiteralValue::UnspecifiedInteger(value),
+///         expr_type: Cell::new(TypeId::unspecified_int()),
+///         location: Location::default(),
+///     })
+/// };
+/// let check = |value, target_type: TypeId| {
+///     check_assignable(&context, &literal(value), &TypeId::unspecified_int(), &target_type, || {
+///         SemanticAnalysisError::InvalidExpression {
+///             message: "mismatch".to_string(),
+///             location: Location::default(),
+///         }
+///     })
+/// };
+///
+/// assert_eq!(check(42, TypeId::i32()).unwrap(), TypeId::i32());
+/// assert!(matches!(
+///     check(3_000_000_000, TypeId::i32()),
+///     Err(SemanticAnalysisError::ValueOutOfRange { .. })
+/// ));
+/// assert!(matches!(
+///     check(42, TypeId::string()),
+///     Err(SemanticAnalysisError::InvalidExpression { .. })
+/// ));
+/// ```
+pub fn check_assignable(
     context: &CompilationContext,
-    let_stmt: &LetStatement,
-    _expr_type: &TypeId,
+    expr: &Expression,
+    expr_type: &TypeId,
+    target_type: &TypeId,
+    mismatch: impl FnOnce() -> SemanticAnalysisError,
 ) -> SemanticResult {
-    if is_integer_type(context, &let_stmt.expr_type) {
-        check_unspecified_int_for_type(context, &let_stmt.value, &let_stmt.expr_type)
-    } else {
-        Err(SemanticAnalysisError::TypeMismatch {
-            expected: let_stmt.expr_type.clone(),
-            actual: TypeId::unspecified_int(),
-            context: Some(let_stmt.name.clone()),
-            location: let_stmt.location,
-        })
+    if expr_type == target_type {
+        return Ok(*target_type);
+    }
+
+    if context.can_coerce(expr_type, target_type, None) {
+        return check_literal_coercion(context, expr, expr_type, target_type);
     }
+
+    Err(mismatch())
 }
 
-/// Handles assignment of an unspecified float literal to a variable with a declared type.
+/// Checks that the value of a function or closure body can be returned from it.
+/// The final expression of the body is returned like the value of a return statement,
+/// so it follows the same rule as `check_assignable`.
 ///
 /// ### Arguments
 /// * `context` - The compilation context
-/// * `let_stmt` - The let statement being analyzed.
-/// * `expr_type` - The type of the initialization expression (should be unspecified_float_type).
+/// * `body` - The body of the function or closure
+/// * `body_type` - The type of the body
+/// * `return_type` - The declared return type
 ///
 /// ### Returns
-/// * `Ok(type_id)` with the declared type if the literal is valid for that type.
-/// * `Err` with a SemanticAnalysisError if there's a type mismatch or value out of range.
-pub fn handle_unspecified_float_assignment(
+/// * `Ok(return_type)` if the body has no final expression or its value can be returned
+/// * `Err` with a ReturnTypeMismatch error if the value has an incompatible type
+pub fn check_body_type(
     context: &CompilationContext,
-    let_stmt: &LetStatement,
-    _expr_type: &TypeId,
+    body: &BlockExpr,
+    body_type: &TypeId,
+    return_type: &TypeId,
 ) -> SemanticResult {
-    if is_float_type(context, &let_stmt.expr_type) {
-        check_unspecified_float_for_type(context, &let_stmt.value, &let_stmt.expr_type)
-    } else {
-        Err(SemanticAnalysisError::TypeMismatch {
-            expected: let_stmt.expr_type.clone(),
-            actual: TypeId::unspecified_float(),
-            context: Some(let_stmt.name.clone()),
-            location: let_stmt.location,
-        })
+    let Some(tail) = &body.return_expr else {
+        return Ok(*return_type);
+    };
+    // Every path through the final expression returns, so its own value is never used
+    if always_returns(tail) {
+        return Ok(*return_type);
     }
+
+    check_assignable(context, tail, body_type, return_type, || {
+        SemanticAnalysisError::ReturnTypeMismatch {
+            expected: *return_type,
+            actual: *body_type,
+            location: tail.location(),
+        }
+    })?;
+    record_literal_types(context, tail, return_type);
+    Ok(*return_type)
 }
 
-/// Checks if mixed-type arithmetic operations are allowed, particularly handling
-/// unspecified literals that can be coerced to match the other operand's type.
-/// Handles the following cases:
-/// - Unspecified integer literal + specific integer type
-/// - Unspecified float literal + specific float type
-/// - String concatenation with the + operator
+/// Checks whether every path through an expression ends in a return statement
 ///
 /// ### Arguments
-/// * `context` - The compilation context
-/// * `left_type` - The type of the left operand
-/// * `right_type` - The type of the right operand
-/// * `bin_expr` - The binary expression containing both operands and the operator
+/// * `expr` - The expression to check
 ///
 /// ### Returns
-/// * `Ok(type_id)` with the resulting operation type if allowed
-/// * `Err` with a descriptive error message if the operation is not allowed
-pub fn check_mixed_arithmetic_operation(
-    context: &CompilationContext,
-    left_type: &TypeId,
-    right_type: &TypeId,
-    bin_expr: &BinaryExpr,
-) -> SemanticResult {
-    if *left_type == TypeId::unspecified_int() && is_integer_type(context, right_type) {
-        return check_unspecified_int_for_type(context, &bin_expr.left, right_type);
-    }
-
-    if *right_type == TypeId::unspecified_int() && is_integer_type(context, left_type) {
-        return check_unspecified_int_for_type(context, &bin_expr.right, left_type);
+/// True if evaluating the expression always returns from the enclosing function
+fn always_returns(expr: &Expression) -> bool {
+    match expr {
+        Expression::Block(block) => block_always_returns(block),
+        Expression::Conditional(cond_expr) => {
+            always_returns(&cond_expr.then_branch) && always_returns(&cond_expr.else_branch)
+        }
+        _ => false,
     }
+}
 
-    if *left_type == TypeId::unspecified_float() && is_float_type(context, right_type) {
-        return check_unspecified_float_for_type(context, &bin_expr.left, right_type);
-    }
+/// Checks whether every path through a block ends in a return statement
+///
+/// ### Arguments
+/// * `block` - The block to check
+///
+/// ### Returns
+/// True if executing the block always returns from the enclosing function
+fn block_always_returns(block: &BlockExpr) -> bool {
+    let statement_returns = |stmt: &Statement| match stmt {
+        Statement::Return(_) => true,
+        Statement::Expression(expr) => always_returns(expr),
+        Statement::If(if_stmt) => match &if_stmt.else_branch {
+            Some(else_branch) => {
+                block_always_returns(&if_stmt.then_branch) && block_always_returns(else_branch)
+            }
+            None => false,
+        },
+        _ => false,
+    };
+    block.statements.iter().any(statement_returns)
+        || block.return_expr.as_deref().is_some_and(always_returns)
+}
 
-    if *right_type == TypeId::unspecified_float() && is_float_type(context, left_type) {
-        return check_unspecified_float_for_type(context, &bin_expr.right, left_type);
+/// Records the type of the unspecified literals that make up the value of an expression.
+/// The type is stored on the literals, so constant folding and the code generator treat
+/// them as values of that type.
+///
+/// The type propagates through the operands of arithmetic and bitwise operations, the
+/// shifted value of a shift, negations and the values of blocks and conditionals.
+/// Comparisons, calls and variables have a type of their own and are left alone.
+///
+/// ### Arguments
+/// * `context` - The compilation context used to resolve the target type
+/// * `expr` - The expression whose value has the target type
+/// * `target_type` - The type of the expression's value
+///
+/// ### Example
+/// ```
+/// use slang_frontend::semantic_analysis::type_system::record_literal_types;
+/// use slang_ir::Location;
+/// use slang_ir::ast::{Expression, LiteralExpr, LiteralValue, UnaryExpr, UnaryOperator};
+/// use slang_shared::CompilationContext;
+/// use slang_types::TypeId;
+/// use std::cell::Cell;
+///
+/// let context = CompilationContext::new();
+/// let negated_five = Expression::Unary(UnaryExpr {
+///     operator: UnaryOperator::Negate,
+///     right: Box::new(Expression::Literal(LiteralExpr {
+///         value: LiteralValue::UnspecifiedInteger(5),
+///         expr_type: Cell::new(TypeId::unspecified_int()),
+///         location: Location::new(1, 1, 2, 1),
+///     })),
+///     expr_type: TypeId::unspecified_int(),
+///     location: Location::new(0, 1, 1, 2),
+/// });
+///
+/// record_literal_types(&context, &negated_five, &TypeId::i32());
+/// let Expression::Unary(unary_expr) = &negated_five else { unreachable!() };
+/// let Expression::Literal(literal) = &*unary_expr.right else { unreachable!() };
+/// assert_eq!(literal.expr_type.get(), TypeId::i32());
+///
+/// // A context without a concrete type keeps the recorded type
+/// record_literal_types(&context, &negated_five, &TypeId::unspecified_int());
+/// assert_eq!(literal.expr_type.get(), TypeId::i32());
+/// ```
+pub fn record_literal_types(context: &CompilationContext, expr: &Expression, target_type: &TypeId) {
+    match expr {
+        Expression::Literal(lit) => {
+            let unspecified = matches!(
+                lit.value,
+                LiteralValue::UnspecifiedInteger(_) | LiteralValue::UnspecifiedFloat(_)
+            );
+            let concrete = matches!(
+                context.get_primitive_type_from_id(target_type),
+                Some(primitive) if primitive.is_numeric()
+                    && primitive != PrimitiveType::UnspecifiedInt
+                    && primitive != PrimitiveType::UnspecifiedFloat
+            );
+            if unspecified && concrete {
+                lit.expr_type.set(*target_type);
+            }
+        }
+        Expression::Unary(unary_expr) if unary_expr.operator != UnaryOperator::Not => {
+            record_literal_types(context, &unary_expr.right, target_type);
+        }
+        Expression::Binary(bin_expr) => match bin_expr.operator {
+            BinaryOperator::Add
+            | BinaryOperator::Subtract
+            | BinaryOperator::Multiply
+            | BinaryOperator::Divide
+            | BinaryOperator::BitAnd
+            | BinaryOperator::BitOr
+            | BinaryOperator::BitXor => {
+                record_literal_types(context, &bin_expr.left, target_type);
+                record_literal_types(context, &bin_expr.right, target_type);
+            }
+            BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight => {
+                record_literal_types(context, &bin_expr.left, target_type);
+            }
+            _ => {}
+        },
+        Expression::Conditional(cond_expr) => {
+            record_literal_types(context, &cond_expr.then_branch, target_type);
+            record_literal_types(context, &cond_expr.else_branch, target_type);
+        }
+        Expression::Block(block) => {
+            if let Some(tail) = &block.return_expr {
+                record_literal_types(context, tail, target_type);
+            }
+        }
+        _ => {}
     }
+}
 
-    if bin_expr.operator == BinaryOperator::Add
-        && *left_type == TypeId::string()
-        && *right_type == TypeId::string()
-    {
-        return Ok(TypeId::string());
+/// Finds a chain of struct fields through which a struct type contains itself by value.
+/// A value of such a type would have infinite size. Function typed fields are an
+/// indirection and therefore never part of a cycle.
+///
+/// ### Arguments
+/// * `context` - The compilation context
+/// * `type_id` - The struct type to check
+///
+/// ### Returns
+/// * `Some(path)` with the struct types of the cycle, starting and ending with `type_id`
+/// * `None` if the struct doesn't contain itself
+///
+/// ### Example
+/// ```
+/// use slang_frontend::semantic_analysis::type_system::find_struct_cycle;
+/// use slang_shared::CompilationContext;
+///
+/// let mut context = CompilationContext::new();
+/// let a = context.declare_struct_type("A").unwrap();
+/// let b = context.declare_struct_type("B").unwrap();
+///
+/// context.define_struct_fields(&a, vec![("b".to_string(), b)]).unwrap();
+/// assert_eq!(find_struct_cycle(&context, &a), None);
+///
+/// context.define_struct_fields(&b, vec![("a".to_string(), a)]).unwrap();
+/// assert_eq!(find_struct_cycle(&context, &b), Some(vec![b, a, b]));
+/// ```
+pub fn find_struct_cycle(context: &CompilationContext, type_id: &TypeId) -> Option<Vec<TypeId>> {
+    fn visit(
+        context: &CompilationContext,
+        target: &TypeId,
+        current: &TypeId,
+        path: &mut Vec<TypeId>,
+        visited: &mut Vec<TypeId>,
+    ) -> bool {
+        let Some(struct_type) = context.get_struct_type(current) else {
+            return false;
+        };
+        for (_, field_type) in &struct_type.fields {
+            if field_type == target {
+                path.push(*field_type);
+                return true;
+            }
+            if visited.contains(field_type) {
+                continue;
+            }
+            visited.push(*field_type);
+            path.push(*field_type);
+            if visit(context, target, field_type, path, visited) {
+                return true;
+            }
+            path.pop();
+        }
+        false
     }
 
-    Err(SemanticAnalysisError::OperationTypeMismatch {
-        operator: bin_expr.operator.to_string(),
-        left_type: left_type.clone(),
-        right_type: right_type.clone(),
-        location: bin_expr.location,
-    })
+    let mut path = vec![*type_id];
+    let mut visited = vec![*type_id];
+    visit(context, type_id, type_id, &mut path, &mut visited).then_some(path)
 }
@@ -1,94 +0,0 @@
-use crate::semantic_analysis::traits::SemanticResult;
-use slang_shared::compilation_context::CompilationContext;
-use slang_types::TypeId;
-use slang_ir::ast::{LiteralValue, Statement, FunctionDeclarationStmt};
-
-/// Type validation handles validation rules and constraints for types
-pub struct TypeValidation<'a> {
-    #[allow(dead_code)] // Context reserved for future validation features
-    context: &'a CompilationContext,
-}
-
-impl<'a> TypeValidation<'a> {
-    pub fn new(context: &'a CompilationContext) -> Self {
-        Self { context }
-    }
-
-    /// Validates that a literal value is within the valid range for its type
-    pub fn validate_literal_range(&self, literal: &LiteralValue, target_type: TypeId) -> SemanticResult {
-        match literal {
-            LiteralValue::UnspecifiedInteger(_value) => {
-                // For now, assume range validation is handled elsewhere
-                Ok(target_type)
-            }
-            LiteralValue::UnspecifiedFloat(_value) => {
-                // For now, assume range validation is handled elsewhere  
-                Ok(target_type)
-            }
-            LiteralValue::I32(_) => Ok(target_type),
-            LiteralValue::I64(_) => Ok(target_type),
-            LiteralValue::U32(_) => Ok(target_type),
-            LiteralValue::U64(_) => Ok(target_type),
-            LiteralValue::F32(_) => Ok(target_type),
-            LiteralValue::F64(_) => Ok(target_type),
-            LiteralValue::Boolean(_) => Ok(target_type),
-            LiteralValue::String(_) => Ok(target_type),
-            LiteralValue::Unit => Ok(target_type),
-        }
-    }
-
-    /// Validates function declaration constraints
-    pub fn validate_function_declaration(&self, func_decl: &FunctionDeclarationStmt) -> SemanticResult {
-        // Validate parameter types exist
-        for param in &func_decl.parameters {
-            self.validate_type_exists(param.param_type.clone())?;
-        }
-
-        // Validate return type exists
-        self.validate_type_exists(func_decl.return_type.clone())?;
-
-        // Return a unit type for successful validation
-        Ok(slang_types::TypeId::unit())
-    }
-
-    /// Validates that a type exists in the type registry
-    pub fn validate_type_exists(&self, type_id: TypeId) -> SemanticResult {
-        // For now, assume all types exist
-        // This would check the type registry in a real implementation
-        Ok(type_id)
-    }
-
-    /// Validates type constraints for variable declarations
-    pub fn validate_variable_type_constraints(&self, var_type: TypeId, _value_type: TypeId) -> SemanticResult {
-        // For now, assume compatibility checking is done elsewhere
-        Ok(var_type)
-    }
-
-    /// Validates that a type is suitable for use in an expression context
-    pub fn validate_expression_type(&self, type_id: TypeId) -> SemanticResult {
-        // For now, assume all types are valid in expressions
-        Ok(type_id)
-    }
-
-    /// Validates that all types in a statement are properly defined
-    pub fn validate_statement_types(&self, statement: &Statement) -> SemanticResult {
-        match statement {
-            Statement::FunctionDeclaration(func_decl) => {
-                self.validate_function_declaration(func_decl)
-            }
-            Statement::Let(let_stmt) => {
-                self.validate_type_exists(let_stmt.expr_type.clone())?;
-                Ok(slang_types::TypeId::unit())
-            }
-            Statement::TypeDefinition(_type_def) => {
-                // Type definition validation would go here
-                // For now, assume it's valid
-                Ok(slang_types::TypeId::unit())
-            }
-            _ => {
-                // Other statements don't need special type validation
-                Ok(slang_types::TypeId::unit())
-            }
-        }
-    }
-}
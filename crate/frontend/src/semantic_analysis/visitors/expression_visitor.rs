@@ -1,10 +1,11 @@
+use slang_error::ErrorCode;
 use slang_ir::ast::*;
-use slang_shared::{CompilationContext, SymbolKind};
-use slang_types::TypeId;
+use slang_shared::{CompilationContext, PATH_SEPARATOR, SymbolKind, qualified_name};
+use slang_types::{FunctionType, TypeId};
 
 use super::super::{
-    error::SemanticAnalysisError, operations, traits::SemanticResult,
-    validation::TypeCheckingCoordinator,
+    analyzer_modules::native_functions, error::SemanticAnalysisError, operations,
+    traits::SemanticResult, type_system,
 };
 
 /// Handles semantic analysis for all expression types
@@ -49,17 +50,13 @@ impl<'a> ExpressionVisitor<'a> {
         self.current_return_type = return_type;
     }
 
-    /// Create a type checking coordinator for this visitor's context
-    fn create_type_coordinator(&self) -> TypeCheckingCoordinator {
-        TypeCheckingCoordinator::new(self.context)
-    }
-
     /// Visit an expression and determine its type
     pub fn visit_expression(&mut self, expr: &Expression) -> SemanticResult {
         match expr {
             Expression::Binary(bin_expr) => self.visit_binary_expression(bin_expr),
             Expression::Unary(unary_expr) => self.visit_unary_expression(unary_expr),
             Expression::Call(call_expr) => self.visit_call_expression(call_expr),
+            Expression::MethodCall(method_call) => self.visit_method_call_expression(method_call),
             Expression::Variable(var_expr) => self.visit_variable_expression(var_expr),
             Expression::Literal(lit_expr) => self.visit_literal_expression(lit_expr),
             Expression::Conditional(cond_expr) => self.visit_conditional_expression(cond_expr),
@@ -67,6 +64,7 @@ impl<'a> ExpressionVisitor<'a> {
             Expression::FunctionType(func_type_expr) => {
                 self.visit_function_type_expression(func_type_expr)
             }
+            Expression::Closure(closure_expr) => self.visit_closure_expression(closure_expr),
         }
     }
 
@@ -85,6 +83,39 @@ impl<'a> ExpressionVisitor<'a> {
             );
         }
 
+        // Handle bitwise operations
+        if matches!(
+            bin_expr.operator,
+            BinaryOperator::BitAnd | BinaryOperator::BitOr | BinaryOperator::BitXor
+        ) {
+            let result_type = operations::check_bitwise_operation(
+                self.context,
+                &left_type,
+                &right_type,
+                bin_expr,
+            )?;
+            type_system::record_literal_types(self.context, &bin_expr.left, &result_type);
+            type_system::record_literal_types(self.context, &bin_expr.right, &result_type);
+            return Ok(result_type);
+        }
+
+        // Handle shift operations
+        if matches!(
+            bin_expr.operator,
+            BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight
+        ) {
+            let result_type = operations::check_shift_operation(
+                self.context,
+                &left_type,
+                &right_type,
+                bin_expr,
+            )?;
+            // The shift amount is a u32 unless it has an integer type of its own
+            type_system::record_literal_types(self.context, &bin_expr.left, &result_type);
+            type_system::record_literal_types(self.context, &bin_expr.right, &TypeId::u32());
+            return Ok(result_type);
+        }
+
         // Handle relational operations
         if matches!(
             bin_expr.operator,
@@ -95,13 +126,18 @@ impl<'a> ExpressionVisitor<'a> {
                 | BinaryOperator::Equal
                 | BinaryOperator::NotEqual
         ) {
-            return operations::check_relational_operation(
+            let result_type = operations::check_relational_operation(
                 self.context,
                 &left_type,
                 &right_type,
                 &bin_expr.operator,
                 &bin_expr.location,
-            );
+            )?;
+            // An unspecified literal is compared as a value of the other operand's type
+            type_system::record_literal_types(self.context, &bin_expr.left, &right_type);
+            type_system::record_literal_types(self.context, &bin_expr.right, &left_type);
+            self.warn_on_float_equality(bin_expr, &left_type, &right_type);
+            return Ok(result_type);
         }
 
         // Handle arithmetic operations
@@ -112,32 +148,72 @@ impl<'a> ExpressionVisitor<'a> {
                 | BinaryOperator::Multiply
                 | BinaryOperator::Divide
         ) {
-            if left_type == right_type {
-                return operations::check_same_type_arithmetic(
+            let result_type = if left_type == right_type {
+                operations::check_same_type_arithmetic(
                     self.context,
                     &left_type,
                     &bin_expr.operator,
                     &bin_expr.location,
-                );
-            }
-
-            // Use coordinator for mixed arithmetic with coercion
-            let coordinator = self.create_type_coordinator();
-            return coordinator.check_mixed_arithmetic_with_coercion(
-                &left_type,
-                &right_type,
-                bin_expr,
-            );
+                )?
+            } else {
+                operations::check_mixed_arithmetic_operation(
+                    self.context,
+                    &left_type,
+                    &right_type,
+                    bin_expr,
+                )?
+            };
+
+            operations::check_division_by_zero(self.context, bin_expr)?;
+            type_system::record_literal_types(self.context, &bin_expr.left, &result_type);
+            type_system::record_literal_types(self.context, &bin_expr.right, &result_type);
+            return Ok(result_type);
         }
 
         Err(SemanticAnalysisError::OperationTypeMismatch {
             operator: bin_expr.operator.to_string(),
-            left_type: left_type.clone(),
-            right_type: right_type.clone(),
+            left_type,
+            right_type,
             location: bin_expr.location,
         })
     }
 
+    /// Collects a warning if floating-point values are compared with `==` or `!=`
+    ///
+    /// Rounding errors make exact float comparisons unreliable, so they are reported
+    /// when float equality warnings are enabled.
+    ///
+    /// ### Arguments
+    /// * `bin_expr` - The comparison, which has already been type checked
+    /// * `left_type` - The type of the left operand
+    /// * `right_type` - The type of the right operand
+    fn warn_on_float_equality(
+        &mut self,
+        bin_expr: &BinaryExpr,
+        left_type: &TypeId,
+        right_type: &TypeId,
+    ) {
+        let is_equality = matches!(
+            bin_expr.operator,
+            BinaryOperator::Equal | BinaryOperator::NotEqual
+        );
+        if !is_equality
+            || !self.context.warns_on_float_equality()
+            || !(self.context.is_float_type(left_type) || self.context.is_float_type(right_type))
+        {
+            return;
+        }
+
+        self.context.add_warning(
+            ErrorCode::FloatEquality,
+            format!(
+                "Comparing floating-point values with '{}' is unreliable due to rounding errors",
+                bin_expr.operator
+            ),
+            bin_expr.location,
+        );
+    }
+
     /// Visit a unary expression
     pub fn visit_unary_expression(&mut self, unary_expr: &UnaryExpr) -> SemanticResult {
         let operand_type = self.visit_expression(&unary_expr.right)?;
@@ -147,25 +223,43 @@ impl<'a> ExpressionVisitor<'a> {
 
     /// Visit a function call expression
     pub fn visit_call_expression(&mut self, call_expr: &FunctionCallExpr) -> SemanticResult {
-        let function_type = if let Some(symbol) = self.context.lookup_symbol(&call_expr.name) {
+        let function_type = if let Some(overloads) =
+            self.context.lookup_function_overloads(&call_expr.name)
+        {
+            let arg_count = call_expr.arguments.len();
+            match overloads.iter().find(|overload| overload.arity == arg_count) {
+                Some(overload) => self.context.get_function_type(&overload.symbol.type_id).cloned(),
+                None => {
+                    let mut arities: Vec<usize> =
+                        overloads.iter().map(|overload| overload.arity).collect();
+                    arities.sort_unstable();
+                    return Err(SemanticAnalysisError::ArgumentCountMismatch {
+                        function_name: call_expr.name.clone(),
+                        expected: arities,
+                        actual: arg_count,
+                        location: call_expr.arguments_location,
+                    });
+                }
+            }
+        } else if let Some(symbol) = self.context.lookup_symbol(&call_expr.name) {
             match symbol.kind() {
-                SymbolKind::Function => {
-                    if self.context.is_function_type(&symbol.type_id) {
-                        self.context.get_function_type(&symbol.type_id).cloned()
-                    } else {
-                        return Err(SemanticAnalysisError::UndefinedFunction {
-                            name: call_expr.name.clone(),
-                            location: call_expr.location,
-                        });
-                    }
+                SymbolKind::Function if self.context.is_function_type(&symbol.type_id) => {
+                    self.context.get_function_type(&symbol.type_id).cloned()
                 }
                 SymbolKind::Variable => {
+                    // The declaration of the variable failed to parse, so its type is unknown
+                    if symbol.type_id == TypeId::unknown() {
+                        for argument in &call_expr.arguments {
+                            self.visit_expression(argument)?;
+                        }
+                        return Ok(TypeId::unknown());
+                    }
                     if self.context.is_function_type(&symbol.type_id) {
                         self.context.get_function_type(&symbol.type_id).cloned()
                     } else {
                         return Err(SemanticAnalysisError::VariableNotCallable {
                             variable_name: call_expr.name.clone(),
-                            variable_type: symbol.type_id.clone(),
+                            variable_type: symbol.type_id,
                             location: call_expr.location,
                         });
                     }
@@ -184,59 +278,75 @@ impl<'a> ExpressionVisitor<'a> {
             });
         };
 
+        self.context.mark_symbol_used(&call_expr.name);
+
         if let Some(func_type) = function_type {
+            let is_variadic = self.context.is_variadic_function(&call_expr.name);
+
             // Check argument count
-            if func_type.param_types.len() != call_expr.arguments.len() {
+            let argument_count_matches = if is_variadic {
+                call_expr.arguments.len() >= func_type.param_types.len()
+            } else {
+                call_expr.arguments.len() == func_type.param_types.len()
+            };
+            if !argument_count_matches {
                 return Err(SemanticAnalysisError::ArgumentCountMismatch {
                     function_name: call_expr.name.clone(),
-                    expected: func_type.param_types.len(),
+                    expected: vec![func_type.param_types.len()],
                     actual: call_expr.arguments.len(),
-                    location: call_expr.location,
+                    location: call_expr.arguments_location,
                 });
             }
 
-            // Check argument types
-            for (i, arg) in call_expr.arguments.iter().enumerate() {
-                let param_type = func_type.param_types[i].clone();
-                let arg_type = self.visit_expression(arg)?;
+            if func_type.return_type == TypeId::unknown() && call_expr.is_type_query() {
+                let arg_type = self.visit_expression(&call_expr.arguments[0])?;
+                return native_functions::check_type_query_call(call_expr, arg_type, self.context);
+            }
 
-                if param_type == TypeId::unknown() {
-                    continue;
-                }
+            if func_type.return_type == TypeId::unknown()
+                && call_expr.name == native_functions::POW_INTRINSIC
+            {
+                let arg_types = call_expr
+                    .arguments
+                    .iter()
+                    .map(|arg| self.visit_expression(arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let result_type =
+                    native_functions::check_pow_call(call_expr, &arg_types, self.context)?;
+                type_system::record_literal_types(self.context, &call_expr.arguments[0], &result_type);
+                return Ok(result_type);
+            }
 
-                // Use coordinator for assignment compatibility checking
-                let coordinator = self.create_type_coordinator();
-                if !coordinator.check_assignment_compatibility(&param_type, &arg_type) {
-                    // For unspecified literals, try range validation
-                    if arg_type == TypeId::unspecified_int()
-                        || arg_type == TypeId::unspecified_float()
-                    {
-                        if coordinator
-                            .validate_literal_range(arg, &param_type)
-                            .is_err()
-                        {
-                            return Err(SemanticAnalysisError::ArgumentTypeMismatch {
-                                function_name: call_expr.name.clone(),
-                                argument_position: i + 1,
-                                expected: param_type.clone(),
-                                actual: arg_type,
-                                location: arg.location(),
-                            });
-                        }
-                    } else {
-                        // For non-literal types, it's a direct type mismatch
-                        return Err(SemanticAnalysisError::ArgumentTypeMismatch {
-                            function_name: call_expr.name.clone(),
-                            argument_position: i + 1,
-                            expected: param_type.clone(),
-                            actual: arg_type,
-                            location: arg.location(),
-                        });
-                    }
+            if func_type.return_type == TypeId::unknown()
+                && native_functions::is_integer_intrinsic(&call_expr.name)
+            {
+                let arg_types = call_expr
+                    .arguments
+                    .iter()
+                    .map(|arg| self.visit_expression(arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let result_type = native_functions::check_integer_intrinsic_call(
+                    call_expr,
+                    &arg_types,
+                    self.context,
+                )?;
+                for arg in &call_expr.arguments {
+                    type_system::record_literal_types(self.context, arg, &result_type);
                 }
+                return Ok(result_type);
             }
 
-            Ok(func_type.return_type.clone())
+            self.check_arguments(
+                &call_expr.name,
+                &call_expr.arguments,
+                &func_type.param_types,
+            )?;
+
+            if is_variadic {
+                native_functions::validate_native_call(call_expr)?;
+            }
+
+            Ok(func_type.return_type)
         } else {
             Err(SemanticAnalysisError::UndefinedFunction {
                 name: call_expr.name.clone(),
@@ -245,10 +355,155 @@ impl<'a> ExpressionVisitor<'a> {
         }
     }
 
+    /// Checks the arguments of a call against the parameter types of the called function
+    ///
+    /// Additional arguments of variadic functions accept any type.
+    ///
+    /// ### Arguments
+    /// * `function_name` - The name of the called function, used in error messages
+    /// * `arguments` - The arguments of the call
+    /// * `param_types` - The parameter types of the called function
+    ///
+    /// ### Returns
+    /// Ok if every argument can be passed for its parameter, otherwise the first mismatch
+    fn check_arguments(
+        &mut self,
+        function_name: &str,
+        arguments: &[Expression],
+        param_types: &[TypeId],
+    ) -> Result<(), SemanticAnalysisError> {
+        for (i, arg) in arguments.iter().enumerate() {
+            let param_type = param_types.get(i).copied().unwrap_or_else(TypeId::unknown);
+            let arg_type = self.visit_expression(arg)?;
+
+            if param_type == TypeId::unknown() {
+                // Without a parameter type an unspecified integer is inferred to be an i64
+                type_system::check_unspecified_int_for_type(self.context, arg, &TypeId::i64())?;
+                continue;
+            }
+
+            // Negated literals are unspecified integers, so their sign has to be checked here
+            if let Expression::Unary(unary_expr) = arg
+                && unary_expr.operator == UnaryOperator::Negate
+                && let Expression::Literal(lit) = &*unary_expr.right
+                && let LiteralValue::UnspecifiedInteger(n) = &lit.value
+                && *n != 0
+                && self.context.is_unsigned_integer_type(&param_type)
+            {
+                return Err(SemanticAnalysisError::ArgumentTypeMismatch {
+                    function_name: function_name.to_string(),
+                    argument_position: i + 1,
+                    expected: param_type,
+                    actual: arg_type,
+                    note: Some(format!("the value -{} is negative", n)),
+                    location: arg.location(),
+                });
+            }
+
+            type_system::check_assignable(self.context, arg, &arg_type, &param_type, || {
+                SemanticAnalysisError::ArgumentTypeMismatch {
+                    function_name: function_name.to_string(),
+                    argument_position: i + 1,
+                    expected: param_type,
+                    actual: arg_type,
+                    note: None,
+                    location: arg.location(),
+                }
+            })?;
+            type_system::record_literal_types(self.context, arg, &param_type);
+        }
+
+        Ok(())
+    }
+
+    /// Visit a method call expression
+    ///
+    /// A method of a type is declared as the function `Type::method` that takes a value of
+    /// the type as its first parameter. The receiver is passed for that parameter, so the
+    /// arguments of the call are checked against the remaining parameters.
+    pub fn visit_method_call_expression(&mut self, method_call: &MethodCallExpr) -> SemanticResult {
+        let receiver_type = self.visit_expression(&method_call.receiver)?;
+
+        // The declaration of the receiver failed to parse, so its methods are unknown
+        if receiver_type == TypeId::unknown() {
+            for argument in &method_call.arguments {
+                self.visit_expression(argument)?;
+            }
+            return Ok(TypeId::unknown());
+        }
+
+        let function_name = qualified_name(
+            &self.context.get_type_name(&receiver_type),
+            &method_call.method,
+        );
+        let methods: Vec<(usize, FunctionType)> = self
+            .context
+            .lookup_function_overloads(&function_name)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|overload| {
+                self.context
+                    .get_function_type(&overload.symbol.type_id)
+                    .filter(|func_type| func_type.param_types.first() == Some(&receiver_type))
+                    .map(|func_type| (overload.arity, func_type.clone()))
+            })
+            .collect();
+        if methods.is_empty() {
+            return Err(SemanticAnalysisError::UndefinedMethod {
+                type_id: receiver_type,
+                method: method_call.method.clone(),
+                location: method_call.location,
+            });
+        }
+
+        let arity = method_call.arguments.len() + 1;
+        let Some((_, func_type)) = methods
+            .iter()
+            .find(|(method_arity, _)| *method_arity == arity)
+        else {
+            let mut arities: Vec<usize> = methods
+                .iter()
+                .map(|(method_arity, _)| method_arity - 1)
+                .collect();
+            arities.sort_unstable();
+            return Err(SemanticAnalysisError::ArgumentCountMismatch {
+                function_name,
+                expected: arities,
+                actual: method_call.arguments.len(),
+                location: method_call.arguments_location,
+            });
+        };
+
+        self.context.mark_symbol_used(&function_name);
+        self.check_arguments(
+            &function_name,
+            &method_call.arguments,
+            &func_type.param_types[1..],
+        )?;
+        method_call.function_name.set(function_name).ok();
+
+        Ok(func_type.return_type)
+    }
+
     /// Visit a variable expression
     pub fn visit_variable_expression(&mut self, var_expr: &VariableExpr) -> SemanticResult {
         if let Some(var_info) = self.resolve_value(&var_expr.name) {
-            Ok(var_info.type_id.clone())
+            if var_info.kind() == SymbolKind::Function
+                && native_functions::is_call_only_intrinsic(&var_expr.name)
+            {
+                return Err(SemanticAnalysisError::InvalidExpression {
+                    message: format!(
+                        "Function '{}' is an intrinsic and can only be called directly",
+                        var_expr.name
+                    ),
+                    location: var_expr.location,
+                });
+            }
+            let type_id = var_info.type_id;
+            self.context.mark_symbol_used(&var_expr.name);
+            Ok(type_id)
+        } else if let Some(variant_type) = self.resolve_enum_variant(var_expr) {
+            variant_type
         } else {
             Err(SemanticAnalysisError::UndefinedVariable {
                 name: var_expr.name.clone(),
@@ -259,7 +514,7 @@ impl<'a> ExpressionVisitor<'a> {
 
     /// Visit a literal expression
     pub fn visit_literal_expression(&mut self, literal_expr: &LiteralExpr) -> SemanticResult {
-        Ok(literal_expr.expr_type.clone())
+        Ok(literal_expr.expr_type.get())
     }
 
     /// Visit a conditional expression
@@ -282,13 +537,21 @@ impl<'a> ExpressionVisitor<'a> {
         } else if else_type == TypeId::unknown() || then_type == else_type {
             Ok(then_type)
         } else {
+            // Point at the value of the else branch, the then branch determined the expected type
+            let location = match &*cond_expr.else_branch {
+                Expression::Block(block) => block
+                    .return_expr
+                    .as_ref()
+                    .map_or(block.location, |return_expr| return_expr.location()),
+                else_branch => else_branch.location(),
+            };
             Err(SemanticAnalysisError::TypeMismatch {
                 expected: then_type,
                 actual: else_type,
                 context: Some(
                     "conditional expression branches must have the same type".to_string(),
                 ),
-                location: cond_expr.location,
+                location,
             })
         }
     }
@@ -296,13 +559,21 @@ impl<'a> ExpressionVisitor<'a> {
     /// Visit a block expression
     pub fn visit_block_expression(&mut self, block_expr: &BlockExpr) -> SemanticResult {
         self.context.begin_scope();
+        // End the scope on errors as well, so declarations of the block don't leak out of it
+        let block_type = self.analyze_block_contents(block_expr);
+        self.context.end_scope();
 
+        block_type
+    }
+
+    /// Analyze the statements and the trailing expression of a block in the current scope
+    fn analyze_block_contents(&mut self, block_expr: &BlockExpr) -> SemanticResult {
         // Process all statements in the block
         for stmt in &block_expr.statements {
             // Create a statement visitor with the current return type context
             let mut stmt_visitor = super::statement_visitor::StatementVisitor::with_return_type(
                 self.context,
-                self.current_return_type.clone(),
+                self.current_return_type,
             );
             match stmt {
                 Statement::Let(let_stmt) => {
@@ -312,11 +583,14 @@ impl<'a> ExpressionVisitor<'a> {
                     stmt_visitor.visit_assignment_statement(assign_stmt)?;
                 }
                 Statement::Expression(expr) => {
-                    self.visit_expression(expr)?;
+                    stmt_visitor.visit_expression_statement(expr)?;
                 }
                 Statement::If(if_stmt) => {
                     stmt_visitor.visit_if_statement(if_stmt)?;
                 }
+                Statement::For(for_stmt) => {
+                    stmt_visitor.visit_for_statement(for_stmt)?;
+                }
                 Statement::Return(return_stmt) => {
                     stmt_visitor.visit_return_statement(return_stmt)?;
                 }
@@ -326,18 +600,20 @@ impl<'a> ExpressionVisitor<'a> {
                 Statement::TypeDefinition(type_def) => {
                     stmt_visitor.visit_type_definition_statement(type_def)?;
                 }
+                Statement::EnumDefinition(enum_def) => {
+                    stmt_visitor.visit_enum_definition_statement(enum_def)?;
+                }
+                Statement::Impl(impl_stmt) => {
+                    stmt_visitor.visit_impl_statement(impl_stmt)?;
+                }
             }
         }
 
-        let block_type = if let Some(return_expr) = &block_expr.return_expr {
-            self.visit_expression(return_expr)?
+        if let Some(return_expr) = &block_expr.return_expr {
+            self.visit_expression(return_expr)
         } else {
-            TypeId::unit()
-        };
-
-        self.context.end_scope();
-
-        Ok(block_type)
+            Ok(TypeId::unit())
+        }
     }
 
     /// Visit a function type expression
@@ -351,7 +627,7 @@ impl<'a> ExpressionVisitor<'a> {
                 return Err(SemanticAnalysisError::InvalidFieldType {
                     struct_name: "function type".to_string(),
                     field_name: "parameter".to_string(),
-                    type_id: param_type.clone(),
+                    type_id: *param_type,
                     location: func_type_expr.location,
                 });
             }
@@ -366,16 +642,103 @@ impl<'a> ExpressionVisitor<'a> {
             return Err(SemanticAnalysisError::InvalidFieldType {
                 struct_name: "function type".to_string(),
                 field_name: "return type".to_string(),
-                type_id: func_type_expr.return_type.clone(),
+                type_id: func_type_expr.return_type,
                 location: func_type_expr.location,
             });
         }
 
-        Ok(func_type_expr.expr_type.clone())
+        Ok(func_type_expr.expr_type)
+    }
+
+    /// Visit a closure expression
+    ///
+    /// The closure body is analyzed in a regular (non-function) scope, so names of the
+    /// enclosing scopes stay visible and are captured by value at runtime.
+    pub fn visit_closure_expression(&mut self, closure_expr: &ClosureExpr) -> SemanticResult {
+        let param_types = closure_expr
+            .parameters
+            .iter()
+            .map(|param| param.param_type)
+            .collect();
+        let function_type_id = self
+            .context
+            .register_function_type(param_types, closure_expr.return_type);
+
+        let previous_return_type = self.current_return_type;
+        self.current_return_type = Some(closure_expr.return_type);
+
+        self.context.begin_scope();
+        let mut result = Ok(function_type_id);
+        for param in &closure_expr.parameters {
+            if self
+                .context
+                .define_symbol(
+                    param.name.clone(),
+                    SymbolKind::Variable,
+                    param.param_type,
+                    true,
+                    Some(param.location),
+                )
+                .is_err()
+            {
+                result = Err(SemanticAnalysisError::SymbolRedefinition {
+                    name: param.name.clone(),
+                    kind: "parameter".to_string(),
+                    location: param.location,
+                    previous_location: self.context.lookup_definition_location(&param.name, None),
+                });
+                break;
+            }
+        }
+        if result.is_ok() {
+            result = self
+                .analyze_block_contents(&closure_expr.body)
+                .and_then(|body_type| {
+                    type_system::check_body_type(
+                        self.context,
+                        &closure_expr.body,
+                        &body_type,
+                        &closure_expr.return_type,
+                    )
+                })
+                .and(Ok(function_type_id));
+        }
+        self.context.end_scope();
+
+        self.current_return_type = previous_return_type;
+        result
     }
 
     // Helper methods
 
+    /// Resolve a variant access of an enum, like `Color::Red`
+    ///
+    /// ### Arguments
+    /// * `var_expr` - The variable expression naming the variant
+    ///
+    /// ### Returns
+    /// None if the path doesn't start with an enum, otherwise the enum type or an error
+    /// if the enum has no such variant
+    fn resolve_enum_variant(&self, var_expr: &VariableExpr) -> Option<SemanticResult> {
+        let (enum_name, variant) = var_expr.name.rsplit_once(PATH_SEPARATOR)?;
+        let type_id = self
+            .context
+            .lookup_symbol(enum_name)
+            .filter(|symbol| symbol.kind() == SymbolKind::Type)?
+            .type_id;
+        let enum_type = self.context.get_enum_type(&type_id)?;
+
+        if enum_type.discriminant(variant).is_some() {
+            Some(Ok(type_id))
+        } else {
+            Some(Err(SemanticAnalysisError::UndefinedEnumVariant {
+                enum_name: enum_name.to_string(),
+                variant: variant.to_string(),
+                location: var_expr.location,
+            }))
+        }
+    }
+
     /// Resolve a symbol that can be used as a value (variables and functions)
     fn resolve_value(&self, name: &str) -> Option<&slang_shared::Symbol> {
         self.context
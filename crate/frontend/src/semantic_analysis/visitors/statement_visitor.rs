@@ -1,7 +1,8 @@
+use slang_error::ErrorCode;
 use slang_ir::Location;
 use slang_ir::ast::*;
 use slang_shared::{CompilationContext, SymbolKind};
-use slang_types::{TYPE_NAME_U32, TYPE_NAME_U64, TypeId};
+use slang_types::TypeId;
 
 use super::super::{error::SemanticAnalysisError, traits::SemanticResult, type_system};
 use super::expression_visitor::ExpressionVisitor;
@@ -62,20 +63,20 @@ impl<'a> StatementVisitor<'a> {
     ) -> SemanticResult {
         let mut param_types = Vec::new();
         for param in &fn_decl.parameters {
-            param_types.push(param.param_type.clone());
+            param_types.push(param.param_type);
         }
 
         let function_type_id = self
             .context
-            .register_function_type(param_types.clone(), fn_decl.return_type.clone());
+            .register_function_type(param_types.clone(), fn_decl.return_type);
 
         if self
             .context
-            .define_symbol(
+            .define_function(
                 fn_decl.name.clone(),
-                SymbolKind::Function,
                 function_type_id,
-                false,
+                fn_decl.parameters.len(),
+                fn_decl.location,
             )
             .is_err()
         {
@@ -83,51 +84,63 @@ impl<'a> StatementVisitor<'a> {
                 name: fn_decl.name.clone(),
                 kind: "function".to_string(),
                 location: fn_decl.location,
+                previous_location: self
+                    .context
+                    .lookup_definition_location(&fn_decl.name, Some(fn_decl.parameters.len())),
             });
         }
 
-        let previous_return_type = self.current_return_type.clone();
-        self.current_return_type = Some(fn_decl.return_type.clone());
+        let previous_return_type = self.current_return_type;
+        self.current_return_type = Some(fn_decl.return_type);
 
-        self.context.begin_scope();
+        self.context.begin_function_scope();
         for param in &fn_decl.parameters {
             if self
                 .context
                 .define_symbol(
                     param.name.clone(),
                     SymbolKind::Variable,
-                    param.param_type.clone(),
+                    param.param_type,
                     true,
+                    Some(param.location),
                 )
                 .is_err()
             {
+                let previous_location = self.context.lookup_definition_location(&param.name, None);
+                self.current_return_type = previous_return_type;
+                self.context.end_scope();
                 return Err(SemanticAnalysisError::SymbolRedefinition {
                     name: param.name.clone(),
                     kind: "parameter".to_string(),
-                    location: fn_decl.location,
+                    location: param.location,
+                    previous_location,
                 });
             }
         }
 
         // For now, we'll need to handle block expression analysis differently
         // This will be resolved when we integrate with expression visitor
-        let result = self.analyze_function_body(&fn_decl.body);
+        let result = self
+            .analyze_function_body(&fn_decl.body)
+            .and_then(|body_type| {
+                type_system::check_body_type(
+                    self.context,
+                    &fn_decl.body,
+                    &body_type,
+                    &fn_decl.return_type,
+                )
+            });
 
         self.current_return_type = previous_return_type;
         self.context.end_scope();
 
-        result.and(Ok(fn_decl.return_type.clone()))
+        result.and(Ok(fn_decl.return_type))
     }
 
     /// Visit a return statement
     pub fn visit_return_statement(&mut self, return_stmt: &ReturnStatement) -> SemanticResult {
-        let error_location = match &return_stmt.value {
-            Some(expr) => expr.location(),
-            None => return_stmt.location,
-        };
-
         if let Some(expected_type) = &self.current_return_type {
-            let expected_type = expected_type.clone();
+            let expected_type = *expected_type;
             if let Some(expr) = &return_stmt.value {
                 return self.check_return_expr_type_internal(
                     expr,
@@ -136,16 +149,17 @@ impl<'a> StatementVisitor<'a> {
                 );
             } else if expected_type != TypeId::unknown() && expected_type != TypeId::unit() {
                 return Err(SemanticAnalysisError::MissingReturnValue {
-                    expected: expected_type.clone(),
-                    location: error_location,
+                    expected: expected_type,
+                    location: return_stmt.location,
                 });
             }
 
             // Empty return is treated as returning unit
             Ok(TypeId::unit())
         } else {
+            // Point at the `return` keyword, the value itself is not the problem
             Err(SemanticAnalysisError::ReturnOutsideFunction {
-                location: error_location,
+                location: return_stmt.location,
             })
         }
     }
@@ -153,24 +167,28 @@ impl<'a> StatementVisitor<'a> {
     /// Visit a let statement
     pub fn visit_let_statement(&mut self, let_stmt: &LetStatement) -> SemanticResult {
         // Check for negative values assigned to unsigned types
-        if let Expression::Unary(unary_expr) = &let_stmt.value {
-            if unary_expr.operator == UnaryOperator::Negate {
-                if let Expression::Literal(lit) = &*unary_expr.right {
-                    if let LiteralValue::UnspecifiedInteger(n) = &lit.value {
-                        if self.context.get_type_name(&let_stmt.expr_type) == TYPE_NAME_U32
-                            || self.context.get_type_name(&let_stmt.expr_type) == TYPE_NAME_U64
-                        {
-                            let negative_value = -n;
-                            return Err(SemanticAnalysisError::ValueOutOfRange {
-                                value: negative_value.to_string(),
-                                target_type: let_stmt.expr_type.clone(),
-                                is_float: false,
-                                location: let_stmt.location,
-                            });
-                        }
-                    }
-                }
-            }
+        if let Expression::Unary(unary_expr) = &let_stmt.value
+            && unary_expr.operator == UnaryOperator::Negate
+            && let Expression::Literal(lit) = &*unary_expr.right
+            && let LiteralValue::UnspecifiedInteger(n) = &lit.value
+            && type_system::is_unsigned_type(self.context, &let_stmt.expr_type)
+        {
+            let negative_value = -n;
+            return Err(SemanticAnalysisError::ValueOutOfRange {
+                value: negative_value.to_string(),
+                target_type: let_stmt.expr_type,
+                is_float: false,
+                location: let_stmt.location,
+            });
+        }
+
+        // `let _ = expr;` only evaluates the initializer, so there is no symbol to check
+        if let_stmt.is_wildcard() {
+            let expr_type = self.visit_expression(&let_stmt.value)?;
+            let final_type = self.determine_let_statement_type(let_stmt, expr_type)?;
+            let final_type = type_system::finalize_inferred_type(final_type);
+            type_system::record_literal_types(self.context, &let_stmt.value, &final_type);
+            return Ok(final_type);
         }
 
         // Check for symbol conflicts
@@ -180,12 +198,14 @@ impl<'a> StatementVisitor<'a> {
                     name: let_stmt.name.clone(),
                     kind: "variable (conflicts with type)".to_string(),
                     location: let_stmt.location,
+                    previous_location: symbol.definition_location,
                 });
             } else if symbol.kind() == SymbolKind::Function {
                 return Err(SemanticAnalysisError::SymbolRedefinition {
                     name: let_stmt.name.clone(),
                     kind: "variable (conflicts with function)".to_string(),
                     location: let_stmt.location,
+                    previous_location: symbol.definition_location,
                 });
             }
         }
@@ -194,20 +214,24 @@ impl<'a> StatementVisitor<'a> {
         let expr_type = self.visit_expression(&let_stmt.value)?;
         let final_type = self.determine_let_statement_type(let_stmt, expr_type)?;
         let final_type = type_system::finalize_inferred_type(final_type);
+        type_system::record_literal_types(self.context, &let_stmt.value, &final_type);
 
         if self
             .context
-            .define_symbol(
+            .define_variable(
                 let_stmt.name.clone(),
-                SymbolKind::Variable,
-                final_type.clone(),
+                final_type,
                 let_stmt.is_mutable,
+                let_stmt.location,
             )
             .is_err()
         {
             return Err(SemanticAnalysisError::VariableRedefinition {
                 name: let_stmt.name.clone(),
                 location: let_stmt.location,
+                previous_location: self
+                    .context
+                    .lookup_definition_location(&let_stmt.name, None),
             });
         }
 
@@ -222,7 +246,7 @@ impl<'a> StatementVisitor<'a> {
         // First check if variable exists and get its type and mutability
         let (var_type_id, is_mutable) =
             if let Some(var_info) = self.resolve_variable(&assign_stmt.name) {
-                (var_info.type_id.clone(), var_info.is_mutable())
+                (var_info.type_id, var_info.is_mutable())
             } else {
                 return Err(SemanticAnalysisError::UndefinedVariable {
                     name: assign_stmt.name.clone(),
@@ -241,19 +265,25 @@ impl<'a> StatementVisitor<'a> {
         // TODO: This will need to be updated to use expression visitor
         let expr_type = self.visit_expression(&assign_stmt.value)?;
 
-        if var_type_id == expr_type
-            || expr_type == TypeId::unspecified_int()
-            || expr_type == TypeId::unspecified_float()
-        {
-            Ok(var_type_id)
-        } else {
-            Err(SemanticAnalysisError::TypeMismatch {
+        // The declaration of the variable failed to parse, so any value is accepted
+        if var_type_id == TypeId::unknown() {
+            return Ok(expr_type);
+        }
+
+        let result = type_system::check_assignable(
+            self.context,
+            &assign_stmt.value,
+            &expr_type,
+            &var_type_id,
+            || SemanticAnalysisError::TypeMismatch {
                 expected: var_type_id,
                 actual: expr_type,
                 context: Some(format!("assignment to variable '{}'", assign_stmt.name)),
                 location: assign_stmt.location,
-            })
-        }
+            },
+        )?;
+        type_system::record_literal_types(self.context, &assign_stmt.value, &var_type_id);
+        Ok(result)
     }
 
     /// Visit a type definition statement
@@ -261,11 +291,22 @@ impl<'a> StatementVisitor<'a> {
         &mut self,
         type_def: &TypeDefinitionStmt,
     ) -> SemanticResult {
-        if self.context.lookup_symbol(&type_def.name).is_some() {
+        // The parser declares structs up front so that their fields can refer to them
+        let declared_type = self
+            .context
+            .lookup_symbol(&type_def.name)
+            .filter(|symbol| symbol.kind() == SymbolKind::Type)
+            .map(|symbol| symbol.type_id)
+            .filter(|type_id| self.context.is_declared_struct_type(type_id));
+
+        if declared_type.is_none() && self.context.lookup_symbol(&type_def.name).is_some() {
             return Err(SemanticAnalysisError::SymbolRedefinition {
                 name: type_def.name.clone(),
                 kind: "type".to_string(),
                 location: type_def.location,
+                previous_location: self
+                    .context
+                    .lookup_definition_location(&type_def.name, None),
             });
         }
 
@@ -278,29 +319,120 @@ impl<'a> StatementVisitor<'a> {
                 return Err(SemanticAnalysisError::InvalidFieldType {
                     struct_name: type_def.name.clone(),
                     field_name: name.clone(),
-                    type_id: type_id.clone(),
+                    type_id: *type_id,
                     location: type_def.location,
                 });
             }
-            field_types_for_registration.push((name.clone(), type_id.clone()));
+            field_types_for_registration.push((name.clone(), *type_id));
         }
 
-        match self
+        let registration = match declared_type {
+            Some(type_id) => self
+                .context
+                .define_struct_fields(&type_id, field_types_for_registration)
+                .map(|_| type_id),
+            None => self
+                .context
+                .register_struct_type(type_def.name.clone(), field_types_for_registration),
+        };
+        let type_id = registration.map_err(|_| SemanticAnalysisError::SymbolRedefinition {
+            name: type_def.name.clone(),
+            kind: "type".to_string(),
+            location: type_def.location,
+            previous_location: self
+                .context
+                .lookup_definition_location(&type_def.name, None),
+        })?;
+
+        if let Some(cycle) = type_system::find_struct_cycle(self.context, &type_id) {
+            return Err(SemanticAnalysisError::RecursiveStructType {
+                struct_name: type_def.name.clone(),
+                cycle: cycle
+                    .iter()
+                    .map(|type_id| self.context.get_type_name(type_id))
+                    .collect(),
+                location: type_def.location,
+            });
+        }
+
+        Ok(type_id)
+    }
+
+    /// Visit an enum definition statement
+    ///
+    /// The parser registers enums as soon as they are declared, so that type annotations
+    /// can refer to them. Enums that weren't registered yet are registered here.
+    pub fn visit_enum_definition_statement(
+        &mut self,
+        enum_def: &EnumDefinitionStmt,
+    ) -> SemanticResult {
+        let registered_type = self
             .context
-            .register_struct_type(type_def.name.clone(), field_types_for_registration)
-        {
-            Ok(type_id) => Ok(type_id),
-            Err(_) => Err(SemanticAnalysisError::SymbolRedefinition {
-                name: type_def.name.clone(),
+            .lookup_symbol(&enum_def.name)
+            .map(|symbol| symbol.type_id)
+            .filter(|type_id| {
+                self.context
+                    .get_enum_type(type_id)
+                    .is_some_and(|enum_type| enum_type.variants == enum_def.variants)
+            });
+        if let Some(type_id) = registered_type {
+            return Ok(type_id);
+        }
+
+        self.context
+            .register_enum_type(enum_def.name.clone(), enum_def.variants.clone())
+            .map_err(|_| SemanticAnalysisError::SymbolRedefinition {
+                name: enum_def.name.clone(),
                 kind: "type".to_string(),
-                location: type_def.location,
-            }),
+                location: enum_def.location,
+                previous_location: self
+                    .context
+                    .lookup_definition_location(&enum_def.name, None),
+            })
+    }
+
+    /// Visit the method declarations of a type
+    ///
+    /// Each method is analyzed like a function declared under its qualified name. All methods
+    /// are declared even if one of them has an error, so that calls to the others can still
+    /// be checked.
+    ///
+    /// ### Returns
+    /// The unit type, or the error of the first method that failed the analysis
+    pub fn visit_impl_statement(&mut self, impl_stmt: &ImplStmt) -> SemanticResult {
+        let mut result = Ok(TypeId::unit());
+        for method in &impl_stmt.methods {
+            let method_result = self.visit_function_declaration(method);
+            if result.is_ok()
+                && let Err(error) = method_result
+            {
+                result = Err(error);
+            }
         }
+        result
     }
 
     /// Visit an expression statement
+    ///
+    /// A value that is computed only to be thrown away usually points to a mistake, so a
+    /// warning is reported when the statement produces a value. Function and method calls
+    /// are exempt, since they are commonly made for their side effects.
     pub fn visit_expression_statement(&mut self, expr: &Expression) -> SemanticResult {
-        self.visit_expression(expr)
+        let expr_type = self.visit_expression(expr)?;
+        if expr_type != TypeId::unit()
+            && expr_type != TypeId::unknown()
+            && !matches!(expr, Expression::Call(_) | Expression::MethodCall(_))
+        {
+            self.context.add_warning(
+                ErrorCode::UnusedResult,
+                format!(
+                    "Value of type '{}' is discarded, bind it with 'let _ = ...' if this is intended",
+                    self.context.format_type(&expr_type)
+                ),
+                expr.location(),
+            );
+        }
+        Ok(expr_type)
     }
 
     /// Visit an if statement
@@ -324,6 +456,77 @@ impl<'a> StatementVisitor<'a> {
         Ok(TypeId::unit())
     }
 
+    /// Visit a for statement
+    ///
+    /// The loop variable is immutable and only visible inside the loop body. Its type is
+    /// the integer type of the range bounds.
+    pub fn visit_for_statement(&mut self, for_stmt: &ForStatement) -> SemanticResult {
+        let start_type = self.visit_expression(&for_stmt.start)?;
+        let end_type = self.visit_expression(&for_stmt.end)?;
+
+        for (bound, bound_type) in [(&for_stmt.start, start_type), (&for_stmt.end, end_type)] {
+            if bound_type != TypeId::unspecified_int() && !self.context.is_integer_type(&bound_type)
+            {
+                return Err(SemanticAnalysisError::InvalidExpression {
+                    message: format!(
+                        "Range bounds must be integers, but found '{}'",
+                        self.context.get_type_name(&bound_type)
+                    ),
+                    location: bound.location(),
+                });
+            }
+        }
+
+        // An unspecified literal bound takes the type of the other bound
+        let variable_type = if start_type == end_type {
+            type_system::finalize_inferred_type(start_type)
+        } else if start_type == TypeId::unspecified_int() {
+            type_system::check_literal_coercion(
+                self.context,
+                &for_stmt.start,
+                &start_type,
+                &end_type,
+            )?
+        } else if end_type == TypeId::unspecified_int() {
+            type_system::check_literal_coercion(
+                self.context,
+                &for_stmt.end,
+                &end_type,
+                &start_type,
+            )?
+        } else {
+            return Err(SemanticAnalysisError::TypeMismatch {
+                expected: start_type,
+                actual: end_type,
+                context: None,
+                location: for_stmt.end.location(),
+            });
+        };
+        type_system::record_literal_types(self.context, &for_stmt.start, &variable_type);
+        type_system::record_literal_types(self.context, &for_stmt.end, &variable_type);
+        for_stmt.variable_type.set(variable_type);
+
+        self.context.begin_scope();
+        let result = self
+            .context
+            .define_variable(
+                for_stmt.variable.clone(),
+                variable_type,
+                false,
+                for_stmt.location,
+            )
+            .map_err(|_| SemanticAnalysisError::VariableRedefinition {
+                name: for_stmt.variable.clone(),
+                location: for_stmt.location,
+                previous_location: None,
+            })
+            .and_then(|_| self.visit_block_expression(&for_stmt.body));
+        self.context.end_scope();
+        result?;
+
+        Ok(TypeId::unit())
+    }
+
     // Helper methods that will be replaced when integrating with expression visitor
 
     fn resolve_variable(&self, name: &str) -> Option<&slang_shared::Symbol> {
@@ -340,37 +543,16 @@ impl<'a> StatementVisitor<'a> {
     ) -> SemanticResult {
         let actual_type = self.visit_expression(expr)?;
 
-        if actual_type == *expected_type {
-            return Ok(actual_type);
-        }
-
-        // Handle coercion of unspecified int to specific integer types
-        if actual_type == TypeId::unspecified_int() {
-            if type_system::is_integer_type(self.context, expected_type) {
-                return type_system::check_unspecified_int_for_type(
-                    self.context,
-                    expr,
-                    expected_type,
-                );
-            }
-        }
-
-        // Handle coercion of unspecified float to specific float types
-        if actual_type == TypeId::unspecified_float() {
-            if type_system::is_float_type(self.context, expected_type) {
-                return type_system::check_unspecified_float_for_type(
-                    self.context,
-                    expr,
-                    expected_type,
-                );
-            }
-        }
-
-        Err(SemanticAnalysisError::ReturnTypeMismatch {
-            expected: expected_type.clone(),
-            actual: actual_type,
-            location: *location,
-        })
+        let result =
+            type_system::check_assignable(self.context, expr, &actual_type, expected_type, || {
+                SemanticAnalysisError::ReturnTypeMismatch {
+                    expected: *expected_type,
+                    actual: actual_type,
+                    location: *location,
+                }
+            })?;
+        type_system::record_literal_types(self.context, expr, expected_type);
+        Ok(result)
     }
 
     fn determine_let_statement_type(
@@ -386,14 +568,14 @@ impl<'a> StatementVisitor<'a> {
     fn visit_expression(&mut self, expr: &Expression) -> SemanticResult {
         // Create a new expression visitor with the current return type context
         let mut expr_visitor =
-            ExpressionVisitor::with_return_type(self.context, self.current_return_type.clone());
+            ExpressionVisitor::with_return_type(self.context, self.current_return_type);
         expr_visitor.visit_expression(expr)
     }
 
     fn visit_block_expression(&mut self, block: &BlockExpr) -> SemanticResult {
         // Create a new expression visitor with the current return type context
         let mut expr_visitor =
-            ExpressionVisitor::with_return_type(self.context, self.current_return_type.clone());
+            ExpressionVisitor::with_return_type(self.context, self.current_return_type);
         expr_visitor.visit_block_expression(block)
     }
 
@@ -0,0 +1,433 @@
+use slang_ir::Visitor;
+use slang_ir::ast::{
+    AssignmentStatement, BinaryExpr, BinaryOperator, BlockExpr, ClosureExpr, ConditionalExpr,
+    EnumDefinitionStmt, Expression, ForStatement, FunctionCallExpr, FunctionDeclarationStmt,
+    FunctionTypeExpr, IfStatement, ImplStmt, LetStatement, LiteralExpr, LiteralValue,
+    MethodCallExpr, Parameter, ReturnStatement, Statement, TypeDefinitionStmt, UnaryExpr,
+    VariableExpr,
+};
+use slang_shared::{CompilationContext, PATH_SEPARATOR};
+use slang_types::{
+    TYPE_NAME_F32, TYPE_NAME_F64, TYPE_NAME_I32, TYPE_NAME_I64, TYPE_NAME_I128, TYPE_NAME_U32,
+    TYPE_NAME_U64, TYPE_NAME_U128, TypeId,
+};
+
+/// Renders statements back into Slang source code
+///
+/// Statements are put on lines of their own and blocks are indented by four spaces.
+/// Parentheses are only emitted where the precedence of the operators requires them.
+///
+/// ### Arguments
+/// * `statements` - The statements to render
+/// * `context` - The compilation context the statements were parsed with, used to name types
+///
+/// ### Returns
+/// The source code of the statements
+///
+/// ### Example
+/// ```
+/// use slang_frontend::{parser, source_printer::to_source, tokenize};
+/// use slang_shared::CompilationContext;
+///
+/// let mut context = CompilationContext::new();
+/// let lexed = tokenize("fn  square(x:i32)->i32{return x*x;} let y=(1+2)*3;").unwrap();
+/// let statements = parser::parse(&lexed.tokens, &lexed.line_info, &mut context).unwrap();
+///
+/// assert_eq!(
+///     to_source(&statements, &context),
+///     "fn square(x: i32) -> i32 {\n    return x * x;\n}\nlet y = (1 + 2) * 3;\n"
+/// );
+/// ```
+pub fn to_source(statements: &[Statement], context: &CompilationContext) -> String {
+    let mut printer = SourcePrinter {
+        context,
+        output: String::new(),
+        indent_level: 0,
+    };
+    for stmt in statements {
+        printer.write_statement(stmt);
+    }
+    printer.output
+}
+
+/// A visitor that renders the AST into source code
+struct SourcePrinter<'a> {
+    /// The compilation context used to name types
+    context: &'a CompilationContext,
+    /// The rendered source code
+    output: String,
+    /// Current indentation level of the rendered lines
+    indent_level: usize,
+}
+
+impl SourcePrinter<'_> {
+    /// Renders a statement on a line of its own
+    ///
+    /// ### Arguments
+    /// * `stmt` - The statement to render
+    fn write_statement(&mut self, stmt: &Statement) {
+        self.output.push_str(&"    ".repeat(self.indent_level));
+        self.visit_statement(stmt);
+        self.output.push('\n');
+    }
+
+    /// Renders a type annotation
+    ///
+    /// ### Arguments
+    /// * `type_id` - The annotated type
+    fn write_type(&mut self, type_id: &TypeId) {
+        let name = self.context.format_type(type_id);
+        self.output.push_str(&name);
+    }
+
+    /// Renders a return type annotation, which is omitted for functions returning unit
+    ///
+    /// ### Arguments
+    /// * `return_type` - The return type of the function
+    fn write_return_type(&mut self, return_type: &TypeId) {
+        if *return_type != TypeId::unit() {
+            self.output.push_str(" -> ");
+            self.write_type(return_type);
+        }
+    }
+
+    /// Renders a comma separated list of parameters
+    ///
+    /// ### Arguments
+    /// * `parameters` - The parameters to render
+    fn write_parameters(&mut self, parameters: &[Parameter]) {
+        for (i, param) in parameters.iter().enumerate() {
+            if i > 0 {
+                self.output.push_str(", ");
+            }
+            self.output.push_str(&param.name);
+            self.output.push_str(": ");
+            self.write_type(&param.param_type);
+        }
+    }
+
+    /// Renders a method of an impl block under its unqualified name
+    ///
+    /// A leading `self` parameter of the implemented type is rendered without its type.
+    ///
+    /// ### Arguments
+    /// * `type_name` - The name of the type the method belongs to
+    /// * `method` - The method to render
+    fn write_method(&mut self, type_name: &str, method: &FunctionDeclarationStmt) {
+        let name = method
+            .name
+            .rsplit(PATH_SEPARATOR)
+            .next()
+            .unwrap_or(&method.name);
+        self.output.push_str(&format!("fn {}(", name));
+
+        let mut parameters = method.parameters.as_slice();
+        if let Some((first, rest)) = parameters.split_first()
+            && first.name == Parameter::SELF
+            && self.context.format_type(&first.param_type) == type_name
+        {
+            self.output.push_str(Parameter::SELF);
+            if !rest.is_empty() {
+                self.output.push_str(", ");
+            }
+            parameters = rest;
+        }
+        self.write_parameters(parameters);
+
+        self.output.push(')');
+        self.write_return_type(&method.return_type);
+        self.output.push(' ');
+        self.visit_block_expression(&method.body);
+    }
+
+    /// Renders the parenthesized, comma separated arguments of a call
+    ///
+    /// ### Arguments
+    /// * `arguments` - The arguments to render
+    fn write_arguments(&mut self, arguments: &[Expression]) {
+        self.output.push('(');
+        for (i, argument) in arguments.iter().enumerate() {
+            if i > 0 {
+                self.output.push_str(", ");
+            }
+            self.visit_expression(argument);
+        }
+        self.output.push(')');
+    }
+
+    /// Renders the operand of an operator, in parentheses if it would bind weaker
+    ///
+    /// ### Arguments
+    /// * `operand` - The operand to render
+    /// * `min_precedence` - The precedence the operand needs to be written without parentheses
+    fn write_operand(&mut self, operand: &Expression, min_precedence: u8) {
+        if precedence(operand) < min_precedence {
+            self.output.push('(');
+            self.visit_expression(operand);
+            self.output.push(')');
+        } else {
+            self.visit_expression(operand);
+        }
+    }
+}
+
+/// The precedence of expressions that aren't operators
+///
+/// They bind strongest, since they can be the operand of any operator without parentheses.
+const PRIMARY_PRECEDENCE: u8 = 12;
+
+/// The precedence of an expression, higher values bind stronger
+fn precedence(expr: &Expression) -> u8 {
+    match expr {
+        Expression::Binary(bin_expr) => operator_precedence(&bin_expr.operator),
+        Expression::Unary(_) => PRIMARY_PRECEDENCE - 1,
+        _ => PRIMARY_PRECEDENCE,
+    }
+}
+
+/// The precedence of a binary operator, following the grammar of the parser
+fn operator_precedence(operator: &BinaryOperator) -> u8 {
+    match operator {
+        BinaryOperator::Or => 1,
+        BinaryOperator::And => 2,
+        BinaryOperator::Equal | BinaryOperator::NotEqual => 3,
+        BinaryOperator::BitOr => 4,
+        BinaryOperator::BitXor => 5,
+        BinaryOperator::BitAnd => 6,
+        BinaryOperator::GreaterThan
+        | BinaryOperator::GreaterThanOrEqual
+        | BinaryOperator::LessThan
+        | BinaryOperator::LessThanOrEqual => 7,
+        BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight => 8,
+        BinaryOperator::Add | BinaryOperator::Subtract => 9,
+        BinaryOperator::Multiply | BinaryOperator::Divide => 10,
+    }
+}
+
+/// Renders a float so that it is read back as a float literal
+fn float_source(value: f64) -> String {
+    let formatted = value.to_string();
+    if value.is_finite() && !formatted.contains('.') {
+        format!("{}.0", formatted)
+    } else {
+        formatted
+    }
+}
+
+impl Visitor<()> for SourcePrinter<'_> {
+    fn visit_expression_statement(&mut self, expr: &Expression) {
+        match expr {
+            // A block is a statement of its own and doesn't need a semicolon
+            Expression::Block(_) => self.visit_expression(expr),
+            // Parentheses keep the expression from being read as an if statement
+            Expression::Conditional(_) => {
+                self.output.push('(');
+                self.visit_expression(expr);
+                self.output.push_str(");");
+            }
+            _ => {
+                self.visit_expression(expr);
+                self.output.push(';');
+            }
+        }
+    }
+
+    fn visit_let_statement(&mut self, stmt: &LetStatement) {
+        self.output.push_str("let ");
+        if stmt.is_mutable {
+            self.output.push_str("mut ");
+        }
+        self.output.push_str(&stmt.name);
+        if stmt.expr_type != TypeId::unknown() {
+            self.output.push_str(": ");
+            self.write_type(&stmt.expr_type);
+        }
+        self.output.push_str(" = ");
+        self.visit_expression(&stmt.value);
+        self.output.push(';');
+    }
+
+    fn visit_type_definition_statement(&mut self, stmt: &TypeDefinitionStmt) {
+        self.output.push_str(&format!("struct {} {{\n", stmt.name));
+        for (name, field_type) in &stmt.fields {
+            self.output.push_str(&"    ".repeat(self.indent_level + 1));
+            self.output.push_str(name);
+            self.output.push_str(": ");
+            self.write_type(field_type);
+            self.output.push_str(",\n");
+        }
+        self.output.push_str(&"    ".repeat(self.indent_level));
+        self.output.push_str("};");
+    }
+
+    fn visit_enum_definition_statement(&mut self, stmt: &EnumDefinitionStmt) {
+        self.output.push_str(&format!("enum {} {{\n", stmt.name));
+        for variant in &stmt.variants {
+            self.output.push_str(&"    ".repeat(self.indent_level + 1));
+            self.output.push_str(variant);
+            self.output.push_str(",\n");
+        }
+        self.output.push_str(&"    ".repeat(self.indent_level));
+        self.output.push('}');
+    }
+
+    fn visit_impl_statement(&mut self, stmt: &ImplStmt) {
+        self.output
+            .push_str(&format!("impl {} {{\n", stmt.type_name));
+        self.indent_level += 1;
+        for method in &stmt.methods {
+            self.output.push_str(&"    ".repeat(self.indent_level));
+            self.write_method(&stmt.type_name, method);
+            self.output.push('\n');
+        }
+        self.indent_level -= 1;
+        self.output.push_str(&"    ".repeat(self.indent_level));
+        self.output.push('}');
+    }
+
+    fn visit_function_declaration_statement(&mut self, stmt: &FunctionDeclarationStmt) {
+        self.output.push_str(&format!("fn {}(", stmt.name));
+        self.write_parameters(&stmt.parameters);
+        self.output.push(')');
+        self.write_return_type(&stmt.return_type);
+        self.output.push(' ');
+        self.visit_block_expression(&stmt.body);
+    }
+
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement) {
+        self.output.push_str("return");
+        if let Some(value) = &stmt.value {
+            self.output.push(' ');
+            self.visit_expression(value);
+        }
+        self.output.push(';');
+    }
+
+    fn visit_assignment_statement(&mut self, stmt: &AssignmentStatement) {
+        self.output.push_str(&stmt.name);
+        self.output.push_str(" = ");
+        self.visit_expression(&stmt.value);
+        self.output.push(';');
+    }
+
+    fn visit_binary_expression(&mut self, expr: &BinaryExpr) {
+        // All binary operators are left associative
+        let own_precedence = operator_precedence(&expr.operator);
+        self.write_operand(&expr.left, own_precedence);
+        self.output.push_str(&format!(" {} ", expr.operator));
+        self.write_operand(&expr.right, own_precedence + 1);
+    }
+
+    fn visit_unary_expression(&mut self, expr: &UnaryExpr) {
+        self.output.push_str(&expr.operator.to_string());
+        // The operand of a unary operator is a primary expression
+        self.write_operand(&expr.right, PRIMARY_PRECEDENCE);
+    }
+
+    fn visit_literal_expression(&mut self, expr: &LiteralExpr) {
+        let source = match &expr.value {
+            LiteralValue::I32(i) => format!("{}{}", i, TYPE_NAME_I32),
+            LiteralValue::I64(i) => format!("{}{}", i, TYPE_NAME_I64),
+            LiteralValue::U32(u) => format!("{}{}", u, TYPE_NAME_U32),
+            LiteralValue::U64(u) => format!("{}{}", u, TYPE_NAME_U64),
+            LiteralValue::I128(i) => format!("{}{}", i, TYPE_NAME_I128),
+            LiteralValue::U128(u) => format!("{}{}", u, TYPE_NAME_U128),
+            LiteralValue::UnspecifiedInteger(i) => i.to_string(),
+            LiteralValue::F32(f) => format!("{}{}", float_source(f64::from(*f)), TYPE_NAME_F32),
+            LiteralValue::F64(f) => format!("{}{}", float_source(*f), TYPE_NAME_F64),
+            LiteralValue::UnspecifiedFloat(f) => float_source(*f),
+            LiteralValue::String(s) => format!("\"{}\"", s),
+            LiteralValue::Boolean(b) => b.to_string(),
+            LiteralValue::Unit => "()".to_string(),
+        };
+        self.output.push_str(&source);
+    }
+
+    fn visit_variable_expression(&mut self, var_expr: &VariableExpr) {
+        self.output.push_str(&var_expr.name);
+    }
+
+    fn visit_call_expression(&mut self, expr: &FunctionCallExpr) {
+        self.output.push_str(&expr.name);
+        self.write_arguments(&expr.arguments);
+    }
+
+    fn visit_method_call_expression(&mut self, expr: &MethodCallExpr) {
+        self.write_operand(&expr.receiver, PRIMARY_PRECEDENCE);
+        self.output.push('.');
+        self.output.push_str(&expr.method);
+        self.write_arguments(&expr.arguments);
+    }
+
+    fn visit_conditional_expression(&mut self, expr: &ConditionalExpr) {
+        self.output.push_str("if ");
+        self.visit_expression(&expr.condition);
+        self.output.push(' ');
+        self.visit_expression(&expr.then_branch);
+        self.output.push_str(" else ");
+        self.visit_expression(&expr.else_branch);
+    }
+
+    fn visit_block_expression(&mut self, expr: &BlockExpr) {
+        if expr.statements.is_empty() && expr.return_expr.is_none() {
+            self.output.push_str("{}");
+            return;
+        }
+
+        self.output.push_str("{\n");
+        self.indent_level += 1;
+        for stmt in &expr.statements {
+            self.write_statement(stmt);
+        }
+        if let Some(return_expr) = &expr.return_expr {
+            self.output.push_str(&"    ".repeat(self.indent_level));
+            self.visit_expression(return_expr);
+            self.output.push('\n');
+        }
+        self.indent_level -= 1;
+        self.output.push_str(&"    ".repeat(self.indent_level));
+        self.output.push('}');
+    }
+
+    fn visit_function_type_expression(&mut self, expr: &FunctionTypeExpr) {
+        self.output.push_str("fn(");
+        for (i, param_type) in expr.param_types.iter().enumerate() {
+            if i > 0 {
+                self.output.push_str(", ");
+            }
+            self.write_type(param_type);
+        }
+        self.output.push_str(") -> ");
+        self.write_type(&expr.return_type);
+    }
+
+    fn visit_closure_expression(&mut self, expr: &ClosureExpr) {
+        self.output.push('|');
+        self.write_parameters(&expr.parameters);
+        self.output.push('|');
+        self.write_return_type(&expr.return_type);
+        self.output.push(' ');
+        self.visit_block_expression(&expr.body);
+    }
+
+    fn visit_if_statement(&mut self, stmt: &IfStatement) {
+        self.output.push_str("if ");
+        self.visit_expression(&stmt.condition);
+        self.output.push(' ');
+        self.visit_block_expression(&stmt.then_branch);
+        if let Some(else_branch) = &stmt.else_branch {
+            self.output.push_str(" else ");
+            self.visit_block_expression(else_branch);
+        }
+    }
+
+    fn visit_for_statement(&mut self, stmt: &ForStatement) {
+        self.output
+            .push_str(&format!("for {} in range(", stmt.variable));
+        self.visit_expression(&stmt.start);
+        self.output.push_str(", ");
+        self.visit_expression(&stmt.end);
+        self.output.push_str(") ");
+        self.visit_block_expression(&stmt.body);
+    }
+}
@@ -1,9 +1,10 @@
 use std::fmt::Display;
 
 /// Types of tokens in the language lexer
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Tokentype {
     Identifier,     // x, y, myVar
+    Underscore,     // _
     IntegerLiteral, // 123
     FloatLiteral,   // 123.45
     StringLiteral,  // "hello world"
@@ -14,23 +15,36 @@ pub enum Tokentype {
     Minus,          // -
     Multiply,       // *
     Divide,         // /
+    Percent,        // %
     Not,            // !
     And,            // &&
     Or,             // ||
+    Pipe,           // |
+    Ampersand,      // &
+    Caret,          // ^
+    Tilde,          // ~
     Greater,        // >
     Less,           // <
     GreaterEqual,   // >=
     LessEqual,      // <=
+    ShiftLeft,      // <<
+    ShiftRight,     // >>
+    ShiftLeftEqual, // <<=
+    ShiftRightEqual, // >>=
     EqualEqual,     // ==
     NotEqual,       // !=
     Invalid,        // Unrecognized token
     Equal,          // =
     Colon,          // :
+    PathSeparator,  // ::
     Semicolon,      // ;
     Struct,         // struct
+    Enum,           // enum
+    Impl,           // impl
     LeftBrace,      // {
     RightBrace,     // }
     Comma,          // ,
+    Dot,            // .
     Fn,             // fn
     LeftParen,      // (
     RightParen,     // )
@@ -38,6 +52,8 @@ pub enum Tokentype {
     Return,         // return
     If,             // if
     Else,           // else
+    For,            // for
+    In,             // in
 
     Eof, // End of file
 }
@@ -49,6 +65,7 @@ impl Display for Tokentype {
             "{}",
             match self {
                 Tokentype::Identifier => "identifier",
+                Tokentype::Underscore => "'_'",
                 Tokentype::IntegerLiteral => "integer literal",
                 Tokentype::FloatLiteral => "float literal",
                 Tokentype::StringLiteral => "string literal",
@@ -59,23 +76,36 @@ impl Display for Tokentype {
                 Tokentype::Minus => "'-'",
                 Tokentype::Multiply => "'*'",
                 Tokentype::Divide => "'/'",
+                Tokentype::Percent => "'%'",
                 Tokentype::Not => "'!'",
                 Tokentype::And => "'&&'",
                 Tokentype::Or => "'||'",
+                Tokentype::Pipe => "'|'",
+                Tokentype::Ampersand => "'&'",
+                Tokentype::Caret => "'^'",
+                Tokentype::Tilde => "'~'",
                 Tokentype::Greater => "'>'",
                 Tokentype::Less => "'<'",
                 Tokentype::GreaterEqual => "'>='",
                 Tokentype::LessEqual => "'<='",
+                Tokentype::ShiftLeft => "'<<'",
+                Tokentype::ShiftRight => "'>>'",
+                Tokentype::ShiftLeftEqual => "'<<='",
+                Tokentype::ShiftRightEqual => "'>>='",
                 Tokentype::EqualEqual => "'=='",
                 Tokentype::NotEqual => "'!='",
                 Tokentype::Invalid => "invalid token",
                 Tokentype::Equal => "'='",
                 Tokentype::Colon => "':'",
+                Tokentype::PathSeparator => "'::'",
                 Tokentype::Semicolon => "';'",
                 Tokentype::Struct => "sturct keyword",
+                Tokentype::Enum => "enum keyword",
+                Tokentype::Impl => "impl keyword",
                 Tokentype::LeftBrace => "'{'",
                 Tokentype::RightBrace => "'}'",
                 Tokentype::Comma => "','",
+                Tokentype::Dot => "'.'",
                 Tokentype::Fn => "fn keyword",
                 Tokentype::LeftParen => "'('",
                 Tokentype::RightParen => "')'",
@@ -83,6 +113,8 @@ impl Display for Tokentype {
                 Tokentype::Return => "return keyword",
                 Tokentype::If => "if keyword",
                 Tokentype::Else => "else keyword",
+                Tokentype::For => "for keyword",
+                Tokentype::In => "in keyword",
                 Tokentype::Eof => "<EOF>",
             }
         )
@@ -115,3 +147,40 @@ impl Display for Token {
         write!(f, "{}: {}", self.token_type, self.lexeme)
     }
 }
+
+/// Kinds of source text that carry no meaning for the parser
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriviaKind {
+    /// A run of spaces, tabs and other whitespace except line breaks
+    Whitespace,
+    /// A single line break
+    Newline,
+    /// A `//` comment, without the line break that ends it
+    LineComment,
+    /// A `/* */` comment, including nested comments
+    BlockComment,
+}
+
+/// A piece of source text between two tokens
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trivia {
+    /// The kind of the trivia
+    pub kind: TriviaKind,
+    /// The source text of the trivia
+    pub text: String,
+    /// Position index of the first character of the trivia
+    pub pos: usize,
+}
+
+/// A token together with the trivia surrounding it
+///
+/// Trivia on the same line after a token is trailing trivia of that token, everything else
+/// is leading trivia of the token that follows it.
+pub struct TokenWithTrivia {
+    /// The token itself
+    pub token: Token,
+    /// Trivia between the previous line break or token and this token
+    pub leading_trivia: Vec<Trivia>,
+    /// Trivia after this token up to the end of its line
+    pub trailing_trivia: Vec<Trivia>,
+}
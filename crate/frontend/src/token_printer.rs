@@ -63,7 +63,11 @@ impl TokenPrinter {
             | Tokentype::Return
             | Tokentype::If
             | Tokentype::Else
-            | Tokentype::Struct => format!("{:15}", format!("{:?}", token_type))
+            | Tokentype::For
+            | Tokentype::In
+            | Tokentype::Struct
+            | Tokentype::Enum
+            | Tokentype::Impl => format!("{:15}", format!("{:?}", token_type))
                 .blue()
                 .bold()
                 .to_string(),
@@ -77,7 +81,7 @@ impl TokenPrinter {
                 .to_string(),
 
             // Identifiers
-            Tokentype::Identifier => format!("{:15}", format!("{:?}", token_type))
+            Tokentype::Identifier | Tokentype::Underscore => format!("{:15}", format!("{:?}", token_type))
                 .cyan()
                 .to_string(),
 
@@ -86,6 +90,7 @@ impl TokenPrinter {
             | Tokentype::Minus
             | Tokentype::Multiply
             | Tokentype::Divide
+            | Tokentype::Percent
             | Tokentype::Equal
             | Tokentype::EqualEqual
             | Tokentype::NotEqual
@@ -93,8 +98,15 @@ impl TokenPrinter {
             | Tokentype::Greater
             | Tokentype::LessEqual
             | Tokentype::GreaterEqual
+            | Tokentype::ShiftLeft
+            | Tokentype::ShiftRight
+            | Tokentype::ShiftLeftEqual
+            | Tokentype::ShiftRightEqual
             | Tokentype::And
             | Tokentype::Or
+            | Tokentype::Ampersand
+            | Tokentype::Caret
+            | Tokentype::Tilde
             | Tokentype::Not => format!("{:15}", format!("{:?}", token_type))
                 .yellow()
                 .to_string(),
@@ -102,12 +114,15 @@ impl TokenPrinter {
             // Punctuation
             Tokentype::Semicolon
             | Tokentype::Comma
+            | Tokentype::Dot
             | Tokentype::Colon
+            | Tokentype::PathSeparator
             | Tokentype::Arrow
             | Tokentype::LeftParen
             | Tokentype::RightParen
             | Tokentype::LeftBrace
-            | Tokentype::RightBrace => format!("{:15}", format!("{:?}", token_type))
+            | Tokentype::RightBrace
+            | Tokentype::Pipe => format!("{:15}", format!("{:?}", token_type))
                 .bright_black()
                 .to_string(),
 
@@ -1,71 +1,110 @@
 use crate::Location;
-use crate::Visitor;
-use slang_types::types::TypeId;
+use crate::{VisitResult, Visitor};
+use slang_derive::NamedEnum;
+use slang_types::types::{PrimitiveType, TypeId};
+use std::cell::{Cell, OnceCell};
 use std::fmt::Display;
 
-#[derive(Debug, PartialEq)]
+/// Binary operators of the language
+///
+/// The textual form of each operator is available through `name()` and can be mapped back
+/// with `from_str()`.
+///
+/// ### Example
+/// ```
+/// use slang_ir::ast::BinaryOperator;
+///
+/// assert_eq!(BinaryOperator::from_str("+"), Some(BinaryOperator::Add));
+/// assert_eq!(BinaryOperator::Add.name(), "+");
+/// assert_eq!(BinaryOperator::ShiftRight.to_string(), ">>");
+/// assert_eq!(BinaryOperator::from_str("=>"), None);
+/// ```
+#[derive(Debug, PartialEq, NamedEnum)]
 pub enum BinaryOperator {
     /// Addition operator
+    #[name = "+"]
     Add,
     /// Subtraction operator
+    #[name = "-"]
     Subtract,
     /// Multiplication operator
+    #[name = "*"]
     Multiply,
     /// Division operator
+    #[name = "/"]
     Divide,
     /// Greater than operator
+    #[name = ">"]
     GreaterThan,
     /// Less than operator
+    #[name = "<"]
     LessThan,
     /// Greater than or equal to operator
+    #[name = ">="]
     GreaterThanOrEqual,
     /// Less than or equal to operator
+    #[name = "<="]
     LessThanOrEqual,
     /// Equality operator
+    #[name = "=="]
     Equal,
     /// Not equal operator
+    #[name = "!="]
     NotEqual,
     /// Logical AND operator
+    #[name = "&&"]
     And,
     /// Logical OR operator
+    #[name = "||"]
     Or,
+    /// Bitwise AND operator
+    #[name = "&"]
+    BitAnd,
+    /// Bitwise OR operator
+    #[name = "|"]
+    BitOr,
+    /// Bitwise XOR operator
+    #[name = "^"]
+    BitXor,
+    /// Left shift operator
+    #[name = "<<"]
+    ShiftLeft,
+    /// Right shift operator
+    #[name = ">>"]
+    ShiftRight,
 }
 
 impl Display for BinaryOperator {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let op_str = match self {
-            BinaryOperator::Add => "+",
-            BinaryOperator::Subtract => "-",
-            BinaryOperator::Multiply => "*",
-            BinaryOperator::Divide => "/",
-            BinaryOperator::GreaterThan => ">",
-            BinaryOperator::LessThan => "<",
-            BinaryOperator::GreaterThanOrEqual => ">=",
-            BinaryOperator::LessThanOrEqual => "<=",
-            BinaryOperator::Equal => "==",
-            BinaryOperator::NotEqual => "!=",
-            BinaryOperator::And => "&&",
-            BinaryOperator::Or => "||",
-        };
-        write!(f, "{}", op_str)
+        write!(f, "{}", self.name())
     }
 }
 
-#[derive(Debug, PartialEq)]
+/// Unary operators of the language
+///
+/// ### Example
+/// ```
+/// use slang_ir::ast::UnaryOperator;
+///
+/// assert_eq!(UnaryOperator::from_str("~"), Some(UnaryOperator::BitNot));
+/// assert_eq!(UnaryOperator::Not.name(), "!");
+/// ```
+#[derive(Debug, PartialEq, NamedEnum)]
 pub enum UnaryOperator {
     /// Negation operator
+    #[name = "-"]
     Negate,
     /// Logical NOT operator
+    #[name = "!"]
     Not,
+    /// Bitwise NOT operator
+    #[name = "~"]
+    BitNot,
 }
 
 impl Display for UnaryOperator {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let op_str = match self {
-            UnaryOperator::Negate => "-",
-            UnaryOperator::Not => "!",
-        };
-        write!(f, "{}", op_str)
+        write!(f, "{}", self.name())
     }
 }
 
@@ -82,12 +121,16 @@ pub enum Expression {
     Unary(UnaryExpr),
     /// A function call
     Call(FunctionCallExpr),
+    /// A method call on a value (e.g., color.name())
+    MethodCall(MethodCallExpr),
     /// A conditional expression (if/else)
     Conditional(ConditionalExpr),
     /// A block expression with statements and optional return value
     Block(BlockExpr),
     /// A function type expression (e.g., fn(i32, string) -> string)
     FunctionType(FunctionTypeExpr),
+    /// An anonymous closure (e.g., |x: i32| -> i32 { x + y })
+    Closure(ClosureExpr),
 }
 
 impl Expression {
@@ -98,9 +141,30 @@ impl Expression {
             Expression::Variable(e) => e.location,
             Expression::Unary(e) => e.location,
             Expression::Call(e) => e.location,
+            Expression::MethodCall(e) => e.location,
             Expression::Conditional(e) => e.location,
             Expression::Block(e) => e.location,
             Expression::FunctionType(e) => e.location,
+            Expression::Closure(e) => e.location,
+        }
+    }
+
+    /// Replaces the source location of the expression
+    ///
+    /// ### Arguments
+    /// * `location` - The new source location
+    pub fn set_location(&mut self, location: Location) {
+        match self {
+            Expression::Literal(e) => e.location = location,
+            Expression::Binary(e) => e.location = location,
+            Expression::Variable(e) => e.location = location,
+            Expression::Unary(e) => e.location = location,
+            Expression::Call(e) => e.location = location,
+            Expression::MethodCall(e) => e.location = location,
+            Expression::Conditional(e) => e.location = location,
+            Expression::Block(e) => e.location = location,
+            Expression::FunctionType(e) => e.location = location,
+            Expression::Closure(e) => e.location = location,
         }
     }
 }
@@ -116,12 +180,18 @@ pub enum Statement {
     Expression(Expression),
     /// Type definition (e.g., struct)
     TypeDefinition(TypeDefinitionStmt),
+    /// Enum definition
+    EnumDefinition(EnumDefinitionStmt),
+    /// Method declarations of a type
+    Impl(ImplStmt),
     /// Function declaration
     FunctionDeclaration(FunctionDeclarationStmt),
     /// Return statement
     Return(ReturnStatement),
     /// Conditional statement (if/else)
     If(IfStatement),
+    /// Counting loop over a range of integers
+    For(ForStatement),
 }
 
 /// A function call expression
@@ -131,12 +201,47 @@ pub struct FunctionCallExpr {
     pub name: String,
     /// Arguments passed to the function
     pub arguments: Vec<Expression>,
+    /// Source location of the argument list, including the parentheses
+    pub arguments_location: Location,
     /// Type of the function call expression
     pub expr_type: TypeId,
     /// Source code location information
     pub location: Location,
 }
 
+impl FunctionCallExpr {
+    /// Name of the built-in that reports the inferred type of its argument at compile time
+    pub const TYPE_QUERY: &'static str = "__type_of";
+
+    /// Checks whether this call is a compile-time type query
+    ///
+    /// A type query doesn't evaluate its argument at runtime, it always produces unit.
+    ///
+    /// ### Returns
+    /// True if the call is of the form `__type_of(expr)`
+    pub fn is_type_query(&self) -> bool {
+        self.name == Self::TYPE_QUERY
+    }
+}
+
+/// A method call expression (e.g., color.name())
+#[derive(Debug)]
+pub struct MethodCallExpr {
+    /// The value the method is called on, it is passed as the `self` argument
+    pub receiver: Box<Expression>,
+    /// Name of the method being called
+    pub method: String,
+    /// Arguments passed to the method, without the receiver
+    pub arguments: Vec<Expression>,
+    /// Source location of the argument list, including the parentheses
+    pub arguments_location: Location,
+    /// Qualified name of the function implementing the method (e.g., `Color::name`), set
+    /// by the semantic analysis once the type of the receiver is known
+    pub function_name: OnceCell<String>,
+    /// Source code location information
+    pub location: Location,
+}
+
 /// A conditional expression (if/else)
 #[derive(Debug)]
 pub struct ConditionalExpr {
@@ -176,6 +281,11 @@ pub struct Parameter {
     pub location: Location,
 }
 
+impl Parameter {
+    /// Name of the parameter that receives the value a method is called on
+    pub const SELF: &'static str = "self";
+}
+
 /// A function declaration statement
 #[derive(Debug)]
 pub struct FunctionDeclarationStmt {
@@ -204,6 +314,21 @@ pub struct FunctionTypeExpr {
     pub location: Location,
 }
 
+/// An anonymous closure expression (e.g., |x: i32| -> i32 { x + y })
+#[derive(Debug)]
+pub struct ClosureExpr {
+    /// Closure parameters
+    pub parameters: Vec<Parameter>,
+    /// Closure return type
+    pub return_type: TypeId,
+    /// Closure body (block expression)
+    pub body: BlockExpr,
+    /// Type of the closure expression (will be a function type)
+    pub expr_type: TypeId,
+    /// Source code location information
+    pub location: Location,
+}
+
 /// A type definition statement (like struct)
 #[derive(Debug)]
 pub struct TypeDefinitionStmt {
@@ -215,17 +340,77 @@ pub struct TypeDefinitionStmt {
     pub location: Location,
 }
 
+/// An enum definition statement
+#[derive(Debug)]
+pub struct EnumDefinitionStmt {
+    /// Name of the defined enum
+    pub name: String,
+    /// Names of the variants in declaration order
+    pub variants: Vec<String>,
+    /// Source code location information
+    pub location: Location,
+}
+
+/// The method declarations of a type (e.g., impl Color { fn name(self) -> string { ... } })
+#[derive(Debug)]
+pub struct ImplStmt {
+    /// Name of the type the methods belong to
+    pub type_name: String,
+    /// The methods, declared under their qualified name (e.g., `Color::name`). Methods
+    /// that take `self` have it as their first parameter.
+    pub methods: Vec<FunctionDeclarationStmt>,
+    /// Source code location information
+    pub location: Location,
+}
+
 /// A literal expression
 #[derive(Debug)]
 pub struct LiteralExpr {
     /// Value of the literal
     pub value: LiteralValue,
-    /// Type of the literal expression
-    pub expr_type: TypeId,
+    /// Type of the literal expression, an unspecified literal gets the type of the context it
+    /// is used in during semantic analysis
+    pub expr_type: Cell<TypeId>,
     /// Source code location information
     pub location: Location,
 }
 
+impl LiteralExpr {
+    /// Gets the value of the literal as the type it has after semantic analysis
+    ///
+    /// ### Returns
+    /// The value converted to the type of the literal, or the value as written if it has no
+    /// concrete type or doesn't fit into it
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_ir::Location;
+    /// use slang_ir::ast::{LiteralExpr, LiteralValue};
+    /// use slang_types::TypeId;
+    /// use std::cell::Cell;
+    ///
+    /// let literal = LiteralExpr {
+    ///     value: LiteralValue::UnspecifiedInteger(5),
+    ///     expr_type: Cell::new(TypeId::unspecified_int()),
+    ///     location: Location::default(),
+    /// };
+    /// assert_eq!(literal.typed_value(), LiteralValue::UnspecifiedInteger(5));
+    ///
+    /// literal.expr_type.set(TypeId::u32());
+    /// assert_eq!(literal.typed_value(), LiteralValue::U32(5));
+    /// ```
+    pub fn typed_value(&self) -> LiteralValue {
+        let unspecified = matches!(
+            self.value,
+            LiteralValue::UnspecifiedInteger(_) | LiteralValue::UnspecifiedFloat(_)
+        );
+        PrimitiveType::from_int(self.expr_type.get().0)
+            .filter(|_| unspecified)
+            .and_then(|primitive| self.value.coerce_to(primitive))
+            .unwrap_or_else(|| self.value.clone())
+    }
+}
+
 /// A variable reference expression
 #[derive(Debug)]
 pub struct VariableExpr {
@@ -249,7 +434,7 @@ pub struct UnaryExpr {
 }
 
 /// Possible values for literal expressions
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum LiteralValue {
     /// 32-bit signed integer
     I32(i32),
@@ -259,8 +444,12 @@ pub enum LiteralValue {
     U32(u32),
     /// 64-bit unsigned integer
     U64(u64),
+    /// 128-bit signed integer
+    I128(i128),
+    /// 128-bit unsigned integer
+    U128(u128),
     /// Integer without specified type (needs inference)
-    UnspecifiedInteger(i64),
+    UnspecifiedInteger(i128),
     /// 32-bit floating point
     F32(f32),
     /// 64-bit floating point
@@ -275,6 +464,84 @@ pub enum LiteralValue {
     Unit,
 }
 
+impl LiteralValue {
+    /// Converts an unspecified literal to the concrete variant of a primitive type
+    ///
+    /// Unspecified integers can become any integer type and unspecified floats any float
+    /// type. A literal that already has a concrete type only converts to that type.
+    ///
+    /// ### Arguments
+    /// * `target` - The primitive type to convert the literal to
+    ///
+    /// ### Returns
+    /// The converted literal, or `None` if the value is out of range for the target type
+    /// or the literal can't have that type
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_ir::ast::LiteralValue;
+    /// use slang_types::PrimitiveType;
+    ///
+    /// let five = LiteralValue::UnspecifiedInteger(5);
+    /// assert_eq!(five.coerce_to(PrimitiveType::I32), Some(LiteralValue::I32(5)));
+    /// assert_eq!(five.coerce_to(PrimitiveType::U128), Some(LiteralValue::U128(5)));
+    /// assert_eq!(five.coerce_to(PrimitiveType::Bool), None);
+    ///
+    /// let large = LiteralValue::UnspecifiedInteger(i128::from(i32::MAX) + 1);
+    /// assert_eq!(large.coerce_to(PrimitiveType::I32), None);
+    /// assert_eq!(large.coerce_to(PrimitiveType::U32), Some(LiteralValue::U32(1 << 31)));
+    /// assert_eq!(LiteralValue::UnspecifiedInteger(-1).coerce_to(PrimitiveType::U64), None);
+    ///
+    /// let half = LiteralValue::UnspecifiedFloat(0.5);
+    /// assert_eq!(half.coerce_to(PrimitiveType::F32), Some(LiteralValue::F32(0.5)));
+    /// assert_eq!(LiteralValue::UnspecifiedFloat(1e300).coerce_to(PrimitiveType::F32), None);
+    ///
+    /// assert_eq!(LiteralValue::I64(7).coerce_to(PrimitiveType::I64), Some(LiteralValue::I64(7)));
+    /// assert_eq!(LiteralValue::I64(7).coerce_to(PrimitiveType::I32), None);
+    /// ```
+    pub fn coerce_to(&self, target: PrimitiveType) -> Option<LiteralValue> {
+        match (self, target) {
+            (LiteralValue::UnspecifiedInteger(n), PrimitiveType::I32) => {
+                i32::try_from(*n).ok().map(LiteralValue::I32)
+            }
+            (LiteralValue::UnspecifiedInteger(n), PrimitiveType::I64) => {
+                i64::try_from(*n).ok().map(LiteralValue::I64)
+            }
+            (LiteralValue::UnspecifiedInteger(n), PrimitiveType::U32) => {
+                u32::try_from(*n).ok().map(LiteralValue::U32)
+            }
+            (LiteralValue::UnspecifiedInteger(n), PrimitiveType::U64) => {
+                u64::try_from(*n).ok().map(LiteralValue::U64)
+            }
+            (LiteralValue::UnspecifiedInteger(n), PrimitiveType::I128) => {
+                Some(LiteralValue::I128(*n))
+            }
+            (LiteralValue::UnspecifiedInteger(n), PrimitiveType::U128) => {
+                u128::try_from(*n).ok().map(LiteralValue::U128)
+            }
+            (LiteralValue::UnspecifiedFloat(f), PrimitiveType::F32) => {
+                let narrowed = *f as f32;
+                (narrowed.is_finite() || !f.is_finite()).then_some(LiteralValue::F32(narrowed))
+            }
+            (LiteralValue::UnspecifiedFloat(f), PrimitiveType::F64) => Some(LiteralValue::F64(*f)),
+            (LiteralValue::I32(_), PrimitiveType::I32)
+            | (LiteralValue::I64(_), PrimitiveType::I64)
+            | (LiteralValue::U32(_), PrimitiveType::U32)
+            | (LiteralValue::U64(_), PrimitiveType::U64)
+            | (LiteralValue::I128(_), PrimitiveType::I128)
+            | (LiteralValue::U128(_), PrimitiveType::U128)
+            | (LiteralValue::UnspecifiedInteger(_), PrimitiveType::UnspecifiedInt)
+            | (LiteralValue::F32(_), PrimitiveType::F32)
+            | (LiteralValue::F64(_), PrimitiveType::F64)
+            | (LiteralValue::UnspecifiedFloat(_), PrimitiveType::UnspecifiedFloat)
+            | (LiteralValue::String(_), PrimitiveType::String)
+            | (LiteralValue::Boolean(_), PrimitiveType::Bool)
+            | (LiteralValue::Unit, PrimitiveType::Unit) => Some(self.clone()),
+            _ => None,
+        }
+    }
+}
+
 /// A binary expression (e.g., a + b)
 #[derive(Debug)]
 pub struct BinaryExpr {
@@ -305,6 +572,19 @@ pub struct LetStatement {
     pub location: Location,
 }
 
+impl LetStatement {
+    /// Name used to discard the value of a let binding
+    pub const WILDCARD: &'static str = "_";
+
+    /// Checks whether this let statement discards its value instead of binding a name
+    ///
+    /// ### Returns
+    /// True if the statement is of the form `let _ = expr;`
+    pub fn is_wildcard(&self) -> bool {
+        self.name == Self::WILDCARD
+    }
+}
+
 /// A variable assignment statement
 #[derive(Debug)]
 pub struct AssignmentStatement {
@@ -329,6 +609,23 @@ pub struct IfStatement {
     pub location: Location,
 }
 
+/// A loop over a range of integers, e.g. `for i in range(0, 10) { ... }`
+#[derive(Debug)]
+pub struct ForStatement {
+    /// Name of the loop variable
+    pub variable: String,
+    /// Value of the loop variable in the first iteration
+    pub start: Expression,
+    /// Exclusive upper bound of the loop variable
+    pub end: Expression,
+    /// Block expression executed once for every value of the loop variable
+    pub body: BlockExpr,
+    /// Type of the loop variable, resolved during semantic analysis
+    pub variable_type: Cell<TypeId>,
+    /// Source code location information
+    pub location: Location,
+}
+
 /// A return statement
 #[derive(Debug)]
 pub struct ReturnStatement {
@@ -346,7 +643,7 @@ impl Statement {
     ///
     /// ### Returns
     /// The result of the visitor's visit method for this statement
-    pub fn accept<T>(&self, visitor: &mut dyn Visitor<T>) -> T {
+    pub fn accept<T: VisitResult>(&self, visitor: &mut dyn Visitor<T>) -> T {
         match self {
             Statement::Let(let_stmt) => visitor.visit_let_statement(let_stmt),
             Statement::Assignment(assign_stmt) => visitor.visit_assignment_statement(assign_stmt),
@@ -354,11 +651,16 @@ impl Statement {
             Statement::TypeDefinition(type_def) => {
                 visitor.visit_type_definition_statement(type_def)
             }
+            Statement::EnumDefinition(enum_def) => {
+                visitor.visit_enum_definition_statement(enum_def)
+            }
+            Statement::Impl(impl_stmt) => visitor.visit_impl_statement(impl_stmt),
             Statement::FunctionDeclaration(fn_decl) => {
                 visitor.visit_function_declaration_statement(fn_decl)
             }
             Statement::Return(return_stmt) => visitor.visit_return_statement(return_stmt),
             Statement::If(if_stmt) => visitor.visit_if_statement(if_stmt),
+            Statement::For(for_stmt) => visitor.visit_for_statement(for_stmt),
         }
     }
 }
@@ -371,18 +673,22 @@ impl Expression {
     ///
     /// ### Returns
     /// The result of the visitor's visit method for this expression
-    pub fn accept<T>(&self, visitor: &mut dyn Visitor<T>) -> T {
+    pub fn accept<T: VisitResult>(&self, visitor: &mut dyn Visitor<T>) -> T {
         match self {
             Expression::Literal(lit) => visitor.visit_literal_expression(lit),
             Expression::Binary(bin) => visitor.visit_binary_expression(bin),
             Expression::Variable(var) => visitor.visit_variable_expression(var),
             Expression::Unary(unary) => visitor.visit_unary_expression(unary),
             Expression::Call(call) => visitor.visit_call_expression(call),
+            Expression::MethodCall(method_call) => {
+                visitor.visit_method_call_expression(method_call)
+            }
             Expression::Conditional(cond) => visitor.visit_conditional_expression(cond),
             Expression::Block(block) => visitor.visit_block_expression(block),
             Expression::FunctionType(func_type) => {
                 visitor.visit_function_type_expression(func_type)
             }
+            Expression::Closure(closure) => visitor.visit_closure_expression(closure),
         }
     }
 }
@@ -1,13 +1,15 @@
 use crate::Visitor;
 use crate::ast::{
-    AssignmentStatement, BinaryExpr, BinaryOperator, BlockExpr, ConditionalExpr, Expression,
-    FunctionCallExpr, FunctionDeclarationStmt, FunctionTypeExpr, IfStatement, LetStatement, LiteralExpr,
-    LiteralValue, ReturnStatement, Statement, TypeDefinitionStmt, UnaryExpr, UnaryOperator,
+    AssignmentStatement, BinaryExpr, BinaryOperator, BlockExpr, ClosureExpr, ConditionalExpr,
+    EnumDefinitionStmt, Expression, ForStatement, FunctionCallExpr, FunctionDeclarationStmt,
+    FunctionTypeExpr, IfStatement, ImplStmt, LetStatement, LiteralExpr, LiteralValue,
+    MethodCallExpr, ReturnStatement, Statement, TypeDefinitionStmt, UnaryExpr, UnaryOperator,
     VariableExpr,
 };
 use slang_types::{
     TYPE_NAME_BOOL, TYPE_NAME_F32, TYPE_NAME_F64, TYPE_NAME_FLOAT, TYPE_NAME_I32, TYPE_NAME_I64,
-    TYPE_NAME_INT, TYPE_NAME_STRING, TYPE_NAME_U32, TYPE_NAME_U64, TYPE_NAME_UNIT,
+    TYPE_NAME_I128, TYPE_NAME_INT, TYPE_NAME_STRING, TYPE_NAME_U32, TYPE_NAME_U64, TYPE_NAME_U128,
+    TYPE_NAME_UNIT,
 };
 
 /// A visitor implementation that prints the AST in a human-readable format
@@ -16,6 +18,12 @@ pub struct ASTPrinter {
     indent_level: usize,
 }
 
+impl Default for ASTPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ASTPrinter {
     /// Creates a new AST printer with no indentation
     pub fn new() -> Self {
@@ -106,6 +114,24 @@ impl Visitor<()> for ASTPrinter {
         self.indent_level -= 1;
     }
 
+    fn visit_enum_definition_statement(&mut self, stmt: &EnumDefinitionStmt) {
+        println!("{}Enum Definition: {}", self.indent(), stmt.name);
+        self.indent_level += 1;
+        for variant in &stmt.variants {
+            println!("{}Variant: {}", self.indent(), variant);
+        }
+        self.indent_level -= 1;
+    }
+
+    fn visit_impl_statement(&mut self, stmt: &ImplStmt) {
+        println!("{}Impl: {}", self.indent(), stmt.type_name);
+        self.indent_level += 1;
+        for method in &stmt.methods {
+            self.visit_function_declaration_statement(method);
+        }
+        self.indent_level -= 1;
+    }
+
     fn visit_call_expression(&mut self, call_expr: &FunctionCallExpr) {
         println!("{}Call: {}", self.indent(), call_expr.name);
 
@@ -120,12 +146,34 @@ impl Visitor<()> for ASTPrinter {
         }
     }
 
+    fn visit_method_call_expression(&mut self, method_call: &MethodCallExpr) {
+        println!("{}Method Call: {}", self.indent(), method_call.method);
+
+        self.indent_level += 1;
+        println!("{}Receiver:", self.indent());
+        self.indent_level += 1;
+        self.visit_expression(&method_call.receiver);
+        self.indent_level -= 1;
+
+        if !method_call.arguments.is_empty() {
+            println!("{}Arguments:", self.indent());
+            self.indent_level += 1;
+            for arg in &method_call.arguments {
+                self.visit_expression(arg);
+            }
+            self.indent_level -= 1;
+        }
+        self.indent_level -= 1;
+    }
+
     fn visit_literal_expression(&mut self, lit_expr: &LiteralExpr) {
         match &lit_expr.value {
             LiteralValue::I32(i) => println!("{}{}: {}", self.indent(), TYPE_NAME_I32, i),
             LiteralValue::I64(i) => println!("{}{}: {}", self.indent(), TYPE_NAME_I64, i),
             LiteralValue::U32(u) => println!("{}{}: {}", self.indent(), TYPE_NAME_U32, u),
             LiteralValue::U64(u) => println!("{}{}: {}", self.indent(), TYPE_NAME_U64, u),
+            LiteralValue::I128(i) => println!("{}{}: {}", self.indent(), TYPE_NAME_I128, i),
+            LiteralValue::U128(u) => println!("{}{}: {}", self.indent(), TYPE_NAME_U128, u),
             LiteralValue::UnspecifiedInteger(i) => {
                 println!("{}{}: {}", self.indent(), TYPE_NAME_INT, i)
             }
@@ -198,6 +246,28 @@ impl Visitor<()> for ASTPrinter {
         self.indent_level -= 1;
     }
 
+    fn visit_for_statement(&mut self, for_stmt: &ForStatement) {
+        println!("{}For Statement: {}", self.indent(), for_stmt.variable);
+
+        self.indent_level += 1;
+        println!("{}Start:", self.indent());
+        self.indent_level += 1;
+        self.visit_expression(&for_stmt.start);
+        self.indent_level -= 1;
+
+        println!("{}End:", self.indent());
+        self.indent_level += 1;
+        self.visit_expression(&for_stmt.end);
+        self.indent_level -= 1;
+
+        println!("{}Body:", self.indent());
+        self.indent_level += 1;
+        self.visit_block_expression(&for_stmt.body);
+        self.indent_level -= 1;
+
+        self.indent_level -= 1;
+    }
+
     fn visit_conditional_expression(&mut self, cond_expr: &ConditionalExpr) {
         println!("{}Conditional Expression:", self.indent());
 
@@ -231,6 +301,30 @@ impl Visitor<()> for ASTPrinter {
         println!(") -> return_type");
     }
 
+    fn visit_closure_expression(&mut self, closure_expr: &ClosureExpr) {
+        println!(
+            "{}Closure: -> {:?}",
+            self.indent(),
+            closure_expr.return_type
+        );
+
+        self.indent_level += 1;
+
+        if !closure_expr.parameters.is_empty() {
+            println!("{}Parameters:", self.indent());
+            self.indent_level += 1;
+            for param in &closure_expr.parameters {
+                println!("{}{}: {:?}", self.indent(), param.name, param.param_type);
+            }
+            self.indent_level -= 1;
+        }
+
+        println!("{}Body:", self.indent());
+        self.indent_level += 1;
+        self.visit_block_expression(&closure_expr.body);
+        self.indent_level -= 2;
+    }
+
     fn visit_block_expression(&mut self, block_expr: &BlockExpr) {
         println!("{}Block Expression:", self.indent());
 
@@ -5,4 +5,4 @@ pub mod location;
 pub mod visitor;
 
 pub use location::Location;
-pub use visitor::Visitor;
+pub use visitor::{VisitResult, Visitor};
@@ -88,6 +88,51 @@ impl Location {
             length: end_pos - start_pos,
         }
     }
+
+    /// Creates the smallest span covering all of the given locations
+    ///
+    /// The line and column of the span are taken from the location that starts first.
+    ///
+    /// ### Arguments
+    ///
+    /// * `locations` - The locations to cover
+    ///
+    /// ### Returns
+    /// A new SourceLocation covering every location, or the default location if the slice is empty
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_ir::Location;
+    ///
+    /// // The arguments of `add(first, 2, third)`
+    /// let arguments = [
+    ///     Location::new(4, 1, 5, 5),
+    ///     Location::new(11, 1, 12, 1),
+    ///     Location::new(14, 1, 15, 5),
+    /// ];
+    /// let span = Location::merge(&arguments);
+    ///
+    /// assert_eq!(span.position, 4);
+    /// assert_eq!(span.column, 5);
+    /// assert_eq!(span.end_position(), 19);
+    /// ```
+    pub fn merge(locations: &[Location]) -> Location {
+        let Some(first) = locations.iter().min_by_key(|location| location.position) else {
+            return Location::default();
+        };
+        let end_pos = locations
+            .iter()
+            .map(Location::end_position)
+            .max()
+            .unwrap_or(first.position);
+
+        Location {
+            position: first.position,
+            line: first.line,
+            column: first.column,
+            length: end_pos - first.position,
+        }
+    }
 }
 
 impl Default for Location {
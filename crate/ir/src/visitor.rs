@@ -1,16 +1,96 @@
 use crate::ast::{
-    AssignmentStatement, BinaryExpr, BlockExpr, ConditionalExpr, Expression, FunctionCallExpr,
-    FunctionDeclarationStmt, FunctionTypeExpr, IfStatement, LetStatement, LiteralExpr, ReturnStatement, Statement, TypeDefinitionStmt,
-    UnaryExpr, VariableExpr,
+    AssignmentStatement, BinaryExpr, BlockExpr, ClosureExpr, ConditionalExpr, EnumDefinitionStmt,
+    Expression, ForStatement, FunctionCallExpr, FunctionDeclarationStmt, FunctionTypeExpr,
+    IfStatement, ImplStmt, LetStatement, LiteralExpr, MethodCallExpr, ReturnStatement, Statement,
+    TypeDefinitionStmt, UnaryExpr, VariableExpr,
 };
 
+/// The result of visiting a node of the AST
+///
+/// The default methods of [`Visitor`] visit the children of a node one after another and
+/// combine their results with this trait.
+pub trait VisitResult {
+    /// The result of visiting a node without children
+    fn empty() -> Self;
+
+    /// Combines this result with the result of visiting the next node
+    ///
+    /// ### Arguments
+    /// * `next` - Visits the next node
+    ///
+    /// ### Returns
+    /// The combined result
+    fn and_then(self, next: impl FnOnce() -> Self) -> Self;
+}
+
+impl VisitResult for () {
+    fn empty() -> Self {}
+
+    fn and_then(self, next: impl FnOnce() -> Self) -> Self {
+        next()
+    }
+}
+
+/// Visiting stops at the first error
+impl<E> VisitResult for Result<(), E> {
+    fn empty() -> Self {
+        Ok(())
+    }
+
+    fn and_then(self, next: impl FnOnce() -> Self) -> Self {
+        self.and_then(|_| next())
+    }
+}
+
 /// Trait implementing the visitor pattern for traversing the AST
 ///
 /// This trait allows implementing different behaviors when traversing
 /// the AST, such as type checking, interpretation, or compilation.
 ///
 /// The generic parameter T represents the return type of the visit methods.
-pub trait Visitor<T> {
+///
+/// Every method has a default implementation that visits the children of the node, so a
+/// visitor only has to override the methods for the nodes it is interested in.
+///
+/// ### Example
+/// ```
+/// use slang_ir::Visitor;
+/// use slang_ir::ast::{BinaryExpr, BinaryOperator, Expression, LiteralExpr, LiteralValue};
+/// use slang_ir::Location;
+/// use slang_types::TypeId;
+/// use std::cell::Cell;
+///
+/// #[derive(Default)]
+/// struct LiteralCounter {
+///     count: usize,
+/// }
+///
+/// impl Visitor<()> for LiteralCounter {
+///     fn visit_literal_expression(&mut self, _expr: &LiteralExpr) {
+///         self.count += 1;
+///     }
+/// }
+///
+/// let literal = |value| {
+///     Box::new(Expression::Literal(LiteralExpr {
+///         value: LiteralValue::UnspecifiedInteger(value),
+///         expr_type: Cell::new(TypeId::unspecified_int()),
+///         location: Location::default(),
+///     }))
+/// };
+/// let sum = Expression::Binary(BinaryExpr {
+///     left: literal(1),
+///     operator: BinaryOperator::Add,
+///     right: literal(2),
+///     expr_type: TypeId::unspecified_int(),
+///     location: Location::default(),
+/// });
+///
+/// let mut counter = LiteralCounter::default();
+/// counter.visit_expression(&sum);
+/// assert_eq!(counter.count, 2);
+/// ```
+pub trait Visitor<T: VisitResult> {
     /// Visit a general statement
     fn visit_statement(&mut self, stmt: &Statement) -> T {
         match stmt {
@@ -18,31 +98,61 @@ pub trait Visitor<T> {
             Statement::Assignment(assign_stmt) => self.visit_assignment_statement(assign_stmt),
             Statement::Expression(expr) => self.visit_expression_statement(expr),
             Statement::TypeDefinition(type_def) => self.visit_type_definition_statement(type_def),
+            Statement::EnumDefinition(enum_def) => self.visit_enum_definition_statement(enum_def),
+            Statement::Impl(impl_stmt) => self.visit_impl_statement(impl_stmt),
             Statement::FunctionDeclaration(fn_decl) => {
                 self.visit_function_declaration_statement(fn_decl)
             }
             Statement::Return(return_stmt) => self.visit_return_statement(return_stmt),
             Statement::If(if_stmt) => self.visit_if_statement(if_stmt),
+            Statement::For(for_stmt) => self.visit_for_statement(for_stmt),
         }
     }
 
     /// Visit an expression statement
-    fn visit_expression_statement(&mut self, expr: &Expression) -> T;
+    fn visit_expression_statement(&mut self, expr: &Expression) -> T {
+        self.visit_expression(expr)
+    }
 
     /// Visit a variable declaration statement
-    fn visit_let_statement(&mut self, stmt: &LetStatement) -> T;
+    fn visit_let_statement(&mut self, stmt: &LetStatement) -> T {
+        self.visit_expression(&stmt.value)
+    }
 
     /// Visit a type definition statement
-    fn visit_type_definition_statement(&mut self, stmt: &TypeDefinitionStmt) -> T;
+    fn visit_type_definition_statement(&mut self, _stmt: &TypeDefinitionStmt) -> T {
+        T::empty()
+    }
+
+    /// Visit an enum definition statement
+    fn visit_enum_definition_statement(&mut self, _stmt: &EnumDefinitionStmt) -> T {
+        T::empty()
+    }
+
+    /// Visit the method declarations of a type
+    fn visit_impl_statement(&mut self, stmt: &ImplStmt) -> T {
+        stmt.methods.iter().fold(T::empty(), |result, method| {
+            result.and_then(|| self.visit_function_declaration_statement(method))
+        })
+    }
 
     /// Visit a function declaration statement
-    fn visit_function_declaration_statement(&mut self, stmt: &FunctionDeclarationStmt) -> T;
+    fn visit_function_declaration_statement(&mut self, stmt: &FunctionDeclarationStmt) -> T {
+        self.visit_block_expression(&stmt.body)
+    }
 
     /// Visit a return statement
-    fn visit_return_statement(&mut self, stmt: &ReturnStatement) -> T;
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement) -> T {
+        match &stmt.value {
+            Some(value) => self.visit_expression(value),
+            None => T::empty(),
+        }
+    }
 
     /// Visit a variable assignment statement
-    fn visit_assignment_statement(&mut self, stmt: &AssignmentStatement) -> T;
+    fn visit_assignment_statement(&mut self, stmt: &AssignmentStatement) -> T {
+        self.visit_expression(&stmt.value)
+    }
 
     /// Visit a general expression
     fn visit_expression(&mut self, expr: &Expression) -> T {
@@ -52,36 +162,94 @@ pub trait Visitor<T> {
             Expression::Variable(var) => self.visit_variable_expression(var),
             Expression::Unary(unary) => self.visit_unary_expression(unary),
             Expression::Call(call) => self.visit_call_expression(call),
+            Expression::MethodCall(method_call) => self.visit_method_call_expression(method_call),
             Expression::Conditional(cond) => self.visit_conditional_expression(cond),
             Expression::Block(block) => self.visit_block_expression(block),
             Expression::FunctionType(func_type) => self.visit_function_type_expression(func_type),
+            Expression::Closure(closure) => self.visit_closure_expression(closure),
         }
     }
 
     /// Visit a binary expression (e.g., a + b)
-    fn visit_binary_expression(&mut self, expr: &BinaryExpr) -> T;
+    fn visit_binary_expression(&mut self, expr: &BinaryExpr) -> T {
+        self.visit_expression(&expr.left)
+            .and_then(|| self.visit_expression(&expr.right))
+    }
 
     /// Visit a unary expression (e.g., -x)
-    fn visit_unary_expression(&mut self, expr: &UnaryExpr) -> T;
+    fn visit_unary_expression(&mut self, expr: &UnaryExpr) -> T {
+        self.visit_expression(&expr.right)
+    }
 
     /// Visit a literal expression (e.g., 42, "hello")
-    fn visit_literal_expression(&mut self, expr: &LiteralExpr) -> T;
+    fn visit_literal_expression(&mut self, _expr: &LiteralExpr) -> T {
+        T::empty()
+    }
 
     /// Visit a variable reference expression
-    fn visit_variable_expression(&mut self, var_expr: &VariableExpr) -> T;
+    fn visit_variable_expression(&mut self, _var_expr: &VariableExpr) -> T {
+        T::empty()
+    }
 
     /// Visit a function call expression
-    fn visit_call_expression(&mut self, expr: &FunctionCallExpr) -> T;
+    fn visit_call_expression(&mut self, expr: &FunctionCallExpr) -> T {
+        expr.arguments.iter().fold(T::empty(), |result, argument| {
+            result.and_then(|| self.visit_expression(argument))
+        })
+    }
+
+    /// Visit a method call expression (e.g., color.name())
+    fn visit_method_call_expression(&mut self, expr: &MethodCallExpr) -> T {
+        expr.arguments
+            .iter()
+            .fold(self.visit_expression(&expr.receiver), |result, argument| {
+                result.and_then(|| self.visit_expression(argument))
+            })
+    }
 
     /// Visit a conditional expression (if/else)
-    fn visit_conditional_expression(&mut self, expr: &ConditionalExpr) -> T;
+    fn visit_conditional_expression(&mut self, expr: &ConditionalExpr) -> T {
+        self.visit_expression(&expr.condition)
+            .and_then(|| self.visit_expression(&expr.then_branch))
+            .and_then(|| self.visit_expression(&expr.else_branch))
+    }
 
     /// Visit a block expression
-    fn visit_block_expression(&mut self, expr: &BlockExpr) -> T;
+    fn visit_block_expression(&mut self, expr: &BlockExpr) -> T {
+        let result = expr.statements.iter().fold(T::empty(), |result, stmt| {
+            result.and_then(|| self.visit_statement(stmt))
+        });
+        match &expr.return_expr {
+            Some(return_expr) => result.and_then(|| self.visit_expression(return_expr)),
+            None => result,
+        }
+    }
 
     /// Visit a function type expression (e.g., fn(i32, string) -> string)
-    fn visit_function_type_expression(&mut self, expr: &FunctionTypeExpr) -> T;
+    fn visit_function_type_expression(&mut self, _expr: &FunctionTypeExpr) -> T {
+        T::empty()
+    }
+
+    /// Visit a closure expression (e.g., |x: i32| -> i32 { x + y })
+    fn visit_closure_expression(&mut self, expr: &ClosureExpr) -> T {
+        self.visit_block_expression(&expr.body)
+    }
 
     /// Visit a conditional statement (if/else)
-    fn visit_if_statement(&mut self, stmt: &IfStatement) -> T;
+    fn visit_if_statement(&mut self, stmt: &IfStatement) -> T {
+        let result = self
+            .visit_expression(&stmt.condition)
+            .and_then(|| self.visit_block_expression(&stmt.then_branch));
+        match &stmt.else_branch {
+            Some(else_branch) => result.and_then(|| self.visit_block_expression(else_branch)),
+            None => result,
+        }
+    }
+
+    /// Visit a loop over a range of integers
+    fn visit_for_statement(&mut self, stmt: &ForStatement) -> T {
+        self.visit_expression(&stmt.start)
+            .and_then(|| self.visit_expression(&stmt.end))
+            .and_then(|| self.visit_block_expression(&stmt.body))
+    }
 }
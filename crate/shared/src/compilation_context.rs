@@ -1,8 +1,49 @@
+use crate::diagnostic_engine::Diagnostic;
 use crate::symbol_table::SymbolData;
-use crate::{Symbol, SymbolKind, SymbolTable};
+use crate::{FunctionOverload, ModuleContext, Symbol, SymbolKind, SymbolTable};
+use slang_error::{ErrorCode, Severity};
+use slang_ir::Location;
+use slang_ir::ast::LiteralValue;
 use slang_types::{
-    FunctionType, PrimitiveType, StructType, TypeId, TypeInfo, TypeKind, TypeRegistry,
+    EnumType, FunctionType, PrimitiveType, StructType, TypeId, TypeInfo, TypeKind, TypeRegistry,
 };
+use std::collections::HashSet;
+
+/// The signature of a function that is visible to a program, with its types rendered as text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionSignature {
+    /// The name under which the function is called
+    pub name: String,
+    /// The formatted types of the parameters
+    pub param_types: Vec<String>,
+    /// The formatted return type
+    pub return_type: String,
+    /// Whether calls may pass further arguments after the declared parameters
+    pub is_variadic: bool,
+}
+
+impl FunctionSignature {
+    /// Returns the number of declared parameters
+    pub fn arity(&self) -> usize {
+        self.param_types.len()
+    }
+}
+
+impl std::fmt::Display for FunctionSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut params = self.param_types.clone();
+        if self.is_variadic {
+            params.push("...".to_string());
+        }
+        write!(
+            f,
+            "fn {}({}) -> {}",
+            self.name,
+            params.join(", "),
+            self.return_type
+        )
+    }
+}
 
 /// Compilation context that owns the type registry and symbol table
 pub struct CompilationContext {
@@ -10,6 +51,18 @@ pub struct CompilationContext {
     type_registry: TypeRegistry,
     /// The symbol table that stores all symbols (variables, types, functions)
     symbol_table: SymbolTable,
+    /// Whether defining a variable that shadows one from an enclosing scope emits a warning
+    warn_on_shadowing: bool,
+    /// Whether comparing floating-point values with `==` or `!=` emits a warning
+    warn_on_float_equality: bool,
+    /// Name of the function the program must define as its entry point, if any
+    entry_point: Option<String>,
+    /// Warnings and notes collected during analysis that don't prevent compilation
+    warnings: Vec<Diagnostic>,
+    /// Names of native functions that accept additional arguments after their fixed parameters
+    native_variadic_functions: HashSet<String>,
+    /// Struct types whose names were declared but whose fields haven't been defined yet
+    declared_struct_types: HashSet<TypeId>,
 }
 
 impl Default for CompilationContext {
@@ -48,6 +101,8 @@ impl CompilationContext {
         define_primitive(PrimitiveType::I64);
         define_primitive(PrimitiveType::U32);
         define_primitive(PrimitiveType::U64);
+        define_primitive(PrimitiveType::I128);
+        define_primitive(PrimitiveType::U128);
         define_primitive(PrimitiveType::F32);
         define_primitive(PrimitiveType::F64);
         define_primitive(PrimitiveType::String);
@@ -58,9 +113,46 @@ impl CompilationContext {
         CompilationContext {
             type_registry,
             symbol_table,
+            warn_on_shadowing: false,
+            warn_on_float_equality: false,
+            entry_point: None,
+            warnings: Vec::new(),
+            native_variadic_functions: HashSet::new(),
+            declared_struct_types: HashSet::new(),
         }
     }
 
+    /// Removes all user-defined types, functions and variables, keeping the primitive types
+    ///
+    /// This resets the context to a fresh session, for example when a REPL session is
+    /// restarted. Settings like shadowing warnings are kept. Native functions are
+    /// registered again by the next semantic analysis.
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_shared::{CompilationContext, SymbolKind};
+    /// use slang_types::TypeId;
+    ///
+    /// let mut context = CompilationContext::new();
+    /// let point = context
+    ///     .register_struct_type("Point".to_string(), vec![("x".to_string(), TypeId::i32())])
+    ///     .unwrap();
+    ///
+    /// context.clear_user_types();
+    ///
+    /// assert!(context.lookup_symbol("Point").is_none());
+    /// assert!(context.get_type_info(&point).is_none());
+    /// assert_eq!(context.lookup_symbol("i32").unwrap().kind(), SymbolKind::Type);
+    /// assert_eq!(context.lookup_symbol("i32").unwrap().type_id, TypeId::i32());
+    /// ```
+    pub fn clear_user_types(&mut self) {
+        let fresh = CompilationContext::new();
+        self.type_registry = fresh.type_registry;
+        self.symbol_table = fresh.symbol_table;
+        self.native_variadic_functions.clear();
+        self.declared_struct_types.clear();
+    }
+
     /// Gets type information for a given type ID
     ///
     /// ### Arguments
@@ -86,6 +178,52 @@ impl CompilationContext {
             .unwrap_or_else(|| format!("UnknownTypeId({:?})", type_id.0))
     }
 
+    /// Formats a type for display in diagnostics
+    ///
+    /// Primitive and struct types are rendered by name, function types are rendered as
+    /// `fn(a, b) -> c` with their parameter and return types formatted recursively.
+    ///
+    /// ### Arguments
+    /// * `type_id` - The type ID to format
+    ///
+    /// ### Returns
+    /// The formatted type, or a debug representation if the type is unknown
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_shared::CompilationContext;
+    /// use slang_types::TypeId;
+    ///
+    /// let mut context = CompilationContext::new();
+    /// let callback = context.register_function_type(vec![TypeId::i32()], TypeId::bool());
+    /// assert_eq!(context.format_type(&callback), "fn(i32) -> bool");
+    ///
+    /// let higher_order =
+    ///     context.register_function_type(vec![callback, TypeId::string()], callback);
+    /// assert_eq!(
+    ///     context.format_type(&higher_order),
+    ///     "fn(fn(i32) -> bool, string) -> fn(i32) -> bool"
+    /// );
+    /// assert_eq!(context.format_type(&TypeId::f64()), "f64");
+    /// ```
+    pub fn format_type(&self, type_id: &TypeId) -> String {
+        match self.get_function_type(type_id) {
+            Some(function_type) => {
+                let params = function_type
+                    .param_types
+                    .iter()
+                    .map(|param| self.format_type(param))
+                    .collect::<Vec<_>>();
+                format!(
+                    "fn({}) -> {}",
+                    params.join(", "),
+                    self.format_type(&function_type.return_type)
+                )
+            }
+            None => self.get_type_name(type_id),
+        }
+    }
+
     /// Gets the primitive type corresponding to a given type ID
     ///
     /// ### Arguments
@@ -203,7 +341,7 @@ impl CompilationContext {
     ///
     /// ### Returns
     /// True if the value is within the valid range for the type, false otherwise
-    pub fn check_value_in_range(&self, value: &i64, type_id: &TypeId) -> bool {
+    pub fn check_value_in_range(&self, value: &i128, type_id: &TypeId) -> bool {
         self.type_registry.check_value_in_range(value, type_id)
     }
 
@@ -220,6 +358,65 @@ impl CompilationContext {
             .check_float_value_in_range(value, type_id)
     }
 
+    /// Checks whether a value of one type can be used where another type is expected
+    ///
+    /// Besides identical types, unspecified integer literals coerce to every integer type
+    /// and unspecified float literals to every float type. If the literal's value is known,
+    /// it also has to be in range for the target type.
+    ///
+    /// ### Arguments
+    /// * `from` - The type of the value
+    /// * `to` - The expected type
+    /// * `value` - The literal value, if the value is a literal
+    ///
+    /// ### Returns
+    /// True if the value can be coerced to the expected type, false otherwise
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_ir::ast::LiteralValue;
+    /// use slang_shared::CompilationContext;
+    /// use slang_types::TypeId;
+    ///
+    /// let context = CompilationContext::new();
+    /// let int = TypeId::unspecified_int();
+    /// let float = TypeId::unspecified_float();
+    ///
+    /// assert!(context.can_coerce(&int, &TypeId::i32(), None));
+    /// assert!(context.can_coerce(&int, &TypeId::i32(), Some(&LiteralValue::UnspecifiedInteger(42))));
+    /// assert!(!context.can_coerce(&int, &TypeId::i32(), Some(&LiteralValue::UnspecifiedInteger(1 << 40))));
+    /// assert!(!context.can_coerce(&int, &TypeId::u64(), Some(&LiteralValue::UnspecifiedInteger(-1))));
+    ///
+    /// assert!(context.can_coerce(&float, &TypeId::f32(), Some(&LiteralValue::UnspecifiedFloat(1.5))));
+    /// assert!(!context.can_coerce(&float, &TypeId::f32(), Some(&LiteralValue::UnspecifiedFloat(3.5e40))));
+    ///
+    /// assert!(!context.can_coerce(&int, &TypeId::f64(), None));
+    /// assert!(!context.can_coerce(&float, &TypeId::i64(), None));
+    /// assert!(!context.can_coerce(&TypeId::i32(), &TypeId::i64(), None));
+    /// assert!(!context.can_coerce(&TypeId::string(), &TypeId::bool(), None));
+    /// ```
+    pub fn can_coerce(&self, from: &TypeId, to: &TypeId, value: Option<&LiteralValue>) -> bool {
+        if from == to {
+            return true;
+        }
+
+        if *from == TypeId::unspecified_int() && self.is_integer_type(to) {
+            return match value {
+                Some(LiteralValue::UnspecifiedInteger(n)) => self.check_value_in_range(n, to),
+                _ => true,
+            };
+        }
+
+        if *from == TypeId::unspecified_float() && self.is_float_type(to) {
+            return match value {
+                Some(LiteralValue::UnspecifiedFloat(f)) => self.check_float_value_in_range(f, to),
+                _ => true,
+            };
+        }
+
+        false
+    }
+
     /// Defines a symbol in the symbol table
     ///
     /// ### Arguments
@@ -227,6 +424,7 @@ impl CompilationContext {
     /// * `kind` - The kind of symbol (variable, type, function)
     /// * `type_id` - The type ID associated with the symbol
     /// * `is_mutable` - Whether the symbol is mutable (only relevant for variables)
+    /// * `location` - The source location of the definition, if the symbol is defined in source
     ///
     /// ### Returns
     /// A Result indicating success or an error message if the symbol cannot be defined
@@ -236,13 +434,280 @@ impl CompilationContext {
         kind: SymbolKind,
         type_id: TypeId,
         is_mutable: bool,
+        location: Option<Location>,
     ) -> Result<(), String> {
         let data = match kind {
             SymbolKind::Type => SymbolData::Type,
             SymbolKind::Variable => SymbolData::Variable { is_mutable },
             SymbolKind::Function => SymbolData::Function,
         };
-        self.symbol_table.define(name, data, type_id)
+        self.symbol_table
+            .define_with_location(name, data, type_id, location)
+    }
+
+    /// Defines a variable in the current scope, recording where it was defined
+    ///
+    /// If shadowing warnings are enabled and the name refers to a variable in an
+    /// enclosing scope, a warning pointing at both definitions is collected.
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the variable
+    /// * `type_id` - The type ID of the variable
+    /// * `is_mutable` - Whether the variable is mutable
+    /// * `location` - The source location of the definition
+    ///
+    /// ### Returns
+    /// A Result indicating success or an error message if the variable cannot be defined
+    pub fn define_variable(
+        &mut self,
+        name: String,
+        type_id: TypeId,
+        is_mutable: bool,
+        location: Location,
+    ) -> Result<(), String> {
+        let shadowed_location = if self.warn_on_shadowing {
+            self.symbol_table
+                .lookup_enclosing(&name)
+                .filter(|symbol| symbol.is_variable())
+                .map(|symbol| symbol.definition_location)
+        } else {
+            None
+        };
+
+        self.symbol_table.define_with_location(
+            name.clone(),
+            SymbolData::Variable { is_mutable },
+            type_id,
+            Some(location),
+        )?;
+
+        if let Some(shadowed_location) = shadowed_location {
+            self.emit_shadowing_warning(&name, location, shadowed_location);
+        }
+        Ok(())
+    }
+
+    /// Defines a native function that accepts any number of additional arguments
+    ///
+    /// The function type only describes the fixed parameters; calls may pass any number
+    /// of further arguments of any type after them.
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the native function
+    /// * `param_types` - The types of the fixed leading parameters
+    /// * `return_type` - The return type of the function
+    ///
+    /// ### Returns
+    /// A Result indicating success or an error message if the name is already defined
+    pub fn define_variadic_native_function(
+        &mut self,
+        name: &str,
+        param_types: Vec<TypeId>,
+        return_type: TypeId,
+    ) -> Result<(), String> {
+        let function_type_id = self.register_function_type(param_types, return_type);
+        self.symbol_table
+            .define(name.to_string(), SymbolData::Function, function_type_id)?;
+        self.native_variadic_functions.insert(name.to_string());
+        Ok(())
+    }
+
+    /// Checks whether a name resolves to a variadic native function
+    ///
+    /// User-declared functions are never variadic, so a declaration that hides a
+    /// variadic native with the same name makes this return false.
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the function
+    ///
+    /// ### Returns
+    /// True if calls to this name may pass more arguments than the function declares
+    pub fn is_variadic_function(&self, name: &str) -> bool {
+        self.native_variadic_functions.contains(name)
+            && self.symbol_table.lookup_function_overloads(name).is_none()
+            && self
+                .symbol_table
+                .lookup(name)
+                .is_some_and(|symbol| symbol.is_function())
+    }
+
+    /// Defines a function in the current scope, allowing overloads that differ in arity
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the function
+    /// * `type_id` - The function type of this signature
+    /// * `arity` - The number of parameters of this signature
+    /// * `location` - The source location of the definition
+    ///
+    /// ### Returns
+    /// A Result indicating success or an error message if the function cannot be defined
+    pub fn define_function(
+        &mut self,
+        name: String,
+        type_id: TypeId,
+        arity: usize,
+        location: Location,
+    ) -> Result<(), String> {
+        self.symbol_table
+            .define_function(name, type_id, arity, Some(location))
+    }
+
+    /// Gives access to the symbols of a module, which are stored under qualified names
+    ///
+    /// ### Arguments
+    /// * `module` - The name of the module
+    ///
+    /// ### Returns
+    /// A ModuleContext for defining and re-exporting symbols of the module
+    pub fn module(&mut self, module: &str) -> ModuleContext<'_> {
+        ModuleContext::new(self, module)
+    }
+
+    /// Collects a warning about a variable shadowing one from an enclosing scope
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the shadowing variable
+    /// * `location` - The location of the shadowing definition
+    /// * `shadowed_location` - The location of the shadowed definition, if known
+    fn emit_shadowing_warning(
+        &mut self,
+        name: &str,
+        location: Location,
+        shadowed_location: Option<Location>,
+    ) {
+        let mut related = Vec::new();
+        let message = match shadowed_location {
+            Some(shadowed) => {
+                related.push(Diagnostic {
+                    severity: Severity::Note,
+                    error_code: ErrorCode::VariableShadowing,
+                    message: format!("Variable '{}' was previously defined here", name),
+                    location: shadowed,
+                    suggestions: Vec::new(),
+                    related: Vec::new(),
+                    phase: None,
+                });
+                format!(
+                    "Variable '{}' shadows a variable defined at line {}, column {}",
+                    name, shadowed.line, shadowed.column
+                )
+            }
+            None => format!("Variable '{}' shadows a variable from an outer scope", name),
+        };
+
+        self.warnings.push(Diagnostic {
+            severity: Severity::Warning,
+            error_code: ErrorCode::VariableShadowing,
+            message,
+            location,
+            suggestions: Vec::new(),
+            related,
+            phase: None,
+        });
+    }
+
+    /// Enables or disables warnings for variables that shadow outer variables
+    ///
+    /// Shadowing warnings are disabled by default.
+    ///
+    /// ### Arguments
+    /// * `enabled` - Whether to emit shadowing warnings
+    pub fn set_warn_on_shadowing(&mut self, enabled: bool) {
+        self.warn_on_shadowing = enabled;
+    }
+
+    /// Checks if shadowing warnings are enabled
+    ///
+    /// ### Returns
+    /// True if defining a shadowing variable emits a warning, false otherwise
+    pub fn warns_on_shadowing(&self) -> bool {
+        self.warn_on_shadowing
+    }
+
+    /// Enables or disables warnings for comparing floating-point values with `==` or `!=`
+    ///
+    /// Float equality warnings are disabled by default.
+    ///
+    /// ### Arguments
+    /// * `enabled` - Whether to emit float equality warnings
+    pub fn set_warn_on_float_equality(&mut self, enabled: bool) {
+        self.warn_on_float_equality = enabled;
+    }
+
+    /// Checks if float equality warnings are enabled
+    ///
+    /// ### Returns
+    /// True if comparing floats with `==` or `!=` emits a warning, false otherwise
+    pub fn warns_on_float_equality(&self) -> bool {
+        self.warn_on_float_equality
+    }
+
+    /// Requires the program to define a function that serves as its entry point
+    ///
+    /// No entry point is required by default, in which case only the top-level
+    /// statements of the program are run.
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the entry point function, or None to not require one
+    pub fn set_entry_point(&mut self, name: Option<String>) {
+        self.entry_point = name;
+    }
+
+    /// Returns the name of the function the program must define as its entry point
+    ///
+    /// ### Returns
+    /// The name of the entry point function, or None if no entry point is required
+    pub fn entry_point(&self) -> Option<&str> {
+        self.entry_point.as_deref()
+    }
+
+    /// Collects a warning that doesn't prevent compilation
+    ///
+    /// ### Arguments
+    /// * `error_code` - The code identifying the kind of warning
+    /// * `message` - The text of the warning
+    /// * `location` - The source location the warning refers to
+    pub fn add_warning(&mut self, error_code: ErrorCode, message: String, location: Location) {
+        self.warnings.push(Diagnostic {
+            severity: Severity::Warning,
+            error_code,
+            message,
+            location,
+            suggestions: Vec::new(),
+            related: Vec::new(),
+            phase: None,
+        });
+    }
+
+    /// Collects a note that is reported together with the warnings
+    ///
+    /// ### Arguments
+    /// * `error_code` - The code identifying the kind of note
+    /// * `message` - The text of the note
+    /// * `location` - The source location the note refers to
+    pub fn add_note(&mut self, error_code: ErrorCode, message: String, location: Location) {
+        self.warnings.push(Diagnostic {
+            severity: Severity::Note,
+            error_code,
+            message,
+            location,
+            suggestions: Vec::new(),
+            related: Vec::new(),
+            phase: None,
+        });
+    }
+
+    /// Returns the warnings collected so far
+    pub fn warnings(&self) -> &[Diagnostic] {
+        &self.warnings
+    }
+
+    /// Removes and returns all collected warnings
+    ///
+    /// ### Returns
+    /// A vector containing all warnings collected since the last call
+    pub fn take_warnings(&mut self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.warnings)
     }
 
     /// Looks up a symbol in the symbol table by name
@@ -256,6 +721,195 @@ impl CompilationContext {
         self.symbol_table.lookup(name)
     }
 
+    /// Looks up a symbol in the symbol table by name for modification
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the symbol to look up
+    ///
+    /// ### Returns
+    /// An optional mutable reference to the Symbol if found, None otherwise
+    pub fn lookup_symbol_mut(&mut self, name: &str) -> Option<&mut Symbol> {
+        self.symbol_table.lookup_mut(name)
+    }
+
+    /// Marks the symbol a name resolves to as used
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the symbol
+    ///
+    /// ### Returns
+    /// True if the name resolved to a symbol, false otherwise
+    pub fn mark_symbol_used(&mut self, name: &str) -> bool {
+        self.symbol_table.mark_used(name)
+    }
+
+    /// Finds where a symbol, or one overload of a function, was defined
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the symbol
+    /// * `arity` - The arity of the overload to look for, if the symbol is a function
+    ///
+    /// ### Returns
+    /// The location of the definition, or None if the symbol is unknown or its location wasn't recorded
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_ir::Location;
+    /// use slang_shared::CompilationContext;
+    /// use slang_types::TypeId;
+    ///
+    /// let mut context = CompilationContext::new();
+    /// let function_type = context.register_function_type(vec![], TypeId::unit());
+    /// let location = Location::new(3, 1, 4, 1);
+    /// context.define_function("f".to_string(), function_type, 0, location).unwrap();
+    ///
+    /// let found = context.lookup_definition_location("f", Some(0)).unwrap();
+    /// assert_eq!((found.line, found.column), (1, 4));
+    /// assert!(context.lookup_definition_location("g", None).is_none());
+    /// ```
+    pub fn lookup_definition_location(&self, name: &str, arity: Option<usize>) -> Option<Location> {
+        let overload = arity.and_then(|arity| {
+            self.lookup_function_overloads(name)?
+                .iter()
+                .find(|overload| overload.arity == arity)
+        });
+        match overload {
+            Some(overload) => overload.symbol.definition_location,
+            None => self.lookup_symbol(name)?.definition_location,
+        }
+    }
+
+    /// Returns all symbols visible from the current scope, starting from innermost
+    ///
+    /// Shadowed names are only reported once, with their innermost binding.
+    ///
+    /// ### Returns
+    /// An iterator over the visible symbols, from the innermost scope outward
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_shared::{CompilationContext, SymbolKind};
+    /// use slang_types::TypeId;
+    ///
+    /// let mut context = CompilationContext::new();
+    /// context.begin_scope();
+    /// context.define_symbol("local".to_string(), SymbolKind::Variable, TypeId::i32(), false, None).unwrap();
+    /// assert!(context.all_symbols_in_scope().any(|symbol| symbol.name == "local"));
+    ///
+    /// context.begin_function_scope();
+    /// assert!(!context.all_symbols_in_scope().any(|symbol| symbol.name == "local"));
+    /// ```
+    pub fn all_symbols_in_scope(&self) -> impl Iterator<Item = &Symbol> + '_ {
+        self.symbol_table.all_in_scope()
+    }
+
+    /// Finds the visible type name that is closest to a misspelled one
+    ///
+    /// Names are compared by their edit distance, where inserting, removing, replacing or
+    /// swapping two adjacent characters each count as one edit. The placeholder types of
+    /// unsuffixed literals are never suggested.
+    ///
+    /// ### Arguments
+    /// * `name` - The unknown type name
+    ///
+    /// ### Returns
+    /// The closest type name within an edit distance of 2, or None if no type is close enough
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_shared::CompilationContext;
+    ///
+    /// let context = CompilationContext::new();
+    /// assert_eq!(context.closest_type_name("i23"), Some("i32"));
+    /// assert_eq!(context.closest_type_name("strin"), Some("string"));
+    /// assert_eq!(context.closest_type_name("Rectangle"), None);
+    /// ```
+    pub fn closest_type_name(&self, name: &str) -> Option<&str> {
+        const MAX_DISTANCE: usize = 2;
+        let placeholders = [
+            TypeId::unspecified_int(),
+            TypeId::unspecified_float(),
+            TypeId::unknown(),
+        ];
+
+        self.all_symbols_in_scope()
+            .filter(|symbol| symbol.is_type() && !placeholders.contains(&symbol.type_id))
+            .map(|symbol| (edit_distance(name, &symbol.name), symbol.name.as_str()))
+            .filter(|(distance, _)| *distance <= MAX_DISTANCE)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, candidate)| candidate)
+    }
+
+    /// Looks up all signatures of a possibly overloaded function by name
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the function to look up
+    ///
+    /// ### Returns
+    /// The overloads of the function, or None if the name doesn't resolve to a declared function
+    pub fn lookup_function_overloads(&self, name: &str) -> Option<&[FunctionOverload]> {
+        self.symbol_table.lookup_function_overloads(name)
+    }
+
+    /// Lists the signatures of all functions visible from the current scope
+    ///
+    /// Every overload of a function name is listed separately. The signatures are ordered
+    /// by name and then by arity.
+    ///
+    /// ### Returns
+    /// The signatures of the native and declared functions
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_shared::CompilationContext;
+    /// use slang_types::TypeId;
+    ///
+    /// let mut context = CompilationContext::new();
+    /// let add = context.register_function_type(vec![TypeId::i32(), TypeId::i32()], TypeId::i32());
+    /// context.define_function("add".to_string(), add, 2, Default::default()).unwrap();
+    /// let greet = context.register_function_type(vec![TypeId::string()], TypeId::unit());
+    /// context.define_function("greet".to_string(), greet, 1, Default::default()).unwrap();
+    ///
+    /// let add3 = context.register_function_type(vec![TypeId::i64(); 3], TypeId::i64());
+    /// context.define_function("add".to_string(), add3, 3, Default::default()).unwrap();
+    ///
+    /// let signatures = context.function_signatures();
+    /// assert_eq!(signatures.len(), 3);
+    /// assert_eq!(signatures[0].name, "add");
+    /// assert_eq!(signatures[0].arity(), 2);
+    /// assert_eq!(signatures[0].to_string(), "fn add(i32, i32) -> i32");
+    /// assert_eq!(signatures[1].to_string(), "fn add(i64, i64, i64) -> i64");
+    /// assert_eq!(signatures[2].arity(), 1);
+    /// assert_eq!(signatures[2].to_string(), "fn greet(string) -> ()");
+    /// ```
+    pub fn function_signatures(&self) -> Vec<FunctionSignature> {
+        let mut signatures: Vec<FunctionSignature> = self
+            .all_symbols_in_scope()
+            .filter(|symbol| symbol.is_function())
+            .flat_map(
+                |symbol| match self.lookup_function_overloads(&symbol.name) {
+                    Some(overloads) => overloads.iter().map(|overload| &overload.symbol).collect(),
+                    None => vec![symbol],
+                },
+            )
+            .filter_map(|symbol| {
+                let function_type = self.get_function_type(&symbol.type_id)?;
+                Some(FunctionSignature {
+                    name: symbol.name.clone(),
+                    param_types: function_type
+                        .param_types
+                        .iter()
+                        .map(|param| self.format_type(param))
+                        .collect(),
+                    return_type: self.format_type(&function_type.return_type),
+                    is_variadic: self.is_variadic_function(&symbol.name),
+                })
+            })
+            .collect();
+        signatures.sort_by(|a, b| a.name.cmp(&b.name).then(a.arity().cmp(&b.arity())));
+        signatures
+    }
+
     /// Registers a custom type with the given name and type kind
     ///
     /// ### Arguments
@@ -287,6 +941,25 @@ impl CompilationContext {
     ///
     /// ### Returns
     /// A Result containing the TypeId of the registered struct type or an error message
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_shared::CompilationContext;
+    /// use slang_types::TypeId;
+    ///
+    /// // Type ids are assigned per compilation, so compiling twice yields the same ids
+    /// let compile = || {
+    ///     let mut context = CompilationContext::new();
+    ///     let point = context
+    ///         .register_struct_type("Point".to_string(), vec![("x".to_string(), TypeId::i32())])
+    ///         .unwrap();
+    ///     let line = context
+    ///         .register_struct_type("Line".to_string(), vec![("start".to_string(), point)])
+    ///         .unwrap();
+    ///     (point, line)
+    /// };
+    /// assert_eq!(compile(), compile());
+    /// ```
     pub fn register_struct_type(
         &mut self,
         name: String,
@@ -297,6 +970,125 @@ impl CompilationContext {
         self.register_custom_type(&name, type_kind)
     }
 
+    /// Declares a struct type by name before its fields are known
+    ///
+    /// This allows field types to refer to the struct itself. The fields are
+    /// supplied later with `define_struct_fields`.
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the struct type
+    ///
+    /// ### Returns
+    /// A Result containing the TypeId of the declared struct type or an error message
+    pub fn declare_struct_type(&mut self, name: &str) -> Result<TypeId, String> {
+        let type_id = self.register_struct_type(name.to_string(), Vec::new())?;
+        self.declared_struct_types.insert(type_id);
+        Ok(type_id)
+    }
+
+    /// Checks if a struct type was declared but its fields haven't been defined yet
+    pub fn is_declared_struct_type(&self, type_id: &TypeId) -> bool {
+        self.declared_struct_types.contains(type_id)
+    }
+
+    /// Defines the fields of a struct type declared with `declare_struct_type`
+    ///
+    /// ### Arguments
+    /// * `type_id` - The TypeId of the declared struct type
+    /// * `fields` - A vector of tuples containing field names and their type IDs
+    ///
+    /// ### Returns
+    /// An error message if the struct type wasn't declared or its fields are already defined
+    pub fn define_struct_fields(
+        &mut self,
+        type_id: &TypeId,
+        fields: Vec<(String, TypeId)>,
+    ) -> Result<(), String> {
+        if !self.declared_struct_types.remove(type_id) {
+            return Err(format!(
+                "Struct '{}' is not awaiting a definition.",
+                self.get_type_name(type_id)
+            ));
+        }
+        self.type_registry.set_struct_fields(type_id, fields);
+        Ok(())
+    }
+
+    /// Gets struct type information
+    pub fn get_struct_type(&self, type_id: &TypeId) -> Option<&StructType> {
+        self.type_registry.get_struct_type(type_id)
+    }
+
+    /// Gets the type of a field of a struct type
+    ///
+    /// ### Arguments
+    /// * `struct_id` - The TypeId of the struct type
+    /// * `field_name` - The name of the field
+    ///
+    /// ### Returns
+    /// The TypeId of the field, or None if the type isn't a struct or has no such field
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_shared::CompilationContext;
+    /// use slang_types::TypeId;
+    ///
+    /// let mut context = CompilationContext::new();
+    /// let fields = vec![
+    ///     ("x".to_string(), TypeId::i32()),
+    ///     ("label".to_string(), TypeId::string()),
+    /// ];
+    /// let point = context.register_struct_type("Point".to_string(), fields).unwrap();
+    ///
+    /// assert_eq!(context.get_struct_field_type(&point, "label"), Some(TypeId::string()));
+    /// assert_eq!(context.get_struct_field_type(&point, "y"), None);
+    /// assert_eq!(context.get_struct_field_type(&TypeId::i32(), "x"), None);
+    /// ```
+    pub fn get_struct_field_type(&self, struct_id: &TypeId, field_name: &str) -> Option<TypeId> {
+        self.get_struct_type(struct_id)?
+            .fields
+            .iter()
+            .find(|(name, _)| name == field_name)
+            .map(|(_, field_type)| *field_type)
+    }
+
+    /// Registers a new enum type with the given name and variants
+    ///
+    /// The variants are numbered in declaration order, starting at zero.
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the enum type
+    /// * `variants` - The names of the variants
+    ///
+    /// ### Returns
+    /// A Result containing the TypeId of the registered enum type or an error message
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_shared::CompilationContext;
+    ///
+    /// let mut context = CompilationContext::new();
+    /// let variants = vec!["Red".to_string(), "Green".to_string(), "Blue".to_string()];
+    /// let color = context.register_enum_type("Color".to_string(), variants).unwrap();
+    ///
+    /// let enum_type = context.get_enum_type(&color).unwrap();
+    /// assert_eq!(enum_type.discriminant("Blue"), Some(2));
+    /// assert!(context.register_enum_type("Color".to_string(), Vec::new()).is_err());
+    /// ```
+    pub fn register_enum_type(
+        &mut self,
+        name: String,
+        variants: Vec<String>,
+    ) -> Result<TypeId, String> {
+        let enum_type = EnumType::new(name.clone(), variants);
+        self.register_custom_type(&name, TypeKind::Enum(enum_type))
+    }
+
+    /// Gets enum type information
+    pub fn get_enum_type(&self, type_id: &TypeId) -> Option<&EnumType> {
+        self.type_registry.get_enum_type(type_id)
+    }
+
     /// Registers a function type and returns its TypeId
     pub fn register_function_type(
         &mut self,
@@ -335,9 +1127,54 @@ impl CompilationContext {
         self.symbol_table.begin_scope();
     }
 
+    /// Begins a new scope for a function body by calling the symbol table
+    /// Locals of enclosing scopes other than the global scope are hidden inside it.
+    pub fn begin_function_scope(&mut self) {
+        self.symbol_table.begin_function_scope();
+    }
+
     /// Ends the current scope by calling the symbol table
     /// Used when exiting a block, function, or other lexical scope.
     pub fn end_scope(&mut self) {
         self.symbol_table.end_scope();
     }
 }
+
+/// Computes the number of edits needed to turn one string into another
+///
+/// Inserting, removing or replacing a character and swapping two adjacent characters each
+/// count as a single edit.
+///
+/// ### Arguments
+/// * `a` - The first string
+/// * `b` - The second string
+///
+/// ### Returns
+/// The edit distance between both strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
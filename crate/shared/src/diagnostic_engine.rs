@@ -1,23 +1,12 @@
 use colored::Colorize;
-use slang_error::{CompilerError, ErrorCode, LineInfo};
+use slang_error::{CompilerError, ErrorCode, LineInfo, Phase, Severity, omitted_errors_message};
 use slang_ir::location::Location;
 
-/// Represents the severity level of a diagnostic message
-#[derive(Debug, Clone)]
-pub enum ErrorSeverity {
-    /// A compilation error that prevents successful compilation
-    Error,
-    /// A warning that doesn't prevent compilation but may indicate issues
-    Warning,
-    /// An informational note providing additional context
-    Note,
-}
-
 /// Represents a single diagnostic message with context and suggestions
 #[derive(Debug, Clone)]
 pub struct Diagnostic {
     /// The severity level of this diagnostic
-    pub severity: ErrorSeverity,
+    pub severity: Severity,
     /// The structured error code for this diagnostic
     pub error_code: ErrorCode,
     /// The human-readable message describing the issue
@@ -28,6 +17,27 @@ pub struct Diagnostic {
     pub suggestions: Vec<Suggestion>,
     /// Related diagnostics that provide additional context
     pub related: Vec<Diagnostic>,
+    /// The compilation phase that reported the diagnostic, if known
+    pub phase: Option<Phase>,
+}
+
+impl Diagnostic {
+    /// Checks whether two diagnostics report the same issue at the same location
+    ///
+    /// ### Arguments
+    /// * `other` - The diagnostic to compare with
+    ///
+    /// ### Returns
+    /// `true` if severity, error code, message and location are identical
+    fn is_duplicate_of(&self, other: &Diagnostic) -> bool {
+        self.severity == other.severity
+            && self.error_code == other.error_code
+            && self.message == other.message
+            && self.location.position == other.location.position
+            && self.location.line == other.location.line
+            && self.location.column == other.location.column
+            && self.location.length == other.location.length
+    }
 }
 
 /// Represents a suggestion for fixing a diagnostic issue
@@ -60,6 +70,7 @@ pub struct Suggestion {
 /// use slang_error::ErrorCode;
 /// use slang_ir::location::Location;
 ///
+/// let source_code = "let x = 42";
 /// let mut engine = DiagnosticEngine::new();
 /// engine.set_file_name("example.sl".to_string());
 /// engine.emit_error(
@@ -76,10 +87,12 @@ pub struct DiagnosticEngine<'a> {
     diagnostics: Vec<Diagnostic>,
     error_count: usize,
     warning_count: usize,
-    max_errors: usize,
+    max_errors: Option<usize>,
     recovery_mode: bool,
     file_name: Option<String>,
     source_text: Option<&'a str>,
+    tab_width: usize,
+    last_emitted: Option<usize>,
 }
 
 impl<'a> DiagnosticEngine<'a> {
@@ -99,51 +112,117 @@ impl<'a> DiagnosticEngine<'a> {
             diagnostics: Vec::new(),
             error_count: 0,
             warning_count: 0,
-            max_errors: 100,
+            max_errors: None,
             recovery_mode: false,
             file_name: None,
             source_text: None,
+            tab_width: 1,
+            last_emitted: None,
         }
     }
 
     /// Emits a diagnostic message to the engine
     ///
-    /// This is the core method for adding diagnostics. It handles error counting,
-    /// enforces error limits, and manages the diagnostic collection.
+    /// This is the core method for adding diagnostics. It handles error counting and
+    /// manages the diagnostic collection. Diagnostics are kept ordered by source position
+    /// (line, then column) independent of the phase that emitted them, and exact duplicates
+    /// are dropped. The error limit is applied when the diagnostics are read, so the
+    /// errors that come first in the source are kept.
     ///
     /// ### Arguments
     /// * `diagnostic` - The diagnostic to emit
     ///
     /// ### Example
     /// ```rust
-    /// use slang_shared::{DiagnosticEngine, Diagnostic, ErrorSeverity};
-    /// use slang_error::ErrorCode;
+    /// use slang_shared::{DiagnosticEngine, Diagnostic};
+    /// use slang_error::{ErrorCode, Severity};
     /// use slang_ir::location::Location;
     ///
     /// let mut engine = DiagnosticEngine::new();
     /// let diagnostic = Diagnostic {
-    ///     severity: ErrorSeverity::Error,
+    ///     severity: Severity::Error,
     ///     error_code: ErrorCode::ExpectedSemicolon,
     ///     message: "Missing semicolon".to_string(),
     ///     location: Location::new(42, 5, 10, 1),
     ///     suggestions: Vec::new(),
     ///     related: Vec::new(),
+    ///     phase: None,
     /// };
-    /// engine.emit(diagnostic);
+    /// engine.emit(diagnostic.clone());
+    /// engine.emit(Diagnostic { location: Location::new(12, 2, 3, 1), ..diagnostic.clone() });
+    /// engine.emit(Diagnostic { location: Location::new(12, 2, 3, 1), ..diagnostic });
+    ///
+    /// let lines: Vec<usize> = engine.get_compiler_errors().iter().map(|e| e.line).collect();
+    /// assert_eq!(lines, vec![2, 5]);
+    /// assert_eq!(engine.error_count(), 2);
     /// ```
     pub fn emit(&mut self, diagnostic: Diagnostic) {
+        if self
+            .diagnostics
+            .iter()
+            .any(|d| d.is_duplicate_of(&diagnostic))
+        {
+            self.last_emitted = None;
+            return;
+        }
+
         match diagnostic.severity {
-            ErrorSeverity::Error => {
-                self.error_count += 1;
-                if self.error_count >= self.max_errors {
-                    self.emit_too_many_errors();
-                    return;
-                }
-            }
-            ErrorSeverity::Warning => self.warning_count += 1,
-            ErrorSeverity::Note => {}
+            Severity::Error => self.error_count += 1,
+            Severity::Warning => self.warning_count += 1,
+            Severity::Note => {}
         }
-        self.diagnostics.push(diagnostic);
+
+        let position = (diagnostic.location.line, diagnostic.location.column);
+        let index = self
+            .diagnostics
+            .partition_point(|d| (d.location.line, d.location.column) <= position);
+        self.diagnostics.insert(index, diagnostic);
+        self.last_emitted = Some(index);
+    }
+
+    /// Attaches a note pointing at a related location to the most recently emitted diagnostic
+    ///
+    /// Notes are reported below the diagnostic they belong to, e.g. to show where a
+    /// redefined symbol was first defined. If the last diagnostic was dropped because it
+    /// was a duplicate, the note is dropped as well.
+    ///
+    /// ### Arguments
+    /// * `message` - The text of the note
+    /// * `location` - The related source location
+    ///
+    /// ### Example
+    /// ```rust
+    /// use slang_shared::DiagnosticEngine;
+    /// use slang_error::ErrorCode;
+    /// use slang_ir::location::Location;
+    ///
+    /// let mut engine = DiagnosticEngine::new();
+    /// engine.emit_error(
+    ///     ErrorCode::SymbolRedefinition,
+    ///     "Function 'f' is already defined".to_string(),
+    ///     Location::new(13, 2, 4, 1)
+    /// );
+    /// engine.emit_note("'f' is first defined here".to_string(), Location::new(3, 1, 4, 1));
+    ///
+    /// let errors = engine.get_compiler_errors();
+    /// assert_eq!(errors[0].related[0].line, 1);
+    /// ```
+    pub fn emit_note(&mut self, message: String, location: Location) {
+        let Some(diagnostic) = self
+            .last_emitted
+            .and_then(|index| self.diagnostics.get_mut(index))
+        else {
+            return;
+        };
+        diagnostic.related.push(Diagnostic {
+            severity: Severity::Note,
+            error_code: diagnostic.error_code,
+            message,
+            location,
+            suggestions: Vec::new(),
+            related: Vec::new(),
+            phase: None,
+        });
     }
 
     /// Emits an error diagnostic with the specified details
@@ -170,12 +249,13 @@ impl<'a> DiagnosticEngine<'a> {
     /// ```
     pub fn emit_error(&mut self, error_code: ErrorCode, message: String, location: Location) {
         self.emit(Diagnostic {
-            severity: ErrorSeverity::Error,
+            severity: Severity::Error,
             error_code,
             message,
             location,
             suggestions: Vec::new(),
             related: Vec::new(),
+            phase: None,
         });
     }
 
@@ -196,19 +276,20 @@ impl<'a> DiagnosticEngine<'a> {
     ///
     /// let mut engine = DiagnosticEngine::new();
     /// engine.emit_warning(
-    ///     ErrorCode::UnusedVariable,
-    ///     "Variable 'x' is declared but never used".to_string(),
+    ///     ErrorCode::VariableShadowing,
+    ///     "Variable 'x' shadows a variable from an outer scope".to_string(),
     ///     Location::new(15, 3, 5, 1)
     /// );
     /// ```
     pub fn emit_warning(&mut self, error_code: ErrorCode, message: String, location: Location) {
         self.emit(Diagnostic {
-            severity: ErrorSeverity::Warning,
+            severity: Severity::Warning,
             error_code,
             message,
             location,
             suggestions: Vec::new(),
             related: Vec::new(),
+            phase: None,
         });
     }
 
@@ -249,19 +330,21 @@ impl<'a> DiagnosticEngine<'a> {
         suggestion: Suggestion,
     ) {
         self.emit(Diagnostic {
-            severity: ErrorSeverity::Error,
+            severity: Severity::Error,
             error_code,
             message,
             location,
             suggestions: vec![suggestion],
             related: Vec::new(),
+            phase: None,
         });
     }
 
     /// Directly emits a CompilerError as a diagnostic
     ///
     /// This method provides seamless integration with the existing CompilerError type,
-    /// allowing for unified error handling across the compiler pipeline.
+    /// allowing for unified error handling across the compiler pipeline. The severity
+    /// of the CompilerError is kept, so warnings don't count as errors.
     ///
     /// ### Arguments
     /// * `error` - The CompilerError to emit as a diagnostic
@@ -269,7 +352,7 @@ impl<'a> DiagnosticEngine<'a> {
     /// ### Example
     /// ```rust
     /// use slang_shared::DiagnosticEngine;
-    /// use slang_error::{CompilerError, ErrorCode};
+    /// use slang_error::{CompilerError, ErrorCode, Severity};
     ///
     /// let mut engine = DiagnosticEngine::new();
     /// let error = CompilerError::new(
@@ -278,22 +361,52 @@ impl<'a> DiagnosticEngine<'a> {
     ///     5, 10, 42, Some(1)
     /// );
     /// engine.emit_compiler_error(error);
+    ///
+    /// let warning = CompilerError::new(
+    ///     ErrorCode::VariableShadowing,
+    ///     "Variable shadows an outer variable".to_string(),
+    ///     6, 5, 50, Some(1)
+    /// ).with_severity(Severity::Warning);
+    /// engine.emit_compiler_error(warning);
+    ///
+    /// assert_eq!(engine.error_count(), 1);
+    /// assert_eq!(engine.warning_count(), 1);
     /// ```
     pub fn emit_compiler_error(&mut self, error: CompilerError) {
         let diagnostic = Diagnostic {
-            severity: ErrorSeverity::Error,
+            severity: error.severity,
             error_code: error.error_code,
-            message: error.message.clone(),
+            message: error.message,
             location: Location::new(
                 error.position,
                 error.line,
                 error.column,
                 error.token_length.unwrap_or(1),
             ),
-            suggestions: Vec::new(),
+            suggestions: error
+                .suggestion
+                .map(|message| Suggestion {
+                    message,
+                    replacement: None,
+                    location: None,
+                })
+                .into_iter()
+                .collect(),
             related: Vec::new(),
+            phase: error.phase,
         };
         self.emit(diagnostic);
+        for note in error.related {
+            self.emit_note(
+                note.message,
+                Location::new(
+                    note.position,
+                    note.line,
+                    note.column,
+                    note.token_length.unwrap_or(1),
+                ),
+            );
+        }
     }
 
     /// Retrieves all error diagnostics as CompilerError instances
@@ -321,18 +434,35 @@ impl<'a> DiagnosticEngine<'a> {
     /// assert_eq!(errors.len(), 1);
     /// ```
     pub fn get_compiler_errors(&self) -> Vec<CompilerError> {
-        self.diagnostics
-            .iter()
-            .filter(|d| matches!(d.severity, ErrorSeverity::Error))
+        self.reported_diagnostics()
+            .filter(|d| matches!(d.severity, Severity::Error))
             .map(|d| {
-                CompilerError::new(
+                let error = CompilerError::new(
                     d.error_code,
                     d.message.clone(),
                     d.location.line,
                     d.location.column,
                     d.location.position,
                     Some(d.location.length),
-                )
+                );
+                let error = match d.phase {
+                    Some(phase) => error.with_phase(phase),
+                    None => error,
+                };
+                let error = d.related.iter().fold(error, |error, note| {
+                    error.with_related(CompilerError::new(
+                        note.error_code,
+                        note.message.clone(),
+                        note.location.line,
+                        note.location.column,
+                        note.location.position,
+                        Some(note.location.length),
+                    ))
+                });
+                match d.suggestions.first() {
+                    Some(suggestion) => error.with_suggestion(suggestion.message.clone()),
+                    None => error,
+                }
             })
             .collect()
     }
@@ -372,13 +502,22 @@ impl<'a> DiagnosticEngine<'a> {
         self.warning_count
     }
 
+    /// Checks whether any diagnostic was collected, including notes
+    ///
+    /// ### Returns
+    /// True if there is something to report, false otherwise
+    pub fn has_diagnostics(&self) -> bool {
+        !self.diagnostics.is_empty()
+    }
+
     /// Finishes diagnostic collection and returns the result
     ///
     /// ### Returns
-    /// `Ok(())` if no errors were collected, otherwise `Err` with all diagnostics
-    pub fn finish(self) -> Result<(), Vec<Diagnostic>> {
+    /// `Ok(())` if no errors were collected, otherwise `Err` with the diagnostics within the
+    /// error limit
+    pub fn finish(mut self) -> Result<(), Vec<Diagnostic>> {
         if self.has_errors() {
-            Err(self.diagnostics)
+            Err(self.take_diagnostics())
         } else {
             Ok(())
         }
@@ -419,20 +558,67 @@ impl<'a> DiagnosticEngine<'a> {
         self.source_text = Some(source_text);
     }
 
-    /// Sets the maximum number of errors before stopping compilation
+    /// Sets the width of a tab character used when displaying source lines
     ///
     /// ### Arguments
-    /// * `max_errors` - The maximum number of errors to collect
-    pub fn set_max_errors(&mut self, max_errors: usize) {
+    /// * `tab_width` - The number of columns between two tab stops, 0 is treated as 1
+    pub fn set_tab_width(&mut self, tab_width: usize) {
+        self.tab_width = tab_width.max(1);
+    }
+
+    /// Returns the width of a tab character used when displaying source lines
+    pub fn tab_width(&self) -> usize {
+        self.tab_width
+    }
+
+    /// Sets the maximum number of errors that are reported
+    ///
+    /// The errors that come first in the source are reported. Errors beyond the cap are
+    /// still counted, but only summarized by a note when the diagnostics are reported.
+    ///
+    /// ### Arguments
+    /// * `max_errors` - The maximum number of errors to collect, or None for no limit
+    ///
+    /// ### Example
+    /// ```rust
+    /// use slang_shared::DiagnosticEngine;
+    /// use slang_error::ErrorCode;
+    /// use slang_ir::location::Location;
+    ///
+    /// let mut engine = DiagnosticEngine::new();
+    /// engine.set_max_errors(Some(2));
+    /// for line in 2..=6 {
+    ///     engine.emit_error(ErrorCode::ExpectedSemicolon, "Missing semicolon".to_string(), Location::new(0, line, 1, 1));
+    /// }
+    /// engine.emit_error(ErrorCode::TypeMismatch, "Type mismatch".to_string(), Location::new(0, 1, 1, 1));
+    ///
+    /// let lines: Vec<usize> = engine.get_compiler_errors().iter().map(|e| e.line).collect();
+    /// assert_eq!(lines, vec![1, 2]);
+    /// assert_eq!(engine.error_count(), 6);
+    /// assert_eq!(engine.omitted_error_count(), 4);
+    /// ```
+    pub fn set_max_errors(&mut self, max_errors: Option<usize>) {
         self.max_errors = max_errors;
     }
 
-    /// Consumes the engine and returns all collected diagnostics
+    /// Returns the number of errors that aren't reported because of the error cap
+    pub fn omitted_error_count(&self) -> usize {
+        self.max_errors
+            .map_or(0, |max| self.error_count.saturating_sub(max))
+    }
+
+    /// Iterates over the diagnostics in source order, skipping errors beyond the error cap
+    fn reported_diagnostics(&self) -> impl Iterator<Item = &Diagnostic> {
+        let mut within_cap = error_cap_filter(self.max_errors);
+        self.diagnostics.iter().filter(move |d| within_cap(d))
+    }
+
+    /// Consumes the engine and returns the collected diagnostics within the error limit
     ///
     /// ### Returns
-    /// A vector containing all diagnostics that were collected
-    pub fn into_errors(self) -> Vec<Diagnostic> {
-        self.diagnostics
+    /// A vector containing the diagnostics that are reported
+    pub fn into_errors(mut self) -> Vec<Diagnostic> {
+        self.take_diagnostics()
     }
 
     /// Reports all diagnostics to stderr with rich formatting
@@ -447,41 +633,31 @@ impl<'a> DiagnosticEngine<'a> {
     /// ```rust
     /// use slang_shared::DiagnosticEngine;
     ///
+    /// let source_code = "let x = 42;";
     /// let engine = DiagnosticEngine::new();
     /// // ... collect some diagnostics ...
-    /// engine.report_all(&source_code);
+    /// engine.report_all(source_code);
     /// ```
     pub fn report_all(&self, source: &str) {
-        let line_info = LineInfo::new(source);
-        for diagnostic in &self.diagnostics {
+        let line_info = LineInfo::new(source).with_tab_width(self.tab_width);
+        for diagnostic in self.reported_diagnostics() {
             self.report_diagnostic(diagnostic, &line_info);
         }
 
+        let omitted_errors = self.omitted_error_count();
+        if omitted_errors > 0 {
+            eprintln!(
+                "{}: {}\n",
+                "note".blue().bold(),
+                omitted_errors_message(omitted_errors)
+            );
+        }
+
         if self.error_count > 0 || self.warning_count > 0 {
             self.report_summary();
         }
     }
 
-    /// Emits a "too many errors" diagnostic when the error limit is reached
-    ///
-    /// This private method is called automatically when the error count reaches
-    /// the configured maximum, helping to prevent overwhelming output in cases
-    /// of cascading errors.
-    fn emit_too_many_errors(&mut self) {
-        let diagnostic = Diagnostic {
-            severity: ErrorSeverity::Error,
-            error_code: ErrorCode::GenericCompileError,
-            message: format!(
-                "Too many errors ({}), stopping compilation",
-                self.max_errors
-            ),
-            location: Location::new(0, 1, 1, 1),
-            suggestions: Vec::new(),
-            related: Vec::new(),
-        };
-        self.diagnostics.push(diagnostic);
-    }
-
     /// Formats and prints a single diagnostic with rich formatting
     ///
     /// This private method handles the detailed formatting of individual diagnostics,
@@ -492,25 +668,30 @@ impl<'a> DiagnosticEngine<'a> {
     /// * `line_info` - Line information for displaying source context
     fn report_diagnostic(&self, diagnostic: &Diagnostic, line_info: &LineInfo) {
         let severity_str = match diagnostic.severity {
-            ErrorSeverity::Error => "error".red().bold(),
-            ErrorSeverity::Warning => "warning".yellow().bold(),
-            ErrorSeverity::Note => "note".blue().bold(),
+            Severity::Error => "error".red().bold(),
+            Severity::Warning => "warning".yellow().bold(),
+            Severity::Note => "note".blue().bold(),
         };
 
         let line = diagnostic.location.line;
         let col = diagnostic.location.column;
         let current_line_text = line_info
-            .get_line_text(line)
-            .unwrap_or("<line not available>");
+            .get_display_line_text(line)
+            .unwrap_or_else(|| "<line not available>".to_string());
 
+        let phase = diagnostic
+            .phase
+            .map(|phase| format!("[{}] ", phase))
+            .unwrap_or_default();
         eprintln!(
-            "{} {}: {}",
+            "{}{} {}: {}",
+            phase,
             severity_str,
             diagnostic.error_code.to_string().bold(),
             diagnostic.message
         );
 
-        eprintln!("  {} {}:{}:{}", "-->".yellow(), "main", line, col);
+        eprintln!("  {} main:{}:{}", "-->".yellow(), line, col);
 
         let line_num_str = format!("{}", line);
         let indent_width = line_num_str.len() + 1;
@@ -520,12 +701,11 @@ impl<'a> DiagnosticEngine<'a> {
         eprintln!("{indent}{}", pipe);
         eprintln!("{} {} {}", line_num_str.yellow(), pipe, current_line_text);
 
-        let error_marker = " ".repeat(col.saturating_sub(1))
-            + &"^"
-                .repeat(diagnostic.location.length.max(1))
-                .bold()
-                .red()
-                .to_string();
+        let marker_width = line_info
+            .span_width(diagnostic.location.position, diagnostic.location.length)
+            .max(1);
+        let error_marker =
+            " ".repeat(col.saturating_sub(1)) + &"^".repeat(marker_width).bold().red().to_string();
         eprintln!("{indent}{} {}", pipe, error_marker);
 
         for suggestion in &diagnostic.suggestions {
@@ -537,9 +717,42 @@ impl<'a> DiagnosticEngine<'a> {
             );
         }
 
+        for note in &diagnostic.related {
+            self.report_related(note, line_info);
+        }
+
         eprintln!();
     }
 
+    /// Prints a note that belongs to a diagnostic, together with its source line
+    ///
+    /// ### Arguments
+    /// * `note` - The related diagnostic to display
+    /// * `line_info` - Line information for displaying source context
+    fn report_related(&self, note: &Diagnostic, line_info: &LineInfo) {
+        let line = note.location.line;
+        let col = note.location.column;
+        let line_text = line_info
+            .get_display_line_text(line)
+            .unwrap_or_else(|| "<line not available>".to_string());
+
+        let line_num_str = format!("{}", line);
+        let indent = " ".repeat(line_num_str.len() + 1);
+        let pipe = "|".yellow();
+
+        eprintln!("{}: {}", "note".blue().bold(), note.message);
+        eprintln!("  {} main:{}:{}", "-->".yellow(), line, col);
+        eprintln!("{indent}{}", pipe);
+        eprintln!("{} {} {}", line_num_str.yellow(), pipe, line_text);
+
+        let marker_width = line_info
+            .span_width(note.location.position, note.location.length)
+            .max(1);
+        let marker =
+            " ".repeat(col.saturating_sub(1)) + &"-".repeat(marker_width).bold().blue().to_string();
+        eprintln!("{indent}{} {}", pipe, marker);
+    }
+
     /// Prints a summary of all collected diagnostics
     ///
     /// This private method displays a final summary showing the total count
@@ -584,18 +797,20 @@ impl<'a> DiagnosticEngine<'a> {
         }
     }
 
-    /// Removes and returns all collected diagnostics, resetting counters
+    /// Removes and returns the collected diagnostics, resetting counters
     ///
-    /// This method provides a way to extract all diagnostics while clearing
+    /// This method provides a way to extract the diagnostics while clearing
     /// the internal state. Useful for batch processing or transferring
-    /// diagnostics to another system.
+    /// diagnostics to another system. Errors beyond the error cap are dropped.
     ///
     /// ### Returns
-    /// A vector containing all previously collected diagnostics
+    /// A vector containing the previously collected diagnostics within the error limit
     pub fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
-        let diagnostics = std::mem::take(&mut self.diagnostics);
+        let mut diagnostics = std::mem::take(&mut self.diagnostics);
+        diagnostics.retain(error_cap_filter(self.max_errors));
         self.error_count = 0;
         self.warning_count = 0;
+        self.last_emitted = None;
         diagnostics
     }
 }
@@ -605,3 +820,24 @@ impl Default for DiagnosticEngine<'_> {
         Self::new()
     }
 }
+
+/// Creates a filter that keeps all diagnostics except the errors beyond an error cap
+///
+/// The filter counts the errors it has seen, so it has to be applied to the diagnostics
+/// in source order.
+///
+/// ### Arguments
+/// * `max_errors` - The maximum number of kept errors, or None for no limit
+///
+/// ### Returns
+/// A predicate that is true for the diagnostics within the cap
+fn error_cap_filter(max_errors: Option<usize>) -> impl FnMut(&Diagnostic) -> bool {
+    let mut errors = 0;
+    move |diagnostic| {
+        if diagnostic.severity != Severity::Error {
+            return true;
+        }
+        errors += 1;
+        max_errors.is_none_or(|max| errors <= max)
+    }
+}
@@ -1,7 +1,9 @@
 pub mod compilation_context;
 pub mod diagnostic_engine;
+pub mod module_context;
 pub mod symbol_table;
 
-pub use compilation_context::CompilationContext;
+pub use compilation_context::{CompilationContext, FunctionSignature};
 pub use diagnostic_engine::{Diagnostic, DiagnosticEngine, Suggestion};
-pub use symbol_table::{Symbol, SymbolKind, SymbolTable};
+pub use module_context::{ModuleContext, PATH_SEPARATOR, qualified_name};
+pub use symbol_table::{FunctionOverload, Symbol, SymbolData, SymbolKind, SymbolTable};
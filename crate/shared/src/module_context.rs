@@ -0,0 +1,157 @@
+use crate::{CompilationContext, SymbolKind};
+use slang_ir::Location;
+use slang_types::TypeId;
+
+/// The separator between a module name and the name of one of its symbols
+pub const PATH_SEPARATOR: &str = "::";
+
+/// Builds the qualified name under which a symbol of a module is stored
+///
+/// ### Arguments
+/// * `module` - The name of the module
+/// * `name` - The unqualified name of the symbol
+///
+/// ### Returns
+/// The qualified name, e.g. `math::add`
+pub fn qualified_name(module: &str, name: &str) -> String {
+    format!("{}{}{}", module, PATH_SEPARATOR, name)
+}
+
+/// Defines the symbols of an imported module in a compilation context
+///
+/// Symbols are stored under their qualified name, so they can only be accessed with
+/// the module prefix unless they are explicitly re-exported.
+///
+/// ### Example
+/// ```
+/// use slang_shared::CompilationContext;
+/// use slang_types::TypeId;
+///
+/// let mut context = CompilationContext::new();
+/// let add_type = context.register_function_type(vec![TypeId::i64(), TypeId::i64()], TypeId::i64());
+///
+/// let mut math = context.module("math");
+/// math.define_function("add", add_type, 2).unwrap();
+///
+/// assert!(context.lookup_symbol("math::add").is_some());
+/// assert!(context.lookup_symbol("add").is_none());
+///
+/// context.module("math").reexport("add").unwrap();
+/// assert!(context.lookup_symbol("add").is_some());
+/// ```
+pub struct ModuleContext<'a> {
+    /// The compilation context the symbols are defined in
+    context: &'a mut CompilationContext,
+    /// The name of the module
+    module: String,
+}
+
+impl<'a> ModuleContext<'a> {
+    /// Creates a module context for the module with the given name
+    ///
+    /// ### Arguments
+    /// * `context` - The compilation context the symbols are defined in
+    /// * `module` - The name of the module
+    pub fn new(context: &'a mut CompilationContext, module: &str) -> Self {
+        Self {
+            context,
+            module: module.to_string(),
+        }
+    }
+
+    /// Defines a symbol of the module under its qualified name
+    ///
+    /// ### Arguments
+    /// * `name` - The unqualified name of the symbol
+    /// * `kind` - The kind of the symbol
+    /// * `type_id` - The type ID of the symbol
+    /// * `is_mutable` - Whether the symbol is mutable (only relevant for variables)
+    ///
+    /// ### Returns
+    /// A Result indicating success or an error message if the symbol cannot be defined
+    pub fn define_symbol(
+        &mut self,
+        name: &str,
+        kind: SymbolKind,
+        type_id: TypeId,
+        is_mutable: bool,
+    ) -> Result<(), String> {
+        self.context.define_symbol(
+            qualified_name(&self.module, name),
+            kind,
+            type_id,
+            is_mutable,
+            None,
+        )
+    }
+
+    /// Defines a function of the module under its qualified name
+    ///
+    /// ### Arguments
+    /// * `name` - The unqualified name of the function
+    /// * `type_id` - The function type of this signature
+    /// * `arity` - The number of parameters of this signature
+    ///
+    /// ### Returns
+    /// A Result indicating success or an error message if the function cannot be defined
+    pub fn define_function(
+        &mut self,
+        name: &str,
+        type_id: TypeId,
+        arity: usize,
+    ) -> Result<(), String> {
+        self.context.define_function(
+            qualified_name(&self.module, name),
+            type_id,
+            arity,
+            Location::default(),
+        )
+    }
+
+    /// Makes a symbol of the module accessible without the module prefix
+    ///
+    /// ### Arguments
+    /// * `name` - The unqualified name of the symbol
+    ///
+    /// ### Returns
+    /// A Result indicating success or an error message if the module doesn't define the
+    /// symbol or the unqualified name is already taken
+    pub fn reexport(&mut self, name: &str) -> Result<(), String> {
+        let qualified = qualified_name(&self.module, name);
+
+        if let Some(overloads) = self.context.lookup_function_overloads(&qualified) {
+            let overloads: Vec<_> = overloads
+                .iter()
+                .map(|overload| {
+                    (
+                        overload.symbol.type_id,
+                        overload.arity,
+                        overload.symbol.definition_location,
+                    )
+                })
+                .collect();
+            for (type_id, arity, location) in overloads {
+                self.context.define_function(
+                    name.to_string(),
+                    type_id,
+                    arity,
+                    location.unwrap_or_default(),
+                )?;
+            }
+            return Ok(());
+        }
+
+        let symbol = self
+            .context
+            .lookup_symbol(&qualified)
+            .ok_or_else(|| format!("Module '{}' has no symbol '{}'.", self.module, name))?;
+        let (kind, type_id, is_mutable, location) = (
+            symbol.kind(),
+            symbol.type_id,
+            symbol.is_mutable(),
+            symbol.definition_location,
+        );
+        self.context
+            .define_symbol(name.to_string(), kind, type_id, is_mutable, location)
+    }
+}
@@ -1,5 +1,6 @@
+use slang_ir::Location;
 use slang_types::types::TypeId;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Represents the specific data for each symbol kind
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -24,6 +25,10 @@ pub struct Symbol {
     pub data: SymbolData,
     /// The type ID associated with this symbol
     pub type_id: TypeId,
+    /// The source location where this symbol was defined, if known
+    pub definition_location: Option<Location>,
+    /// Whether the symbol was referenced after it was defined
+    pub used: bool,
 }
 
 impl Symbol {
@@ -68,11 +73,22 @@ pub enum SymbolKind {
     Function,
 }
 
-/// Represents a lexical scope containing symbols
+/// A single signature of a function name that may be overloaded by arity
 #[derive(Debug, Clone)]
+pub struct FunctionOverload {
+    /// The number of parameters of this signature
+    pub arity: usize,
+    /// The function symbol for this signature
+    pub symbol: Symbol,
+}
+
+/// Represents a lexical scope containing symbols
+#[derive(Debug, Clone, Default)]
 pub struct Scope {
     /// Map of symbol names to symbols in this scope
     symbols: HashMap<String, Symbol>,
+    /// Map of function names to all signatures defined under that name in this scope
+    functions: HashMap<String, Vec<FunctionOverload>>,
 }
 
 /// A symbol table for managing symbols during compilation
@@ -88,7 +104,7 @@ pub struct Scope {
 /// use slang_types::TypeId;
 ///
 /// let mut table = SymbolTable::new();
-/// let type_id = TypeId::new(); // Example type ID
+/// let type_id = TypeId::i32(); // Example type ID
 ///
 /// // Define a variable symbol
 /// table.define("my_var".to_string(), SymbolData::Variable { is_mutable: true }, type_id).unwrap();
@@ -101,6 +117,8 @@ pub struct Scope {
 pub struct SymbolTable {
     /// Stack of scopes, with the innermost scope at the end
     scopes: Vec<Scope>,
+    /// Indices of the scopes that start a function body, with the innermost function at the end
+    function_scopes: Vec<usize>,
 }
 
 impl SymbolTable {
@@ -118,9 +136,8 @@ impl SymbolTable {
     /// ```
     pub fn new() -> Self {
         SymbolTable {
-            scopes: vec![Scope {
-                symbols: HashMap::new(),
-            }],
+            scopes: vec![Scope::default()],
+            function_scopes: Vec::new(),
         }
     }
 
@@ -128,9 +145,38 @@ impl SymbolTable {
     ///
     /// Used when entering a block, function, or other lexical scope.
     pub fn begin_scope(&mut self) {
-        self.scopes.push(Scope {
-            symbols: HashMap::new(),
-        });
+        self.scopes.push(Scope::default());
+    }
+
+    /// Begins a new scope for the body of a function
+    ///
+    /// Local variables of scopes outside this one (except the global scope) are not
+    /// visible from within it, since functions don't capture their environment.
+    /// Functions and types of enclosing scopes remain visible.
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_shared::{SymbolTable, SymbolData};
+    /// use slang_types::TypeId;
+    ///
+    /// let mut table = SymbolTable::new();
+    /// table.define("value".to_string(), SymbolData::Variable { is_mutable: false }, TypeId::i32()).unwrap();
+    /// table.begin_scope();
+    /// table.define("local".to_string(), SymbolData::Variable { is_mutable: false }, TypeId::i32()).unwrap();
+    /// table.define("value".to_string(), SymbolData::Variable { is_mutable: false }, TypeId::bool()).unwrap();
+    /// table.define("helper".to_string(), SymbolData::Function, TypeId::i32()).unwrap();
+    ///
+    /// table.begin_function_scope();
+    /// assert!(table.lookup("local").is_none());
+    /// assert!(table.lookup("helper").is_some());
+    /// assert_eq!(table.lookup("value").unwrap().type_id, TypeId::i32());
+    ///
+    /// table.end_scope();
+    /// assert!(table.lookup("local").is_some());
+    /// ```
+    pub fn begin_function_scope(&mut self) {
+        self.function_scopes.push(self.scopes.len());
+        self.begin_scope();
     }
 
     /// Ends the current scope by popping it from the scope stack
@@ -140,6 +186,13 @@ impl SymbolTable {
     pub fn end_scope(&mut self) {
         if self.scopes.len() > 1 {
             self.scopes.pop();
+            while self
+                .function_scopes
+                .last()
+                .is_some_and(|&start| start >= self.scopes.len())
+            {
+                self.function_scopes.pop();
+            }
         } else {
             panic!("Cannot end the global scope");
         }
@@ -166,7 +219,7 @@ impl SymbolTable {
     /// use slang_types::TypeId;
     ///
     /// let mut table = SymbolTable::new();
-    /// let type_id = TypeId::new();
+    /// let type_id = TypeId::i32();
     ///
     /// // Define a new variable
     /// assert!(table.define("x".to_string(), SymbolData::Variable { is_mutable: true }, type_id.clone()).is_ok());
@@ -179,25 +232,49 @@ impl SymbolTable {
         name: String,
         data: SymbolData,
         type_id: TypeId,
+    ) -> Result<(), String> {
+        self.define_with_location(name, data, type_id, None)
+    }
+
+    /// Defines a new symbol in the current (innermost) scope and records where it was defined
+    ///
+    /// Behaves like `define`, but additionally stores the source location of the
+    /// definition so that diagnostics can point back at it later.
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the symbol to define
+    /// * `data` - The specific data for this symbol kind
+    /// * `type_id` - The type ID associated with this symbol
+    /// * `location` - The source location of the definition, if known
+    ///
+    /// ### Returns
+    /// * `Ok(())` if the symbol was successfully defined
+    /// * `Err(String)` with an error message if the name is already taken
+    pub fn define_with_location(
+        &mut self,
+        name: String,
+        data: SymbolData,
+        type_id: TypeId,
+        location: Option<Location>,
     ) -> Result<(), String> {
         // Check if symbol already exists in current scope
-        if let Some(current_scope) = self.scopes.last() {
-            if let Some(existing_symbol) = current_scope.symbols.get(&name) {
-                let error_message = match (&existing_symbol.data, &data) {
-                    (SymbolData::Type, _) => {
-                        format!("Type '{}' is already defined in the current scope.", name)
-                    }
-                    (SymbolData::Function, _) => format!(
-                        "Function '{}' is already defined in the current scope.",
-                        name
-                    ),
-                    (SymbolData::Variable { .. }, _) => format!(
-                        "Variable '{}' is already defined in the current scope.",
-                        name
-                    ),
-                };
-                return Err(error_message);
-            }
+        if let Some(current_scope) = self.scopes.last()
+            && let Some(existing_symbol) = current_scope.symbols.get(&name)
+        {
+            let error_message = match (&existing_symbol.data, &data) {
+                (SymbolData::Type, _) => {
+                    format!("Type '{}' is already defined in the current scope.", name)
+                }
+                (SymbolData::Function, _) => format!(
+                    "Function '{}' is already defined in the current scope.",
+                    name
+                ),
+                (SymbolData::Variable { .. }, _) => format!(
+                    "Variable '{}' is already defined in the current scope.",
+                    name
+                ),
+            };
+            return Err(error_message);
         }
 
         // Add symbol to current scope
@@ -208,12 +285,107 @@ impl SymbolTable {
                     name,
                     data,
                     type_id,
+                    definition_location: location,
+                    used: false,
                 },
             );
         }
         Ok(())
     }
 
+    /// Defines a function in the current (innermost) scope, allowing overloads by arity
+    ///
+    /// A function name may be defined several times in the same scope as long as every
+    /// definition has a different number of parameters. The first definition is the one
+    /// returned by `lookup`; all of them are available through `lookup_function_overloads`.
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the function
+    /// * `type_id` - The function type of this signature
+    /// * `arity` - The number of parameters of this signature
+    /// * `location` - The source location of the definition, if known
+    ///
+    /// ### Returns
+    /// * `Ok(())` if the function was successfully defined
+    /// * `Err(String)` with an error message if the name is taken by a non-function symbol
+    ///   or by a function with the same arity
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_shared::SymbolTable;
+    /// use slang_types::TypeId;
+    ///
+    /// let mut table = SymbolTable::new();
+    ///
+    /// assert!(table.define_function("add".to_string(), TypeId::i32(), 1, None).is_ok());
+    /// assert!(table.define_function("add".to_string(), TypeId::i32(), 2, None).is_ok());
+    /// assert!(table.define_function("add".to_string(), TypeId::i32(), 2, None).is_err());
+    ///
+    /// let arities: Vec<usize> = table
+    ///     .lookup_function_overloads("add")
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|overload| overload.arity)
+    ///     .collect();
+    /// assert_eq!(arities, vec![1, 2]);
+    /// ```
+    pub fn define_function(
+        &mut self,
+        name: String,
+        type_id: TypeId,
+        arity: usize,
+        location: Option<Location>,
+    ) -> Result<(), String> {
+        let symbol = Symbol {
+            name: name.clone(),
+            data: SymbolData::Function,
+            type_id,
+            definition_location: location,
+            used: false,
+        };
+
+        if let Some(current_scope) = self.scopes.last_mut()
+            && let Some(overloads) = current_scope.functions.get_mut(&name)
+        {
+            if overloads.iter().any(|overload| overload.arity == arity) {
+                return Err(format!(
+                    "Function '{}' with {} parameters is already defined in the current scope.",
+                    name, arity
+                ));
+            }
+            overloads.push(FunctionOverload { arity, symbol });
+            return Ok(());
+        }
+
+        self.define_with_location(name.clone(), SymbolData::Function, type_id, location)?;
+        if let Some(current_scope) = self.scopes.last_mut() {
+            current_scope
+                .functions
+                .insert(name, vec![FunctionOverload { arity, symbol }]);
+        }
+        Ok(())
+    }
+
+    /// Looks up all signatures of a function name, starting from the innermost scope
+    ///
+    /// Only the innermost scope that defines the name is considered, so a variable
+    /// shadowing a function hides all of its overloads.
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the function to look up
+    ///
+    /// ### Returns
+    /// * `Some(&[FunctionOverload])` if the name resolves to a function defined with `define_function`
+    /// * `None` otherwise
+    pub fn lookup_function_overloads(&self, name: &str) -> Option<&[FunctionOverload]> {
+        let scope = self
+            .scopes
+            .iter()
+            .rev()
+            .find(|scope| scope.symbols.contains_key(name))?;
+        scope.functions.get(name).map(Vec::as_slice)
+    }
+
     /// Looks up a symbol by name in all scopes, starting from innermost
     ///
     /// Searches for a symbol with the given name starting from the innermost
@@ -233,7 +405,7 @@ impl SymbolTable {
     /// use slang_types::TypeId;
     ///
     /// let mut table = SymbolTable::new();
-    /// let type_id = TypeId::new();
+    /// let type_id = TypeId::i32();
     ///
     /// table.define("my_function".to_string(), SymbolData::Function, type_id).unwrap();
     ///
@@ -244,12 +416,152 @@ impl SymbolTable {
     /// assert!(table.lookup("nonexistent").is_none());
     /// ```
     pub fn lookup(&self, name: &str) -> Option<&Symbol> {
+        let index = self.resolve_scope(name)?;
+        self.scopes[index].symbols.get(name)
+    }
+
+    /// Looks up a symbol by name in all scopes and returns a mutable reference to it
+    ///
+    /// Resolves the name exactly like `lookup`, so locals of an enclosing function are
+    /// not found from within a function body.
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the symbol to look up
+    ///
+    /// ### Returns
+    /// * `Some(&mut Symbol)` if a symbol with the given name exists
+    /// * `None` if no symbol with the given name is found
+    pub fn lookup_mut(&mut self, name: &str) -> Option<&mut Symbol> {
+        let index = self.resolve_scope(name)?;
+        self.scopes[index].symbols.get_mut(name)
+    }
+
+    /// Marks the symbol a name resolves to as used
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the symbol
+    ///
+    /// ### Returns
+    /// True if the name resolved to a symbol, false otherwise
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_shared::{SymbolTable, SymbolData};
+    /// use slang_types::TypeId;
+    ///
+    /// let mut table = SymbolTable::new();
+    /// table.define("x".to_string(), SymbolData::Variable { is_mutable: false }, TypeId::i32()).unwrap();
+    /// assert!(!table.lookup("x").unwrap().used);
+    ///
+    /// assert!(table.mark_used("x"));
+    /// assert!(table.lookup("x").unwrap().used);
+    /// assert!(!table.mark_used("y"));
+    /// ```
+    pub fn mark_used(&mut self, name: &str) -> bool {
+        match self.lookup_mut(name) {
+            Some(symbol) => {
+                symbol.used = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Finds the index of the innermost scope a name resolves to
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the symbol to resolve
+    ///
+    /// ### Returns
+    /// The index of the scope defining the name, or None if the name can't be resolved
+    fn resolve_scope(&self, name: &str) -> Option<usize> {
+        let function_start = self.function_scopes.last().copied().unwrap_or(0);
+
         // Search from innermost to outermost scope
-        for scope in self.scopes.iter().rev() {
+        for (index, scope) in self.scopes.iter().enumerate().rev() {
             if let Some(symbol) = scope.symbols.get(name) {
-                return Some(symbol);
+                // Locals of an enclosing function or block can't be captured, but they don't
+                // hide a global of the same name either
+                if symbol.is_variable() && index > 0 && index < function_start {
+                    continue;
+                }
+                return Some(index);
             }
         }
         None
     }
+
+    /// Returns all symbols visible from the current scope, starting from innermost
+    ///
+    /// A name that is defined in several scopes is only reported once, with the
+    /// innermost binding. Symbols of the same scope are ordered by name. Local
+    /// variables that `lookup` can't see from within a function body are skipped.
+    ///
+    /// ### Returns
+    /// An iterator over the visible symbols, from the innermost scope outward
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_shared::{SymbolTable, SymbolData, SymbolKind};
+    /// use slang_types::TypeId;
+    ///
+    /// let mut table = SymbolTable::new();
+    /// table.define("x".to_string(), SymbolData::Variable { is_mutable: false }, TypeId::i32()).unwrap();
+    /// table.define("outer".to_string(), SymbolData::Function, TypeId::i32()).unwrap();
+    ///
+    /// table.begin_scope();
+    /// let inner_type = TypeId::i64();
+    /// table.define("x".to_string(), SymbolData::Variable { is_mutable: true }, inner_type).unwrap();
+    /// table.define("y".to_string(), SymbolData::Variable { is_mutable: false }, TypeId::i32()).unwrap();
+    ///
+    /// let names: Vec<&str> = table.all_in_scope().map(|symbol| symbol.name.as_str()).collect();
+    /// assert_eq!(names, vec!["x", "y", "outer"]);
+    ///
+    /// let x = table.all_in_scope().find(|symbol| symbol.name == "x").unwrap();
+    /// assert_eq!(x.kind(), SymbolKind::Variable);
+    /// assert_eq!(x.type_id, inner_type);
+    /// assert!(x.is_mutable());
+    ///
+    /// table.end_scope();
+    /// let names: Vec<&str> = table.all_in_scope().map(|symbol| symbol.name.as_str()).collect();
+    /// assert_eq!(names, vec!["outer", "x"]);
+    /// ```
+    pub fn all_in_scope(&self) -> impl Iterator<Item = &Symbol> + '_ {
+        let function_start = self.function_scopes.last().copied().unwrap_or(0);
+        let mut seen = HashSet::new();
+
+        self.scopes
+            .iter()
+            .enumerate()
+            .rev()
+            .flat_map(|(index, scope)| {
+                let mut symbols: Vec<&Symbol> = scope.symbols.values().collect();
+                symbols.sort_by(|a, b| a.name.cmp(&b.name));
+                symbols.into_iter().map(move |symbol| (index, symbol))
+            })
+            .filter(move |(index, symbol)| {
+                !(symbol.is_variable() && *index > 0 && *index < function_start)
+            })
+            .filter(move |(_, symbol)| seen.insert(symbol.name.as_str()))
+            .map(|(_, symbol)| symbol)
+    }
+
+    /// Looks up a symbol by name in all scopes enclosing the current one
+    ///
+    /// Works like `lookup`, but skips the innermost scope. This is useful for
+    /// detecting whether a new definition would shadow a symbol from an outer scope.
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the symbol to look up
+    ///
+    /// ### Returns
+    /// * `Some(&Symbol)` if a symbol with the given name exists in an enclosing scope
+    /// * `None` if no enclosing scope defines the name
+    pub fn lookup_enclosing(&self, name: &str) -> Option<&Symbol> {
+        self.scopes
+            .iter()
+            .rev()
+            .skip(1)
+            .find_map(|scope| scope.symbols.get(name))
+    }
 }
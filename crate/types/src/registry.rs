@@ -1,4 +1,4 @@
-use crate::{FunctionType, PrimitiveType, TypeId, TypeInfo, TypeKind};
+use crate::{EnumType, FunctionType, PrimitiveType, StructType, TypeId, TypeInfo, TypeKind};
 use std::collections::HashMap;
 
 /// Registry that stores all available types in the language
@@ -7,14 +7,20 @@ pub struct TypeRegistry {
     types: HashMap<TypeId, TypeInfo>,
     /// Map from function signatures to TypeIds for fast function type deduplication
     function_type_cache: HashMap<FunctionType, TypeId>,
+    /// The id assigned to the next registered type
+    next_id: usize,
 }
 
+/// The id of the first type registered at runtime, above the range of the primitive types
+const FIRST_CUSTOM_TYPE_ID: usize = 1000;
+
 impl TypeRegistry {
     /// Creates a new TypeRegistry with built-in types registered.
     pub fn new_instance() -> Self {
         let mut registry = TypeRegistry {
             types: HashMap::new(),
             function_type_cache: HashMap::new(),
+            next_id: FIRST_CUSTOM_TYPE_ID,
         };
         registry.register_built_in_types();
         registry
@@ -33,6 +39,9 @@ impl TypeRegistry {
 
     /// Registers a new type in the registry
     ///
+    /// Ids are assigned in registration order and only depend on the types registered
+    /// before in the same registry, so the same program always gets the same ids.
+    ///
     /// ### Arguments
     ///
     /// * `name` - The name of the type
@@ -40,14 +49,28 @@ impl TypeRegistry {
     ///
     /// ### Returns
     /// A TypeId representing the newly registered type
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_types::{StructType, TypeKind, TypeRegistry};
+    ///
+    /// let point = || TypeKind::Struct(StructType::new("Point".to_string(), Vec::new()));
+    /// let mut first = TypeRegistry::new_instance();
+    /// let mut second = TypeRegistry::new_instance();
+    ///
+    /// let id = first.register_type("Point", point());
+    /// assert_eq!(second.register_type("Point", point()), id);
+    /// assert_ne!(first.register_type("Point", point()), id);
+    /// ```
     pub fn register_type(&mut self, name: &str, kind: TypeKind) -> TypeId {
-        let id = TypeId::new();
+        let id = TypeId(self.next_id);
+        self.next_id += 1;
         let type_info = TypeInfo {
-            id: id.clone(),
+            id,
             name: name.to_string(),
             kind,
         };
-        self.types.insert(id.clone(), type_info);
+        self.types.insert(id, type_info);
         id
     }
 
@@ -60,7 +83,7 @@ impl TypeRegistry {
     /// * `id` - The TypeId for the primitive type
     fn register_primitive_type(&mut self, name: &str, kind: TypeKind, id: TypeId) {
         let type_info = TypeInfo {
-            id: id.clone(),
+            id,
             name: name.to_string(),
             kind,
         };
@@ -103,13 +126,28 @@ impl TypeRegistry {
 
     /// Checks if a value is within the valid range for a given type
     ///
+    /// The value is taken as an `i128` so that every `i64` and every `u64` value can be checked.
+    ///
     /// ### Arguments
     /// * `value` - The value to check
     /// * `type_id` - The TypeId of the type to check against
     ///
     /// ### Returns
     /// A boolean indicating whether the value is within the valid range
-    pub fn check_value_in_range(&self, value: &i64, type_id: &TypeId) -> bool {
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_types::{TypeId, TypeRegistry};
+    ///
+    /// let registry = TypeRegistry::new_instance();
+    ///
+    /// assert!(registry.check_value_in_range(&(u64::MAX as i128), &TypeId::u64()));
+    /// assert!(!registry.check_value_in_range(&(u64::MAX as i128 + 1), &TypeId::u64()));
+    /// assert!(!registry.check_value_in_range(&(i64::MAX as i128 + 1), &TypeId::i64()));
+    /// assert!(registry.check_value_in_range(&(i64::MIN as i128), &TypeId::i64()));
+    /// assert!(!registry.check_value_in_range(&-1, &TypeId::u32()));
+    /// ```
+    pub fn check_value_in_range(&self, value: &i128, type_id: &TypeId) -> bool {
         let type_info = match self.get_type_info(type_id) {
             Some(info) => info,
             None => return false,
@@ -117,16 +155,18 @@ impl TypeRegistry {
 
         match &type_info.kind {
             TypeKind::Integer(int_type) => match (int_type.signed, int_type.bits) {
-                (true, 32) => *value >= i32::MIN as i64 && *value <= i32::MAX as i64,
-                (true, 64) => true,
-                (false, 32) => *value >= 0 && *value <= u32::MAX as i64,
-                (false, 64) => *value >= 0,
+                (true, 32) => i32::try_from(*value).is_ok(),
+                (true, 64) => i64::try_from(*value).is_ok(),
+                (true, 128) => true,
+                (false, 32) => u32::try_from(*value).is_ok(),
+                (false, 64) => u64::try_from(*value).is_ok(),
+                (false, 128) => *value >= 0,
                 _ => false,
             },
             TypeKind::Float(float_type) => match float_type.bits {
-                32 => *value >= f32::MIN as i64 && *value <= f32::MAX as i64,
+                32 => *value >= f32::MIN as i128 && *value <= f32::MAX as i128,
                 64 => true,
-                _ => *value >= f64::MIN as i64 && *value <= f64::MAX as i64,
+                _ => *value >= f64::MIN as i128 && *value <= f64::MAX as i128,
             },
             _ => false,
         }
@@ -134,6 +174,9 @@ impl TypeRegistry {
 
     /// Checks if a float value is within the valid range for a given type
     ///
+    /// Infinities and NaN are valid values of every float type. A finite value is out of
+    /// range if converting it to the type would overflow to infinity.
+    ///
     /// ### Arguments
     /// * `value` - The float value to check
     /// * `type_id` - The TypeId of the type to check against
@@ -148,7 +191,7 @@ impl TypeRegistry {
 
         match &type_info.kind {
             TypeKind::Float(float_type) => match float_type.bits {
-                32 => *value >= f32::MIN as f64 && *value <= f32::MAX as f64,
+                32 => !value.is_finite() || value.abs() <= f32::MAX as f64,
                 64 => true,
                 _ => false,
             },
@@ -170,7 +213,7 @@ impl TypeRegistry {
         return_type: TypeId,
     ) -> TypeId {
         // Create a function type signature for lookup
-        let function_signature = FunctionType::new(param_types.clone(), return_type.clone());
+        let function_signature = FunctionType::new(param_types.clone(), return_type);
 
         if let Some(&existing_type_id) = self.function_type_cache.get(&function_signature) {
             return existing_type_id;
@@ -227,4 +270,50 @@ impl TypeRegistry {
             _ => None,
         })
     }
+
+    /// Gets the struct type information for a given TypeId
+    ///
+    /// ### Arguments
+    /// * `id` - The TypeId to look up
+    ///
+    /// ### Returns
+    /// An Option containing the StructType if found, or None if not found or not a struct
+    pub fn get_struct_type(&self, id: &TypeId) -> Option<&StructType> {
+        self.get_type_info(id).and_then(|info| match &info.kind {
+            TypeKind::Struct(struct_type) => Some(struct_type),
+            _ => None,
+        })
+    }
+
+    /// Gets the enum type information for a given TypeId
+    ///
+    /// ### Arguments
+    /// * `id` - The TypeId to look up
+    ///
+    /// ### Returns
+    /// An Option containing the EnumType if found, or None if not found or not an enum
+    pub fn get_enum_type(&self, id: &TypeId) -> Option<&EnumType> {
+        self.get_type_info(id).and_then(|info| match &info.kind {
+            TypeKind::Enum(enum_type) => Some(enum_type),
+            _ => None,
+        })
+    }
+
+    /// Replaces the fields of a registered struct type
+    ///
+    /// ### Arguments
+    /// * `id` - The TypeId of the struct type
+    /// * `fields` - The new fields of the struct with their names and types
+    ///
+    /// ### Returns
+    /// True if the fields were replaced, false if the type is not a registered struct
+    pub fn set_struct_fields(&mut self, id: &TypeId, fields: Vec<(String, TypeId)>) -> bool {
+        match self.types.get_mut(id).map(|info| &mut info.kind) {
+            Some(TypeKind::Struct(struct_type)) => {
+                struct_type.fields = fields;
+                true
+            }
+            _ => false,
+        }
+    }
 }
@@ -5,6 +5,8 @@ pub const TYPE_NAME_I32: &str = PrimitiveType::I32.name();
 pub const TYPE_NAME_I64: &str = PrimitiveType::I64.name();
 pub const TYPE_NAME_U32: &str = PrimitiveType::U32.name();
 pub const TYPE_NAME_U64: &str = PrimitiveType::U64.name();
+pub const TYPE_NAME_I128: &str = PrimitiveType::I128.name();
+pub const TYPE_NAME_U128: &str = PrimitiveType::U128.name();
 pub const TYPE_NAME_F32: &str = PrimitiveType::F32.name();
 pub const TYPE_NAME_F64: &str = PrimitiveType::F64.name();
 pub const TYPE_NAME_BOOL: &str = PrimitiveType::Bool.name();
@@ -25,6 +27,10 @@ pub enum PrimitiveType {
     U32,
     /// 64-bit unsigned integer
     U64,
+    /// 128-bit signed integer
+    I128,
+    /// 128-bit unsigned integer
+    U128,
     /// 32-bit floating point
     F32,
     /// 64-bit floating point
@@ -60,6 +66,8 @@ impl PrimitiveType {
                 | PrimitiveType::I64
                 | PrimitiveType::U32
                 | PrimitiveType::U64
+                | PrimitiveType::I128
+                | PrimitiveType::U128
                 | PrimitiveType::UnspecifiedInt
         )
     }
@@ -76,13 +84,56 @@ impl PrimitiveType {
     pub fn is_signed_integer(&self) -> bool {
         matches!(
             self,
-            PrimitiveType::I32 | PrimitiveType::I64 | PrimitiveType::UnspecifiedInt
+            PrimitiveType::I32
+                | PrimitiveType::I64
+                | PrimitiveType::I128
+                | PrimitiveType::UnspecifiedInt
         )
     }
 
+    /// Check if this is a signed numeric type, whose values can be negated
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_types::PrimitiveType;
+    ///
+    /// assert!(PrimitiveType::I64.is_signed());
+    /// assert!(PrimitiveType::F32.is_signed());
+    /// assert!(!PrimitiveType::U32.is_signed());
+    /// assert!(!PrimitiveType::Bool.is_signed());
+    /// ```
+    pub fn is_signed(&self) -> bool {
+        self.is_signed_integer() || self.is_float()
+    }
+
     /// Check if this is an unsigned integer type
     pub fn is_unsigned_integer(&self) -> bool {
-        matches!(self, PrimitiveType::U32 | PrimitiveType::U64)
+        matches!(
+            self,
+            PrimitiveType::U32 | PrimitiveType::U64 | PrimitiveType::U128
+        )
+    }
+
+    /// Get the signed integer type with the same bit width as this unsigned integer type
+    ///
+    /// ### Returns
+    /// The signed counterpart, or None if this isn't an unsigned integer type
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_types::PrimitiveType;
+    ///
+    /// assert_eq!(PrimitiveType::U32.signed_counterpart(), Some(PrimitiveType::I32));
+    /// assert_eq!(PrimitiveType::U64.signed_counterpart(), Some(PrimitiveType::I64));
+    /// assert_eq!(PrimitiveType::I32.signed_counterpart(), None);
+    /// ```
+    pub fn signed_counterpart(&self) -> Option<PrimitiveType> {
+        match self {
+            PrimitiveType::U32 => Some(PrimitiveType::I32),
+            PrimitiveType::U64 => Some(PrimitiveType::I64),
+            PrimitiveType::U128 => Some(PrimitiveType::I128),
+            _ => None,
+        }
     }
 
     /// Get the bit width of this type (0 for unspecified types)
@@ -90,6 +141,7 @@ impl PrimitiveType {
         match self {
             PrimitiveType::I32 | PrimitiveType::U32 | PrimitiveType::F32 => 32,
             PrimitiveType::I64 | PrimitiveType::U64 | PrimitiveType::F64 => 64,
+            PrimitiveType::I128 | PrimitiveType::U128 => 128,
             PrimitiveType::Bool => 1,
             PrimitiveType::Unit => 0,
             _ => 0,
@@ -122,6 +174,16 @@ impl PrimitiveType {
                 bits: 64,
                 is_unspecified: false,
             }),
+            PrimitiveType::I128 => TypeKind::Integer(IntegerType {
+                signed: true,
+                bits: 128,
+                is_unspecified: false,
+            }),
+            PrimitiveType::U128 => TypeKind::Integer(IntegerType {
+                signed: false,
+                bits: 128,
+                is_unspecified: false,
+            }),
             PrimitiveType::UnspecifiedInt => TypeKind::Integer(IntegerType {
                 signed: true,
                 bits: 0,
@@ -170,12 +232,6 @@ impl Default for TypeId {
 }
 
 impl TypeId {
-    /// Creates a new unique type identifier for custom types
-    pub fn new() -> Self {
-        static NEXT_ID: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(1000); // above primitive type range
-        TypeId(NEXT_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed))
-    }
-
     /// Creates a TypeId for a primitive type - PREFERRED METHOD
     ///
     /// This ensures consistent TypeId assignment for primitive types
@@ -235,6 +291,18 @@ impl TypeId {
         Self::from_primitive(PrimitiveType::U64)
     }
 
+    /// Returns the TypeId for i128 type
+    #[inline]
+    pub fn i128() -> Self {
+        Self::from_primitive(PrimitiveType::I128)
+    }
+
+    /// Returns the TypeId for u128 type
+    #[inline]
+    pub fn u128() -> Self {
+        Self::from_primitive(PrimitiveType::U128)
+    }
+
     /// Returns the TypeId for f32 type
     #[inline]
     pub fn f32() -> Self {
@@ -293,6 +361,8 @@ pub enum TypeKind {
     Unit,
     /// Struct type with fields
     Struct(StructType),
+    /// Enum type with named variants
+    Enum(EnumType),
     /// Function type with parameters and return type
     Function(FunctionType),
     /// Unknown or not yet determined type
@@ -320,6 +390,46 @@ pub struct IntegerType {
     pub is_unspecified: bool,
 }
 
+impl IntegerType {
+    /// Returns the smallest and largest value of the integer type
+    ///
+    /// ### Returns
+    /// The inclusive bounds, or None for the unspecified integer type which has no fixed width
+    /// and for u128, whose maximum doesn't fit in an i128
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_types::types::IntegerType;
+    ///
+    /// let u32_type = IntegerType { signed: false, bits: 32, is_unspecified: false };
+    /// assert_eq!(u32_type.bounds(), Some((0, u32::MAX as i128)));
+    ///
+    /// let i64_type = IntegerType { signed: true, bits: 64, is_unspecified: false };
+    /// assert_eq!(i64_type.bounds(), Some((i64::MIN as i128, i64::MAX as i128)));
+    ///
+    /// let i128_type = IntegerType { signed: true, bits: 128, is_unspecified: false };
+    /// assert_eq!(i128_type.bounds(), Some((i128::MIN, i128::MAX)));
+    ///
+    /// let u128_type = IntegerType { signed: false, bits: 128, is_unspecified: false };
+    /// assert_eq!(u128_type.bounds(), None);
+    /// ```
+    pub fn bounds(&self) -> Option<(i128, i128)> {
+        if self.is_unspecified || self.bits == 0 || self.bits > 128 {
+            return None;
+        }
+        if self.bits == 128 {
+            return self.signed.then_some((i128::MIN, i128::MAX));
+        }
+
+        if self.signed {
+            let max = (1i128 << (self.bits - 1)) - 1;
+            Some((-max - 1, max))
+        } else {
+            Some((0, (1i128 << self.bits) - 1))
+        }
+    }
+}
+
 /// Represents a floating point type with its properties
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FloatType {
@@ -345,6 +455,45 @@ impl StructType {
     }
 }
 
+/// Represents a C-style enum type, whose variants are identified by their discriminant
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnumType {
+    /// Name of the enum
+    pub name: String,
+    /// Names of the variants in declaration order
+    pub variants: Vec<String>,
+}
+
+impl EnumType {
+    /// Creates a new EnumType.
+    pub fn new(name: String, variants: Vec<String>) -> Self {
+        EnumType { name, variants }
+    }
+
+    /// Returns the discriminant of a variant, which is its position in the declaration
+    ///
+    /// ### Arguments
+    /// * `variant` - The name of the variant
+    ///
+    /// ### Returns
+    /// The discriminant of the variant, or None if the enum has no such variant
+    ///
+    /// ### Example
+    /// ```
+    /// use slang_types::EnumType;
+    ///
+    /// let color = EnumType::new("Color".to_string(), vec!["Red".to_string(), "Green".to_string()]);
+    /// assert_eq!(color.discriminant("Green"), Some(1));
+    /// assert_eq!(color.discriminant("Purple"), None);
+    /// ```
+    pub fn discriminant(&self, variant: &str) -> Option<i32> {
+        self.variants
+            .iter()
+            .position(|name| name == variant)
+            .map(|index| index as i32)
+    }
+}
+
 /// Represents a function type with its parameters and return type
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FunctionType {
@@ -1,13 +1,18 @@
-use crate::compilation_pipeline::CompilationResult;
+use crate::compilation_pipeline::{CompilationPipeline, CompilationResult, PipelineStage};
 use crate::compiler::{CompileOptions, Compiler};
 use crate::error::{CliError, CliResult};
 use crate::exit;
-use clap::{Parser as ClapParser, Subcommand};
+use clap::{Args, Parser as ClapParser, Subcommand, ValueEnum};
 use colored::Colorize;
 use slang_backend::bytecode::Chunk;
-use slang_backend::vm;
+use slang_backend::vm::{OverflowMode, VM};
+use slang_frontend::optimizer::OptLevel;
+use slang_frontend::token_printer::TokenPrinter;
+use slang_ir::ast_printer::ASTPrinter;
+use slang_error::ErrorCode;
+use slang_shared::{CompilationContext, DiagnosticEngine, SymbolKind};
 use std::fs::{self, File};
-use std::io::Write;
+use std::io::{self, IsTerminal, Write};
 use std::path::Path;
 use zip::{ZipArchive, ZipWriter, write::FileOptions};
 
@@ -23,6 +28,10 @@ It features compilation to bytecode and execution of both source files and compi
 pub struct Parser {
     #[command(subcommand)]
     pub command: Option<Commands>,
+
+    /// When to color the output
+    #[arg(long, global = true, value_enum, default_value_t = ColorChoice::Auto)]
+    pub color: ColorChoice,
 }
 
 /// Available commands for the Slang CLI
@@ -36,49 +45,225 @@ pub enum Commands {
         /// Output bytecode file (default: same as input with .sip extension)
         #[arg(short, long)]
         output: Option<String>,
+
+        #[command(flatten)]
+        warnings: WarningFlags,
+
+        /// Print an intermediate representation instead of writing bytecode
+        #[arg(long, value_enum)]
+        emit: Option<EmitKind>,
+
+        /// Number of columns a tab advances to in reported error columns
+        #[arg(long, default_value_t = 1)]
+        tab_width: usize,
+
+        /// Stop reporting errors after this many, summarizing the rest in a note
+        #[arg(long, value_parser = parse_max_errors)]
+        max_errors: Option<usize>,
+
+        /// Run optimization passes such as constant folding before generating bytecode
+        #[arg(short = 'O', long)]
+        optimize: bool,
+
+        /// Print the wall-clock duration of each compilation phase
+        #[arg(long)]
+        timings: bool,
+    },
+
+    /// Check a Slang source file for errors without generating bytecode
+    Check {
+        /// Input source file
+        input: String,
+
+        #[command(flatten)]
+        warnings: WarningFlags,
+
+        /// Number of columns a tab advances to in reported error columns
+        #[arg(long, default_value_t = 1)]
+        tab_width: usize,
+
+        /// Stop reporting errors after this many, summarizing the rest in a note
+        #[arg(long, value_parser = parse_max_errors)]
+        max_errors: Option<usize>,
     },
 
     /// Run a compiled Slang bytecode file
     Run {
         /// Input compiled bytecode file
         input: String,
+
+        /// Wrap around on integer overflow instead of failing with a runtime error
+        #[arg(long)]
+        wrapping_arithmetic: bool,
+
+        /// Arguments passed to the program, given after `--`
+        #[arg(last = true)]
+        args: Vec<String>,
     },
 
     /// Run a Slang source file directly
     Execute {
         /// Input source file
         input: String,
+
+        #[command(flatten)]
+        warnings: WarningFlags,
+
+        /// Wrap around on integer overflow instead of failing with a runtime error
+        #[arg(long)]
+        wrapping_arithmetic: bool,
+
+        /// Number of columns a tab advances to in reported error columns
+        #[arg(long, default_value_t = 1)]
+        tab_width: usize,
+
+        /// Stop reporting errors after this many, summarizing the rest in a note
+        #[arg(long, value_parser = parse_max_errors)]
+        max_errors: Option<usize>,
+
+        /// Run optimization passes such as constant folding before generating bytecode
+        #[arg(short = 'O', long)]
+        optimize: bool,
+
+        /// Print the wall-clock duration of each compilation phase
+        #[arg(long)]
+        timings: bool,
+
+        /// Function to call after the top-level statements, its i32 result is the exit code
+        #[arg(long, value_name = "FUNCTION")]
+        entry_point: Option<String>,
+
+        /// Arguments passed to the program, given after `--`
+        #[arg(last = true)]
+        args: Vec<String>,
     },
 }
 
+/// Optional warnings that can be enabled for commands analyzing source files
+#[derive(Args, Clone, Copy, Default)]
+pub struct WarningFlags {
+    /// Warn when a variable shadows a variable from an enclosing scope
+    #[arg(long)]
+    pub warn_shadowing: bool,
+
+    /// Warn when floating-point values are compared with == or !=
+    #[arg(long)]
+    pub warn_float_equality: bool,
+}
+
+/// Intermediate representations that can be printed by the compile command
+#[derive(Clone, Copy, ValueEnum)]
+pub enum EmitKind {
+    /// The tokens produced by the lexer
+    Tokens,
+    /// The abstract syntax tree produced by the parser
+    Ast,
+    /// The top-level symbols known after semantic analysis
+    Symbols,
+    /// The disassembled bytecode produced by the code generator
+    Disasm,
+}
+
+/// Controls whether diagnostics and other output are colored
+#[derive(Clone, Copy, Default, ValueEnum)]
+pub enum ColorChoice {
+    /// Always emit colors, even when the output is redirected
+    Always,
+    /// Never emit colors
+    Never,
+    /// Emit colors only when stdout and stderr are terminals
+    #[default]
+    Auto,
+}
+
 /// The extension for compiled Slang bytecode files
 const SLANG_BYTECODE_EXTENSION: &str = "sip";
 
+/// Options that control how the virtual machine runs a program
+pub struct RuntimeOptions {
+    /// How integer overflow is handled at runtime
+    pub overflow_mode: OverflowMode,
+    /// The command line arguments passed to the program
+    pub program_args: Vec<String>,
+    /// Function that is called after the top-level statements, if any
+    pub entry_point: Option<String>,
+}
+
+/// Parses the value of `--max-errors`, which has to allow at least one error
+///
+/// ### Arguments
+/// * `value` - The value given on the command line
+///
+/// ### Returns
+/// The maximum number of reported errors, or a message describing the invalid value
+fn parse_max_errors(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(0) => Err("the error limit must be at least 1".to_string()),
+        Ok(max_errors) => Ok(max_errors),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
 /// Represents different execution modes for source file processing
 enum ExecutionMode {
     /// Compile source to bytecode
     Compile { output_path: String },
     /// Execute source directly
-    Execute,
+    Execute { runtime: RuntimeOptions },
 }
 
 /// Run a compiled Slang bytecode file
 ///
 /// ### Arguments
 /// * `input` - The input compiled bytecode file
-pub fn run_file(input: &str) -> CliResult<()> {
+/// * `runtime` - The options for running the program
+///
+/// ### Returns
+/// The exit status of the program, or a CliError on failure
+pub fn run_file(input: &str, runtime: RuntimeOptions) -> CliResult<i32> {
     println!("Running compiled file: {}", input);
 
     // Validate file extension for better user experience
     validate_file_extension(input, SLANG_BYTECODE_EXTENSION, "bytecode execution")?;
 
     let chunk = read_bytecode_from_file(input)?;
-    vm::execute_bytecode(&chunk).map_err(|e| CliError::Generic {
+    execute_chunk(&chunk, runtime, input)
+}
+
+/// Execute a bytecode chunk in a fresh virtual machine
+///
+/// If an entry point is configured, it is called after the top-level statements have run.
+/// An `i32` returned by the entry point becomes the exit status of the program.
+///
+/// ### Arguments
+/// * `chunk` - The bytecode chunk to execute
+/// * `runtime` - The options for running the program
+/// * `input` - The file the chunk originates from, used for error reporting
+///
+/// ### Returns
+/// The exit status of the program, or a CliError describing the runtime error
+fn execute_chunk(chunk: &Chunk, runtime: RuntimeOptions, input: &str) -> CliResult<i32> {
+    let runtime_error = |e: String| CliError::Generic {
         message: format!("{}: {} (in file '{}')", "Runtime Error".red(), e, input),
         exit_code: exit::Code::Software,
-    })?;
+    };
 
-    Ok(())
+    let mut vm = VM::new();
+    vm.set_overflow_mode(runtime.overflow_mode);
+    vm.set_program_args(runtime.program_args);
+    vm.interpret(chunk).map_err(runtime_error)?;
+
+    let Some(entry_point) = runtime.entry_point else {
+        return Ok(0);
+    };
+    // Integer literals returned by the entry point may be stored with a wider type
+    let status = vm
+        .call_global(chunk, &entry_point)
+        .map_err(runtime_error)?
+        .as_i64()
+        .and_then(|status| i32::try_from(status).ok())
+        .unwrap_or(0);
+    Ok(status)
 }
 
 /// Process a source file for either compilation or execution
@@ -86,17 +271,41 @@ pub fn run_file(input: &str) -> CliResult<()> {
 /// ### Arguments
 /// * `input` - The input source file path
 /// * `mode` - The execution mode (compile or execute)
+/// * `warnings` - The optional warnings that are enabled
+/// * `tab_width` - The number of columns a tab advances to in reported columns
+/// * `max_errors` - The maximum number of reported errors, if limited
+/// * `opt_level` - The optimization passes that run before code generation
+/// * `timings` - Whether the duration of each compilation phase is printed
 ///
 /// ### Returns
-/// Result indicating success or failure
-fn process_source_file(input: &str, mode: ExecutionMode) -> CliResult<()> {
+/// The exit status of an executed program, which is 0 when compiling, or a CliError on failure
+fn process_source_file(
+    input: &str,
+    mode: ExecutionMode,
+    warnings: WarningFlags,
+    tab_width: usize,
+    max_errors: Option<usize>,
+    opt_level: OptLevel,
+    timings: bool,
+) -> CliResult<i32> {
     let source = read_source_file(input)?;
     let compiler = Compiler::new();
-    let recovery_mode = matches!(mode, ExecutionMode::Execute);
+    let recovery_mode = matches!(mode, ExecutionMode::Execute { .. });
+    let entry_point = match &mode {
+        ExecutionMode::Execute { runtime } => runtime.entry_point.clone(),
+        ExecutionMode::Compile { .. } => None,
+    };
 
     let compile_options = CompileOptions {
         recovery_mode,
         file_name: Some(input.to_string()),
+        warn_on_shadowing: warnings.warn_shadowing,
+        warn_on_float_equality: warnings.warn_float_equality,
+        tab_width,
+        max_errors,
+        opt_level,
+        entry_point,
+        timings,
     };
 
     let result = compiler.compile_source(&source, compile_options);
@@ -105,8 +314,7 @@ fn process_source_file(input: &str, mode: ExecutionMode) -> CliResult<()> {
         CompilationResult::Success {
             chunk, diagnostics, ..
         } => {
-            let has_diagnostics = diagnostics.error_count() > 0 || diagnostics.warning_count() > 0;
-            if has_diagnostics {
+            if diagnostics.has_diagnostics() {
                 diagnostics.report_all(&source);
             }
 
@@ -114,15 +322,19 @@ fn process_source_file(input: &str, mode: ExecutionMode) -> CliResult<()> {
                 ExecutionMode::Compile { output_path } => {
                     write_bytecode(&chunk, &output_path)?;
                     println!("Successfully compiled to {}", output_path);
+                    Ok(0)
                 }
-                ExecutionMode::Execute => {
-                    vm::execute_bytecode(&chunk).map_err(|e| CliError::Generic {
-                        message: format!("{}: {} (in file '{}')", "Runtime Error".red(), e, input),
+                // Without a valid entry point there is nothing that could be run
+                ExecutionMode::Execute { runtime }
+                    if runtime.entry_point.is_some() && has_entry_point_error(&diagnostics) =>
+                {
+                    Err(CliError::Generic {
+                        message: format!("Compilation failed for file '{}'", input),
                         exit_code: exit::Code::Software,
-                    })?;
+                    })
                 }
+                ExecutionMode::Execute { runtime } => execute_chunk(&chunk, runtime, input),
             }
-            Ok(())
         }
         CompilationResult::Failed { diagnostics, .. } => {
             diagnostics.report_all(&source);
@@ -134,6 +346,22 @@ fn process_source_file(input: &str, mode: ExecutionMode) -> CliResult<()> {
     }
 }
 
+/// Checks whether the entry point required by the execute command is missing or invalid
+///
+/// ### Arguments
+/// * `diagnostics` - The diagnostics collected during compilation
+///
+/// ### Returns
+/// True if an entry point error was reported, false otherwise
+fn has_entry_point_error(diagnostics: &DiagnosticEngine) -> bool {
+    diagnostics.get_compiler_errors().iter().any(|error| {
+        matches!(
+            error.error_code,
+            ErrorCode::MissingEntryPoint | ErrorCode::InvalidEntryPoint
+        )
+    })
+}
+
 /// Determine the output path for a compiled file
 ///
 /// ### Arguments
@@ -329,18 +557,265 @@ fn validate_file_extension(path: &str, expected_ext: &str, operation: &str) -> C
 /// ### Arguments
 /// * `input` - The input source file
 /// * `output` - The output file path (if provided)
-pub fn compile_file(input: &str, output: Option<String>) -> CliResult<()> {
+/// * `warnings` - The optional warnings that are enabled
+/// * `tab_width` - The number of columns a tab advances to in reported columns
+/// * `max_errors` - The maximum number of reported errors, if limited
+/// * `opt_level` - The optimization passes that run before code generation
+/// * `timings` - Whether the duration of each compilation phase is printed
+pub fn compile_file(
+    input: &str,
+    output: Option<String>,
+    warnings: WarningFlags,
+    tab_width: usize,
+    max_errors: Option<usize>,
+    opt_level: OptLevel,
+    timings: bool,
+) -> CliResult<()> {
     let output_path = resolve_output_path(input, output);
     println!("Compiling {} to {}", input, output_path);
-    process_source_file(input, ExecutionMode::Compile { output_path })
+    process_source_file(
+        input,
+        ExecutionMode::Compile { output_path },
+        warnings,
+        tab_width,
+        max_errors,
+        opt_level,
+        timings,
+    )
+    .map(|_| ())
+}
+
+/// Print an intermediate representation of a Slang source file
+///
+/// Compilation stops after the stage that produces the requested representation,
+/// so no bytecode is generated or written.
+///
+/// ### Arguments
+/// * `input` - The input source file
+/// * `emit` - The representation to print
+/// * `tab_width` - The number of columns a tab advances to in reported columns
+/// * `opt_level` - The optimization passes that run before code generation
+pub fn emit_file(
+    input: &str,
+    emit: EmitKind,
+    tab_width: usize,
+    opt_level: OptLevel,
+) -> CliResult<()> {
+    let source = read_source_file(input)?;
+    let compiler = Compiler::new();
+    let compile_options = CompileOptions {
+        file_name: Some(input.to_string()),
+        tab_width,
+        opt_level,
+        ..CompileOptions::default()
+    };
+
+    match emit {
+        EmitKind::Tokens => match compiler.tokenize_source(&source, compile_options) {
+            PipelineStage::Success { data, .. } => {
+                TokenPrinter::new().print(&data);
+                Ok(())
+            }
+            PipelineStage::Failed { pipeline } => report_pipeline_failure(pipeline, &source, input),
+        },
+        EmitKind::Ast => match compiler.parse_source(&source, compile_options) {
+            PipelineStage::Success { data, .. } => {
+                ASTPrinter::new().print(&data);
+                Ok(())
+            }
+            PipelineStage::Failed { pipeline } => report_pipeline_failure(pipeline, &source, input),
+        },
+        EmitKind::Symbols => match compiler.check_source(&source, compile_options) {
+            PipelineStage::Success { pipeline, .. } => {
+                print_symbols(pipeline.context());
+                Ok(())
+            }
+            PipelineStage::Failed { pipeline } => report_pipeline_failure(pipeline, &source, input),
+        },
+        EmitKind::Disasm => match compiler.compile_source(&source, compile_options) {
+            CompilationResult::Success { chunk, .. } => {
+                chunk.disassemble(input);
+                Ok(())
+            }
+            CompilationResult::Failed { diagnostics } => {
+                diagnostics.report_all(&source);
+                Err(CliError::Generic {
+                    message: format!("Compilation failed for file '{}'", input),
+                    exit_code: exit::Code::Software,
+                })
+            }
+        },
+    }
+}
+
+/// Print the symbols visible in the top-level scope with their kinds and types
+///
+/// Every overload of a function is printed on its own line.
+///
+/// ### Arguments
+/// * `context` - The compilation context after semantic analysis
+fn print_symbols(context: &CompilationContext) {
+    println!("=== Symbols ===");
+    for symbol in context.all_symbols_in_scope() {
+        let kind = match symbol.kind() {
+            SymbolKind::Type => "type",
+            SymbolKind::Variable if symbol.is_mutable() => "variable mut",
+            SymbolKind::Variable => "variable",
+            SymbolKind::Function => "function",
+        };
+        match context.lookup_function_overloads(&symbol.name) {
+            Some(overloads) if symbol.is_function() => {
+                for overload in overloads {
+                    let type_name = context.format_type(&overload.symbol.type_id);
+                    println!("{:<8} {}: {}", kind, symbol.name, type_name);
+                }
+            }
+            _ => {
+                let type_name = context.format_type(&symbol.type_id);
+                println!("{:<8} {}: {}", kind, symbol.name, type_name);
+            }
+        }
+    }
+}
+
+/// Check a Slang source file for errors
+///
+/// Lexing, parsing and semantic analysis are run and all diagnostics are reported,
+/// but the backend is never invoked.
+///
+/// ### Arguments
+/// * `input` - The input source file
+/// * `warnings` - The optional warnings that are enabled
+/// * `tab_width` - The number of columns a tab advances to in reported columns
+/// * `max_errors` - The maximum number of reported errors, if limited
+pub fn check_file(
+    input: &str,
+    warnings: WarningFlags,
+    tab_width: usize,
+    max_errors: Option<usize>,
+) -> CliResult<()> {
+    let source = read_source_file(input)?;
+    let compiler = Compiler::new();
+    let compile_options = CompileOptions {
+        file_name: Some(input.to_string()),
+        warn_on_shadowing: warnings.warn_shadowing,
+        warn_on_float_equality: warnings.warn_float_equality,
+        tab_width,
+        max_errors,
+        ..CompileOptions::default()
+    };
+
+    match compiler.check_source(&source, compile_options) {
+        PipelineStage::Success { pipeline, .. } => {
+            let diagnostics = pipeline.diagnostics();
+            if diagnostics.has_diagnostics() {
+                diagnostics.report_all(&source);
+            }
+            println!("No errors found in {}", input);
+            Ok(())
+        }
+        PipelineStage::Failed { pipeline } => report_pipeline_failure(pipeline, &source, input),
+    }
+}
+
+/// Report the diagnostics of a pipeline that failed before reaching its final stage
+///
+/// ### Arguments
+/// * `pipeline` - The failed compilation pipeline
+/// * `source` - The source code that was being compiled
+/// * `input` - The input source file, used for error reporting
+///
+/// ### Returns
+/// A CliError describing the failed compilation
+fn report_pipeline_failure(pipeline: CompilationPipeline, source: &str, input: &str) -> CliResult<()> {
+    if let CompilationResult::Failed { diagnostics } = pipeline.finish() {
+        diagnostics.report_all(source);
+    }
+    Err(CliError::Generic {
+        message: format!("Compilation failed for file '{}'", input),
+        exit_code: exit::Code::Software,
+    })
 }
 
 /// Execute a Slang source file with enhanced error handling and diagnostics
 ///
 /// ### Arguments
 /// * `input` - The input source file
-pub fn execute_file(input: &str) -> CliResult<()> {
+/// * `warnings` - The optional warnings that are enabled
+/// * `tab_width` - The number of columns a tab advances to in reported columns
+/// * `max_errors` - The maximum number of reported errors, if limited
+/// * `opt_level` - The optimization passes that run before code generation
+/// * `timings` - Whether the duration of each compilation phase is printed
+/// * `runtime` - The options for running the program
+///
+/// ### Returns
+/// The exit status of the program, or a CliError on failure
+pub fn execute_file(
+    input: &str,
+    warnings: WarningFlags,
+    tab_width: usize,
+    max_errors: Option<usize>,
+    opt_level: OptLevel,
+    timings: bool,
+    runtime: RuntimeOptions,
+) -> CliResult<i32> {
     println!("Executing source file: {}", input);
-    process_source_file(input, ExecutionMode::Execute)
+    process_source_file(
+        input,
+        ExecutionMode::Execute { runtime },
+        warnings,
+        tab_width,
+        max_errors,
+        opt_level,
+        timings,
+    )
+}
+
+/// Select the runtime overflow mode from the command line flag
+///
+/// ### Arguments
+/// * `wrapping_arithmetic` - Whether wrapping arithmetic was requested
+///
+/// ### Returns
+/// The corresponding overflow mode for the virtual machine
+pub fn overflow_mode(wrapping_arithmetic: bool) -> OverflowMode {
+    if wrapping_arithmetic {
+        OverflowMode::Wrapping
+    } else {
+        OverflowMode::Checked
+    }
+}
+
+/// Configure colored output according to the color choice
+///
+/// In auto mode colors are turned off when stdout or stderr is redirected, so logs don't
+/// contain escape sequences. Otherwise the `NO_COLOR` and `CLICOLOR` conventions apply.
+///
+/// ### Arguments
+/// * `choice` - The color choice from the command line
+pub fn apply_color_choice(choice: ColorChoice) {
+    match choice {
+        ColorChoice::Always => colored::control::set_override(true),
+        ColorChoice::Never => colored::control::set_override(false),
+        ColorChoice::Auto => {
+            if !io::stdout().is_terminal() || !io::stderr().is_terminal() {
+                colored::control::set_override(false);
+            }
+        }
+    }
 }
 
+/// Select the optimization level from the command line flag
+///
+/// ### Arguments
+/// * `optimize` - Whether optimizations were requested
+///
+/// ### Returns
+/// The corresponding optimization level for the compilation pipeline
+pub fn opt_level(optimize: bool) -> OptLevel {
+    if optimize {
+        OptLevel::Basic
+    } else {
+        OptLevel::None
+    }
+}
@@ -1,8 +1,12 @@
 use slang_backend::bytecode::Chunk;
-use slang_error::LineInfo;
+use slang_error::{CompileResultExt, LineInfo, Phase};
 use slang_frontend::Token;
+use slang_frontend::optimizer::{self, OptLevel};
 use slang_ir::ast::Statement;
-use slang_shared::{CompilationContext, DiagnosticEngine};
+use slang_shared::{CompilationContext, Diagnostic, DiagnosticEngine};
+use std::time::Instant;
+
+use crate::timings::PhaseTimings;
 
 /// A composable compilation pipeline providing error recovery capabilities
 ///
@@ -39,6 +43,10 @@ pub struct CompilationPipeline<'a> {
     source: &'a str,
     /// Optional file name for better error reporting
     file_name: Option<String>,
+    /// The optimization passes that run before code generation
+    opt_level: OptLevel,
+    /// Receives the duration of each phase, if timings are enabled
+    timings: Option<Box<dyn PhaseTimings>>,
 }
 
 impl<'a> CompilationPipeline<'a> {
@@ -76,6 +84,8 @@ impl<'a> CompilationPipeline<'a> {
             diagnostics,
             source,
             file_name,
+            opt_level: OptLevel::None,
+            timings: None,
         }
     }
 
@@ -102,6 +112,160 @@ impl<'a> CompilationPipeline<'a> {
         self
     }
 
+    /// Enables or disables warnings for variables that shadow outer variables
+    ///
+    /// When enabled, semantic analysis emits a warning whenever a `let` binding
+    /// shadows a variable from an enclosing scope. Shadowing is still allowed;
+    /// the warning never causes compilation to fail.
+    ///
+    /// ### Arguments
+    /// * `enabled` - Whether to enable shadowing warnings
+    ///
+    /// ### Returns
+    /// The pipeline with shadowing warnings configured
+    ///
+    /// ### Example
+    /// ```rust
+    /// let pipeline = CompilationPipeline::new(source, None)
+    ///     .with_shadowing_warnings(true);
+    /// ```
+    pub fn with_shadowing_warnings(mut self, enabled: bool) -> Self {
+        self.context.set_warn_on_shadowing(enabled);
+        self
+    }
+
+    /// Enables or disables warnings for comparing floating-point values with `==` or `!=`
+    ///
+    /// When enabled, semantic analysis emits a warning for every equality comparison of
+    /// float operands, as rounding errors make exact comparisons unreliable. The
+    /// comparison itself keeps its IEEE 754 semantics.
+    ///
+    /// ### Arguments
+    /// * `enabled` - Whether to enable float equality warnings
+    ///
+    /// ### Returns
+    /// The pipeline with float equality warnings configured
+    pub fn with_float_equality_warnings(mut self, enabled: bool) -> Self {
+        self.context.set_warn_on_float_equality(enabled);
+        self
+    }
+
+    /// Requires the program to declare a function that serves as its entry point
+    ///
+    /// Semantic analysis reports an error if the function is missing or if it takes
+    /// parameters or returns anything but `i32` or `()`.
+    ///
+    /// ### Arguments
+    /// * `entry_point` - The name of the entry point function, or None to not require one
+    ///
+    /// ### Returns
+    /// The pipeline with the entry point configured
+    ///
+    /// ### Example
+    /// ```rust
+    /// let pipeline = CompilationPipeline::new(source, None)
+    ///     .with_entry_point(Some("main".to_string()));
+    /// ```
+    pub fn with_entry_point(mut self, entry_point: Option<String>) -> Self {
+        self.context.set_entry_point(entry_point);
+        self
+    }
+
+    /// Sets the width of a tab character used for reported columns
+    ///
+    /// A tab advances the column to the next multiple of the tab width, so that error
+    /// markers line up with tab-indented source lines. The default width of 1 counts a
+    /// tab as a single column.
+    ///
+    /// ### Arguments
+    /// * `tab_width` - The number of columns between two tab stops
+    ///
+    /// ### Returns
+    /// The pipeline with the tab width configured
+    ///
+    /// ### Example
+    /// ```rust
+    /// let pipeline = CompilationPipeline::new(source, None)
+    ///     .with_tab_width(4);
+    /// ```
+    pub fn with_tab_width(mut self, tab_width: usize) -> Self {
+        self.diagnostics.set_tab_width(tab_width);
+        self
+    }
+
+    /// Limits the number of errors that are collected and reported
+    ///
+    /// Errors beyond the limit are summarized by a single note, which keeps the output
+    /// readable when one mistake causes a cascade of errors.
+    ///
+    /// ### Arguments
+    /// * `max_errors` - The maximum number of errors, or None to report all errors
+    ///
+    /// ### Returns
+    /// The pipeline with the error limit configured
+    ///
+    /// ### Example
+    /// ```rust
+    /// let pipeline = CompilationPipeline::new(source, None)
+    ///     .with_max_errors(Some(10));
+    /// ```
+    pub fn with_max_errors(mut self, max_errors: Option<usize>) -> Self {
+        self.diagnostics.set_max_errors(max_errors);
+        self
+    }
+
+    /// Sets the optimization level used before code generation
+    ///
+    /// At `OptLevel::None` no optimization passes run, so the generated bytecode
+    /// reflects the structure of the source code, which makes debugging easier.
+    ///
+    /// ### Arguments
+    /// * `opt_level` - The optimization level
+    ///
+    /// ### Returns
+    /// The pipeline with the optimization level configured
+    ///
+    /// ### Example
+    /// ```rust
+    /// let pipeline = CompilationPipeline::new(source, None)
+    ///     .with_opt_level(OptLevel::Basic);
+    /// ```
+    pub fn with_opt_level(mut self, opt_level: OptLevel) -> Self {
+        self.opt_level = opt_level;
+        self
+    }
+
+    /// Sets the receiver of the duration of each compilation phase
+    ///
+    /// The lex, parse, type and codegen phases are timed, optimization passes are not.
+    ///
+    /// ### Arguments
+    /// * `timings` - The receiver of the phase durations, or None to disable timings
+    ///
+    /// ### Returns
+    /// The pipeline with the timings configured
+    ///
+    /// ### Example
+    /// ```rust
+    /// let pipeline = CompilationPipeline::new(source, None)
+    ///     .with_timings(Some(Box::new(PrintedTimings)));
+    /// ```
+    pub fn with_timings(mut self, timings: Option<Box<dyn PhaseTimings>>) -> Self {
+        self.timings = timings;
+        self
+    }
+
+    /// Reports the duration of a finished phase to the configured timings
+    ///
+    /// ### Arguments
+    /// * `phase` - The phase that finished
+    /// * `start` - The instant the phase started at
+    fn record_timing(&mut self, phase: Phase, start: Instant) {
+        if let Some(timings) = self.timings.as_mut() {
+            timings.record(phase, start.elapsed());
+        }
+    }
+
     /// Tokenizes the source code into a stream of tokens
     ///
     /// This is the first stage of compilation, converting raw source text into
@@ -126,7 +290,13 @@ impl<'a> CompilationPipeline<'a> {
     /// }
     /// ```
     pub fn tokenize(mut self) -> PipelineStage<'a, Vec<Token>> {
-        let tokenize_result = slang_frontend::lexer::tokenize(self.source);
+        let start = Instant::now();
+        let tokenize_result = slang_frontend::lexer::tokenize_with_tab_width(
+            self.source,
+            self.diagnostics.tab_width(),
+        )
+        .with_phase(Phase::Lex);
+        self.record_timing(Phase::Lex, start);
         match tokenize_result {
             Ok(result) => PipelineStage::Success {
                 pipeline: self,
@@ -165,52 +335,71 @@ impl<'a> CompilationPipeline<'a> {
     /// let result = pipeline.parse(tokens);
     /// ```
     pub fn parse(self, tokens: Vec<Token>) -> PipelineStage<'a, Vec<Statement>> {
-        match self {
-            Self {
-                mut context,
-                mut diagnostics,
-                source,
-                file_name,
-            } => {
-                let line_info = LineInfo::new(&source);
-                match slang_frontend::parser::parse(&tokens, &line_info, &mut context) {
-                    Ok(statements) => PipelineStage::Success {
-                        pipeline: Self {
-                            context,
-                            diagnostics,
-                            source,
-                            file_name,
-                        },
-                        data: statements,
-                    },
-                    Err(errors) => {
-                        for error in errors {
-                            diagnostics.emit_compiler_error(error);
-                        }
+        let recovery_mode = self.diagnostics.is_recovery_mode();
+        self.parse_stage(tokens, recovery_mode)
+    }
 
-                        if diagnostics.is_recovery_mode() {
-                            PipelineStage::Success {
-                                pipeline: Self {
-                                    context,
-                                    diagnostics,
-                                    source,
-                                    file_name,
-                                },
-                                data: Vec::new(),
-                            }
-                        } else {
-                            PipelineStage::Failed {
-                                pipeline: Self {
-                                    context,
-                                    diagnostics,
-                                    source,
-                                    file_name,
-                                },
-                            }
-                        }
-                    }
-                }
+    /// Parses tokens into an AST, continuing with the statements that parsed successfully
+    ///
+    /// Unlike `parse`, syntax errors never stop the pipeline here. The statements
+    /// containing a syntax error are skipped, so the following stages still report
+    /// their diagnostics for the rest of the program. Outside of recovery mode the
+    /// semantic analysis fails afterwards, so no bytecode is generated.
+    ///
+    /// ### Arguments
+    /// * `tokens` - The vector of tokens to parse
+    ///
+    /// ### Returns
+    /// A PipelineStage containing the pipeline and the successfully parsed statements
+    ///
+    /// ### Example
+    /// ```rust
+    /// let result = pipeline
+    ///     .parse_partial(tokens)
+    ///     .and_then(|pipeline, ast| pipeline.semantic_analysis(ast));
+    /// ```
+    pub fn parse_partial(self, tokens: Vec<Token>) -> PipelineStage<'a, Vec<Statement>> {
+        self.parse_stage(tokens, true)
+    }
+
+    /// Parses tokens into an AST and emits the syntax errors
+    ///
+    /// ### Arguments
+    /// * `tokens` - The vector of tokens to parse
+    /// * `continue_on_errors` - Whether the parsed statements are passed on despite syntax errors
+    ///
+    /// ### Returns
+    /// A PipelineStage containing the parsed statements, or the failed pipeline
+    fn parse_stage(
+        mut self,
+        tokens: Vec<Token>,
+        continue_on_errors: bool,
+    ) -> PipelineStage<'a, Vec<Statement>> {
+        let start = Instant::now();
+        let line_info = LineInfo::new(self.source).with_tab_width(self.diagnostics.tab_width());
+        let (statements, mut errors) =
+            slang_frontend::parser::parse_partial(&tokens, &line_info, &mut self.context);
+        self.record_timing(Phase::Parse, start);
+
+        if !errors.has_errors() {
+            return PipelineStage::Success {
+                pipeline: self,
+                data: statements,
+            };
+        }
+
+        for error in errors.take_errors() {
+            self.diagnostics
+                .emit_compiler_error(error.with_phase(Phase::Parse));
+        }
+
+        if continue_on_errors {
+            PipelineStage::Success {
+                pipeline: self,
+                data: statements,
             }
+        } else {
+            PipelineStage::Failed { pipeline: self }
         }
     }
 
@@ -248,14 +437,45 @@ impl<'a> CompilationPipeline<'a> {
                 mut diagnostics,
                 source,
                 file_name,
+                opt_level,
+                mut timings,
             } => {
-                match slang_frontend::semantic_analysis::execute(&statements, &mut context) {
+                let start = Instant::now();
+                let analysis_result =
+                    slang_frontend::semantic_analysis::execute(&statements, &mut context)
+                        .with_phase(Phase::Type);
+                if let Some(timings) = timings.as_mut() {
+                    timings.record(Phase::Type, start.elapsed());
+                }
+                for warning in context.take_warnings() {
+                    diagnostics.emit(Diagnostic {
+                        phase: Some(Phase::Type),
+                        ..warning
+                    });
+                }
+
+                match analysis_result {
+                    // Errors of earlier stages that continued with partial results fail here
+                    Ok(()) if diagnostics.has_errors() && !diagnostics.is_recovery_mode() => {
+                        PipelineStage::Failed {
+                            pipeline: Self {
+                                context,
+                                diagnostics,
+                                source,
+                                file_name,
+                                opt_level,
+                                timings,
+                            },
+                        }
+                    }
                     Ok(()) => PipelineStage::Success {
                         pipeline: Self {
                             context,
                             diagnostics,
                             source,
                             file_name,
+                            opt_level,
+                            timings,
                         },
                         data: statements,
                     },
@@ -272,6 +492,8 @@ impl<'a> CompilationPipeline<'a> {
                                     diagnostics,
                                     source,
                                     file_name,
+                                    opt_level,
+                                    timings,
                                 },
                                 data: statements,
                             }
@@ -282,6 +504,8 @@ impl<'a> CompilationPipeline<'a> {
                                     diagnostics,
                                     source,
                                     file_name,
+                                    opt_level,
+                                    timings,
                                 },
                             }
                         }
@@ -291,6 +515,29 @@ impl<'a> CompilationPipeline<'a> {
         }
     }
 
+    /// Runs the optimization passes enabled by the pipeline's optimization level
+    ///
+    /// This stage rewrites the semantically validated AST, for example by folding
+    /// constant expressions into literals. It never fails.
+    ///
+    /// ### Arguments
+    /// * `statements` - The semantically validated AST statements
+    ///
+    /// ### Returns
+    /// A PipelineStage containing the pipeline and the optimized statements
+    ///
+    /// ### Example
+    /// ```rust
+    /// let result = pipeline.optimize(statements);
+    /// ```
+    pub fn optimize(self, mut statements: Vec<Statement>) -> PipelineStage<'a, Vec<Statement>> {
+        optimizer::optimize(&mut statements, &self.context, self.opt_level);
+        PipelineStage::Success {
+            pipeline: self,
+            data: statements,
+        }
+    }
+
     /// Generates bytecode from the semantically analyzed AST
     ///
     /// This is the final compilation stage that produces executable bytecode
@@ -324,18 +571,45 @@ impl<'a> CompilationPipeline<'a> {
                 mut diagnostics,
                 source: _source,
                 file_name: _file_name,
-            } => match slang_backend::codegen::generate_bytecode(&statements) {
-                Ok(chunk) => CompilationResult::Success { chunk, diagnostics },
-                Err(errors) => {
-                    for error in errors {
-                        diagnostics.emit_compiler_error(error);
+                opt_level: _opt_level,
+                mut timings,
+            } => {
+                let start = Instant::now();
+                let codegen_result = slang_backend::codegen::generate_bytecode(&statements)
+                    .with_phase(Phase::Codegen);
+                if let Some(timings) = timings.as_mut() {
+                    timings.record(Phase::Codegen, start.elapsed());
+                }
+
+                match codegen_result {
+                    Ok(chunk) => CompilationResult::Success { chunk, diagnostics },
+                    Err(errors) => {
+                        for error in errors {
+                            diagnostics.emit_compiler_error(error);
+                        }
+                        CompilationResult::Failed { diagnostics }
                     }
-                    CompilationResult::Failed { diagnostics }
                 }
-            },
+            }
         }
     }
 
+    /// Returns the diagnostics collected by the pipeline so far
+    ///
+    /// ### Returns
+    /// A reference to the pipeline's diagnostic engine
+    pub fn diagnostics(&self) -> &DiagnosticEngine<'a> {
+        &self.diagnostics
+    }
+
+    /// Returns the compilation context holding the types and symbols defined so far
+    ///
+    /// ### Returns
+    /// A reference to the pipeline's compilation context
+    pub fn context(&self) -> &CompilationContext {
+        &self.context
+    }
+
     /// Finalizes the pipeline and returns a failed compilation result
     ///
     /// This method is typically called when the pipeline needs to terminate
@@ -494,7 +768,7 @@ pub fn create_pipeline(
     source: &str,
     file_name: Option<String>,
     recovery_mode: bool,
-) -> CompilationPipeline {
+) -> CompilationPipeline<'_> {
     CompilationPipeline::new(source, file_name).with_recovery_mode(recovery_mode)
 }
 
@@ -519,57 +793,58 @@ pub fn create_pipeline(
 pub fn execute_compilation_stages(pipeline: CompilationPipeline) -> CompilationResult {
     match pipeline
         .tokenize()
-        .and_then(|pipeline, tokens| pipeline.parse(tokens))
+        .and_then(|pipeline, tokens| pipeline.parse_partial(tokens))
         .and_then(|pipeline, statements| pipeline.semantic_analysis(statements))
+        .and_then(|pipeline, statements| pipeline.optimize(statements))
     {
         PipelineStage::Success { pipeline, data } => pipeline.codegen(data),
         PipelineStage::Failed { pipeline } => pipeline.finish(),
     }
 }
 
-/// Compiles source code to bytecode using the diagnostic-aware pipeline
+/// Executes the compilation stages up to and including parsing
 ///
-/// This is the highest-level compilation function that combines pipeline creation
-/// and execution into a single call. It's the main entry point for most compilation
-/// tasks and provides the complete end-to-end compilation experience.
+/// This is used by tooling that only needs the syntax tree of a program,
+/// such as printing the AST for debugging purposes.
 ///
 /// ### Arguments
-/// * `source` - The source code to compile
-/// * `file_name` - Optional file name for better error reporting and debugging
-/// * `recovery_mode` - Whether to enable error recovery mode for collecting multiple errors
+/// * `pipeline` - The compilation pipeline to execute
 ///
 /// ### Returns
-/// The compilation result with either executable bytecode or comprehensive error information
+/// A PipelineStage containing either the parsed statements or the failed pipeline
 ///
 /// ### Example
 /// ```rust
-/// let result = compile_to_bytecode(
-///     "let x = 42; print(x);",
-///     Some("example.sl".to_string()),
-///     true  // Enable recovery mode
-/// );
+/// let pipeline = create_pipeline(source, file_name, false);
+/// let stage = execute_parse_stages(pipeline);
+/// ```
+pub fn execute_parse_stages(pipeline: CompilationPipeline<'_>) -> PipelineStage<'_, Vec<Statement>> {
+    pipeline
+        .tokenize()
+        .and_then(|pipeline, tokens| pipeline.parse(tokens))
+}
+
+/// Executes the compilation stages up to and including semantic analysis
 ///
-/// match result {
-///     CompilationResult::Success { chunk, diagnostics } => {
-///         // Handle any warnings
-///         if diagnostics.warning_count() > 0 {
-///             diagnostics.report_all(source);
-///         }
-///         // Execute the bytecode
-///         vm.execute(chunk);
-///     }
-///     CompilationResult::Failed { diagnostics } => {
-///         // Report all errors
-///         diagnostics.report_all(source);
-///         std::process::exit(1);
-///     }
-/// }
+/// This is used by tooling that only needs to validate a program, such as
+/// checking a source file for errors without generating bytecode.
+///
+/// ### Arguments
+/// * `pipeline` - The compilation pipeline to execute
+///
+/// ### Returns
+/// A PipelineStage containing either the analyzed statements or the failed pipeline
+///
+/// ### Example
+/// ```rust
+/// let pipeline = create_pipeline(source, file_name, false);
+/// let stage = execute_analysis_stages(pipeline);
 /// ```
-pub fn compile_to_bytecode(
-    source: &str,
-    file_name: Option<String>,
-    recovery_mode: bool,
-) -> CompilationResult {
-    let pipeline = create_pipeline(source, file_name, recovery_mode);
-    execute_compilation_stages(pipeline)
+pub fn execute_analysis_stages(
+    pipeline: CompilationPipeline<'_>,
+) -> PipelineStage<'_, Vec<Statement>> {
+    pipeline
+        .tokenize()
+        .and_then(|pipeline, tokens| pipeline.parse_partial(tokens))
+        .and_then(|pipeline, statements| pipeline.semantic_analysis(statements))
 }
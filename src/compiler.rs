@@ -1,5 +1,12 @@
 
-use crate::compilation_pipeline::{CompilationResult, compile_to_bytecode};
+use crate::compilation_pipeline::{
+    CompilationResult, PipelineStage, create_pipeline, execute_analysis_stages,
+    execute_compilation_stages, execute_parse_stages,
+};
+use crate::timings::PrintedTimings;
+use slang_frontend::Token;
+use slang_frontend::optimizer::OptLevel;
+use slang_ir::ast::Statement;
 
 /// Configuration options for compilation
 #[derive(Default)]
@@ -8,6 +15,20 @@ pub struct CompileOptions {
     pub recovery_mode: bool,
     /// File name for better error reporting
     pub file_name: Option<String>,
+    /// Warn when a variable shadows a variable from an enclosing scope
+    pub warn_on_shadowing: bool,
+    /// Warn when floating-point values are compared with `==` or `!=`
+    pub warn_on_float_equality: bool,
+    /// Number of columns a tab advances to in reported columns, 0 is treated as 1
+    pub tab_width: usize,
+    /// Maximum number of errors that are reported, None reports all errors
+    pub max_errors: Option<usize>,
+    /// The optimization passes that run before code generation
+    pub opt_level: OptLevel,
+    /// Name of the function the program must declare as its entry point, if any
+    pub entry_point: Option<String>,
+    /// Print the duration of each compilation phase
+    pub timings: bool,
 }
 
 /// High-level compiler facade that encapsulates the compilation pipeline
@@ -31,6 +52,59 @@ impl Compiler {
     /// ### Returns
     /// The compilation result with diagnostics
     pub fn compile_source<'a>(&self, source: &'a str, options: CompileOptions) -> CompilationResult<'a> {
-        compile_to_bytecode(source, options.file_name, options.recovery_mode)
+        let pipeline = create_pipeline(source, options.file_name, options.recovery_mode)
+            .with_shadowing_warnings(options.warn_on_shadowing)
+            .with_float_equality_warnings(options.warn_on_float_equality)
+            .with_entry_point(options.entry_point)
+            .with_tab_width(options.tab_width)
+            .with_max_errors(options.max_errors)
+            .with_opt_level(options.opt_level)
+            .with_timings(options.timings.then(|| Box::new(PrintedTimings) as _));
+        execute_compilation_stages(pipeline)
     }
-}
\ No newline at end of file
+
+    /// Analyze source code for errors without generating bytecode
+    ///
+    /// ### Arguments
+    /// * `source` - The source code to analyze
+    /// * `options` - Compilation options
+    ///
+    /// ### Returns
+    /// The pipeline stage holding either the analyzed statements or the diagnostics
+    pub fn check_source<'a>(&self, source: &'a str, options: CompileOptions) -> PipelineStage<'a, Vec<Statement>> {
+        let pipeline = create_pipeline(source, options.file_name, options.recovery_mode)
+            .with_shadowing_warnings(options.warn_on_shadowing)
+            .with_float_equality_warnings(options.warn_on_float_equality)
+            .with_tab_width(options.tab_width)
+            .with_max_errors(options.max_errors);
+        execute_analysis_stages(pipeline)
+    }
+
+    /// Parse source code into an abstract syntax tree without generating bytecode
+    ///
+    /// ### Arguments
+    /// * `source` - The source code to parse
+    /// * `options` - Compilation options
+    ///
+    /// ### Returns
+    /// The pipeline stage holding either the parsed statements or the parse diagnostics
+    pub fn parse_source<'a>(&self, source: &'a str, options: CompileOptions) -> PipelineStage<'a, Vec<Statement>> {
+        let pipeline = create_pipeline(source, options.file_name, options.recovery_mode)
+            .with_tab_width(options.tab_width);
+        execute_parse_stages(pipeline)
+    }
+
+    /// Tokenize source code without parsing it
+    ///
+    /// ### Arguments
+    /// * `source` - The source code to tokenize
+    /// * `options` - Compilation options
+    ///
+    /// ### Returns
+    /// The pipeline stage holding either the tokens or the lexical diagnostics
+    pub fn tokenize_source<'a>(&self, source: &'a str, options: CompileOptions) -> PipelineStage<'a, Vec<Token>> {
+        create_pipeline(source, options.file_name, options.recovery_mode)
+            .with_tab_width(options.tab_width)
+            .tokenize()
+    }
+}
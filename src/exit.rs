@@ -1,3 +1,5 @@
+use std::io::Write;
+
 /// Exit the program with an appropriate exit code
 ///
 /// ### Arguments
@@ -10,6 +12,18 @@ pub fn with_code(code: Code, message: &str) -> ! {
     std::process::exit(code.into());
 }
 
+/// Exit the program with the exit status of the executed Slang program
+///
+/// ### Arguments
+/// * `status` - The exit status returned by the entry point of the program
+///
+/// ### Returns
+/// This function does not return. Buffered output is flushed before the program terminates.
+pub fn with_status(status: i32) -> ! {
+    let _ = std::io::stdout().flush();
+    std::process::exit(status);
+}
+
 /// Based on unix exit codes
 #[allow(dead_code)] // Not all codes may be used
 #[derive(Debug, Copy, Clone)]
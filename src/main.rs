@@ -3,34 +3,96 @@ mod error;
 mod exit;
 mod compilation_pipeline;
 mod compiler;
+mod timings;
 
 use clap::Parser;
 
 /// Application entry point
 fn main() {
-    if let Err(err) = run() {
-        exit::with_code(err.exit_code(), &err.to_string());
+    match run() {
+        Ok(0) => {}
+        Ok(status) => exit::with_status(status),
+        Err(err) => exit::with_code(err.exit_code(), &err.to_string()),
     }
 }
 
 /// Main application logic separated from exit handling for testability
-fn run() -> error::CliResult<()> {
+///
+/// ### Returns
+/// The exit status of an executed program, which is 0 for all other commands
+fn run() -> error::CliResult<i32> {
     let input = cli::Parser::parse();
     #[cfg(windows)]
     colored::control::set_virtual_terminal(true);
+    cli::apply_color_choice(input.color);
 
     match &input.command {
-        Some(cli::Commands::Compile { input, output }) => {
-            cli::compile_file(input, output.clone())
+        Some(cli::Commands::Compile {
+            input,
+            output,
+            warnings,
+            emit,
+            tab_width,
+            max_errors,
+            optimize,
+            timings,
+        }) => match emit {
+            Some(emit) => cli::emit_file(input, *emit, *tab_width, cli::opt_level(*optimize)),
+            None => cli::compile_file(
+                input,
+                output.clone(),
+                *warnings,
+                *tab_width,
+                *max_errors,
+                cli::opt_level(*optimize),
+                *timings,
+            ),
         }
+        .map(|()| 0),
 
-        Some(cli::Commands::Run { input }) => {
-            cli::run_file(input)
-        }
+        Some(cli::Commands::Check {
+            input,
+            warnings,
+            tab_width,
+            max_errors,
+        }) => cli::check_file(input, *warnings, *tab_width, *max_errors).map(|()| 0),
 
-        Some(cli::Commands::Execute { input }) => {
-            cli::execute_file(input)
-        }
+        Some(cli::Commands::Run {
+            input,
+            wrapping_arithmetic,
+            args,
+        }) => cli::run_file(
+            input,
+            cli::RuntimeOptions {
+                overflow_mode: cli::overflow_mode(*wrapping_arithmetic),
+                program_args: args.clone(),
+                entry_point: None,
+            },
+        ),
+
+        Some(cli::Commands::Execute {
+            input,
+            warnings,
+            wrapping_arithmetic,
+            tab_width,
+            max_errors,
+            optimize,
+            timings,
+            entry_point,
+            args,
+        }) => cli::execute_file(
+            input,
+            *warnings,
+            *tab_width,
+            *max_errors,
+            cli::opt_level(*optimize),
+            *timings,
+            cli::RuntimeOptions {
+                overflow_mode: cli::overflow_mode(*wrapping_arithmetic),
+                program_args: args.clone(),
+                entry_point: entry_point.clone(),
+            },
+        ),
         
         None => {
             Err(error::CliError::Generic {
@@ -0,0 +1,25 @@
+use slang_error::Phase;
+use std::time::Duration;
+
+/// Receives the wall-clock duration of each compilation phase
+///
+/// The compilation pipeline reports a phase once it has finished, so the
+/// durations arrive in the order the phases ran. Implementations decide
+/// whether the durations are printed, collected or discarded.
+pub trait PhaseTimings {
+    /// Records the duration of a finished compilation phase
+    ///
+    /// ### Arguments
+    /// * `phase` - The phase that finished
+    /// * `duration` - The wall-clock time the phase took
+    fn record(&mut self, phase: Phase, duration: Duration);
+}
+
+/// Prints the duration of every compilation phase as soon as it finishes
+pub struct PrintedTimings;
+
+impl PhaseTimings for PrintedTimings {
+    fn record(&mut self, phase: Phase, duration: Duration) {
+        println!("{:<8} {:?}", format!("{}:", phase), duration);
+    }
+}
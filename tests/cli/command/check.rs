@@ -0,0 +1,428 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn valid_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+    let output_file = temp_dir.path().join("test.sip");
+
+    fs::write(&input_file, "let x: i32 = 42;\nprint_value(x);").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.current_dir(&temp_dir)
+        .arg("check")
+        .arg(&input_file)
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("No errors found").and(predicate::str::contains("42").not()),
+        );
+
+    assert!(!output_file.exists());
+}
+
+#[test]
+fn type_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("error.sl");
+
+    fs::write(&input_file, "let x: i32 = true;").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .arg(&input_file)
+        .assert()
+        .failure()
+        .code(70) // Software exit code
+        .stderr(predicate::str::contains("E2"));
+}
+
+#[test]
+fn parse_and_type_errors_are_reported_together() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("errors.sl");
+
+    fs::write(&input_file, "let y: i32 = ;\nlet x: i32 = true;").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .arg(&input_file)
+        .assert()
+        .failure()
+        .code(70)
+        .stderr(predicate::str::contains("E1009"))
+        .stderr(predicate::str::contains(
+            "Type mismatch: variable x is i32 but expression is bool",
+        ))
+        .stderr(predicate::str::contains("2 errors"));
+}
+
+#[test]
+fn malformed_statement_in_block_reports_single_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("errors.sl");
+
+    fs::write(
+        &input_file,
+        "fn main() {\n    let a = 1;\n    let b = ;\n    let c = 3;\n    print_value(a + c);\n}",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .arg(&input_file)
+        .assert()
+        .failure()
+        .code(70)
+        .stderr(predicate::str::contains("E1009"))
+        .stderr(predicate::str::contains("main:3:13"))
+        .stderr(predicate::str::contains("1 error"))
+        .stderr(predicate::str::contains("Undefined variable").not());
+}
+
+#[test]
+fn malformed_multi_line_expression_in_block_reports_single_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("errors.sl");
+
+    fs::write(
+        &input_file,
+        "fn main() {\n    let a = 1 + * 2\n        + 3;\n    let b = 2 *\n        / 4;\n    print_value(a + b);\n}",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .arg(&input_file)
+        .assert()
+        .failure()
+        .code(70)
+        .stderr(predicate::str::contains("main:2:17"))
+        .stderr(predicate::str::contains("main:5:9"))
+        .stderr(predicate::str::contains("2 errors"));
+}
+
+#[test]
+fn malformed_declarations_keep_their_names_declared() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("errors.sl");
+
+    fs::write(
+        &input_file,
+        "let a = ;\nprint_value(a);\nfn f(x: i32 {\n    return x;\n}\nprint_value(f(1));\n\
+         {\n    let mut b: i32 = 1 +;\n    b = 3;\n    print_value(b);\n}\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .arg(&input_file)
+        .assert()
+        .failure()
+        .code(70)
+        .stderr(predicate::str::contains("main:1:9"))
+        .stderr(predicate::str::contains("main:3:13"))
+        .stderr(predicate::str::contains("main:8:25"))
+        .stderr(predicate::str::contains("3 errors"))
+        .stderr(predicate::str::contains("Undefined").not())
+        .stderr(predicate::str::contains("Type mismatch").not());
+}
+
+#[test]
+fn max_errors_caps_reported_errors() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("errors.sl");
+
+    fs::write(
+        &input_file,
+        "let a: i32 = true;\nlet b: i32 = \"b\";\nlet c: bool = 3;\nlet d: string = false;\nlet e: i32 = \"e\";",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    let assert = cmd
+        .arg("check")
+        .arg(&input_file)
+        .arg("--max-errors")
+        .arg("2")
+        .assert()
+        .failure()
+        .code(70)
+        .stderr(predicate::str::contains("... and 3 more errors"));
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert_eq!(stderr.matches("E2005").count(), 2);
+    assert!(stderr.contains("main:2:5"));
+    assert!(!stderr.contains("main:3:5"));
+}
+
+#[test]
+fn max_errors_keeps_errors_that_come_first_in_source() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("errors.sl");
+
+    fs::write(
+        &input_file,
+        "let a: i32 = true;\nlet b = ;\nprint_value(1);\nlet c = ;\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    let assert = cmd
+        .arg("check")
+        .arg(&input_file)
+        .arg("--max-errors")
+        .arg("2")
+        .assert()
+        .failure()
+        .code(70)
+        .stderr(predicate::str::contains("... and 1 more error"));
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(stderr.contains("main:1:5"));
+    assert!(stderr.contains("main:2:9"));
+    assert!(!stderr.contains("main:4:9"));
+}
+
+#[test]
+fn max_errors_rejects_zero() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("valid.sl");
+    fs::write(&input_file, "print_value(1);").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .arg(&input_file)
+        .arg("--max-errors")
+        .arg("0")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the error limit must be at least 1",
+        ));
+}
+
+#[test]
+fn nonexistent_file() {
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .arg("nonexistent.sl")
+        .assert()
+        .failure()
+        .code(66); // NoInput exit code
+}
+
+#[test]
+fn tab_width_adjusts_reported_column() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("tabs.sl");
+
+    fs::write(&input_file, "fn main() {\n\tlet x: i32 = true;\n}").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .arg("--tab-width")
+        .arg("4")
+        .arg(&input_file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("main:2:9"))
+        .stderr(predicate::str::contains("2 |     let x: i32 = true;"))
+        .stderr(predicate::str::contains("  |         ^\n"));
+}
+
+#[test]
+fn multi_byte_characters_count_as_one_column() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("emoji.sl");
+
+    fs::write(&input_file, "let s = \"\u{1F600}\"; let x: i32 = y;").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .arg(&input_file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("main:1:27"))
+        .stderr(predicate::str::contains(
+            "  |                           ^\n",
+        ));
+}
+
+#[test]
+fn multi_byte_characters_in_span_count_as_one_caret() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("span.sl");
+
+    fs::write(
+        &input_file,
+        "let a: i32 = 1;\nlet b = a < \"h\u{e9}llo\u{1F600}\";",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .arg(&input_file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("main:2:9"))
+        .stderr(predicate::str::contains("  |         ^^^^^^^^^^^^\n"));
+}
+
+#[test]
+fn default_tab_width_counts_tab_as_one_column() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("tabs.sl");
+
+    fs::write(&input_file, "fn main() {\n\tlet x: i32 = true;\n}").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .arg(&input_file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("main:2:6"));
+}
+
+#[test]
+fn parse_error_is_tagged_with_parse_phase() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("parse_error.sl");
+
+    fs::write(&input_file, "let x = 1").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .arg(&input_file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("[parse] error [E1001]"));
+}
+
+#[test]
+fn type_error_is_tagged_with_type_phase() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("type_error.sl");
+
+    fs::write(&input_file, "let x: i32 = true;").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .arg(&input_file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("[type] error [E2005]"))
+        .stderr(predicate::str::contains("[parse]").not());
+}
+
+#[test]
+fn redefinition_error_notes_the_original_definition() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("redefinition.sl");
+
+    fs::write(&input_file, "fn f() {}\n\nfn f() {}").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .arg(&input_file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("main:3:4"))
+        .stderr(predicate::str::contains(
+            "note: 'f' is first defined here\n  --> main:1:4",
+        ));
+}
+
+#[test]
+fn duplicate_parameter_notes_the_first_parameter() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("duplicate_parameter.sl");
+
+    fs::write(&input_file, "fn f(a: i32, a: i32) {}").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .arg(&input_file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("main:1:14"))
+        .stderr(predicate::str::contains(
+            "note: 'a' is first defined here\n  --> main:1:6",
+        ));
+}
+
+/// Checks a file with a type error using the given extra arguments
+fn check_type_error_with_args(args: &[&str]) -> assert_cmd::assert::Assert {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("error.sl");
+
+    fs::write(&input_file, "let x: i32 = true;").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("check")
+        .args(args)
+        .arg(&input_file)
+        .assert()
+        .failure()
+}
+
+#[test]
+fn color_never_emits_no_escape_sequences() {
+    check_type_error_with_args(&["--color", "never"])
+        .stderr(predicate::str::contains("E2005"))
+        .stderr(predicate::str::contains("\x1b[").not());
+}
+
+#[test]
+fn color_is_disabled_when_output_is_redirected() {
+    check_type_error_with_args(&[])
+        .stderr(predicate::str::contains("E2005"))
+        .stderr(predicate::str::contains("\x1b[").not());
+}
+
+#[test]
+fn color_always_emits_escape_sequences() {
+    check_type_error_with_args(&["--color", "always"]).stderr(predicate::str::contains("\x1b["));
+}
+
+#[test]
+fn diagnostics_are_reported_in_source_order_without_duplicates() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("order.sl");
+
+    // The parse errors on line 5 and 8 are emitted before the type errors on line 2. The
+    // parser records the error in the nested block twice, as it first tries to parse the
+    // block as the value of the enclosing block and then again as a statement.
+    fs::write(
+        &input_file,
+        "let value = 1;\nlet broken: i32 = \"text\"; let other: bool = 1;\nprint_value(value);\nprint_value(value);\nlet = 5;\n\
+         let nested = {\n    {\n        let inner = ;\n    }\n    1\n};\n",
+    )
+    .unwrap();
+
+    let output = Command::cargo_bin("slang")
+        .unwrap()
+        .arg("check")
+        .arg(&input_file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Compilation finished with 4 errors",
+        ))
+        .get_output()
+        .clone();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    let locations: Vec<&str> = stderr
+        .lines()
+        .filter_map(|line| line.trim_start().strip_prefix("--> "))
+        .collect();
+    assert_eq!(
+        locations,
+        vec!["main:2:5", "main:2:31", "main:5:5", "main:8:21"]
+    );
+}
@@ -90,3 +90,395 @@ fn permission_denied_error() {
         .code(77); // NoPerm exit code
 }
 
+
+#[test]
+fn emit_ast_prints_syntax_tree() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(
+        &input_file,
+        "fn add(a: i32, b: i32) -> i32 { return a + b; }\nlet x = add(1, 2);\nprint_value(x);",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.current_dir(&temp_dir)
+        .arg("compile")
+        .arg("test.sl")
+        .arg("--emit")
+        .arg("ast")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("AST Root"))
+        .stdout(predicate::str::contains("Function: add"))
+        .stdout(predicate::str::contains("Return:"))
+        .stdout(predicate::str::contains("Op: +"))
+        .stdout(predicate::str::contains("Let: x ="))
+        .stdout(predicate::str::contains("Call: print_value"));
+
+    assert!(!temp_dir.path().join("test.sip").exists());
+}
+
+#[test]
+fn emit_ast_reports_syntax_errors() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("invalid.sl");
+
+    fs::write(&input_file, "let = 5;").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("compile")
+        .arg(&input_file)
+        .arg("--emit")
+        .arg("ast")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("AST Root").not());
+}
+
+#[test]
+fn emit_tokens_prints_lexer_output() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(&input_file, "let x").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.current_dir(&temp_dir)
+        .arg("compile")
+        .arg("test.sl")
+        .arg("--emit")
+        .arg("tokens")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"Let\s+'let' \(pos: 0\)").unwrap())
+        .stdout(predicate::str::is_match(r"Identifier\s+'x' \(pos: 4\)").unwrap())
+        .stdout(predicate::str::contains("Eof"));
+
+    assert!(!temp_dir.path().join("test.sip").exists());
+}
+
+#[test]
+fn emit_disasm_prints_bytecode() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(&input_file, "print_value(2 * 3 + 1);").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.current_dir(&temp_dir)
+        .arg("compile")
+        .arg("test.sl")
+        .arg("--emit")
+        .arg("disasm")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("MULTIPLY"))
+        .stdout(predicate::str::contains("ADD"))
+        .stdout(predicate::str::contains("'7'").not());
+
+    assert!(!temp_dir.path().join("test.sip").exists());
+}
+
+#[test]
+fn optimize_folds_constant_expressions() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(&input_file, "print_value(2 * 3 + 1);").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.current_dir(&temp_dir)
+        .arg("compile")
+        .arg("test.sl")
+        .arg("--emit")
+        .arg("disasm")
+        .arg("-O")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("CONSTANT            0 '7'"))
+        .stdout(predicate::str::contains("MULTIPLY").not())
+        .stdout(predicate::str::contains("ADD").not());
+}
+
+#[test]
+fn optimize_propagates_immutable_literal_bindings() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(&input_file, "let x = 5;\nprint_value(x + 1);").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.current_dir(&temp_dir)
+        .arg("compile")
+        .arg("test.sl")
+        .arg("--emit")
+        .arg("disasm")
+        .arg("-O")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("CONSTANT            1 '6'"))
+        .stdout(predicate::str::contains("GET_VARIABLE        0 'x'").not())
+        .stdout(predicate::str::contains("ADD").not());
+}
+
+#[test]
+fn optimize_removes_branch_of_constant_if_statement() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(
+        &input_file,
+        "if 1 > 0 {\n    print_value(\"then\");\n} else {\n    print_value(\"else\");\n}",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.current_dir(&temp_dir)
+        .arg("compile")
+        .arg("test.sl")
+        .arg("--emit")
+        .arg("disasm")
+        .arg("-O")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("'then'"))
+        .stdout(predicate::str::contains("'else'").not())
+        .stdout(predicate::str::contains("JUMP").not());
+}
+
+#[test]
+fn optimize_selects_branch_of_constant_conditional_expression() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(
+        &input_file,
+        "let count = arg_count();\nprint_value(if 2 < 1 { count + 1 } else { count - 1 });",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.current_dir(&temp_dir)
+        .arg("compile")
+        .arg("test.sl")
+        .arg("--emit")
+        .arg("disasm")
+        .arg("-O")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("SUBTRACT"))
+        .stdout(predicate::str::contains("ADD").not())
+        .stdout(predicate::str::contains("JUMP").not());
+}
+
+#[test]
+fn optimize_keeps_mutable_bindings() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(&input_file, "let mut x = 5;\nx = 7;\nprint_value(x + 1);").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("execute")
+        .arg("-O")
+        .arg(&input_file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("8"));
+}
+
+#[test]
+fn optimize_keeps_runtime_errors() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(
+        &input_file,
+        "print_value(1 + 1);\nprint_value(2147483647i32 + 1i32);",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("execute")
+        .arg("--optimize")
+        .arg(&input_file)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("2"))
+        .stderr(predicate::str::contains("Integer overflow"));
+}
+
+#[test]
+fn optimize_folds_string_concatenation() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(&input_file, r#"print_value("foo" + "bar");"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.current_dir(&temp_dir)
+        .arg("compile")
+        .arg("test.sl")
+        .arg("--emit")
+        .arg("disasm")
+        .arg("-O")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("CONSTANT            0 'foobar'"))
+        .stdout(predicate::str::contains("ADD").not());
+}
+
+#[test]
+fn optimize_keeps_concatenation_with_variable() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(&input_file, "let mut x = \"b\";\nprint_value(\"a\" + x);").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.current_dir(&temp_dir)
+        .arg("compile")
+        .arg("test.sl")
+        .arg("--emit")
+        .arg("disasm")
+        .arg("-O")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("'a'"))
+        .stdout(predicate::str::contains("ADD"));
+}
+
+#[test]
+fn optimize_merges_trailing_string_literals() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(
+        &input_file,
+        "let mut x = \"a\";\nprint_value(x + \"b\" + \"c\");",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("execute")
+        .arg("--optimize")
+        .arg(&input_file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("abc"));
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.current_dir(&temp_dir)
+        .arg("compile")
+        .arg("test.sl")
+        .arg("--emit")
+        .arg("disasm")
+        .arg("-O")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("'bc'"));
+}
+
+#[test]
+fn equal_constants_share_one_pool_entry() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(&input_file, "print_value(\"hi\");\nprint_value(\"hi\");").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    let assert = cmd
+        .current_dir(&temp_dir)
+        .arg("compile")
+        .arg("test.sl")
+        .arg("--emit")
+        .arg("disasm")
+        .assert()
+        .success();
+
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout);
+    assert_eq!(stdout.matches("CONSTANT            0 'hi'").count(), 2);
+    assert!(!stdout.contains("CONSTANT            1"));
+}
+
+#[test]
+fn emit_symbols_prints_top_level_symbols() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(
+        &input_file,
+        "fn add(a: i32, b: i32) -> i32 { return a + b; }\nlet mut x = add(1, 2);",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.current_dir(&temp_dir)
+        .arg("compile")
+        .arg("test.sl")
+        .arg("--emit")
+        .arg("symbols")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("function add: fn(i32, i32) -> i32"))
+        .stdout(predicate::str::contains("variable mut x: i32"))
+        .stdout(predicate::str::contains("type     i32: i32"))
+        .stdout(predicate::str::contains(" a: ").not());
+
+    assert!(!temp_dir.path().join("test.sip").exists());
+}
+
+#[test]
+fn emit_symbols_reports_semantic_errors() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("invalid.sl");
+
+    fs::write(&input_file, "let x: i32 = true;").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("compile")
+        .arg(&input_file)
+        .arg("--emit")
+        .arg("symbols")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("=== Symbols ===").not());
+}
+
+#[test]
+fn timings_print_the_duration_of_each_phase() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(&input_file, "let x = 1 + 2;\nprint_value(x);").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.current_dir(&temp_dir)
+        .arg("compile")
+        .arg("test.sl")
+        .arg("--timings")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"(?m)^lex: +\d").unwrap())
+        .stdout(predicate::str::is_match(r"(?m)^parse: +\d").unwrap())
+        .stdout(predicate::str::is_match(r"(?m)^type: +\d").unwrap())
+        .stdout(predicate::str::is_match(r"(?m)^codegen: +\d").unwrap());
+}
+
+#[test]
+fn timings_are_not_printed_by_default() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("test.sl");
+
+    fs::write(&input_file, "let x = 1;").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.current_dir(&temp_dir)
+        .arg("compile")
+        .arg("test.sl")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("codegen:").not());
+}
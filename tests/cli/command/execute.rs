@@ -28,3 +28,104 @@ fn with_runtime_error() {
     let mut cmd = Command::cargo_bin("slang").unwrap();
     cmd.arg("execute").arg(&input_file).assert().failure();
 }
+
+#[test]
+fn entry_point_return_value_is_exit_code() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("main.sl");
+
+    fs::write(
+        &input_file,
+        "print(\"top level\");\nfn main() -> i32 {\n    print(\"in main\");\n    return 3;\n}",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("execute")
+        .arg("--entry-point")
+        .arg("main")
+        .arg(&input_file)
+        .assert()
+        .code(3)
+        .stdout(predicate::str::contains("top level\nin main"));
+}
+
+#[test]
+fn entry_point_returning_unit_exits_successfully() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("main.sl");
+
+    fs::write(&input_file, "fn main() {\n    print(\"in main\");\n}").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("execute")
+        .arg("--entry-point")
+        .arg("main")
+        .arg(&input_file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("in main"));
+}
+
+#[test]
+fn missing_entry_point() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("main.sl");
+
+    fs::write(&input_file, "fn start() {\n    print(\"in start\");\n}").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("execute")
+        .arg("--entry-point")
+        .arg("main")
+        .arg(&input_file)
+        .assert()
+        .code(70)
+        .stderr(predicate::str::contains(
+            "[E2026]: Entry point function 'main' is not defined",
+        ))
+        .stdout(predicate::str::contains("in start").not());
+}
+
+#[test]
+fn entry_point_with_parameters() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("main.sl");
+
+    fs::write(
+        &input_file,
+        "fn main(code: i32) -> i32 {\n    return code;\n}",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("execute")
+        .arg("--entry-point")
+        .arg("main")
+        .arg(&input_file)
+        .assert()
+        .code(70)
+        .stderr(predicate::str::contains(
+            "[E2027]: Entry point function 'main' must have the signature '() -> i32' or '() -> ()'",
+        ))
+        .stderr(predicate::str::contains("--> main:1:4"));
+}
+
+#[test]
+fn main_is_not_called_without_entry_point() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("main.sl");
+
+    fs::write(
+        &input_file,
+        "fn main() -> i32 {\n    print(\"in main\");\n    return 3;\n}",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("execute")
+        .arg(&input_file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("in main").not());
+}
@@ -7,7 +7,7 @@ fn command() {
     cmd.arg("--help")
         .assert()
         .success()
-        .stdout(predicate::str::contains("Usage: slang [COMMAND]"));
+        .stdout(predicate::str::contains("Usage: slang [OPTIONS] [COMMAND]"));
 }
 
 #[test]
@@ -15,6 +15,6 @@ fn is_shown_when_no_subcommand() {
     let mut cmd = Command::cargo_bin("slang").unwrap();
     cmd.assert()
         .code(2)
-        .stderr(predicate::str::contains("Usage: slang [COMMAND]"));
+        .stderr(predicate::str::contains("Usage: slang [OPTIONS] [COMMAND]"));
 }
 
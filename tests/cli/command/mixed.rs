@@ -28,3 +28,92 @@ fn compile_and_run() {
         .stdout(predicate::str::contains("42"));
 }
 
+/// Compiles the program to bytecode, runs it and checks the reported runtime error
+fn compile_and_run_expect_runtime_error(program: &str, expected_error: &str) {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("test.sl");
+    let bytecode_file = temp_dir.path().join("test.sip");
+
+    fs::write(&source_file, program).unwrap();
+
+    Command::cargo_bin("slang")
+        .unwrap()
+        .arg("compile")
+        .arg(&source_file)
+        .arg("--output")
+        .arg(&bytecode_file)
+        .assert()
+        .success();
+
+    Command::cargo_bin("slang")
+        .unwrap()
+        .arg("run")
+        .arg(&bytecode_file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(expected_error));
+}
+
+#[test]
+fn compiled_overflow_reports_source_line() {
+    let program = r#"
+        let max = 2147483647i32;
+
+        print_value(max + 1i32);
+    "#;
+    compile_and_run_expect_runtime_error(program, "Integer overflow in I32 addition at line 4");
+}
+
+#[test]
+fn compiled_division_by_zero_reports_source_line() {
+    let program = r#"
+        let zero = 0i64;
+        print_value(10i64);
+        print_value(1i64 / zero);
+    "#;
+    compile_and_run_expect_runtime_error(program, "Division by zero at line 4");
+}
+
+#[test]
+fn compiled_recursion_limit_reports_source_line() {
+    let program = r#"
+        fn forever(n: i32) -> i32 {
+            return forever(n) + 1;
+        }
+
+        print_value(forever(1));
+    "#;
+    compile_and_run_expect_runtime_error(
+        program,
+        "Maximum recursion depth of 10000 exceeded in function 'forever' at line 3",
+    );
+}
+
+#[test]
+fn compiled_shared_constants_are_preserved() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("test.sl");
+    let bytecode_file = temp_dir.path().join("test.sip");
+
+    fs::write(
+        &source_file,
+        "print_value(\"hi\");\nprint_value(7);\nprint_value(\"hi\");\nprint_value(7);",
+    )
+    .unwrap();
+
+    Command::cargo_bin("slang")
+        .unwrap()
+        .arg("compile")
+        .arg(&source_file)
+        .arg("--output")
+        .arg(&bytecode_file)
+        .assert()
+        .success();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("run")
+        .arg(&bytecode_file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hi\n7\nhi\n7"));
+}
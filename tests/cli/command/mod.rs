@@ -1,3 +1,4 @@
+mod check;
 mod compile;
 mod execute;
 mod help;
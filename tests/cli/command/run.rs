@@ -1,4 +1,5 @@
 use assert_cmd::Command;
+use predicates::prelude::*;
 use std::fs;
 use tempfile::TempDir;
 
@@ -48,3 +49,54 @@ fn permission_denied_error() {
         .failure()
         .code(65); // Permission denied exit code
 }
+
+#[test]
+fn program_arguments() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("args.sl");
+    let bytecode_file = temp_dir.path().join("args.sip");
+
+    fs::write(
+        &source_file,
+        "print(\"{} arguments\", arg_count());\nprint_value(arg(0));\nprint_value(arg(1));",
+    )
+    .unwrap();
+
+    Command::cargo_bin("slang")
+        .unwrap()
+        .arg("compile")
+        .arg(&source_file)
+        .arg("-o")
+        .arg(&bytecode_file)
+        .assert()
+        .success();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("run")
+        .arg(&bytecode_file)
+        .arg("--")
+        .arg("first")
+        .arg("second value")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2 arguments\nfirst\nsecond value\n"));
+}
+
+#[test]
+fn program_argument_index_out_of_range() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("args.sl");
+
+    fs::write(&source_file, "print_value(arg(1));").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("execute")
+        .arg(&source_file)
+        .arg("--")
+        .arg("only")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Argument index 1 is out of range for 1 program arguments",
+        ));
+}
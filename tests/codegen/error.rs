@@ -13,3 +13,31 @@ fn too_many_constants() {
         "Too many constants",
     );
 }
+
+#[test]
+fn loop_body_too_large() {
+    let mut program = String::from("for i in range(0, 1) {\n");
+    for _ in 0..20000 {
+        program.push_str("print_value(i);\n");
+    }
+    program.push_str("}\n");
+    execute_program_expect_error(
+        &program,
+        ErrorCode::GenericCompileError,
+        "Loop body too large",
+    );
+}
+
+#[test]
+fn too_many_locals_in_one_function() {
+    let mut program = String::from("fn f() {\n");
+    for i in 0..257 {
+        program.push_str(&format!("    let a{} = 1;\n", i));
+    }
+    program.push_str("}\n");
+    execute_program_expect_error(
+        &program,
+        ErrorCode::GenericCompileError,
+        "Too many local variables in one function",
+    );
+}
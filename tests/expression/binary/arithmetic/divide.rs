@@ -1,5 +1,7 @@
 use crate::ErrorCode;
-use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+use crate::test_utils::{
+    execute_program_and_assert, execute_program_expect_error, execute_program_expect_runtime_error,
+};
 use rstest::rstest;
 
 #[rstest]
@@ -192,3 +194,46 @@ fn with_native_function() {
     );
 }
 
+
+#[rstest]
+#[case("1 / 0")]
+#[case("1i32 / 0i32")]
+#[case("1u64 / 0")]
+#[case("1.0 / 0.0")]
+fn by_literal_zero(#[case] division: &str) {
+    let program = format!("let a = {};", division);
+    execute_program_expect_error(
+        &program,
+        ErrorCode::DivisionByZero,
+        "Division by zero: right operand of '/' is zero",
+    );
+}
+
+#[rstest]
+#[case("1 / -0")]
+#[case("1i64 / -0i64")]
+#[case("1.0 / -0.0")]
+#[case("1 / (2 - 2)")]
+fn by_constant_zero(#[case] division: &str) {
+    let program = format!("let a = {};", division);
+    execute_program_expect_error(
+        &program,
+        ErrorCode::DivisionByZero,
+        "Division by zero: right operand of '/' is zero",
+    );
+}
+
+#[test]
+fn by_literal_zero_points_at_divisor() {
+    let program = "let a = 10 / 0;";
+    execute_program_expect_error(program, ErrorCode::DivisionByZero, "|              ^\n");
+}
+
+#[test]
+fn by_zero_variable_is_a_runtime_error() {
+    let program = r#"
+        let x = 0;
+        print_value(1 / x);
+    "#;
+    execute_program_expect_runtime_error(program, "Division by zero");
+}
@@ -2,4 +2,5 @@ mod divide;
 mod minus;
 mod misc;
 mod multiply;
+mod overflow;
 mod plus;
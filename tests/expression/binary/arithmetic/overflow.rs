@@ -0,0 +1,48 @@
+use crate::test_utils::{
+    execute_program_expect_runtime_error, execute_program_with_args_and_assert,
+};
+
+#[test]
+fn checked_addition_overflow_is_runtime_error() {
+    let program = r#"
+        let max = 2147483647i32;
+        print_value(max + 1i32);
+    "#;
+    execute_program_expect_runtime_error(program, "Integer overflow in I32 addition at line 3");
+}
+
+#[test]
+fn checked_subtraction_underflow_is_runtime_error() {
+    let program = r#"
+        let min = 0u32;
+        print_value(min - 1u32);
+    "#;
+    execute_program_expect_runtime_error(program, "Integer underflow in U32 subtraction");
+}
+
+#[test]
+fn wrapping_addition_wraps_around() {
+    let program = r#"
+        let max = 2147483647i32;
+        print_value(max + 1i32);
+    "#;
+    execute_program_with_args_and_assert(program, &["--wrapping-arithmetic"], "-2147483648");
+}
+
+#[test]
+fn wrapping_multiplication_wraps_around() {
+    let program = r#"
+        let max = 4294967295u32;
+        print_value(max * 2u32);
+    "#;
+    execute_program_with_args_and_assert(program, &["--wrapping-arithmetic"], "4294967294");
+}
+
+#[test]
+fn division_by_zero_reports_line() {
+    let program = r#"
+        let zero = 0i64;
+        print_value(1i64 / zero);
+    "#;
+    execute_program_expect_runtime_error(program, "Division by zero at line 3");
+}
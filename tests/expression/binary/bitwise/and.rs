@@ -0,0 +1,54 @@
+use crate::ErrorCode;
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+
+#[test]
+fn with_int_literals() {
+    let program = "print_value(6 & 3);";
+    execute_program_and_assert(program, "2");
+}
+
+#[test]
+fn with_unsigned_variables() {
+    let program = r#"
+        let a: u32 = 12u32;
+        let b: u32 = 10u32;
+        print_value(a & b);
+    "#;
+    execute_program_and_assert(program, "8");
+}
+
+#[test]
+fn binds_tighter_than_equality() {
+    let program = "print_value(6 & 3 == 2);";
+    execute_program_and_assert(program, "true");
+}
+
+#[test]
+fn does_not_interfere_with_logical_and() {
+    let program = "print_value(true && false);";
+    execute_program_and_assert(program, "false");
+}
+
+#[test]
+fn with_booleans() {
+    let program = r#"
+        let result = true & false;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::OperationTypeMismatch,
+        "Type mismatch: cannot apply '&' operator on bool and bool",
+    );
+}
+
+#[test]
+fn with_floats() {
+    let program = r#"
+        let result = 1.5 & 2.5;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::OperationTypeMismatch,
+        "Type mismatch: cannot apply '&' operator on float and float",
+    );
+}
@@ -0,0 +1,4 @@
+mod and;
+mod or;
+mod shift;
+mod xor;
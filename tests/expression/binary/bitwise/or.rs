@@ -0,0 +1,26 @@
+use crate::ErrorCode;
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+
+#[test]
+fn with_int_literals() {
+    let program = "print_value(5 | 2);";
+    execute_program_and_assert(program, "7");
+}
+
+#[test]
+fn does_not_interfere_with_logical_or() {
+    let program = "print_value(false || true);";
+    execute_program_and_assert(program, "true");
+}
+
+#[test]
+fn with_strings() {
+    let program = r#"
+        let result = "a" | "b";
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::OperationTypeMismatch,
+        "Type mismatch: cannot apply '|' operator on string and string",
+    );
+}
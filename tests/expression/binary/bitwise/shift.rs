@@ -0,0 +1,54 @@
+use crate::ErrorCode;
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+
+#[test]
+fn shift_left_with_int_literals() {
+    let program = "print_value(1 << 4);";
+    execute_program_and_assert(program, "16");
+}
+
+#[test]
+fn shift_right_with_int_literals() {
+    let program = "print_value(256 >> 2);";
+    execute_program_and_assert(program, "64");
+}
+
+#[test]
+fn shift_binds_tighter_than_comparison() {
+    let program = "print_value(1 << 2 < 5);";
+    execute_program_and_assert(program, "true");
+}
+
+#[test]
+fn shift_amount_larger_than_bit_width() {
+    let program = r#"
+        let a: u32 = 1u32;
+        print_value(a << 40u32);
+        print_value(-8 >> 100);
+    "#;
+    execute_program_and_assert(program, "0\n-1");
+}
+
+#[test]
+fn shift_float() {
+    let program = r#"
+        let result = 1.0 << 2;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::OperationTypeMismatch,
+        "Type mismatch: cannot apply '<<' operator on float and int",
+    );
+}
+
+#[test]
+fn shift_by_negative_amount() {
+    let program = r#"
+        let result = 1 << -1;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "Integer literal -1 is out of range for type u32",
+    );
+}
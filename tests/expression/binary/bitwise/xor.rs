@@ -0,0 +1,20 @@
+use crate::ErrorCode;
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+
+#[test]
+fn with_int_literals() {
+    let program = "print_value(6 ^ 3);";
+    execute_program_and_assert(program, "5");
+}
+
+#[test]
+fn with_booleans() {
+    let program = r#"
+        let result = true ^ true;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::OperationTypeMismatch,
+        "Type mismatch: cannot apply '^' operator on bool and bool",
+    );
+}
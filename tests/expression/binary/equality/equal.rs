@@ -70,6 +70,37 @@ fn not_equal_float(#[case] type_name: &str) {
     execute_program_and_assert(&program, "false");
 }
 
+#[rstest]
+#[case("f32")]
+#[case("f64")]
+fn nan_is_not_equal_to_itself(#[case] type_name: &str) {
+    let program = format!(
+        r#"
+        let a: {} = nan;
+
+        print_value(a == a);
+    "#,
+        type_name
+    );
+    execute_program_and_assert(&program, "false");
+}
+
+#[rstest]
+#[case("f32")]
+#[case("f64")]
+fn signed_zeros_are_equal(#[case] type_name: &str) {
+    let program = format!(
+        r#"
+        let a: {} = 0.0;
+        let b: {} = -0.0;
+
+        print_value(a == b);
+    "#,
+        type_name, type_name
+    );
+    execute_program_and_assert(&program, "true");
+}
+
 #[test]
 fn test_equal_operator_with_booleans() {
     let program = r#"
@@ -84,6 +115,18 @@ fn test_equal_operator_with_booleans() {
     execute_program_and_assert(program, "true\ntrue\nfalse");
 }
 
+#[test]
+fn booleans_are_not_ordered() {
+    let program = r#"
+        print_value(true < false);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::OperationTypeMismatch,
+        "Type mismatch: cannot apply '<' operator on bool and bool",
+    );
+}
+
 #[test]
 fn with_strings() {
     let program = r#"
@@ -102,11 +145,20 @@ fn with_unit() {
         let x = ();
         let y = ();
         print_value(x == y);
+        print_value(() == ());
+    "#;
+    execute_program_and_assert(program, "true\ntrue");
+}
+
+#[test]
+fn unit_and_boolean() {
+    let program = r#"
+        print_value(() == true);
     "#;
     execute_program_expect_error(
         program,
         ErrorCode::OperationTypeMismatch,
-        "Type mismatch: cannot apply '==' operator on () and ()",
+        "Type mismatch: cannot apply '==' operator on () and bool",
     );
 }
 
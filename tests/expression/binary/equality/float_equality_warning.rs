@@ -0,0 +1,50 @@
+use predicates::prelude::*;
+use slang_error::ErrorCode;
+
+use crate::test_utils::execute_program;
+
+#[test]
+fn warns_when_enabled() {
+    let program = r#"
+        let a: f64 = 0.1 + 0.2;
+        print_value(a == 0.3);
+        print_value(a != 0.3);
+    "#;
+    execute_program(program, &["--warn-float-equality"])
+        .success()
+        .stdout(predicate::str::contains("false\ntrue"))
+        .stderr(predicate::str::contains(
+            ErrorCode::FloatEquality.to_string(),
+        ))
+        .stderr(predicate::str::contains(
+            "Comparing floating-point values with '==' is unreliable due to rounding errors",
+        ))
+        .stderr(predicate::str::contains("'!='"))
+        .stderr(predicate::str::contains("2 warnings"));
+}
+
+#[test]
+fn no_warning_by_default() {
+    let program = r#"
+        let a: f64 = 0.5;
+        print_value(a == 0.5);
+    "#;
+    execute_program(program, &[])
+        .success()
+        .stdout(predicate::str::contains("true"))
+        .stderr(predicate::str::contains(ErrorCode::FloatEquality.to_string()).not());
+}
+
+#[test]
+fn no_warning_for_ordering_or_integer_equality() {
+    let program = r#"
+        let a: f64 = 0.5;
+        let b: i32 = 1;
+        print_value(a < 1.0);
+        print_value(b == 1);
+    "#;
+    execute_program(program, &["--warn-float-equality"])
+        .success()
+        .stdout(predicate::str::contains("true\ntrue"))
+        .stderr(predicate::str::contains(ErrorCode::FloatEquality.to_string()).not());
+}
@@ -1,2 +1,3 @@
 mod equal;
+mod float_equality_warning;
 mod not_equal;
@@ -70,6 +70,21 @@ fn not_equal_float(#[case] type_name: &str) {
     execute_program_and_assert(&program, "true");
 }
 
+#[rstest]
+#[case("f32")]
+#[case("f64")]
+fn nan_is_not_equal_to_itself(#[case] type_name: &str) {
+    let program = format!(
+        r#"
+        let a: {} = nan;
+
+        print_value(a != a);
+    "#,
+        type_name
+    );
+    execute_program_and_assert(&program, "true");
+}
+
 #[test]
 fn with_booleans() {
     let program = r#"
@@ -102,11 +117,20 @@ fn with_unit() {
         let x = ();
         let y = ();
         print_value(x != y);
+        print_value(() != ());
+    "#;
+    execute_program_and_assert(program, "false\nfalse");
+}
+
+#[test]
+fn unit_and_boolean() {
+    let program = r#"
+        print_value(() != true);
     "#;
     execute_program_expect_error(
         program,
         ErrorCode::OperationTypeMismatch,
-        "Type mismatch: cannot apply '!=' operator on () and ()",
+        "Type mismatch: cannot apply '!=' operator on () and bool",
     );
 }
 
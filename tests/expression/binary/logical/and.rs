@@ -1,5 +1,7 @@
 use crate::ErrorCode;
-use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+use crate::test_utils::{
+    execute_program_and_assert, execute_program_expect_error, execute_program_expect_runtime_error,
+};
 use rstest::rstest;
 
 #[rstest]
@@ -49,12 +51,37 @@ fn short_circuit() {
     // If short-circuiting works correctly, this will not cause an error
     // because the second part won't be evaluated when the first is false
     let program = r#"
-        let result = false && (1 / 0 > 0);
+        let zero = 0;
+        let result = false && (1 / zero > 0);
         print_value(result);
     "#;
     execute_program_and_assert(program, "false");
 }
 
+#[test]
+fn short_circuit_skips_call() {
+    let program = r#"
+        fn fails() -> bool {
+            let zero = 0;
+            return 1 / zero > 0;
+        }
+        print_value(false && fails());
+    "#;
+    execute_program_and_assert(program, "false");
+}
+
+#[test]
+fn evaluates_call_when_needed() {
+    let program = r#"
+        fn fails() -> bool {
+            let zero = 0;
+            return 1 / zero > 0;
+        }
+        print_value(true && fails());
+    "#;
+    execute_program_expect_runtime_error(program, "Division by zero");
+}
+
 #[test]
 fn with_function() {
     let program = r#"
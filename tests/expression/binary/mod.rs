@@ -1,4 +1,6 @@
 mod arithmetic;
+mod bitwise;
 mod equality;
 mod logical;
 mod relational;
+mod span;
@@ -127,12 +127,21 @@ fn with_booleans() {
 #[test]
 fn with_strings() {
     let program = r#"
-        let result1 = "hello" <= "hello";
+        print_value("apple" <= "banana");
+        print_value("hello" <= "hello");
+    "#;
+    execute_program_and_assert(program, "true\ntrue");
+}
+
+#[test]
+fn string_and_integer() {
+    let program = r#"
+        let result1 = "a" <= 1;
     "#;
     execute_program_expect_error(
         program,
         ErrorCode::OperationTypeMismatch,
-        "Type mismatch: cannot apply '<=' operator on string and string",
+        "Type mismatch: cannot apply '<=' operator on string and int",
     );
 }
 
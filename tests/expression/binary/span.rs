@@ -0,0 +1,52 @@
+use crate::test_utils::execute_program_expect_error;
+use slang_error::ErrorCode;
+
+#[test]
+fn arithmetic_error_spans_both_operands() {
+    let program = "let a: i32 = 1; let b = a + true;";
+    execute_program_expect_error(
+        program,
+        ErrorCode::OperationTypeMismatch,
+        "|                         ^^^^^^^^\n",
+    );
+}
+
+#[test]
+fn grouped_operand_includes_parentheses() {
+    let program = "let a: i32 = 1; let b = (a + 1) * true;";
+    execute_program_expect_error(
+        program,
+        ErrorCode::OperationTypeMismatch,
+        "|                         ^^^^^^^^^^^^^^\n",
+    );
+}
+
+#[test]
+fn relational_error_includes_string_quotes() {
+    let program = "let a: i32 = 1; let b = a < \"hello\";";
+    execute_program_expect_error(
+        program,
+        ErrorCode::OperationTypeMismatch,
+        "|                         ^^^^^^^^^^^\n",
+    );
+}
+
+#[test]
+fn logical_error_spans_both_operands() {
+    let program = "let a = true && 12345;";
+    execute_program_expect_error(
+        program,
+        ErrorCode::LogicalOperatorTypeMismatch,
+        "|         ^^^^^^^^^^^^^\n",
+    );
+}
+
+#[test]
+fn call_operand_starts_at_function_name() {
+    let program = "fn one() -> i32 { return 1; } let a = one() + true;";
+    execute_program_expect_error(
+        program,
+        ErrorCode::OperationTypeMismatch,
+        "|                                       ^^^^^^^^^^^^\n",
+    );
+}
@@ -1,4 +1,5 @@
-use crate::test_utils::execute_program_and_assert;
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+use slang_error::ErrorCode;
 
 #[test]
 fn without_return() {
@@ -11,3 +12,98 @@ fn without_return() {
     "#;
     execute_program_and_assert(program, "()");
 }
+
+#[test]
+fn let_initializer_declarations_do_not_leak() {
+    let program = r#"
+        let x = { let y = 1; y + 1 };
+        print_value(y);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::UndefinedVariable,
+        "Undefined variable: y",
+    );
+}
+
+#[test]
+fn let_initializer_name_can_be_reused_after_block() {
+    let program = r#"
+        let x = { let y = 1; y + 1 };
+        let y = 40;
+        print_value(x + y);
+    "#;
+    execute_program_and_assert(program, "42");
+}
+
+#[test]
+fn let_initializer_takes_tail_expression_type() {
+    let program = r#"
+        let x = { let y = 1i32; y + 1i32 };
+        let z: i64 = x;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::TypeMismatch,
+        "Type mismatch: variable z is i64 but expression is i32",
+    );
+}
+
+#[test]
+fn let_initializer_tail_literal_coerces_to_declared_type() {
+    let program = r#"
+        let x: f32 = { let y = 1; 2.5 };
+        print_value(x);
+    "#;
+    execute_program_and_assert(program, "2.5");
+}
+
+#[test]
+fn let_initializer_tail_literal_out_of_range() {
+    let program = r#"
+        let x: u32 = { let y = 1; -1 };
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "Integer literal -1 is out of range for type u32",
+    );
+}
+
+#[test]
+fn ending_in_if_else_statement_is_unit() {
+    let program = r#"
+        let c = true;
+        let result: () = {
+            if c { print_value(1); } else { print_value(2); }
+        };
+        print_value(result);
+    "#;
+    execute_program_and_assert(program, "1\n()");
+}
+
+#[test]
+fn ending_in_if_statement_without_else_is_unit() {
+    let program = r#"
+        let c = false;
+        let result = {
+            let x = 1;
+            if c { print_value(x); }
+        };
+        print_value(result);
+    "#;
+    execute_program_and_assert(program, "()");
+}
+
+#[test]
+fn ending_in_if_statement_is_not_a_value() {
+    let program = r#"
+        let c = true;
+        let result: i32 = { if c { print_value(1); } else { print_value(2); } };
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::TypeMismatch,
+        "Type mismatch: variable result is i32 but expression is ()",
+    );
+}
@@ -0,0 +1,211 @@
+use crate::ErrorCode;
+use crate::test_utils::{
+    execute_program_and_assert, execute_program_expect_error, execute_program_expect_runtime_error,
+};
+
+#[test]
+fn saturating_add_clamps_to_maximum() {
+    let program = r#"
+        print_value(saturating_add(4294967290u32, 10u32) == 4294967295u32);
+    "#;
+    execute_program_and_assert(program, "true");
+}
+
+#[test]
+fn wrapping_add_wraps_around() {
+    let program = r#"
+        print_value(wrapping_add(4294967290u32, 10u32) == 4u32);
+    "#;
+    execute_program_and_assert(program, "true");
+}
+
+#[test]
+fn saturating_sub_clamps_to_minimum() {
+    let program = r#"
+        print_value(saturating_sub(-2147483640i32, 100));
+    "#;
+    execute_program_and_assert(program, "-2147483648");
+}
+
+#[test]
+fn wrapping_mul_on_i64() {
+    let program = r#"
+        print_value(wrapping_mul(9223372036854775807i64, 2i64));
+    "#;
+    execute_program_and_assert(program, "-2");
+}
+
+#[test]
+fn result_has_operand_type() {
+    let program = r#"
+        let x: u64 = saturating_mul(10u64, 3u64);
+        print_value(x);
+    "#;
+    execute_program_and_assert(program, "30");
+}
+
+#[test]
+fn mismatched_integer_types() {
+    let program = r#"
+        wrapping_add(1i32, 2u32);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ArgumentTypeMismatch,
+        "Type mismatch: function 'wrapping_add' expects argument 2 to be i32, but got u32",
+    );
+}
+
+#[test]
+fn non_integer_argument() {
+    let program = r#"
+        saturating_add(1.5, 2);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::InvalidExpression,
+        "Function 'saturating_add' expects integer arguments, but argument 1 is float",
+    );
+}
+
+#[test]
+fn literal_arguments_are_inferred_as_i64() {
+    let program = r#"
+        let x: i32 = wrapping_add(2147483647, 1);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::TypeMismatch,
+        "Type mismatch: variable x is i32 but expression is i64",
+    );
+}
+
+#[test]
+fn literal_arguments_wrap_as_i64() {
+    let program = r#"
+        let x = wrapping_add(9223372036854775807, 1);
+        print_value(x);
+    "#;
+    execute_program_and_assert(program, "-9223372036854775808");
+}
+
+#[test]
+fn intrinsic_used_as_value() {
+    let program = r#"
+        let g = saturating_add;
+        g(1.5, 2.5);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::InvalidExpression,
+        "Function 'saturating_add' is an intrinsic and can only be called directly",
+    );
+}
+
+#[test]
+fn pow_of_integers_is_an_integer() {
+    let program = r#"
+        let x: i64 = pow(2, 10);
+        print_value(x);
+        print_value(pow(3u32, 2u32) == 9u32);
+    "#;
+    execute_program_and_assert(program, "1024\ntrue");
+}
+
+#[test]
+fn pow_of_floats_is_a_float() {
+    let program = r#"
+        let x: f64 = pow(2.0, 0.5);
+        print_value(x > 1.414 && x < 1.415);
+        print_value(pow(1.5, 2));
+    "#;
+    execute_program_and_assert(program, "true\n2.25");
+}
+
+#[test]
+fn pow_result_has_base_type() {
+    let program = r#"
+        let x: i32 = pow(2.0, 0.5);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::TypeMismatch,
+        "Type mismatch: variable x is i32 but expression is f64",
+    );
+}
+
+#[test]
+fn pow_of_literals_is_inferred_as_i64() {
+    let program = r#"
+        let a: u32 = pow(2, 40);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::TypeMismatch,
+        "Type mismatch: variable a is u32 but expression is i64",
+    );
+}
+
+#[test]
+fn pow_with_typed_base_checks_range() {
+    let program = r#"
+        let a: u32 = pow(2u32, 40u32);
+        print_value(a);
+    "#;
+    execute_program_expect_runtime_error(program, "Integer overflow in pow");
+}
+
+#[test]
+fn pow_used_as_value() {
+    let program = r#"
+        let p = pow;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::InvalidExpression,
+        "Function 'pow' is an intrinsic and can only be called directly",
+    );
+}
+
+#[test]
+fn pow_integer_base_with_float_exponent() {
+    let program = r#"
+        pow(2, 0.5);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::InvalidExpression,
+        "Function 'pow' expects an integer exponent for an integer base, but argument 2 is float",
+    );
+}
+
+#[test]
+fn pow_non_numeric_base() {
+    let program = r#"
+        pow("2", 2);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::InvalidExpression,
+        "Function 'pow' expects a numeric base, but argument 1 is string",
+    );
+}
+
+#[test]
+fn pow_integer_overflow() {
+    let program = r#"
+        pow(2i32, 31i32);
+    "#;
+    execute_program_expect_runtime_error(program, "Integer overflow in pow");
+}
+
+#[test]
+fn pow_negative_exponent_for_integer_base() {
+    let program = r#"
+        pow(2i32, -1i32);
+    "#;
+    execute_program_expect_runtime_error(
+        program,
+        "pow exponent must not be negative for integer bases",
+    );
+}
@@ -1,2 +1,8 @@
+mod intrinsics;
+mod print;
+mod qualified;
+mod string_functions;
+mod trailing_comma;
+mod type_query;
 mod type_errors;
 
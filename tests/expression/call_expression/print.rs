@@ -0,0 +1,56 @@
+use crate::ErrorCode;
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+
+#[test]
+fn print_substitutes_placeholders_in_order() {
+    let program = r#"
+        let name = "world";
+        let count: i32 = 3;
+        print("Hello, {}! You have {} new messages: {}", name, count, true);
+    "#;
+    execute_program_and_assert(program, "Hello, world! You have 3 new messages: true");
+}
+
+#[test]
+fn print_without_placeholders() {
+    let program = r#"
+        print("plain text");
+    "#;
+    execute_program_and_assert(program, "plain text");
+}
+
+#[test]
+fn print_with_fewer_values_than_placeholders() {
+    let program = r#"
+        print("{} and {}", 1);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::FormatArgumentCountMismatch,
+        "Format string of 'print' has 2 placeholders, but 1 values were given",
+    );
+}
+
+#[test]
+fn print_with_more_values_than_placeholders() {
+    let program = r#"
+        print("{}", 1, 2);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::FormatArgumentCountMismatch,
+        "Format string of 'print' has 1 placeholders, but 2 values were given",
+    );
+}
+
+#[test]
+fn print_requires_a_format_string() {
+    let program = r#"
+        print();
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ArgumentCountMismatch,
+        "Function 'print' expects 1 arguments, but got 0",
+    );
+}
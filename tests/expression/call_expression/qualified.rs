@@ -0,0 +1,26 @@
+use crate::ErrorCode;
+use crate::test_utils::execute_program_expect_error;
+
+#[test]
+fn unknown_module_function() {
+    let program = r#"
+        math::add(1, 2);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::UndefinedFunction,
+        "Undefined function: math::add",
+    );
+}
+
+#[test]
+fn missing_path_segment() {
+    let program = r#"
+        let x = math::;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ExpectedIdentifier,
+        "Expected identifier after '::'",
+    );
+}
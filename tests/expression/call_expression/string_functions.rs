@@ -0,0 +1,82 @@
+use crate::ErrorCode;
+use crate::test_utils::{
+    execute_program_and_assert, execute_program_expect_error, execute_program_expect_runtime_error,
+};
+
+#[test]
+fn len_counts_characters() {
+    let program = r#"
+        print_value(len("hello"));
+    "#;
+    execute_program_and_assert(program, "5");
+}
+
+#[test]
+fn len_counts_characters_not_bytes() {
+    let program = r#"
+        print_value(len("héllo"));
+    "#;
+    execute_program_and_assert(program, "5");
+}
+
+#[test]
+fn len_of_empty_string() {
+    let program = r#"
+        print_value(len(""));
+    "#;
+    execute_program_and_assert(program, "0");
+}
+
+#[test]
+fn len_returns_i64() {
+    let program = r#"
+        let length: i64 = len("abc");
+        print_value(length * 2);
+    "#;
+    execute_program_and_assert(program, "6");
+}
+
+#[test]
+fn char_at_indexes_characters() {
+    let program = r#"
+        let word = "héllo";
+        print_value(char_at(word, 1));
+        print_value(char_at(word, len(word) - 1));
+    "#;
+    execute_program_and_assert(program, "é\no");
+}
+
+#[test]
+fn char_at_out_of_bounds() {
+    let program = r#"
+        print_value(char_at("abc", 3));
+    "#;
+    execute_program_expect_runtime_error(
+        program,
+        "Character index 3 is out of range for a string of length 3 at line 2",
+    );
+}
+
+#[test]
+fn char_at_negative_index() {
+    let program = r#"
+        print_value(char_at("abc", -1));
+    "#;
+    execute_program_expect_runtime_error(
+        program,
+        "Character index -1 is out of range for a string of length 3",
+    );
+}
+
+#[test]
+fn len_of_non_string() {
+    let program = r#"
+        let flag = true;
+        len(flag);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ArgumentTypeMismatch,
+        "Type mismatch: function 'len' expects argument 1 to be string, but got bool",
+    );
+}
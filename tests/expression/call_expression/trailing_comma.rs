@@ -0,0 +1,35 @@
+use crate::ErrorCode;
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+
+#[test]
+fn trailing_comma_in_arguments() {
+    let program = r#"
+        fn f(a: i32, b: i32) -> i32 {
+            return a + b;
+        }
+
+        print_value(f(1, 2,));
+    "#;
+    execute_program_and_assert(program, "3");
+}
+
+#[test]
+fn trailing_comma_in_closure_parameters() {
+    let program = r#"
+        let multiply = |a: i32, b: i32,| -> i32 { a * b };
+        print_value(multiply(3, 4,));
+    "#;
+    execute_program_and_assert(program, "12");
+}
+
+#[test]
+fn lone_comma_is_not_an_argument_list() {
+    let program = r#"
+        fn f() -> i32 {
+            return 1;
+        }
+
+        f(,);
+    "#;
+    execute_program_expect_error(program, ErrorCode::ExpectedExpression, "Expected expression");
+}
@@ -35,7 +35,7 @@ fn with_string_variable() {
         a();
     "#;
     execute_program_expect_error(
-        &program,
+        program,
         crate::ErrorCode::VariableNotCallable,
         "Cannot call string type 'a' as a function",
     );
@@ -60,9 +60,52 @@ fn with_unit_variable() {
         a();
     "#;
     execute_program_expect_error(
-        &program,
+        program,
         ErrorCode::VariableNotCallable,
         "Cannot call () type 'a' as a function",
     );
 }
 
+
+#[test]
+fn argument_count_error_underlines_argument_list() {
+    let program = "fn add(a: i32, b: i32) -> i32 { return a + b; } add(1, 2, 3);";
+    execute_program_expect_error(
+        program,
+        ErrorCode::ArgumentCountMismatch,
+        "|                                                    ^^^^^^^^^\n",
+    );
+}
+
+#[test]
+fn argument_type_error_underlines_nested_call() {
+    let program = "fn id(a: i32) -> i32 { return a; } id(print(\"{}\", 1) == 0);";
+    execute_program_expect_error(
+        program,
+        ErrorCode::ArgumentTypeMismatch,
+        "|                                       ^^^^^^^^^^^^^^^^^^^\n",
+    );
+}
+
+#[test]
+fn negative_literal_for_unsigned_parameter() {
+    let program = r#"
+        fn f(x: u64) -> u64 { return x; }
+        f(-1);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ArgumentTypeMismatch,
+        "Type mismatch: function 'f' expects argument 1 to be u64, but got int (note: the value -1 is negative)",
+    );
+}
+
+#[test]
+fn negative_literal_for_unsigned_parameter_underlines_argument() {
+    let program = "fn f(x: u64) -> u64 { return x; } f(-1);";
+    execute_program_expect_error(
+        program,
+        ErrorCode::ArgumentTypeMismatch,
+        "|                                     ^^\n",
+    );
+}
@@ -0,0 +1,75 @@
+use predicates::prelude::*;
+use slang_error::ErrorCode;
+
+use crate::test_utils::{
+    execute_program, execute_program_and_assert, execute_program_expect_error,
+};
+
+#[test]
+fn reports_inferred_integer_type() {
+    execute_program("__type_of(1 + 2);", &[])
+        .success()
+        .stderr(predicate::str::contains(ErrorCode::TypeQuery.to_string()))
+        .stderr(predicate::str::contains(
+            "Inferred type of the expression is int, which becomes i64",
+        ));
+}
+
+#[test]
+fn reports_declared_type_of_variable() {
+    let program = r#"
+        let value: u32 = 4;
+        __type_of(value + 1);
+    "#;
+    execute_program(program, &[])
+        .success()
+        .stderr(predicate::str::contains(
+            "Inferred type of the expression is u32",
+        ));
+}
+
+#[test]
+fn argument_is_not_evaluated() {
+    let program = r#"
+        __type_of(print_value("evaluated"));
+        print_value("done");
+    "#;
+    execute_program(program, &[])
+        .success()
+        .stdout(predicate::str::contains("done"))
+        .stdout(predicate::str::contains("evaluated").not())
+        .stderr(predicate::str::contains(
+            "Inferred type of the expression is i32",
+        ));
+}
+
+#[test]
+fn type_query_has_unit_type() {
+    let program = r#"
+        let result: () = __type_of(1.5);
+        print_value(result);
+    "#;
+    execute_program_and_assert(program, "()");
+}
+
+#[test]
+fn type_query_takes_one_argument() {
+    execute_program_expect_error(
+        "__type_of(1, 2);",
+        ErrorCode::ArgumentCountMismatch,
+        "Function '__type_of' expects 1 argument",
+    );
+}
+
+#[test]
+fn type_query_used_as_value() {
+    let program = r#"
+        let f = __type_of;
+        f(1);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::InvalidExpression,
+        "Function '__type_of' is an intrinsic and can only be called directly",
+    );
+}
@@ -0,0 +1,69 @@
+use crate::ErrorCode;
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+
+#[test]
+fn closure_capturing_local_is_called() {
+    let program = r#"
+        fn main() -> i32 {
+            let offset: i32 = 10;
+            let add = |x: i32| -> i32 { x + offset };
+            add(5)
+        }
+        print_value(main());
+    "#;
+    execute_program_and_assert(program, "15");
+}
+
+#[test]
+fn closure_captures_by_value() {
+    let program = r#"
+        fn main() {
+            let mut counter: i32 = 1;
+            let get = || -> i32 { counter };
+            counter = 2;
+            print_value(get());
+        }
+        main();
+    "#;
+    execute_program_and_assert(program, "1");
+}
+
+#[test]
+fn closure_captures_top_level_variable_by_value() {
+    let program = r#"
+        let mut counter: i32 = 1;
+        let get = || -> i32 { counter };
+        counter = 2;
+        print_value(get());
+    "#;
+    execute_program_and_assert(program, "1");
+}
+
+#[test]
+fn closure_calls_function_declared_at_top_level() {
+    let program = r#"
+        fn double(x: i32) -> i32 {
+            x * 2
+        }
+        let apply = |x: i32| -> i32 { double(x) };
+        print_value(apply(4));
+    "#;
+    execute_program_and_assert(program, "8");
+}
+
+#[test]
+fn closure_referencing_out_of_scope_name() {
+    let program = r#"
+        fn main() {
+            {
+                let hidden: i32 = 1;
+            }
+            let add = |x: i32| -> i32 { x + hidden };
+        }
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::UndefinedVariable,
+        "Undefined variable: hidden",
+    );
+}
@@ -0,0 +1,2 @@
+mod capture;
+mod return_type;
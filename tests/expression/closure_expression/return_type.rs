@@ -0,0 +1,67 @@
+use crate::ErrorCode;
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+
+#[test]
+fn final_expression_type_mismatch() {
+    let program = r#"
+        let f = |x: i32| -> i32 { "s" };
+        print_value(f(1));
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ReturnTypeMismatch,
+        "Type mismatch: function returns i32 but got string",
+    );
+}
+
+#[test]
+fn return_statement_type_mismatch() {
+    let program = r#"
+        let f = |x: i32| -> i32 { return "s"; };
+        print_value(f(1));
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ReturnTypeMismatch,
+        "Type mismatch: function returns i32 but got string",
+    );
+}
+
+#[test]
+fn literal_final_expression_takes_return_type() {
+    let program = r#"
+        let f = |x: u32| -> u32 { x + 2 };
+        let limit = || -> u64 { 3 };
+        print_value(f(1) + 1u32);
+        print_value(limit() + 1u64);
+    "#;
+    execute_program_and_assert(program, "4\n4");
+}
+
+#[test]
+fn literal_out_of_range_for_return_type() {
+    let program = r#"
+        let f = || -> i32 { 3000000000 };
+        print_value(f());
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "out of range for type i32",
+    );
+}
+
+#[test]
+fn final_expression_returning_on_every_path() {
+    let program = r#"
+        let sign = |x: i32| -> string {
+            if x < 0 {
+                return "negative";
+            } else {
+                return "positive";
+            }
+        };
+        print_value(sign(-2));
+    "#;
+    execute_program_and_assert(program, "negative");
+}
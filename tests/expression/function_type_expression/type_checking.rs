@@ -36,3 +36,19 @@ fn return_mismatch() {
     "#;
     execute_program_expect_error(program, ErrorCode::TypeMismatch, "Type mismatch");
 }
+
+#[test]
+fn nested_function_type_in_message() {
+    let program = r#"
+        fn apply(f: fn(i32) -> i32, x: i32) -> i32 {
+            return f(x);
+        }
+
+        let func_var: fn(fn(i32) -> i32, i32) -> bool = apply;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::TypeMismatch,
+        "Type mismatch: variable func_var is fn(fn(i32) -> i32, i32) -> bool but expression is fn(fn(i32) -> i32, i32) -> i32",
+    );
+}
@@ -194,6 +194,39 @@ fn must_have_else_branch() {
     );
 }
 
+#[test]
+fn let_without_else_requires_else_branch() {
+    let program = r#"
+        let c = true;
+        let x = if c { 1 };
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ExpectedElse,
+        "an if that produces a value needs both branches",
+    );
+}
+
+#[test]
+fn without_else_in_unit_context() {
+    let program = r#"
+        fn f() {
+            print_value("called");
+        }
+
+        fn run(c: bool) {
+            if c { f(); }
+        }
+
+        let c = true;
+        if c { f(); }
+        let unit = { if c { f(); } };
+        run(c);
+        print_value(unit);
+    "#;
+    execute_program_and_assert(program, "called\ncalled\ncalled\n()");
+}
+
 #[test]
 fn with_unit_branches() {
     let program = r#"
@@ -231,3 +264,13 @@ fn with_native_function_branches() {
     execute_program_and_assert(program, "100");
 }
 
+
+#[test]
+fn branch_type_mismatch_points_at_else_branch() {
+    let program = "let result: i32 = if true { 10 } else { \"string\" };";
+    execute_program_expect_error(
+        program,
+        ErrorCode::TypeMismatch,
+        "|                                         ^^^^^^^^\n",
+    );
+}
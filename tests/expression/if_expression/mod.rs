@@ -1,2 +1,2 @@
-mod if_expression;
+mod basic;
 
@@ -1,5 +1,6 @@
-use crate::test_utils::execute_program_and_assert;
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
 use rstest::rstest;
+use slang_error::ErrorCode;
 
 #[test]
 fn basic() {
@@ -33,3 +34,72 @@ fn negative() {
     "#;
     execute_program_and_assert(program, "-3.14");
 }
+
+#[rstest]
+#[case("inf", "inf")]
+#[case("-inf", "-inf")]
+#[case("nan", "NaN")]
+#[case("-0.0", "-0")]
+fn special_values(#[case] literal: &str, #[case] expected: &str) {
+    let program = format!(r#"print_value({});"#, literal);
+    execute_program_and_assert(&program, expected);
+}
+
+#[test]
+fn infinity_is_valid_f32() {
+    let program = r#"
+        let x: f32 = -inf;
+        print_value(x);
+    "#;
+    execute_program_and_assert(program, "-inf");
+}
+
+#[test]
+fn nan_is_not_equal_to_itself() {
+    let program = r#"
+        print_value(nan == nan);
+    "#;
+    execute_program_and_assert(program, "false");
+}
+
+#[test]
+fn f32_suffix_overflowing_to_infinity() {
+    let program = r#"
+        print_value(3.5e40f32);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "Value 3.5e40 is out of range for f32",
+    );
+}
+
+#[test]
+fn f32_annotation_overflowing_to_infinity() {
+    let program = r#"
+        let x: f32 = 3.5e40;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "is out of range for type f32",
+    );
+}
+
+#[test]
+fn f64_overflowing_to_infinity() {
+    let program = r#"
+        print_value(1.0e400);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "Value 1.0e400 is out of range for f64",
+    );
+}
+
+#[test]
+fn unspecified_literal_takes_type_of_f32_context() {
+    let program = "let a: f32 = 1.5; print_value(a + 2.0);";
+    execute_program_and_assert(program, "3.5");
+}
@@ -1,5 +1,8 @@
 use crate::ErrorCode;
-use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+use crate::test_utils::{
+    execute_program_and_assert, execute_program_expect_error, execute_program_expect_runtime_error,
+    execute_program_with_args_and_assert,
+};
 use rstest::rstest;
 
 #[test]
@@ -15,6 +18,8 @@ fn basic() {
 #[case("42i64")]
 #[case("42u32")]
 #[case("42u64")]
+#[case("42i128")]
+#[case("42u128")]
 fn with_suffix(#[case] literal: &str) {
     let program = format!(r#"print_value({});"#, literal);
     execute_program_and_assert(&program, "42");
@@ -31,7 +36,197 @@ fn negative() {
 #[test]
 fn integer_overflow_error() {
     let program = r#"
-        print_value(999999999999999999999999999999);
+        print_value(999999999999999999999999999999999999999999);
     "#;
     execute_program_expect_error(program, ErrorCode::InvalidNumberLiteral, "Invalid integer");
 }
+
+#[test]
+fn inferred_literal_out_of_i64_range() {
+    let program = r#"
+        print_value(999999999999999999999999999999);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "Integer literal 999999999999999999999999999999 is out of range for type i64",
+    );
+}
+
+#[rstest]
+#[case(
+    "5000000000u32",
+    "Value 5000000000 is out of range for u32 (valid range is 0..=4294967295)"
+)]
+#[case(
+    "2147483648i32",
+    "Value 2147483648 is out of range for i32 (valid range is -2147483648..=2147483647)"
+)]
+fn suffix_out_of_range_reports_bounds(#[case] literal: &str, #[case] message: &str) {
+    let program = format!("print_value({});", literal);
+    execute_program_expect_error(&program, ErrorCode::ValueOutOfRange, message);
+}
+
+#[rstest]
+#[case("1_000u32", "1000")]
+#[case("4_000_000_000u32", "4000000000")]
+#[case("0xFF_FFu32", "65535")]
+#[case("0x2a", "42")]
+#[case("1_000_000", "1000000")]
+fn with_separators_and_suffix(#[case] literal: &str, #[case] expected: &str) {
+    let program = format!("print_value({});", literal);
+    execute_program_and_assert(&program, expected);
+}
+
+#[rstest]
+#[case(
+    "5_000_000_000u32",
+    "Value 5000000000 is out of range for u32 (valid range is 0..=4294967295)"
+)]
+#[case(
+    "0x1_0000_0000u32",
+    "Value 4294967296 is out of range for u32 (valid range is 0..=4294967295)"
+)]
+fn with_separators_out_of_range(#[case] literal: &str, #[case] message: &str) {
+    let program = format!("print_value({});", literal);
+    execute_program_expect_error(&program, ErrorCode::ValueOutOfRange, message);
+}
+
+#[test]
+fn hex_prefix_without_digits() {
+    let program = "print_value(0x);";
+    execute_program_expect_error(
+        program,
+        ErrorCode::InvalidNumberLiteral,
+        "Invalid integer: 0x",
+    );
+}
+
+#[rstest]
+#[case("18446744073709551616u128", "18446744073709551616")]
+#[case(
+    "340282366920938463463374607431768211455u128",
+    "340282366920938463463374607431768211455"
+)]
+#[case("0xFFFF_FFFF_FFFF_FFFF_FFFFu128", "1208925819614629174706175")]
+#[case(
+    "-170141183460469231731687303715884105727i128",
+    "-170141183460469231731687303715884105727"
+)]
+fn wide_suffix_beyond_u64(#[case] literal: &str, #[case] expected: &str) {
+    let program = format!("print_value({});", literal);
+    execute_program_and_assert(&program, expected);
+}
+
+#[test]
+fn wide_arithmetic() {
+    let program = r#"
+        let max_u64 = 18446744073709551615u128;
+        print_value(max_u64 + 1u128);
+    "#;
+    execute_program_and_assert(program, "18446744073709551616");
+}
+
+#[test]
+fn i128_suffix_out_of_range() {
+    let program = "print_value(170141183460469231731687303715884105728i128);";
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "Value 170141183460469231731687303715884105728 is out of range for i128",
+    );
+}
+
+#[rstest]
+#[case("print_value(18446744073709551615u64);")]
+#[case("let max: u64 = 18446744073709551615; print_value(max);")]
+fn max_u64(#[case] program: &str) {
+    execute_program_and_assert(program, "18446744073709551615");
+}
+
+#[test]
+fn u64_suffix_just_above_max() {
+    let program = "print_value(18446744073709551616u64);";
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "Value 18446744073709551616 is out of range for u64 (valid range is 0..=18446744073709551615)",
+    );
+}
+
+#[test]
+fn u64_annotation_just_above_max() {
+    let program = "let max: u64 = 18446744073709551616;";
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "Integer literal 18446744073709551616 is out of range for type u64",
+    );
+}
+
+#[test]
+fn inferred_literal_above_i64_max() {
+    let program = "let x = 9223372036854775808;";
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "Integer literal 9223372036854775808 is out of range for type i64",
+    );
+}
+
+#[rstest]
+#[case("let x: i64 = -9223372036854775808; print_value(x);")]
+#[case("let x = -9223372036854775808; print_value(x);")]
+#[case("print_value(-9223372036854775808i64);")]
+fn min_i64(#[case] program: &str) {
+    execute_program_and_assert(program, "-9223372036854775808");
+}
+
+#[rstest]
+#[case("print_value(-2147483648i32);", "-2147483648")]
+#[case(
+    "print_value(-170141183460469231731687303715884105728i128);",
+    "-170141183460469231731687303715884105728"
+)]
+fn min_signed_suffix(#[case] program: &str, #[case] expected: &str) {
+    execute_program_and_assert(program, expected);
+}
+
+#[test]
+fn i64_suffix_just_below_min() {
+    let program = "print_value(-9223372036854775809i64);";
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "Value 9223372036854775809 is out of range for i64",
+    );
+}
+
+#[rstest]
+#[case("let a: u32 = 5; print_value(a + 3);", "8")]
+#[case("let a: i32 = 5; print_value(2 * a - 1);", "9")]
+#[case("fn f(x: u64) -> u64 { x } print_value(f(7) + 1);", "8")]
+#[case(
+    "let n: u32 = 3; for i in range(0, n) { print_value(i + 1); }",
+    "1\n2\n3"
+)]
+fn unspecified_literal_takes_type_of_context(#[case] program: &str, #[case] expected: &str) {
+    execute_program_and_assert(program, expected);
+}
+
+#[test]
+fn unspecified_literal_in_u32_subtraction_underflows() {
+    let program = "let a: u32 = 5; print_value(a - 6);";
+    execute_program_expect_runtime_error(program, "Integer underflow in U32 subtraction");
+}
+
+#[rstest]
+#[case("let x: i32 = -3; print_value(x + 1i32);", "-2")]
+#[case("let a: u32 = 2 + 3; print_value(a + 1u32);", "6")]
+#[case(
+    "let n: u32 = 3; for i in range(0, n) { print_value(i + 1); }",
+    "1\n2\n3"
+)]
+fn folded_literal_keeps_type_of_context(#[case] program: &str, #[case] expected: &str) {
+    execute_program_with_args_and_assert(program, &["-O"], expected);
+}
@@ -5,4 +5,5 @@ mod call_expression;
 mod if_expression;
 mod block_expression;
 mod function_type_expression;
+mod closure_expression;
 mod variable_expression;
\ No newline at end of file
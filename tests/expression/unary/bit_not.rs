@@ -0,0 +1,29 @@
+use crate::ErrorCode;
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+
+#[test]
+fn with_i32_literal() {
+    let program = "print_value(~0i32);";
+    execute_program_and_assert(program, "-1");
+}
+
+#[test]
+fn with_unsigned_variable() {
+    let program = r#"
+        let a: u32 = 0u32;
+        print_value(~a);
+    "#;
+    execute_program_and_assert(program, "4294967295");
+}
+
+#[test]
+fn with_boolean() {
+    let program = r#"
+        let a = ~true;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::InvalidUnaryOperation,
+        "Cannot apply operator '~' to type bool",
+    );
+}
@@ -0,0 +1,48 @@
+use crate::test_utils::execute_program_and_assert;
+use rstest::rstest;
+use slang_frontend::{const_eval, parser, tokenize};
+use slang_ir::ast::{LiteralValue, Statement};
+use slang_shared::CompilationContext;
+
+/// Parses a single expression statement and evaluates it at compile time
+fn fold(source: &str) -> Option<LiteralValue> {
+    let mut context = CompilationContext::new();
+    let lexed = tokenize(source).expect("expression should lex");
+    let statements = parser::parse(&lexed.tokens, &lexed.line_info, &mut context)
+        .expect("expression should parse");
+    let [Statement::Expression(expr)] = statements.as_slice() else {
+        panic!("expected a single expression statement");
+    };
+    const_eval(expr, &context)
+}
+
+#[rstest]
+#[case("-(-5);", LiteralValue::UnspecifiedInteger(5))]
+#[case("-3.14;", LiteralValue::UnspecifiedFloat(-3.14))]
+#[case("-(7i32);", LiteralValue::I32(-7))]
+#[case("-(-2.5f64);", LiteralValue::F64(2.5))]
+#[case("-2147483648;", LiteralValue::UnspecifiedInteger(i128::from(i32::MIN)))]
+#[case(
+    "-9223372036854775808;",
+    LiteralValue::UnspecifiedInteger(i128::from(i64::MIN))
+)]
+#[case("-9223372036854775808i64;", LiteralValue::I64(i64::MIN))]
+#[allow(clippy::approx_constant)]
+fn negation_is_folded(#[case] source: &str, #[case] expected: LiteralValue) {
+    assert_eq!(fold(source), Some(expected));
+}
+
+#[rstest]
+#[case("-(-2147483648i32);")]
+#[case("-(-9223372036854775808i64);")]
+fn negation_overflow_is_not_folded(#[case] source: &str) {
+    assert_eq!(fold(source), None);
+}
+
+#[rstest]
+#[case("i32", "-2147483648")]
+#[case("i64", "-9223372036854775808")]
+fn minimum_value_binding(#[case] type_name: &str, #[case] value: &str) {
+    let program = format!("let MIN: {} = {}; print_value(MIN);", type_name, value);
+    execute_program_and_assert(&program, value);
+}
@@ -1,2 +1,4 @@
+mod bit_not;
+mod constant_folding;
 mod negate;
 mod not;
@@ -1,5 +1,6 @@
 use crate::ErrorCode;
 use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+use rstest::rstest;
 
 #[test]
 fn with_integer_variable() {
@@ -31,6 +32,30 @@ fn with_float_literal() {
     execute_program_and_assert(program, "-42.5");
 }
 
+#[rstest]
+#[case("i32", "7")]
+#[case("i64", "7")]
+#[case("i128", "7")]
+#[case("f32", "7.5")]
+#[case("f64", "7.5")]
+fn with_signed_type(#[case] type_name: &str, #[case] value: &str) {
+    let program = format!("let a: {} = {}; print_value(-a);", type_name, value);
+    execute_program_and_assert(&program, &format!("-{}", value));
+}
+
+#[rstest]
+#[case("u32")]
+#[case("u64")]
+#[case("u128")]
+fn with_unsigned_type(#[case] type_name: &str) {
+    let program = format!("let a: {} = 7; print_value(-a);", type_name);
+    execute_program_expect_error(
+        &program,
+        ErrorCode::InvalidUnaryOperation,
+        &format!("Cannot negate unsigned type {}", type_name),
+    );
+}
+
 #[test]
 fn with_string() {
     let program = r#"
@@ -69,6 +94,37 @@ fn with_unsigned_integer() {
     );
 }
 
+#[test]
+fn unsigned_negation_suggests_signed_type() {
+    let program = r#"
+        let a: u32 = 42;
+        print_value(-a);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::InvalidUnaryOperation,
+        "Cannot negate unsigned type u32",
+    );
+    execute_program_expect_error(
+        program,
+        ErrorCode::InvalidUnaryOperation,
+        "help: Consider changing the type from u32 to the signed type i32",
+    );
+}
+
+#[test]
+fn u64_negation_suggests_i64() {
+    let program = r#"
+        let a: u64 = 42;
+        print_value(-a);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::InvalidUnaryOperation,
+        "Consider changing the type from u64 to the signed type i64",
+    );
+}
+
 #[test]
 fn double_negation() {
     let program = r#"
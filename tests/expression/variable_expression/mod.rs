@@ -1,3 +1,4 @@
 mod basic;
 mod scoping;
 mod undefined;
+mod usage;
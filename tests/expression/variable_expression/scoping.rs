@@ -57,3 +57,92 @@ fn test_variable_out_of_scope_error() {
     "#;
     execute_program_expect_error(program, ErrorCode::UndefinedVariable, "Undefined variable");
 }
+
+#[test]
+fn shadowed_parameter_is_restored_after_inner_scope() {
+    let program = r#"
+        fn shadow(n: i32) -> i32 {
+            if true {
+                let n = 7;
+                print_value(n);
+            }
+            {
+                let n = n + 100;
+                print_value(n);
+            }
+            n
+        }
+        print_value(shadow(3));
+    "#;
+    execute_program_and_assert(program, "7\n103\n3");
+}
+
+#[test]
+fn nested_shadowing_reads_innermost_binding() {
+    let program = r#"
+        fn shadow() {
+            let value = 1;
+            {
+                let value = value + 1;
+                {
+                    let value = value * 10;
+                    print_value(value);
+                }
+                print_value(value);
+            }
+            print_value(value);
+        }
+        shadow();
+    "#;
+    execute_program_and_assert(program, "20\n2\n1");
+}
+
+#[test]
+fn assignment_in_inner_scope_updates_outer_binding() {
+    let program = r#"
+        let mut global = 1;
+        fn counter() -> i32 {
+            let mut count: i32 = 0;
+            {
+                count = count + 1;
+                global = global + 1;
+            }
+            count
+        }
+        print_value(counter());
+        print_value(global);
+    "#;
+    execute_program_and_assert(program, "1\n2");
+}
+
+#[test]
+fn closure_captures_innermost_binding() {
+    let program = r#"
+        fn main() {
+            let value = 1;
+            let get = {
+                let value: i32 = 2;
+                || -> i32 { value }
+            };
+            print_value(get());
+            print_value(value);
+        }
+        main();
+    "#;
+    execute_program_and_assert(program, "2\n1");
+}
+
+#[test]
+fn locals_of_ended_scopes_release_their_slots() {
+    let mut program = String::new();
+    for i in 0..300 {
+        program.push_str(&format!("{{ let a = {}; }}\n", i % 3));
+    }
+    program.push_str("fn f() -> i32 {\n");
+    for _ in 0..300 {
+        program.push_str("    { let b = 1; let c = b; }\n");
+    }
+    program.push_str("    let d: i32 = 7;\n    return d;\n}\n");
+    program.push_str("{ let e = 5; print_value(e); }\nprint_value(f());\n");
+    execute_program_and_assert(&program, "5\n7");
+}
@@ -0,0 +1,43 @@
+use slang_frontend::{parser, semantic_analysis, tokenize};
+use slang_shared::CompilationContext;
+
+/// Analyzes a program and returns the context holding its global symbols
+fn analyze(source: &str) -> CompilationContext {
+    let mut context = CompilationContext::new();
+    let lexed = tokenize(source).expect("program should lex");
+    let statements =
+        parser::parse(&lexed.tokens, &lexed.line_info, &mut context).expect("program should parse");
+    semantic_analysis::execute(&statements, &mut context).expect("program should analyze");
+    context
+}
+
+/// Whether the global symbol with the given name was marked as used
+fn is_used(context: &CompilationContext, name: &str) -> bool {
+    context
+        .lookup_symbol(name)
+        .expect("symbol should be defined")
+        .used
+}
+
+#[test]
+fn resolved_variable_is_marked_used() {
+    let context = analyze("let read = 1;\nlet unread = 2;\nprint_value(read);");
+
+    assert!(is_used(&context, "read"));
+    assert!(!is_used(&context, "unread"));
+}
+
+#[test]
+fn assignment_does_not_mark_variable_used() {
+    let context = analyze("let mut x = 1;\nx = 2;");
+
+    assert!(!is_used(&context, "x"));
+}
+
+#[test]
+fn called_function_is_marked_used() {
+    let context = analyze("fn called() {}\nfn uncalled() {}\ncalled();");
+
+    assert!(is_used(&context, "called"));
+    assert!(!is_used(&context, "uncalled"));
+}
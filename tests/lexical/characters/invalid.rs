@@ -0,0 +1,50 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+use slang_error::ErrorCode;
+use std::fs;
+use tempfile::TempDir;
+
+/// Checks a program and returns the assertion for further checks
+fn check_program(program: &str) -> assert_cmd::assert::Assert {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("invalid.sl");
+    fs::write(&input_file, program).unwrap();
+
+    Command::cargo_bin("slang")
+        .unwrap()
+        .arg("check")
+        .arg(&input_file)
+        .assert()
+}
+
+#[test]
+fn reports_every_unexpected_character() {
+    let program = "let x = 1;\n@#$\n";
+    check_program(program)
+        .failure()
+        .stderr(predicate::str::contains(format!(
+            "{}: Unexpected character '@'\n  --> main:2:1",
+            ErrorCode::UnexpectedCharacter
+        )))
+        .stderr(predicate::str::contains(format!(
+            "{}: Unexpected character '#'\n  --> main:2:2",
+            ErrorCode::UnexpectedCharacter
+        )))
+        .stderr(predicate::str::contains(format!(
+            "{}: Unexpected character '$'\n  --> main:2:3",
+            ErrorCode::UnexpectedCharacter
+        )))
+        .stderr(predicate::str::contains("3 errors"));
+}
+
+#[test]
+fn continues_lexing_after_unexpected_character() {
+    let program = "let x = 1 @ 2;\nlet y = \"unterminated\n";
+    check_program(program)
+        .failure()
+        .stderr(predicate::str::contains("Unexpected character '@'"))
+        .stderr(predicate::str::contains(
+            ErrorCode::ExpectedClosingQuote.to_string(),
+        ))
+        .stderr(predicate::str::contains("2 errors"));
+}
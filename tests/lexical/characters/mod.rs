@@ -1 +1,2 @@
 mod basic;
+mod invalid;
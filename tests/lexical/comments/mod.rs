@@ -1 +1 @@
-mod comments;
+mod basic;
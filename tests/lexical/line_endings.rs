@@ -0,0 +1,61 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+use slang_error::ErrorCode;
+use std::fs;
+use tempfile::TempDir;
+
+/// Checks a program and returns the assertion for further checks
+fn check_program(program: &str) -> assert_cmd::assert::Assert {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("line_endings.sl");
+    fs::write(&input_file, program).unwrap();
+
+    Command::cargo_bin("slang")
+        .unwrap()
+        .arg("check")
+        .arg(&input_file)
+        .assert()
+}
+
+#[test]
+fn crlf_type_error_position() {
+    let program = "let a = 1;\r\nlet b: i32 = true;\r\n";
+    check_program(program)
+        .failure()
+        .stderr(predicate::str::contains(format!(
+            "{}: Type mismatch",
+            ErrorCode::TypeMismatch
+        )))
+        .stderr(predicate::str::contains("--> main:2:5"))
+        .stderr(predicate::str::contains("let b: i32 = true;\n"))
+        .stderr(predicate::str::contains('\r').not());
+}
+
+#[test]
+fn crlf_unexpected_character_position() {
+    let program = "let a = 1;\r\nlet b = 2;\r\n  @\r\n";
+    check_program(program)
+        .failure()
+        .stderr(predicate::str::contains(format!(
+            "{}: Unexpected character '@'\n  --> main:3:3",
+            ErrorCode::UnexpectedCharacter
+        )))
+        .stderr(predicate::str::contains('\r').not());
+}
+
+#[test]
+fn crlf_and_lf_report_the_same_positions() {
+    let lf = check_program("let a = 1;\nlet b = 2\nlet c = 3;\n").failure();
+    let crlf = check_program("let a = 1;\r\nlet b = 2\r\nlet c = 3;\r\n").failure();
+
+    // The last line names the temporary file, which differs between both runs
+    let diagnostics = |assert: &assert_cmd::assert::Assert| {
+        let stderr = String::from_utf8(assert.get_output().stderr.clone()).unwrap();
+        stderr
+            .lines()
+            .filter(|line| !line.starts_with("Compilation failed for file"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    assert_eq!(diagnostics(&lf), diagnostics(&crlf));
+}
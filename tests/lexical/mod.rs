@@ -2,3 +2,5 @@ mod characters;
 mod comments;
 mod digits;
 mod identifiers;
+mod line_endings;
+mod operators;
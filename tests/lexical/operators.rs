@@ -0,0 +1,51 @@
+use rstest::rstest;
+use slang_frontend::{Tokentype, tokenize};
+
+/// Lexes a source and returns the types of its tokens, without the final end of file
+fn token_types(source: &str) -> Vec<Tokentype> {
+    let lexed = tokenize(source).expect("source should lex");
+    let mut types: Vec<Tokentype> = lexed.tokens.iter().map(|token| token.token_type).collect();
+    assert_eq!(types.pop(), Some(Tokentype::Eof));
+    types
+}
+
+#[rstest]
+#[case("<<=", Tokentype::ShiftLeftEqual)]
+#[case("<=", Tokentype::LessEqual)]
+#[case("<<", Tokentype::ShiftLeft)]
+#[case("<", Tokentype::Less)]
+#[case(">>=", Tokentype::ShiftRightEqual)]
+#[case(">=", Tokentype::GreaterEqual)]
+#[case(">>", Tokentype::ShiftRight)]
+#[case(">", Tokentype::Greater)]
+#[case("%", Tokentype::Percent)]
+#[case("->", Tokentype::Arrow)]
+#[case("::", Tokentype::PathSeparator)]
+fn operator_lexes_as_single_token(#[case] source: &str, #[case] expected: Tokentype) {
+    assert_eq!(token_types(source), vec![expected]);
+}
+
+#[test]
+fn longest_operator_is_matched_first() {
+    assert_eq!(
+        token_types("<<<=<"),
+        vec![Tokentype::ShiftLeft, Tokentype::LessEqual, Tokentype::Less]
+    );
+    assert_eq!(
+        token_types("- > ->"),
+        vec![Tokentype::Minus, Tokentype::Greater, Tokentype::Arrow]
+    );
+}
+
+#[test]
+fn slash_before_comment_is_not_an_operator() {
+    assert_eq!(
+        token_types("a / b // c\n/* d */ %"),
+        vec![
+            Tokentype::Identifier,
+            Tokentype::Divide,
+            Tokentype::Identifier,
+            Tokentype::Percent,
+        ]
+    );
+}
@@ -6,6 +6,7 @@ pub mod test_utils;
 mod types;
 mod cli;
 mod codegen;
+mod vm;
 
 // Re-export ErrorCode for use in tests
 pub use slang_error::ErrorCode;
\ No newline at end of file
@@ -0,0 +1,44 @@
+use slang_frontend::{parser, semantic_analysis, tokenize};
+use slang_ir::Location;
+use slang_shared::CompilationContext;
+
+/// Analyzes a program and returns the context holding its global symbols
+fn analyze(source: &str) -> CompilationContext {
+    let mut context = CompilationContext::new();
+    let lexed = tokenize(source).expect("program should lex");
+    let statements =
+        parser::parse(&lexed.tokens, &lexed.line_info, &mut context).expect("program should parse");
+    semantic_analysis::execute(&statements, &mut context).expect("program should analyze");
+    context
+}
+
+/// The line, column and length of the location a symbol was defined at
+fn definition_of(context: &CompilationContext, name: &str) -> Option<(usize, usize, usize)> {
+    context
+        .lookup_symbol(name)
+        .and_then(|symbol| symbol.definition_location)
+        .map(|location: Location| (location.line, location.column, location.length))
+}
+
+#[test]
+fn variable_reports_let_location() {
+    let context = analyze("let a = 1;\nlet mut counter: i32 = 2;");
+
+    assert_eq!(definition_of(&context, "a"), Some((1, 5, 1)));
+    assert_eq!(definition_of(&context, "counter"), Some((2, 9, 7)));
+}
+
+#[test]
+fn function_reports_declaration_location() {
+    let context = analyze("let a = 1;\n\nfn square(x: i32) -> i32 { return x * x; }");
+
+    assert_eq!(definition_of(&context, "square"), Some((3, 4, 6)));
+}
+
+#[test]
+fn native_function_has_no_definition_location() {
+    let context = analyze("");
+
+    assert!(context.lookup_symbol("print_value").is_some());
+    assert_eq!(definition_of(&context, "print_value"), None);
+}
@@ -0,0 +1,47 @@
+use crate::test_utils::execute_program_and_assert;
+
+#[test]
+fn declare_enum() {
+    let program = r#"
+        enum Color { Red, Green, Blue }
+        print_value("enum defined successfully");
+    "#;
+    execute_program_and_assert(program, "enum defined successfully");
+}
+
+#[test]
+fn variant_is_its_discriminant() {
+    let program = r#"
+        enum Color { Red, Green, Blue }
+        let color: Color = Color::Green;
+        print_value(color);
+        print_value(Color::Blue);
+    "#;
+    execute_program_and_assert(program, "1\n2");
+}
+
+#[test]
+fn compare_variants() {
+    let program = r#"
+        enum Color { Red, Green, Blue }
+        let color = Color::Red;
+        print_value(color == Color::Red);
+        print_value(color != Color::Red);
+    "#;
+    execute_program_and_assert(program, "true\nfalse");
+}
+
+#[test]
+fn enum_as_parameter_before_definition() {
+    let program = r#"
+        fn is_red(color: Color) -> bool {
+            color == Color::Red
+        }
+
+        enum Color { Red, Green, Blue }
+
+        print_value(is_red(Color::Red));
+        print_value(is_red(Color::Blue));
+    "#;
+    execute_program_and_assert(program, "true\nfalse");
+}
@@ -0,0 +1,67 @@
+use crate::ErrorCode;
+use crate::test_utils::execute_program_expect_error;
+
+#[test]
+fn unknown_variant() {
+    let program = r#"
+        enum Color { Red, Green, Blue }
+        let color = Color::Purple;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::UndefinedEnumVariant,
+        "Enum 'Color' has no variant named 'Purple'",
+    );
+}
+
+#[test]
+fn duplicate_variant() {
+    let program = r#"
+        enum Color { Red, Red }
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::SymbolRedefinition,
+        "Variant 'Red' is already defined in enum 'Color'",
+    );
+}
+
+#[test]
+fn redefined_enum() {
+    let program = r#"
+        enum Color { Red }
+        enum Color { Green }
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::SymbolRedefinition,
+        "Type 'Color' is already defined",
+    );
+}
+
+#[test]
+fn enum_inside_function() {
+    let program = r#"
+        fn main() {
+            enum Color { Red }
+        }
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::InvalidSyntax,
+        "Enums can only be defined at the top level",
+    );
+}
+
+#[test]
+fn variant_type_mismatch() {
+    let program = r#"
+        enum Color { Red }
+        let value: i32 = Color::Red;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::TypeMismatch,
+        "Type mismatch: variable value is i32 but expression is Color",
+    );
+}
@@ -0,0 +1,62 @@
+use predicates::prelude::*;
+use slang_error::ErrorCode;
+
+use crate::test_utils::execute_program;
+
+#[test]
+fn warns_when_value_is_discarded() {
+    let program = r#"
+        1 + 2;
+        print_value(3);
+    "#;
+    execute_program(program, &[])
+        .success()
+        .stdout(predicate::str::contains("3"))
+        .stderr(predicate::str::contains(
+            ErrorCode::UnusedResult.to_string(),
+        ))
+        .stderr(predicate::str::contains(
+            "is discarded, bind it with 'let _ = ...' if this is intended",
+        ));
+}
+
+#[test]
+fn warns_when_value_is_discarded_inside_block() {
+    let program = r#"
+        fn main() -> i32 {
+            let x: i32 = 4;
+            x * 2;
+            return 0;
+        }
+    "#;
+    execute_program(program, &[])
+        .success()
+        .stderr(predicate::str::contains("Value of type 'i32' is discarded"));
+}
+
+#[test]
+fn no_warning_for_function_call() {
+    let program = r#"
+        fn double(x: i32) -> i32 {
+            return x * 2;
+        }
+        print_value(1);
+        double(2);
+    "#;
+    execute_program(program, &[])
+        .success()
+        .stdout(predicate::str::contains("1"))
+        .stderr(predicate::str::contains(ErrorCode::UnusedResult.to_string()).not());
+}
+
+#[test]
+fn no_warning_for_unit_value() {
+    let program = r#"
+        ();
+        print_value(1);
+    "#;
+    execute_program(program, &[])
+        .success()
+        .stdout(predicate::str::contains("1"))
+        .stderr(predicate::str::contains(ErrorCode::UnusedResult.to_string()).not());
+}
@@ -0,0 +1 @@
+mod discarded_result;
@@ -0,0 +1,123 @@
+use crate::ErrorCode;
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+
+#[test]
+fn body_runs_once_per_value() {
+    let program = r#"
+        let mut iterations = 0;
+        for i in range(0, 10) {
+            iterations = iterations + 1;
+        }
+        print_value(iterations);
+    "#;
+    execute_program_and_assert(program, "10");
+}
+
+#[test]
+fn loop_variable_counts_up() {
+    let program = r#"
+        let mut sum = 0;
+        for i in range(1, 5) {
+            sum = sum + i;
+        }
+        print_value(sum);
+    "#;
+    execute_program_and_assert(program, "10");
+}
+
+#[test]
+fn empty_range_skips_body() {
+    let program = r#"
+        for i in range(5, 5) {
+            print_value("unreachable");
+        }
+        print_value("done");
+    "#;
+    execute_program_and_assert(program, "done");
+}
+
+#[test]
+fn bounds_are_expressions() {
+    let program = r#"
+        fn count(n: i32) -> i32 {
+            let mut total: i32 = 0;
+            for i in range(n - 2, n * 2) {
+                total = total + 1;
+            }
+            total
+        }
+        print_value(count(3));
+    "#;
+    execute_program_and_assert(program, "5");
+}
+
+#[test]
+fn nested_loops() {
+    let program = r#"
+        let mut pairs = 0;
+        for i in range(0, 3) {
+            for j in range(0, 4) {
+                pairs = pairs + 1;
+            }
+        }
+        print_value(pairs);
+    "#;
+    execute_program_and_assert(program, "12");
+}
+
+#[test]
+fn loop_variable_not_visible_after_loop() {
+    let program = r#"
+        for i in range(0, 3) {
+            print_value(i);
+        }
+        print_value(i);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::UndefinedVariable,
+        "Undefined variable: i",
+    );
+}
+
+#[test]
+fn loop_variable_is_immutable() {
+    let program = r#"
+        for i in range(0, 3) {
+            i = 5;
+        }
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::AssignmentToImmutableVariable,
+        "Cannot assign to immutable variable 'i'",
+    );
+}
+
+#[test]
+fn non_integer_bound() {
+    let program = r#"
+        for i in range(0, "ten") {
+            print_value(i);
+        }
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::InvalidExpression,
+        "Range bounds must be integers, but found 'string'",
+    );
+}
+
+#[test]
+fn missing_range() {
+    let program = r#"
+        for i in 10 {
+            print_value(i);
+        }
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::InvalidSyntax,
+        "Expected 'range' after 'in'",
+    );
+}
@@ -0,0 +1 @@
+mod for_statements;
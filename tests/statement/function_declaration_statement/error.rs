@@ -63,6 +63,22 @@ fn return_type_mismatch() {
     );
 }
 
+#[test]
+fn final_expression_type_mismatch() {
+    let program = r#"
+        fn get_number() -> i32 {
+            "not a number"
+        }
+
+        print_value(get_number());
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ReturnTypeMismatch,
+        "Type mismatch: function returns i32 but got string",
+    );
+}
+
 #[test]
 fn undefined_function() {
     let program = r#"
@@ -184,3 +184,15 @@ fn print_native_function() {
     "#;
     execute_program_and_assert(program, "<native fn print_value>");
 }
+
+#[test]
+fn trailing_comma_in_parameters() {
+    let program = r#"
+        fn f(a: i32, b: i32,) {
+            print_value(a + b);
+        }
+
+        f(1, 2);
+    "#;
+    execute_program_and_assert(program, "3");
+}
@@ -1,4 +1,6 @@
 mod error;
 mod function_basics;
+mod nested_functions;
+mod overloading;
+mod recursion;
 mod type_signature_parsing_error;
-
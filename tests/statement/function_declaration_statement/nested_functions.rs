@@ -0,0 +1,94 @@
+use crate::ErrorCode;
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+
+#[test]
+fn nested_function_is_callable_inside_enclosing_function() {
+    let program = r#"
+        fn outer(x: i32) -> i32 {
+            fn double(y: i32) -> i32 {
+                return y * 2;
+            }
+            return double(x) + 1;
+        }
+
+        print_value(outer(5));
+    "#;
+    execute_program_and_assert(program, "11");
+}
+
+#[test]
+fn nested_function_sees_outer_functions() {
+    let program = r#"
+        fn increment(x: i32) -> i32 {
+            return x + 1;
+        }
+
+        fn outer(x: i32) -> i32 {
+            fn twice(y: i32) -> i32 {
+                return increment(increment(y));
+            }
+            return twice(x);
+        }
+
+        print_value(outer(40));
+    "#;
+    execute_program_and_assert(program, "42");
+}
+
+#[test]
+fn nested_function_is_not_callable_from_outside() {
+    let program = r#"
+        fn outer() -> i32 {
+            fn helper() -> i32 {
+                return 1;
+            }
+            return helper();
+        }
+
+        helper();
+    "#;
+    execute_program_expect_error(program, ErrorCode::UndefinedFunction, "Undefined function: helper");
+}
+
+#[test]
+fn nested_function_does_not_capture_local_variables() {
+    let program = r#"
+        fn outer(x: i32) -> i32 {
+            fn helper() -> i32 {
+                return x;
+            }
+            return helper();
+        }
+    "#;
+    execute_program_expect_error(program, ErrorCode::UndefinedVariable, "Undefined variable: x");
+}
+
+#[test]
+fn local_variable_of_enclosing_function_does_not_hide_global() {
+    let program = r#"
+        let x: i32 = 10;
+        fn outer(x: i32) -> i32 {
+            fn helper() -> i32 {
+                return x;
+            }
+            return helper() + x;
+        }
+        print_value(outer(1));
+    "#;
+    execute_program_and_assert(program, "11");
+}
+
+#[test]
+fn function_declared_in_block_is_scoped_to_block() {
+    let program = r#"
+        let result = {
+            fn square(x: i32) -> i32 {
+                return x * x;
+            }
+            square(7)
+        };
+        print_value(result);
+        square(2);
+    "#;
+    execute_program_expect_error(program, ErrorCode::UndefinedFunction, "Undefined function: square");
+}
@@ -0,0 +1,80 @@
+use crate::ErrorCode;
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+
+#[test]
+fn overloads_are_resolved_by_argument_count() {
+    let program = r#"
+        fn describe(a: i32) -> i32 {
+            return a;
+        }
+
+        fn describe(a: i32, b: i32) -> i32 {
+            return a * b;
+        }
+
+        print_value(describe(7));
+        print_value(describe(3, 4));
+    "#;
+    execute_program_and_assert(program, "7\n12");
+}
+
+#[test]
+fn overloads_can_recurse_into_themselves() {
+    let program = r#"
+        fn sum(n: i64) -> i64 {
+            if n == 0 {
+                return 0;
+            }
+            return n + sum(n - 1);
+        }
+
+        fn sum(n: i64, acc: i64) -> i64 {
+            if n == 0 {
+                return acc;
+            }
+            return sum(n - 1, acc + n);
+        }
+
+        print_value(sum(4));
+        print_value(sum(4, 100));
+    "#;
+    execute_program_and_assert(program, "10\n110");
+}
+
+#[test]
+fn call_matching_no_overload_lists_available_arities() {
+    let program = r#"
+        fn describe(a: i32) -> i32 {
+            return a;
+        }
+
+        fn describe(a: i32, b: i32) -> i32 {
+            return a * b;
+        }
+
+        describe(1, 2, 3);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ArgumentCountMismatch,
+        "Function 'describe' expects 1 or 2 arguments, but got 3",
+    );
+}
+
+#[test]
+fn redefinition_with_same_arity_is_rejected() {
+    let program = r#"
+        fn describe(a: i32) -> i32 {
+            return a;
+        }
+
+        fn describe(b: i32) -> i32 {
+            return b;
+        }
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::SymbolRedefinition,
+        "Function 'describe' is already defined in the current scope.",
+    );
+}
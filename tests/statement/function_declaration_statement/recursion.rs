@@ -0,0 +1,59 @@
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_runtime_error};
+
+#[test]
+fn infinite_recursion_exceeds_maximum_depth() {
+    let program = r#"
+        fn forever(n: i32) -> i32 {
+            return forever(n) + 1;
+        }
+
+        print_value(forever(1));
+    "#;
+    execute_program_expect_runtime_error(
+        program,
+        "Maximum recursion depth of 10000 exceeded in function 'forever'",
+    );
+}
+
+#[test]
+fn recursion_limit_reports_line_of_call() {
+    let program = r#"
+        fn forever(n: i32) -> i32 {
+            let next = n;
+            return forever(next) + 1;
+        }
+
+        print_value(forever(1));
+    "#;
+    execute_program_expect_runtime_error(program, "exceeded in function 'forever' at line 4");
+}
+
+#[test]
+fn deep_recursion_below_limit() {
+    let program = r#"
+        fn depth(n: i32) -> i32 {
+            if n == 0 {
+                return 0;
+            }
+            return depth(n - 1) + 1;
+        }
+
+        print_value(depth(5000));
+    "#;
+    execute_program_and_assert(program, "5000");
+}
+
+#[test]
+fn tail_recursion_is_not_limited() {
+    let program = r#"
+        fn countdown(n: i32) -> i32 {
+            if n == 0 {
+                return 0;
+            }
+            return countdown(n - 1);
+        }
+
+        print_value(countdown(20000));
+    "#;
+    execute_program_and_assert(program, "0");
+}
@@ -0,0 +1,81 @@
+use crate::test_utils::execute_program_and_assert;
+
+#[test]
+fn call_method() {
+    let program = r#"
+        enum Color { Red, Green, Blue }
+        impl Color {
+            fn is_red(self) -> bool {
+                self == Color::Red
+            }
+        }
+        let color = Color::Red;
+        print_value(color.is_red());
+        print_value(Color::Blue.is_red());
+    "#;
+    execute_program_and_assert(program, "true\nfalse");
+}
+
+#[test]
+fn call_method_with_arguments() {
+    let program = r#"
+        enum Color { Red, Green, Blue }
+        impl Color {
+            fn matches(self, other: Color) -> bool {
+                self == other
+            }
+        }
+        print_value(Color::Green.matches(Color::Green));
+        print_value(Color::Green.matches(Color::Blue));
+    "#;
+    execute_program_and_assert(program, "true\nfalse");
+}
+
+#[test]
+fn method_calls_method() {
+    let program = r#"
+        enum Color { Red, Green, Blue }
+        impl Color {
+            fn name(self) -> string {
+                if self == Color::Red { "red" } else { "other" }
+            }
+            fn mix(self, other: Color) -> string {
+                self.name() + "-" + other.name()
+            }
+        }
+        print_value(Color::Red.mix(Color::Blue));
+    "#;
+    execute_program_and_assert(program, "red-other");
+}
+
+#[test]
+fn chain_method_calls() {
+    let program = r#"
+        enum Color { Red, Green, Blue }
+        impl Color {
+            fn next(self) -> Color {
+                if self == Color::Red { Color::Green } else { Color::Blue }
+            }
+        }
+        print_value(Color::Red.next().next());
+    "#;
+    execute_program_and_assert(program, "2");
+}
+
+#[test]
+fn call_method_through_its_path() {
+    let program = r#"
+        enum Color { Red, Green, Blue }
+        impl Color {
+            fn is_red(self) -> bool {
+                self == Color::Red
+            }
+            fn default() -> Color {
+                Color::Green
+            }
+        }
+        print_value(Color::is_red(Color::Red));
+        print_value(Color::default());
+    "#;
+    execute_program_and_assert(program, "true\n1");
+}
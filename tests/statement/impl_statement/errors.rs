@@ -0,0 +1,87 @@
+use crate::ErrorCode;
+use crate::test_utils::execute_program_expect_error;
+
+#[test]
+fn undefined_method() {
+    let program = r#"
+        enum Color { Red, Green, Blue }
+        impl Color {
+            fn is_red(self) -> bool {
+                self == Color::Red
+            }
+        }
+        print_value(Color::Red.is_blue());
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::UndefinedMethod,
+        "Type 'Color' has no method named 'is_blue'",
+    );
+}
+
+#[test]
+fn associated_function_is_not_a_method() {
+    let program = r#"
+        enum Color { Red, Green, Blue }
+        impl Color {
+            fn default() -> Color {
+                Color::Green
+            }
+        }
+        print_value(Color::Red.default());
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::UndefinedMethod,
+        "Type 'Color' has no method named 'default'",
+    );
+}
+
+#[test]
+fn method_argument_count_mismatch() {
+    let program = r#"
+        enum Color { Red, Green, Blue }
+        impl Color {
+            fn is_red(self) -> bool {
+                self == Color::Red
+            }
+        }
+        print_value(Color::Red.is_red(Color::Blue));
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ArgumentCountMismatch,
+        "Function 'Color::is_red' expects 0 arguments, but got 1",
+    );
+}
+
+#[test]
+fn impl_for_primitive_type() {
+    let program = r#"
+        impl i32 {
+            fn double(self) -> i32 {
+                self * 2
+            }
+        }
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::UnknownType,
+        "Methods can only be implemented for structs and enums, found 'i32'",
+    );
+}
+
+#[test]
+fn impl_inside_function() {
+    let program = r#"
+        enum Color { Red, Green, Blue }
+        fn main() {
+            impl Color {}
+        }
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::InvalidSyntax,
+        "Methods can only be implemented at the top level",
+    );
+}
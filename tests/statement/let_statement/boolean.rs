@@ -90,7 +90,7 @@ fn using_boolean_type_as_name() {
         let bool: bool = true;
     "#;
     execute_program_expect_error(
-        &program,
+        program,
         ErrorCode::SymbolRedefinition,
         "Symbol \'bool\' of kind \'variable (conflicts with type)\' is already defined or conflicts with an existing symbol.",
     );
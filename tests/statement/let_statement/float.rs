@@ -237,3 +237,15 @@ fn using_type_as_variable_name(#[case] type_name: &str) {
         ),
     );
 }
+
+#[test]
+fn f32_negative_value_out_of_range() {
+    let program = r#"
+        let a: f32 = -3.5e40;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "is out of range for type f32",
+    );
+}
@@ -208,6 +208,27 @@ fn i32_value_out_of_range() {
     );
 }
 
+#[test]
+fn i32_negative_value_out_of_range() {
+    let program = r#"
+        let a: i32 = -2147483649;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "Integer literal -2147483649 is out of range for type i32",
+    );
+}
+
+#[test]
+fn i32_minimum_negative_value() {
+    let program = r#"
+        let a: i32 = -2147483648;
+        print_value(a);
+    "#;
+    execute_program_and_assert(program, "-2147483648");
+}
+
 #[test]
 fn u32_unsigned_negative_value_error() {
     let program = r#"
@@ -241,3 +262,15 @@ fn using_type_as_variable_name(#[case] type_name: &str) {
         ),
     );
 }
+
+#[test]
+fn u32_value_out_of_range_reports_bounds() {
+    let program = r#"
+        let a: u32 = 4294967296;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "Integer literal 4294967296 is out of range for type u32 (valid range is 0..=4294967295)",
+    );
+}
@@ -2,5 +2,7 @@ mod boolean;
 mod float;
 mod function;
 mod integer;
+mod shadowing;
 mod string;
 mod unit;
+mod wildcard;
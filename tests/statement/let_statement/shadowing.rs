@@ -0,0 +1,58 @@
+use predicates::prelude::*;
+use slang_error::ErrorCode;
+
+use crate::test_utils::execute_program;
+
+#[test]
+fn warns_when_nested_block_shadows_outer_variable() {
+    let program = r#"
+        let value = 1;
+        {
+            let value = 3;
+            print_value(value);
+        }
+        print_value(value);
+    "#;
+    execute_program(program, &["--warn-shadowing"])
+        .success()
+        .stdout(predicate::str::contains("3\n1"))
+        .stderr(predicate::str::contains(
+            ErrorCode::VariableShadowing.to_string(),
+        ))
+        .stderr(predicate::str::contains(
+            "Variable 'value' shadows a variable defined at line 2",
+        ))
+        .stderr(predicate::str::contains("1 warning"));
+}
+
+#[test]
+fn no_warning_for_sibling_scopes() {
+    let program = r#"
+        {
+            let value = 1;
+            print_value(value);
+        }
+        {
+            let value = 2;
+            print_value(value);
+        }
+    "#;
+    execute_program(program, &["--warn-shadowing"])
+        .success()
+        .stdout(predicate::str::contains("1\n2"))
+        .stderr(predicate::str::contains(ErrorCode::VariableShadowing.to_string()).not());
+}
+
+#[test]
+fn no_warning_when_not_enabled() {
+    let program = r#"
+        let value = 1;
+        {
+            let value = 3;
+            print_value(value);
+        }
+    "#;
+    execute_program(program, &[])
+        .success()
+        .stderr(predicate::str::contains(ErrorCode::VariableShadowing.to_string()).not());
+}
@@ -0,0 +1,101 @@
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+use slang_error::ErrorCode;
+
+#[test]
+fn wildcard_evaluates_initializer() {
+    let program = r#"
+        fn side_effect() -> i32 {
+            print_value("called");
+            return 42;
+        }
+        let _ = side_effect();
+        print_value("done");
+    "#;
+    execute_program_and_assert(program, "called\ndone");
+}
+
+#[test]
+fn wildcard_can_be_used_multiple_times() {
+    let program = r#"
+        let _ = 1;
+        let _ = "two";
+        let _: bool = true;
+        {
+            let _ = 3.0;
+        }
+        print_value("ok");
+    "#;
+    execute_program_and_assert(program, "ok");
+}
+
+#[test]
+fn wildcard_initializer_is_type_checked() {
+    let program = r#"
+        let _: i32 = "not a number";
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::TypeMismatch,
+        "Type mismatch: variable _ is i32 but expression is string",
+    );
+}
+
+#[test]
+fn wildcard_is_not_an_expression() {
+    let program = r#"
+        let _ = 5;
+        print_value(_);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ExpectedExpression,
+        "Expected expression, found '_'",
+    );
+}
+
+#[test]
+fn wildcard_is_not_a_parameter_name() {
+    let program = r#"
+        fn f(_: i32) -> i32 {
+            return 4;
+        }
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ExpectedIdentifier,
+        "Expected parameter name",
+    );
+}
+
+#[test]
+fn wildcard_is_not_a_closure_parameter_name() {
+    let program = r#"
+        let f = |_: i32| -> i32 { 4 };
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ExpectedIdentifier,
+        "Expected parameter name",
+    );
+}
+
+#[test]
+fn wildcard_is_not_a_function_name() {
+    let program = r#"
+        fn _() {}
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ExpectedIdentifier,
+        "Expected function name found '_'",
+    );
+}
+
+#[test]
+fn leading_underscore_is_part_of_an_identifier() {
+    let program = r#"
+        let _value = 3;
+        print_value(_value);
+    "#;
+    execute_program_and_assert(program, "3");
+}
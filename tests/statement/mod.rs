@@ -1,6 +1,11 @@
 mod assignment_statement;
+mod definition_location;
+mod enum_definition_statement;
+mod expression_statement;
+mod for_statement;
 mod function_declaration_statement;
 mod if_statement;
+mod impl_statement;
 mod let_statement;
 mod return_statement;
 mod type_definition_statement;
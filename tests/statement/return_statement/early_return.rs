@@ -58,3 +58,54 @@ fn unreachable_code_after_return() {
     // This might be a warning rather than an error, depending on implementation
     execute_program_and_assert(program, "42");
 }
+
+#[test]
+fn return_in_branch_exits_function() {
+    let program = r#"
+        fn f(x: i32) -> i32 { if x > 0 { return 1; } return 2; }
+        print_value(f(5));
+        print_value(f(0));
+        print_value(f(-5));
+    "#;
+    execute_program_and_assert(program, "1\n2\n2");
+}
+
+#[test]
+fn return_from_nested_blocks_unwinds_their_scopes() {
+    let program = r#"
+        fn f(x: i32) -> i32 {
+            if x > 0 {
+                let a: i32 = 1;
+                if x > 5 {
+                    let b: i32 = 2;
+                    return a + b;
+                }
+                return a;
+            }
+            return 0;
+        }
+
+        let a: i32 = 10;
+        print_value(f(6));
+        print_value(f(3));
+        print_value(a);
+    "#;
+    execute_program_and_assert(program, "3\n1\n10");
+}
+
+#[test]
+fn return_in_branch_skips_rest_of_unit_function() {
+    let program = r#"
+        fn report(x: i32) {
+            if x > 0 {
+                print_value("positive");
+                return;
+            }
+            print_value("not positive");
+        }
+
+        report(1);
+        report(0);
+    "#;
+    execute_program_and_assert(program, "positive\nnot positive");
+}
@@ -0,0 +1,27 @@
+use crate::ErrorCode;
+use crate::test_utils::execute_program_expect_error;
+
+#[test]
+fn return_outside_function() {
+    let program = r#"
+        return 5;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ReturnOutsideFunction,
+        "Return statement outside of function",
+    );
+}
+
+#[test]
+fn return_outside_function_points_at_keyword() {
+    let program = "let a = 1;\n  return 5;";
+    execute_program_expect_error(program, ErrorCode::ReturnOutsideFunction, "--> main:2:3\n");
+    execute_program_expect_error(program, ErrorCode::ReturnOutsideFunction, "|   ^^^^^^\n");
+}
+
+#[test]
+fn missing_return_value_points_at_keyword() {
+    let program = "fn f() -> i32 {\n    return;\n}";
+    execute_program_expect_error(program, ErrorCode::MissingReturnValue, "--> main:2:5\n");
+}
@@ -1,4 +1,6 @@
 mod basic;
 mod early_return;
+mod errors;
+mod tail_call;
 mod with_expression;
 mod without_expression;
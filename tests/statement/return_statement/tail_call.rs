@@ -0,0 +1,46 @@
+use crate::test_utils::execute_program_and_assert;
+
+#[test]
+fn tail_recursive_countdown_runs_in_constant_stack() {
+    let program = r#"
+        fn countdown(n: i64) -> i64 {
+            if n == 0 {
+                return 0;
+            }
+            return countdown(n - 1);
+        }
+
+        print_value(countdown(1000000));
+    "#;
+    execute_program_and_assert(program, "0");
+}
+
+#[test]
+fn tail_recursive_accumulator() {
+    let program = r#"
+        fn sum_to(n: i64, acc: i64) -> i64 {
+            if n == 0 {
+                return acc;
+            }
+            return sum_to(n - 1, acc + n);
+        }
+
+        print_value(sum_to(100, 0));
+    "#;
+    execute_program_and_assert(program, "5050");
+}
+
+#[test]
+fn non_tail_recursion_still_returns_to_caller() {
+    let program = r#"
+        fn factorial(n: i64) -> i64 {
+            if n <= 1 {
+                return 1;
+            }
+            return n * factorial(n - 1);
+        }
+
+        print_value(factorial(10));
+    "#;
+    execute_program_and_assert(program, "3628800");
+}
@@ -94,3 +94,45 @@ fn duplicate_definition() {
         "Type \'Point\' is already defined in the current scope.",
     );
 }
+
+#[test]
+fn self_referential_field() {
+    let program = r#"
+        struct Node {
+            value: i32,
+            next: Node,
+        };
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::RecursiveStructType,
+        "Struct 'Node' contains itself by value and would have infinite size: Node -> Node",
+    );
+}
+
+#[test]
+fn mutually_recursive_fields() {
+    let program = r#"
+        struct A {
+            b: B,
+        };
+        struct B {
+            a: A,
+        };
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::RecursiveStructType,
+        "Struct 'B' contains itself by value and would have infinite size: B -> A -> B",
+    );
+}
+
+#[test]
+fn unknown_field_type_is_still_rejected() {
+    let program = r#"
+        struct A {
+            b: Missing,
+        };
+    "#;
+    execute_program_expect_error(program, ErrorCode::UnknownType, "Unknown type: Missing");
+}
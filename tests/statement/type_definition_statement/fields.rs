@@ -10,3 +10,65 @@ fn function_type_field() {
     "#;
     execute_program_and_assert(program, "struct with function type field defined");
 }
+
+#[test]
+fn struct_type_field() {
+    let program = r#"
+        struct Inner {
+            value: i32,
+        };
+        struct Outer {
+            inner: Inner,
+        };
+        print_value("nested struct defined");
+    "#;
+    execute_program_and_assert(program, "nested struct defined");
+}
+
+#[test]
+fn self_reference_through_function_type() {
+    let program = r#"
+        struct Handler {
+            next: fn(Handler) -> i32,
+        };
+        print_value("recursive handler defined");
+    "#;
+    execute_program_and_assert(program, "recursive handler defined");
+}
+
+#[test]
+fn trailing_comma_after_last_field() {
+    let program = r#"
+        struct S { x: i32, };
+        print_value("struct with trailing comma defined");
+    "#;
+    execute_program_and_assert(program, "struct with trailing comma defined");
+}
+
+#[test]
+fn field_of_later_declared_struct() {
+    let program = r#"
+        struct Outer {
+            inner: Inner,
+        };
+        struct Inner {
+            value: i32,
+        };
+        print_value("forward reference resolved");
+    "#;
+    execute_program_and_assert(program, "forward reference resolved");
+}
+
+#[test]
+fn function_type_field_with_later_declared_struct() {
+    let program = r#"
+        struct Handler {
+            callback: fn(Event) -> bool,
+        };
+        struct Event {
+            id: i32,
+        };
+        print_value("forward reference in function type resolved");
+    "#;
+    execute_program_and_assert(program, "forward reference in function type resolved");
+}
@@ -1,2 +1,3 @@
 mod basic;
 mod errors;
+mod round_trip;
@@ -0,0 +1,76 @@
+use slang_frontend::source_printer::to_source;
+use slang_frontend::{parser, tokenize};
+use slang_ir::ast::Statement;
+use slang_shared::CompilationContext;
+
+/// Parses a program with a fresh compilation context
+fn parse_program(source: &str) -> (Vec<Statement>, CompilationContext) {
+    let mut context = CompilationContext::new();
+    let lexed = tokenize(source).expect("program should lex");
+    let statements =
+        parser::parse(&lexed.tokens, &lexed.line_info, &mut context).expect("program should parse");
+    (statements, context)
+}
+
+/// Renders the AST without source locations, which differ after reformatting
+fn ast_without_locations(statements: &[Statement]) -> String {
+    let mut ast = format!("{:?}", statements);
+    while let Some(start) = ast.find("Location {") {
+        let end = start + ast[start..].find('}').expect("location should be closed");
+        ast.replace_range(start..=end, "_");
+    }
+    ast
+}
+
+#[test]
+fn parse_render_parse_yields_identical_ast() {
+    let program = r#"
+        struct Point { x: i32, y: i32 };
+        enum Color { Red, Green }
+        impl Color {
+            fn is(self, other: Color) -> bool { self == other }
+            fn default() -> Color { Color::Red }
+        }
+
+        fn apply(f: fn(i32) -> i32, value: i32) -> i32 { return f(value); }
+        fn log(message: string) { print_value(message); }
+
+        let mut total: i64 = 0;
+        let offset = 3;
+        let add_offset = |x: i32| -> i32 { x + offset };
+        let nothing = || { };
+        let signature = fn(i32, bool) -> string;
+        let color = Color::Green;
+        let same = (-1 < 0) == color.is(Color::default());
+        for i in range(0, 10) {
+            if i / 2 * 2 == i && !(i > 6) { total = total + i; } else { total = total - 1; }
+            log("step");
+        }
+        let mixed = (1 + 2) * -(3 - 4) / 5 << 1 | 6 ^ 7 & ~8;
+        let grouped = 10 - (2 - 3);
+        let choice = if total >= 5 { 1.5 } else { 2.0e3 };
+        let wide = 18446744073709551615u64 + 0u64;
+        let small = 1.25f32;
+        let unit = ();
+        let inner = {
+            let a = apply(add_offset, 2);
+            a * 2
+        };
+        {
+            print_value(inner != 4 || false);
+        }
+        (if true { 1 } else { 2 });
+    "#;
+
+    let (statements, context) = parse_program(program);
+    let rendered = to_source(&statements, &context);
+    let (reparsed, reparsed_context) = parse_program(&rendered);
+
+    assert_eq!(
+        ast_without_locations(&statements),
+        ast_without_locations(&reparsed),
+        "rendered source:\n{}",
+        rendered
+    );
+    assert_eq!(to_source(&reparsed, &reparsed_context), rendered);
+}
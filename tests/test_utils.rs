@@ -45,6 +45,24 @@ pub fn execute_program_and_assert(program: &str, expected_output: &str) {
         .stdout(predicate::str::contains(expected_output));
 }
 
+/// Helper function to run a program and return the assertion for further checks of its output
+///
+/// ### Arguments
+/// * `program` - The source code of the program to be executed
+/// * `args` - Additional arguments passed to the `execute` command
+///
+/// ### Returns
+/// The assertion on the finished `execute` command
+pub fn execute_program(program: &str, args: &[&str]) -> assert_cmd::assert::Assert {
+    let temp_dir = tempdir().unwrap();
+    let source_path = temp_dir.path().join("test_program.sl");
+
+    fs::write(&source_path, program).unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("execute").args(args).arg(&source_path).assert()
+}
+
 /// Helper function to test for error cases, checking stderr
 ///
 /// ### Arguments
@@ -68,3 +86,35 @@ pub fn execute_program_expect_error(
         .stderr(predicate::str::contains(expected_error_code.to_string()))
         .stderr(predicate::str::contains(expected_error));
 }
+
+/// Helper function to run a program with additional command line arguments and assert its output
+///
+/// ### Arguments
+/// * `program` - The source code of the program to be executed
+/// * `args` - Additional arguments passed to the `execute` command
+/// * `expected_output` - The expected output of the program
+pub fn execute_program_with_args_and_assert(program: &str, args: &[&str], expected_output: &str) {
+    execute_program(program, args)
+        .success()
+        .stdout(predicate::str::contains(expected_output));
+}
+
+/// Helper function to test for runtime errors, checking stderr
+///
+/// ### Arguments
+/// * `program` - The source code of the program to be executed
+/// * `expected_error` - The expected runtime error message
+pub fn execute_program_expect_runtime_error(program: &str, expected_error: &str) {
+    let temp_dir = tempdir().unwrap();
+    let source_path = temp_dir.path().join("test_program.sl");
+
+    fs::write(&source_path, program).unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("execute")
+        .arg(&source_path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Runtime Error"))
+        .stderr(predicate::str::contains(expected_error));
+}
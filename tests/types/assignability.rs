@@ -0,0 +1,92 @@
+use crate::ErrorCode;
+use crate::test_utils::{execute_program_and_assert, execute_program_expect_error};
+
+const OUT_OF_RANGE_I32: &str = "Integer literal 3000000000 is out of range for type i32";
+
+#[test]
+fn out_of_range_literal_in_let() {
+    let program = r#"
+        let x: i32 = 3000000000;
+    "#;
+    execute_program_expect_error(program, ErrorCode::ValueOutOfRange, OUT_OF_RANGE_I32);
+}
+
+#[test]
+fn out_of_range_literal_in_assignment() {
+    let program = r#"
+        let mut x: i32 = 0;
+        x = 3000000000;
+    "#;
+    execute_program_expect_error(program, ErrorCode::ValueOutOfRange, OUT_OF_RANGE_I32);
+}
+
+#[test]
+fn out_of_range_literal_as_argument() {
+    let program = r#"
+        fn take(a: i32) {}
+        take(3000000000);
+    "#;
+    execute_program_expect_error(program, ErrorCode::ValueOutOfRange, OUT_OF_RANGE_I32);
+}
+
+#[test]
+fn out_of_range_literal_in_return() {
+    let program = r#"
+        fn give() -> i32 {
+            return 3000000000;
+        }
+    "#;
+    execute_program_expect_error(program, ErrorCode::ValueOutOfRange, OUT_OF_RANGE_I32);
+}
+
+#[test]
+fn negative_literal_assigned_to_unsigned() {
+    let program = r#"
+        let mut u: u32 = 1;
+        u = -1;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ValueOutOfRange,
+        "Integer literal -1 is out of range for type u32",
+    );
+}
+
+#[test]
+fn int_literal_assigned_to_string() {
+    let program = r#"
+        let mut s: string = "a";
+        s = 5;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::TypeMismatch,
+        "Type mismatch: variable assignment to variable 's' is string but expression is int",
+    );
+}
+
+#[test]
+fn float_literal_passed_to_int_parameter() {
+    let program = r#"
+        fn take(a: i32) {}
+        take(1.5);
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::ArgumentTypeMismatch,
+        "function 'take' expects argument 1 to be i32, but got float",
+    );
+}
+
+#[test]
+fn in_range_literals_are_accepted_everywhere() {
+    let program = r#"
+        fn take(a: i64) -> i64 {
+            return a;
+        }
+        let mut x: i64 = 0;
+        x = 3000000000;
+        print_value(take(x));
+    "#;
+    execute_program_and_assert(program, "3000000000");
+}
@@ -1,3 +1,8 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
 use crate::ErrorCode;
 use crate::test_utils::execute_program_expect_error;
 
@@ -25,3 +30,29 @@ fn unknown_type() {
         "'unknown' is not a valid type specifier",
     );
 }
+
+#[test]
+fn misspelled_type_suggests_closest_type() {
+    let program = r#"
+        let a: i23 = 0;
+    "#;
+    execute_program_expect_error(
+        program,
+        ErrorCode::UnknownType,
+        "help: did you mean 'i32'?",
+    );
+}
+
+#[test]
+fn unknown_type_without_close_match_has_no_suggestion() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_path = temp_dir.path().join("test_program.sl");
+    fs::write(&source_path, "let a: Rectangle = 0;").unwrap();
+
+    let mut cmd = Command::cargo_bin("slang").unwrap();
+    cmd.arg("execute")
+        .arg(&source_path)
+        .assert()
+        .stderr(predicate::str::contains("Unknown type: Rectangle"))
+        .stderr(predicate::str::contains("did you mean").not());
+}
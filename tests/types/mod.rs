@@ -1 +1,2 @@
+mod assignability;
 mod errors;
@@ -0,0 +1,40 @@
+use slang_backend::bytecode::{Chunk, Function, OpCode};
+use slang_backend::value::Value;
+use slang_backend::vm::VM;
+
+/// Builds a chunk that calls a function taking two parameters with the given arguments
+fn call_with_arguments(arguments: &[i32]) -> Chunk {
+    let mut chunk = Chunk::new();
+    for argument in arguments {
+        let constant = chunk.add_constant(Value::I32(*argument));
+        chunk.write_op(OpCode::Constant, 1);
+        chunk.write_byte(constant as u8, 1);
+    }
+
+    let function = Value::Function(Box::new(Function {
+        name: "add".to_string(),
+        arity: 2,
+        code_offset: 0,
+        locals: vec!["a".to_string(), "b".to_string()],
+    }));
+    let constant = chunk.add_constant(function);
+    chunk.write_op(OpCode::Constant, 1);
+    chunk.write_byte(constant as u8, 1);
+    chunk.write_op(OpCode::Call, 1);
+    chunk.write_byte(arguments.len() as u8, 1);
+    chunk
+}
+
+#[test]
+fn call_with_too_few_arguments_is_rejected() {
+    let chunk = call_with_arguments(&[1]);
+    let error = VM::new().interpret(&chunk).unwrap_err();
+    assert_eq!(error, "Expected 2 arguments but got 1 at line 1 (offset 4)");
+}
+
+#[test]
+fn call_with_too_many_arguments_is_rejected() {
+    let chunk = call_with_arguments(&[1, 2, 3]);
+    let error = VM::new().interpret(&chunk).unwrap_err();
+    assert_eq!(error, "Expected 2 arguments but got 3 at line 1 (offset 8)");
+}
@@ -0,0 +1,2 @@
+mod call_arity;
+mod output;
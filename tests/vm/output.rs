@@ -0,0 +1,45 @@
+use slang_backend::codegen::generate_bytecode;
+use slang_backend::vm::VM;
+use slang_frontend::{parser, semantic_analysis, tokenize};
+use slang_shared::CompilationContext;
+
+/// Compiles and runs a program, returning everything it printed
+fn captured_output(source: &str) -> String {
+    let mut context = CompilationContext::new();
+    let lexed = tokenize(source).expect("program should lex");
+    let statements =
+        parser::parse(&lexed.tokens, &lexed.line_info, &mut context).expect("program should parse");
+    semantic_analysis::execute(&statements, &mut context).expect("program should analyze");
+    let chunk = generate_bytecode(&statements).expect("program should compile");
+
+    let mut output = Vec::new();
+    VM::with_output(Box::new(&mut output))
+        .interpret(&chunk)
+        .expect("program should run");
+    String::from_utf8(output).expect("output should be valid UTF-8")
+}
+
+#[test]
+fn print_value_writes_to_output() {
+    let output = captured_output(
+        r#"
+        let name = "slang";
+        print_value(name);
+        print_value(1 + 2);
+    "#,
+    );
+
+    assert_eq!(output, "slang\n3\n");
+}
+
+#[test]
+fn print_writes_formatted_string_to_output() {
+    let output = captured_output(
+        r#"
+        let x: i32 = 4;
+        print("{} squared is {}", x, x * x);
+    "#,
+    );
+
+    assert_eq!(output, "4 squared is 16\n");
+}